@@ -259,10 +259,21 @@ impl Engine {
             #[cfg(not(feature = "no_closure"))]
             crate::func::ensure_no_data_race(name, args, false)?;
 
-            ast.shared_lib()
-                .get_script_fn(name, args.len())
+            let lib = ast.shared_lib();
+            let num_args = args.len();
+
+            lib.get_script_fn(name, num_args)
+                .or_else(|| {
+                    lib.find_script_fn(name, |f| f.accepts_num_args(num_args))
+                        .and_then(crate::func::RhaiFunc::get_script_fn_def)
+                })
                 .map_or_else(
-                    || Err(ERR::ErrorFunctionNotFound(name.into(), Position::NONE).into()),
+                    || {
+                        Err(
+                            ERR::ErrorFunctionNotFound(name.into(), Vec::new(), Position::NONE)
+                                .into(),
+                        )
+                    },
                     |fn_def| {
                         self.call_script_fn(
                             global,
@@ -350,6 +350,68 @@ impl Definitions<'_> {
         s
     }
 
+    /// Return definitions for the items of a registered static [module][Module] that are imported
+    /// into the unqualified namespace by an `import "name" for { ... }` statement.
+    ///
+    /// Each item is a pair of its name and its optional alias, e.g. `("sqrt", None)` for `sqrt`
+    /// and `("PI", Some("pi"))` for `PI as pi`.
+    ///
+    /// Returns [`None`] if there is no registered static [module][Module] with the specified name.
+    ///
+    /// Not available under `no_module`.
+    #[cfg(not(feature = "no_module"))]
+    #[must_use]
+    pub fn imported_items(
+        &self,
+        module_name: &str,
+        items: &[(&str, Option<&str>)],
+    ) -> Option<String> {
+        self.imported_items_impl(module_name, |name| {
+            items
+                .iter()
+                .find(|&&(n, ..)| n == name)
+                .map(|&(n, alias)| alias.unwrap_or(n).into())
+        })
+    }
+
+    /// Return definitions for all the items of a registered static [module][Module] that are
+    /// imported into the unqualified namespace by an `import "name" as *` statement.
+    ///
+    /// Returns [`None`] if there is no registered static [module][Module] with the specified name.
+    ///
+    /// Not available under `no_module`.
+    #[cfg(not(feature = "no_module"))]
+    #[inline(always)]
+    #[must_use]
+    pub fn all_imported_items(&self, module_name: &str) -> Option<String> {
+        self.imported_items_impl(module_name, |name| Some(name.into()))
+    }
+
+    /// Return definitions for the items of a registered static [module][Module] that are imported
+    /// under the names returned by `imported_name`.
+    #[cfg(not(feature = "no_module"))]
+    fn imported_items_impl(
+        &self,
+        module_name: &str,
+        imported_name: impl Fn(&str) -> Option<crate::ImmutableString>,
+    ) -> Option<String> {
+        let module = self
+            .engine
+            .global_sub_modules
+            .get(module_name)?
+            .extract_items(imported_name);
+
+        let mut s = if self.config.write_headers {
+            String::from("module static;\n\n")
+        } else {
+            String::new()
+        };
+
+        module.write_definition(&mut s, self).unwrap();
+
+        Some(s)
+    }
+
     /// Return a (module name, definitions) pair for each registered static [module][Module].
     ///
     /// Not available under `no_module`.
@@ -560,6 +622,15 @@ fn def_type_name<'a>(ty: &'a str, engine: &'a Engine) -> Cow<'a, str> {
     #[cfg(not(feature = "no_time"))]
     let ty = ty.replace(type_name::<crate::Instant>(), "Instant");
 
+    #[cfg(not(feature = "no_time"))]
+    let ty = ty.replace(type_name::<std::time::Duration>(), "Duration");
+
+    #[cfg(not(feature = "no_time"))]
+    let ty = ty.replace(type_name::<crate::DateTime>(), "DateTime");
+
+    #[cfg(feature = "regex")]
+    let ty = ty.replace(type_name::<crate::Regex>(), "Regex");
+
     let ty = ty.replace(type_name::<FnPtr>(), "FnPtr");
 
     ty.into()
@@ -453,7 +453,7 @@ impl Engine {
     ///     }
     ///     // Return the standard property-not-found error
     ///     _ => Err(EvalAltResult::ErrorPropertyNotFound(
-    ///                 prop.to_string(), Position::NONE
+    ///                 prop.to_string(), Vec::new(), Position::NONE
     ///          ).into()),
     /// });
     ///
@@ -2,8 +2,8 @@
 use crate::packages::iter_basic::{BitRange, CharsStream, StepRange};
 use crate::parser::{ParseResult, ParseState};
 use crate::{
-    Engine, ExclusiveRange, FnPtr, ImmutableString, InclusiveRange, Position, RhaiError,
-    SmartString, ERR,
+    Dynamic, Engine, ExclusiveRange, FnPtr, ImmutableString, InclusiveRange, Position, RhaiError,
+    RhaiResultOf, SmartString, ERR,
 };
 use std::any::type_name;
 #[cfg(feature = "no_std")]
@@ -39,6 +39,18 @@ pub fn map_std_type_name(name: &str, shorthands: bool) -> &str {
     if name == type_name::<rust_decimal::Decimal>() {
         return if shorthands { "decimal" } else { "Decimal" };
     }
+    #[cfg(feature = "bigint")]
+    if name == type_name::<num_bigint::BigInt>() {
+        return if shorthands { "bigint" } else { "BigInt" };
+    }
+    #[cfg(feature = "rational")]
+    if name == type_name::<crate::Rational>() {
+        return if shorthands { "rational" } else { "Rational" };
+    }
+    #[cfg(feature = "regex")]
+    if name == type_name::<crate::Regex>() {
+        return if shorthands { "regex" } else { "Regex" };
+    }
     if name == type_name::<FnPtr>() || name == "FnPtr" {
         return if shorthands { "Fn" } else { "FnPtr" };
     }
@@ -50,6 +62,34 @@ pub fn map_std_type_name(name: &str, shorthands: bool) -> &str {
     if name == type_name::<crate::Blob>() || name == "Blob" {
         return if shorthands { "blob" } else { "Blob" };
     }
+    #[cfg(not(feature = "no_index"))]
+    if name == type_name::<crate::Set>() || name == "Set" {
+        return if shorthands { "set" } else { "Set" };
+    }
+    #[cfg(not(feature = "no_index"))]
+    if name == type_name::<crate::Tuple>() || name == "Tuple" {
+        return if shorthands { "tuple" } else { "Tuple" };
+    }
+    #[cfg(not(feature = "no_index"))]
+    if name == type_name::<crate::LazyIterator>() || name == "Iterator" {
+        return if shorthands { "iterator" } else { "Iterator" };
+    }
+    #[cfg(not(feature = "no_float"))]
+    if name == type_name::<crate::Vec2>() || name == "Vec2" {
+        return if shorthands { "vec2" } else { "Vec2" };
+    }
+    #[cfg(not(feature = "no_float"))]
+    if name == type_name::<crate::Vec3>() || name == "Vec3" {
+        return if shorthands { "vec3" } else { "Vec3" };
+    }
+    #[cfg(not(feature = "no_float"))]
+    if name == type_name::<crate::Vec4>() || name == "Vec4" {
+        return if shorthands { "vec4" } else { "Vec4" };
+    }
+    #[cfg(not(feature = "no_float"))]
+    if name == type_name::<crate::Mat>() || name == "Mat" {
+        return if shorthands { "mat" } else { "Mat" };
+    }
     #[cfg(not(feature = "no_object"))]
     if name == type_name::<crate::Map>() || name == "Map" {
         return if shorthands { "map" } else { "Map" };
@@ -58,6 +98,14 @@ pub fn map_std_type_name(name: &str, shorthands: bool) -> &str {
     if name == type_name::<crate::Instant>() || name == "Instant" {
         return if shorthands { "timestamp" } else { "Instant" };
     }
+    #[cfg(not(feature = "no_time"))]
+    if name == type_name::<std::time::Duration>() || name == "Duration" {
+        return if shorthands { "duration" } else { "Duration" };
+    }
+    #[cfg(not(feature = "no_time"))]
+    if name == type_name::<crate::DateTime>() || name == "DateTime" {
+        return if shorthands { "datetime" } else { "DateTime" };
+    }
     if name == type_name::<ExclusiveRange>() || name == "ExclusiveRange" {
         return if shorthands {
             "range"
@@ -248,6 +296,49 @@ impl Engine {
             .into()
     }
 
+    /// Does a value of the type `value_type` satisfy the type annotation `typ`?
+    ///
+    /// An annotation matches the name returned by `type_of` for the value (which takes into
+    /// account names registered via [`register_type_with_name`][Engine::register_type_with_name]),
+    /// or the full Rust type name.
+    ///
+    /// `int` and `float` are aliases for the integer and floating-point types, and `Dynamic`
+    /// matches any value.
+    #[must_use]
+    pub(crate) fn is_type_annotation_match(&self, typ: &str, value_type: &str) -> bool {
+        match typ {
+            "Dynamic" => true,
+            "int" => value_type == type_name::<crate::INT>(),
+            #[cfg(not(feature = "no_float"))]
+            "float" => value_type == type_name::<crate::FLOAT>(),
+            _ => typ == value_type || typ == self.map_type_name(value_type),
+        }
+    }
+
+    /// Check a value against an optional type annotation.
+    ///
+    /// Returns `Err(`[`EvalAltResult::ErrorMismatchDataType`][ERR::ErrorMismatchDataType]`)` if
+    /// the value does not satisfy the annotation.
+    #[inline]
+    pub(crate) fn check_type_annotation(
+        &self,
+        typ: Option<&str>,
+        value: &Dynamic,
+        pos: Position,
+    ) -> RhaiResultOf<()> {
+        match typ {
+            Some(typ) if !self.is_type_annotation_match(typ, value.type_name()) => {
+                Err(ERR::ErrorMismatchDataType(
+                    typ.into(),
+                    self.map_type_name(value.type_name()).into(),
+                    pos,
+                )
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Compact a script to eliminate insignificant whitespaces and comments.
     ///
     /// This is useful to prepare a script for further compressing.
@@ -169,6 +169,24 @@ pub fn format_map_as_json(map: &Map) -> String {
     result
 }
 
+/// Return the JSON representation of a [set][crate::Set], which is an array.
+#[cfg(not(feature = "no_index"))]
+#[must_use]
+pub(crate) fn format_set_as_json(set: &crate::Set) -> String {
+    let mut result = String::from('[');
+
+    for (i, value) in set.iter().enumerate() {
+        if i > 0 {
+            result += ",";
+        }
+        format_dynamic_as_json(&mut result, &value);
+    }
+
+    result += "]";
+
+    result
+}
+
 /// Format a [`Dynamic`] value as JSON.
 fn format_dynamic_as_json(result: &mut String, value: &Dynamic) {
     match value.0 {
@@ -206,6 +224,53 @@ fn format_dynamic_as_json(result: &mut String, value: &Dynamic) {
             }
             *result += "]";
         }
+        #[cfg(not(feature = "no_index"))]
+        Union::Variant(ref v, _, _) if (***v).is::<crate::Set>() => {
+            *result += &format_set_as_json((***v).as_any().downcast_ref().unwrap());
+        }
+        #[cfg(not(feature = "no_index"))]
+        Union::Variant(ref v, _, _) if (***v).is::<crate::Tuple>() => {
+            let tuple = (***v).as_any().downcast_ref::<crate::Tuple>().unwrap();
+
+            *result += "[";
+            for (i, x) in tuple.iter().enumerate() {
+                if i > 0 {
+                    *result += ",";
+                }
+                format_dynamic_as_json(result, x);
+            }
+            *result += "]";
+        }
+        #[cfg(feature = "bigint")]
+        Union::Variant(ref v, _, _) if (***v).is::<num_bigint::BigInt>() => {
+            use num_traits::ToPrimitive;
+
+            let value = (***v)
+                .as_any()
+                .downcast_ref::<num_bigint::BigInt>()
+                .unwrap();
+
+            // Same as serialization: numbers too large for `i64` become strings
+            match value.to_i64() {
+                Some(n) => write!(result, "{n}").unwrap(),
+                None => write!(result, "{:?}", value.to_string()).unwrap(),
+            }
+        }
+        #[cfg(feature = "rational")]
+        Union::Variant(ref v, _, _) if (***v).is::<crate::Rational>() => {
+            let value = (***v).as_any().downcast_ref::<crate::Rational>().unwrap();
+            write!(result, "{:?}", value.to_string()).unwrap();
+        }
+        #[cfg(not(feature = "no_time"))]
+        Union::Variant(ref v, _, _) if (***v).is::<crate::DateTime>() => {
+            let value = (***v).as_any().downcast_ref::<crate::DateTime>().unwrap();
+            write!(result, "{:?}", value.to_string()).unwrap();
+        }
+        #[cfg(feature = "regex")]
+        Union::Variant(ref v, _, _) if (***v).is::<crate::Regex>() => {
+            let value = (***v).as_any().downcast_ref::<crate::Regex>().unwrap();
+            write!(result, "{:?}", value.as_str()).unwrap();
+        }
         #[cfg(not(feature = "no_closure"))]
         Union::Shared(ref v, _, _) => {
             let value = &*crate::func::locked_read(v).unwrap();
@@ -83,11 +83,27 @@ pub struct Limits {
     /// Not available under `no_index`.
     #[cfg(not(feature = "no_index"))]
     pub array_size: Option<NonZeroUsize>,
+    /// Maximum number of items in a [set][crate::Set].
+    ///
+    /// Not available under `no_index`.
+    #[cfg(not(feature = "no_index"))]
+    pub set_size: Option<NonZeroUsize>,
     /// Maximum number of properties in an [object map][crate::Map].
     ///
     /// Not available under `no_object`.
     #[cfg(not(feature = "no_object"))]
     pub map_size: Option<NonZeroUsize>,
+    /// Maximum compiled size (in bytes) of a [regular expression][crate::Regex].
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub regex_size: Option<NonZeroUsize>,
+    /// Maximum number of bits in a [`BigInt`][num_bigint::BigInt] produced by raising to a power
+    /// or shifting left.
+    ///
+    /// Requires the `bigint` feature.
+    #[cfg(feature = "bigint")]
+    pub bigint_bits: Option<NonZeroU64>,
 }
 
 impl Limits {
@@ -111,8 +127,14 @@ impl Limits {
             string_len: None,
             #[cfg(not(feature = "no_index"))]
             array_size: None,
+            #[cfg(not(feature = "no_index"))]
+            set_size: None,
             #[cfg(not(feature = "no_object"))]
             map_size: None,
+            #[cfg(feature = "regex")]
+            regex_size: None,
+            #[cfg(feature = "bigint")]
+            bigint_bits: None,
         }
     }
 }
@@ -321,6 +343,29 @@ impl Engine {
         #[cfg(feature = "no_index")]
         return 0;
     }
+    /// Set the maximum number of items in [sets][crate::Set] (0 for unlimited).
+    ///
+    /// Not available under `unchecked` or `no_index`.
+    #[cfg(not(feature = "no_index"))]
+    #[inline(always)]
+    pub fn set_max_set_size(&mut self, max_size: usize) -> &mut Self {
+        self.limits.set_size = NonZeroUsize::new(max_size);
+        self
+    }
+    /// The maximum number of items in [sets][crate::Set] (0 for unlimited).
+    ///
+    /// Not available under `unchecked` or `no_index`.
+    #[inline]
+    #[must_use]
+    pub const fn max_set_size(&self) -> usize {
+        #[cfg(not(feature = "no_index"))]
+        return match self.limits.set_size {
+            Some(n) => n.get(),
+            None => 0,
+        };
+        #[cfg(feature = "no_index")]
+        return 0;
+    }
     /// Set the maximum size of [object maps][crate::Map] (0 for unlimited).
     ///
     /// Not available under `unchecked` or `no_object`.
@@ -344,4 +389,53 @@ impl Engine {
         #[cfg(feature = "no_object")]
         return 0;
     }
+    /// Set the maximum compiled size (in bytes) of [regular expressions][crate::Regex]
+    /// (0 for the default limit of the [`regex`](https://crates.io/crates/regex) crate).
+    ///
+    /// The nesting depth of regular expressions is limited by the
+    /// [maximum expression depth][Engine::max_expr_depth].
+    ///
+    /// Not available under `unchecked`. Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    pub fn set_max_regex_size(&mut self, max_size: usize) -> &mut Self {
+        self.limits.regex_size = NonZeroUsize::new(max_size);
+        self
+    }
+    /// The maximum compiled size (in bytes) of [regular expressions][crate::Regex]
+    /// (0 for the default limit of the [`regex`](https://crates.io/crates/regex) crate).
+    ///
+    /// Not available under `unchecked`. Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    #[inline]
+    #[must_use]
+    pub const fn max_regex_size(&self) -> usize {
+        match self.limits.regex_size {
+            Some(n) => n.get(),
+            None => 0,
+        }
+    }
+    /// Set the maximum number of bits in a [`BigInt`][num_bigint::BigInt] produced by raising to
+    /// a power or shifting left (0 for the default limit of 1,048,576 bits).
+    ///
+    /// Not available under `unchecked`. Requires the `bigint` feature.
+    #[cfg(feature = "bigint")]
+    #[inline(always)]
+    pub fn set_max_bigint_bits(&mut self, max_bits: u64) -> &mut Self {
+        self.limits.bigint_bits = NonZeroU64::new(max_bits);
+        self
+    }
+    /// The maximum number of bits in a [`BigInt`][num_bigint::BigInt] produced by raising to a
+    /// power or shifting left (0 for the default limit of 1,048,576 bits).
+    ///
+    /// Not available under `unchecked`. Requires the `bigint` feature.
+    #[cfg(feature = "bigint")]
+    #[inline]
+    #[must_use]
+    pub const fn max_bigint_bits(&self) -> u64 {
+        match self.limits.bigint_bits {
+            Some(n) => n.get(),
+            None => 0,
+        }
+    }
 }
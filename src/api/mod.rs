@@ -26,6 +26,8 @@ pub mod formatting;
 
 pub mod custom_syntax;
 
+pub mod type_check;
+
 pub mod build_type;
 
 #[cfg(feature = "metadata")]
@@ -50,6 +52,11 @@ pub mod default_limits {
     pub const MAX_DYNAMIC_PARAMETERS: usize = 16;
     /// Maximum number of strings interned.
     pub const MAX_STRINGS_INTERNED: usize = 256;
+    /// Maximum number of compiled regular expressions cached.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub const MAX_REGEX_CACHED: usize = 64;
 }
 
 impl Engine {
@@ -75,6 +82,36 @@ impl Engine {
             locked_read(interner).map_or(0, |guard| guard.max())
         })
     }
+    /// Set the maximum number of compiled [regular expressions][crate::Regex] to be cached.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    pub fn set_max_regex_cached(&mut self, max: usize) -> &mut Self {
+        use crate::packages::regex_basic::RegexCache;
+
+        if max == 0 {
+            self.regex_cache = None;
+        } else if let Some(ref cache) = self.regex_cache {
+            if let Some(mut guard) = locked_write(cache) {
+                guard.set_max(max);
+            }
+        } else {
+            self.regex_cache = Some(RegexCache::new(max).into());
+        }
+        self
+    }
+    /// The maximum number of compiled [regular expressions][crate::Regex] to be cached.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_regex_cached(&self) -> usize {
+        self.regex_cache
+            .as_ref()
+            .map_or(0, |cache| locked_read(cache).map_or(0, |guard| guard.max()))
+    }
 
     /// The module resolution service used by the [`Engine`].
     ///
@@ -242,6 +279,62 @@ impl Engine {
         Ok(self)
     }
 
+    /// Register a suffix for numeric literals, mapping it to a constructor function.
+    ///
+    /// A numeric literal immediately followed by the suffix (e.g. `200ms`) is parsed as a call to
+    /// the constructor function with the number as its only argument (e.g. `milliseconds(200)`).
+    ///
+    /// If the constructor function is registered with the [`Engine`] when the script is compiled,
+    /// it is called at parse time and the result is kept as a constant.
+    /// Otherwise, the function is called during evaluation.
+    ///
+    /// The suffix must be a valid identifier starting with an alphabetic character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_fn("kilobytes", |x: i64| x * 1024);
+    ///
+    /// // Register the literal suffix 'KB'
+    /// engine.register_literal_suffix("KB", "kilobytes").expect("should succeed");
+    ///
+    /// assert_eq!(engine.eval_expression::<i64>("4KB + 1")?, 4097);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_literal_suffix(
+        &mut self,
+        suffix: impl AsRef<str>,
+        fn_name: impl AsRef<str>,
+    ) -> Result<&mut Self, String> {
+        use crate::tokenizer::{is_id_first_alphabetic, is_valid_function_name};
+
+        let suffix = suffix.as_ref();
+        let fn_name = fn_name.as_ref();
+
+        if !suffix.chars().next().map_or(false, is_id_first_alphabetic)
+            || !crate::tokenizer::is_valid_identifier(suffix)
+        {
+            return Err(format!("'{suffix}' is not a valid literal suffix"));
+        }
+        #[cfg(feature = "bigint")]
+        if suffix == "n" {
+            return Err("'n' is reserved for big integer literals".to_string());
+        }
+        if !is_valid_function_name(fn_name) {
+            return Err(format!("'{fn_name}' is not a valid function name"));
+        }
+
+        self.literal_suffixes.insert(suffix.into(), fn_name.into());
+
+        Ok(self)
+    }
+
     /// Get the default value of the custom state for each evaluation run.
     #[inline(always)]
     pub const fn default_tag(&self) -> &Dynamic {
@@ -65,6 +65,15 @@ impl Engine {
             optimization_level,
         );
 
+        // Keep inline modules
+        #[cfg(not(feature = "no_module"))]
+        #[cfg(not(feature = "no_function"))]
+        for (name, module) in ast.shared_lib().iter_sub_modules_raw() {
+            _new_ast
+                .lib_mut()
+                .set_sub_module(name.clone(), module.clone());
+        }
+
         #[cfg(feature = "metadata")]
         {
             _new_ast.doc = std::mem::take(&mut ast.doc);
@@ -32,6 +32,9 @@ bitflags! {
         const FAIL_ON_INVALID_MAP_PROPERTY = 0b_0001_0000_0000;
         /// Fast operators mode?
         const FAST_OPS = 0b_0010_0000_0000;
+        /// Are regular expression literals (e.g. `r/[a-z]+/`) allowed?
+        #[cfg(feature = "regex")]
+        const REGEX_LITERAL = 0b_0100_0000_0000;
     }
 }
 
@@ -207,4 +210,26 @@ impl Engine {
         self.options.set(LangOptions::FAST_OPS, enable);
         self
     }
+    /// Are regular expression literals (e.g. `r/[a-z]+/`) allowed?
+    /// Default is `false`.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn allow_regex_literal(&self) -> bool {
+        self.options.intersects(LangOptions::REGEX_LITERAL)
+    }
+    /// Set whether regular expression literals (e.g. `r/[a-z]+/`) are allowed.
+    ///
+    /// When enabled, `r/` (without whitespace in between) at the start of an expression always
+    /// begins a regular expression literal, so a variable named `r` must be divided via `r / x`.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    pub fn set_allow_regex_literal(&mut self, enable: bool) -> &mut Self {
+        self.options.set(LangOptions::REGEX_LITERAL, enable);
+        self
+    }
 }
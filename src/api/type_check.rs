@@ -0,0 +1,869 @@
+//! Module that implements static type checking of an [`AST`].
+
+use crate::ast::{ASTFlags, ASTNode, Expr, FnCallExpr, Stmt, StmtBlock};
+use crate::func::RhaiFunc;
+use crate::module::FuncMetadata;
+use crate::tokenizer::{is_reserved_keyword_or_symbol, is_valid_function_name, Token};
+use crate::{Dynamic, Engine, ImmutableString, Position, RhaiError, Scope, AST, ERR, INT};
+use std::any::TypeId;
+use std::collections::BTreeSet;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// A statically-inferred type.
+#[derive(Debug, Clone, Copy)]
+struct Type {
+    /// [`TypeId`] of the type.
+    id: TypeId,
+    /// Name of the type, as returned by [`Dynamic::type_name`].
+    name: &'static str,
+}
+
+impl Type {
+    /// Get the [`Type`] of a value.
+    #[inline]
+    #[must_use]
+    fn of(value: &Dynamic) -> Self {
+        Self {
+            id: value.type_id(),
+            name: value.type_name(),
+        }
+    }
+    /// Get the [`Type`] of the second loop variable of a `for` loop or comprehension, given the
+    /// [`Type`] of the value being iterated.
+    ///
+    /// It is the counter (an integer) unless an object map (or a value of unknown type) is
+    /// iterated, in which case it holds property values.
+    #[must_use]
+    fn of_loop_counter(iterable: Option<Self>) -> Option<Self> {
+        #[cfg(not(feature = "no_object"))]
+        let iterable = iterable.filter(|t| t.id != TypeId::of::<crate::Map>());
+
+        iterable.map(|_| Self::of(&Dynamic::from_int(0)))
+    }
+    /// Get the [`Type`] for a type name in a type annotation or function metadata.
+    ///
+    /// Only standard types are recognized.
+    #[allow(dead_code)]
+    #[must_use]
+    fn from_name(name: &str) -> Option<Self> {
+        // Extract `T` from `RhaiResultOf<T>` and `Result<T, Box<EvalAltResult>>`
+        let name = name.trim();
+        let name = name
+            .strip_prefix("RhaiResultOf<")
+            .and_then(|s| s.strip_suffix('>'))
+            .or_else(|| {
+                name.split_once("Result<")
+                    .and_then(|(_, s)| s.split_once(','))
+                    .map(|(s, _)| s)
+            })
+            .unwrap_or(name)
+            .trim();
+        let name = name.rsplit("::").next().unwrap_or(name);
+
+        let value = match name {
+            "()" => Dynamic::UNIT,
+            "bool" => Dynamic::FALSE,
+            "char" => Dynamic::from_char(' '),
+            "int" | "INT" => Dynamic::from_int(0),
+            #[cfg(not(feature = "no_float"))]
+            "float" | "FLOAT" => Dynamic::from_float(0.0),
+            "string" | "String" | "&str" | "ImmutableString" => {
+                Dynamic::from(ImmutableString::new())
+            }
+            #[cfg(not(feature = "no_index"))]
+            "array" | "Array" => Dynamic::from_array(crate::Array::new()),
+            #[cfg(not(feature = "no_index"))]
+            "blob" | "Blob" => Dynamic::from_blob(crate::Blob::new()),
+            #[cfg(not(feature = "no_index"))]
+            "set" | "Set" => Dynamic::from(crate::Set::new()),
+            #[cfg(not(feature = "no_index"))]
+            "tuple" | "Tuple" => Dynamic::from(crate::Tuple::default()),
+            #[cfg(not(feature = "no_index"))]
+            "iterator" | "Iterator" => Dynamic::from(crate::LazyIterator::default()),
+            #[cfg(not(feature = "no_float"))]
+            "vec2" | "Vec2" => Dynamic::from(crate::Vec2::default()),
+            #[cfg(not(feature = "no_float"))]
+            "vec3" | "Vec3" => Dynamic::from(crate::Vec3::default()),
+            #[cfg(not(feature = "no_float"))]
+            "vec4" | "Vec4" => Dynamic::from(crate::Vec4::default()),
+            #[cfg(not(feature = "no_float"))]
+            "mat" | "Mat" => Dynamic::from(crate::Mat::identity(1)),
+            #[cfg(not(feature = "no_object"))]
+            "map" | "Map" => Dynamic::from_map(crate::Map::new()),
+            #[cfg(feature = "bigint")]
+            "bigint" | "BigInt" => Dynamic::from(num_bigint::BigInt::default()),
+            #[cfg(feature = "rational")]
+            "rational" | "Rational" => Dynamic::from(crate::Rational::default()),
+            #[cfg(feature = "regex")]
+            "regex" | "Regex" => Dynamic::from(crate::Regex::new("").unwrap()),
+            #[cfg(not(feature = "no_time"))]
+            "duration" | "Duration" => Dynamic::from(std::time::Duration::ZERO),
+            #[cfg(not(feature = "no_time"))]
+            "datetime" | "DateTime" => {
+                Dynamic::from(crate::DateTime::from_system_time(std::time::UNIX_EPOCH))
+            }
+            _ if name == std::any::type_name::<INT>() => Dynamic::from_int(0),
+            #[cfg(not(feature = "no_float"))]
+            _ if name == std::any::type_name::<crate::FLOAT>() => Dynamic::from_float(0.0),
+            _ => return None,
+        };
+
+        Some(Self::of(&value))
+    }
+    /// Is this the [`Map`][crate::Map] type?
+    #[inline(always)]
+    #[must_use]
+    fn is_map(self) -> bool {
+        #[cfg(not(feature = "no_object"))]
+        return self.id == TypeId::of::<crate::Map>();
+        #[cfg(feature = "no_object")]
+        return false;
+    }
+}
+
+/// State of the type checker.
+struct TypeChecker<'a> {
+    /// The [`Engine`].
+    engine: &'a Engine,
+    /// Global runtime state containing the script-defined functions (used for suggestions).
+    global: crate::eval::GlobalRuntimeState,
+    /// Names of variables that are assigned to somewhere in the script.
+    /// Their types cannot be statically inferred.
+    assigned: BTreeSet<crate::Identifier>,
+    /// Variables in scope, with their inferred types (if any).
+    vars: Vec<(&'a str, Option<Type>)>,
+    /// Type annotation of the return value of the function being checked, if any.
+    return_type: Option<&'a str>,
+    /// Errors found.
+    errors: Vec<RhaiError>,
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Find the type of a variable.
+    #[must_use]
+    fn find_var(&self, name: &str) -> Option<Option<Type>> {
+        self.vars
+            .iter()
+            .rev()
+            .find(|&&(n, ..)| n == name)
+            .map(|&(.., t)| t)
+    }
+
+    /// Iterate all functions visible to an unqualified call.
+    fn functions(&self) -> impl Iterator<Item = (&RhaiFunc, &FuncMetadata)> {
+        let engine = self.engine;
+
+        #[cfg(not(feature = "no_function"))]
+        let lib = self.global.lib.iter().flat_map(|m| m.iter_fn());
+        #[cfg(feature = "no_function")]
+        let lib = std::iter::empty();
+
+        #[cfg(not(feature = "no_module"))]
+        let sub_modules = engine.global_sub_modules.values().flat_map(|m| {
+            m.iter_fn()
+                .filter(|(_, m)| m.namespace == crate::FnNamespace::Global)
+        });
+        #[cfg(feature = "no_module")]
+        let sub_modules = std::iter::empty();
+
+        lib.chain(engine.global_modules.iter().flat_map(|m| m.iter_fn()))
+            .chain(sub_modules)
+    }
+
+    /// Does an argument match a function parameter?
+    #[must_use]
+    fn is_arg_match(&self, func: &RhaiFunc, meta: &FuncMetadata, index: usize, arg: Type) -> bool {
+        #[cfg(not(feature = "no_function"))]
+        if let Some(fn_def) = func.get_script_fn_def() {
+            return match fn_def.param_types.get(index) {
+                Some(Some(typ)) => self.engine.is_type_annotation_match(typ, arg.name),
+                _ => true,
+            };
+        }
+
+        let _ = func;
+
+        match meta.param_types.get(index) {
+            Some(&id) => id == arg.id || id == TypeId::of::<Dynamic>(),
+            None => true,
+        }
+    }
+
+    /// Can a function be called with the specified number of arguments?
+    #[must_use]
+    fn accepts_num_args(func: &RhaiFunc, meta: &FuncMetadata, num_args: usize) -> bool {
+        #[cfg(not(feature = "no_function"))]
+        if let Some(fn_def) = func.get_script_fn_def() {
+            return fn_def.accepts_num_args(num_args);
+        }
+
+        let _ = func;
+
+        meta.num_params == num_args
+    }
+
+    /// Get the return type of a function, if known.
+    #[must_use]
+    fn return_type_of(func: &RhaiFunc, _meta: &FuncMetadata) -> Option<Type> {
+        #[cfg(not(feature = "no_function"))]
+        if let Some(fn_def) = func.get_script_fn_def() {
+            return fn_def.return_type.as_deref().and_then(Type::from_name);
+        }
+
+        let _ = func;
+
+        #[cfg(feature = "metadata")]
+        return Type::from_name(&_meta.return_type);
+        #[cfg(not(feature = "metadata"))]
+        return None;
+    }
+
+    /// Generate a function call signature in the same format as runtime errors.
+    #[must_use]
+    fn gen_signature(&self, name: &str, args: &[Option<Type>]) -> String {
+        let args = args
+            .iter()
+            .map(|t| match t {
+                Some(t) if t.id == TypeId::of::<ImmutableString>() => {
+                    "&str | ImmutableString | String"
+                }
+                Some(t) => self.engine.map_type_name(t.name),
+                None => "?",
+            })
+            .collect::<crate::FnArgsVec<_>>();
+
+        format!("{name} ({})", args.join(", "))
+    }
+
+    /// Resolve a function call against the available functions.
+    ///
+    /// If `target` is [`Some`], the call is a method call on a value of that type.
+    ///
+    /// Returns `Err` if there are functions with the same name but none match the arguments,
+    /// `Ok(None)` if the call cannot be checked, and `Ok(Some(type))` with the inferred return
+    /// type (if known) otherwise.
+    fn resolve_call(
+        &self,
+        name: &str,
+        target: Option<Type>,
+        args: &[Option<Type>],
+    ) -> Result<Option<Option<Type>>, ()> {
+        let mut found = false;
+        let mut return_type: Option<Option<Type>> = None;
+
+        for (func, meta) in self.functions().filter(|(_, m)| m.name == name) {
+            found = true;
+
+            // Script-defined functions bind the target to `this` instead of a parameter
+            let params = match target {
+                Some(..) if !func.is_script() => {
+                    if meta.num_params != args.len() + 1 {
+                        continue;
+                    }
+                    if !self.is_arg_match(func, meta, 0, target.unwrap()) {
+                        continue;
+                    }
+                    1
+                }
+                _ if !Self::accepts_num_args(func, meta, args.len()) => continue,
+                _ => 0,
+            };
+
+            if args.iter().enumerate().any(|(i, arg)| {
+                arg.map_or(false, |t| !self.is_arg_match(func, meta, params + i, t))
+            }) {
+                continue;
+            }
+
+            let typ = Self::return_type_of(func, meta);
+
+            return_type = match return_type {
+                None => Some(typ),
+                Some(Some(t)) if typ.map_or(false, |typ| typ.id == t.id) => Some(Some(t)),
+                Some(..) => Some(None),
+            };
+        }
+
+        match return_type {
+            Some(typ) => Ok(Some(typ)),
+            None if found => Err(()),
+            None => Ok(None),
+        }
+    }
+
+    /// Check a function call and infer its return type.
+    fn check_fn_call(
+        &mut self,
+        x: &'a FnCallExpr,
+        target: Option<Type>,
+        pos: Position,
+    ) -> Option<Type> {
+        let args = x
+            .args
+            .iter()
+            .map(|e| self.check_expr(e))
+            .collect::<crate::FnArgsVec<_>>();
+
+        // Operators
+        if let Some(ref op) = x.op_token {
+            return Self::infer_operator(op, &args);
+        }
+
+        // Qualified calls, calls with named or spread arguments, keywords and calls to function pointers
+        // held in variables
+        #[cfg(not(feature = "no_module"))]
+        if x.is_qualified() {
+            return None;
+        }
+        if !x.named_args.is_empty() || x.has_spread_args() {
+            return None;
+        }
+        if !is_valid_function_name(&x.name)
+            || is_reserved_keyword_or_symbol(&x.name).0
+            || (target.is_none() && self.find_var(&x.name).is_some())
+        {
+            return None;
+        }
+
+        match self.resolve_call(&x.name, target, &args) {
+            Ok(typ) => typ.flatten(),
+            Err(()) => {
+                let sig = match target {
+                    Some(t) => {
+                        let mut all_args = crate::FnArgsVec::with_capacity(args.len() + 1);
+                        all_args.push(Some(t));
+                        all_args.extend(args.iter().copied());
+                        self.gen_signature(&x.name, &all_args)
+                    }
+                    None => self.gen_signature(&x.name, &args),
+                };
+                let suggestions = self.engine.suggest_fn(&self.global, None, &x.name);
+                self.errors
+                    .push(ERR::ErrorFunctionNotFound(sig, suggestions, pos).into());
+                None
+            }
+        }
+    }
+
+    /// Infer the result type of an operator.
+    #[must_use]
+    fn infer_operator(op: &Token, args: &[Option<Type>]) -> Option<Type> {
+        let is_number = |t: Type| {
+            #[cfg(not(feature = "no_float"))]
+            if t.id == TypeId::of::<crate::FLOAT>() {
+                return true;
+            }
+            t.id == TypeId::of::<INT>()
+        };
+
+        match (op, args) {
+            (Token::Bang, [Some(t)]) if t.id == TypeId::of::<bool>() => Some(*t),
+            (Token::UnaryMinus | Token::UnaryPlus, [Some(t)]) if is_number(*t) => Some(*t),
+
+            (
+                Token::EqualsTo
+                | Token::NotEqualsTo
+                | Token::LessThan
+                | Token::LessThanEqualsTo
+                | Token::GreaterThan
+                | Token::GreaterThanEqualsTo,
+                [Some(..), Some(..)],
+            ) => Some(Type::of(&Dynamic::FALSE)),
+
+            (
+                Token::Plus
+                | Token::Minus
+                | Token::Multiply
+                | Token::Divide
+                | Token::Modulo
+                | Token::PowerOf,
+                [Some(t1), Some(t2)],
+            ) if t1.id == t2.id && is_number(*t1) => Some(*t1),
+
+            (Token::Plus, [Some(t1), Some(t2)])
+                if t1.id == TypeId::of::<ImmutableString>()
+                    && t2.id == TypeId::of::<ImmutableString>() =>
+            {
+                Some(*t1)
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Check a property access on a value of the `target` type.
+    ///
+    /// If `is_setter` is `true`, the property is being assigned to.
+    #[cfg(not(feature = "no_object"))]
+    fn check_property(
+        &mut self,
+        target: Option<Type>,
+        prop: &str,
+        is_setter: bool,
+        pos: Position,
+    ) -> Option<Type> {
+        let target = match target {
+            Some(t) if !t.is_map() => t,
+            _ => return None,
+        };
+
+        let string = Some(Type::of(&Dynamic::from(ImmutableString::new())));
+
+        let (accessor, indexer, args): (_, _, &[_]) = if is_setter {
+            let accessor = crate::engine::make_setter(prop);
+            (accessor, crate::engine::FN_IDX_SET, &[None, string, None])
+        } else {
+            let accessor = crate::engine::make_getter(prop);
+            (accessor, crate::engine::FN_IDX_GET, &[None, string])
+        };
+
+        let target_arg = Some(target);
+        let accessor_args = if is_setter {
+            &[target_arg, None][..]
+        } else {
+            &[target_arg][..]
+        };
+
+        if let Ok(Some(typ)) = self.resolve_call(&accessor, None, accessor_args) {
+            return typ;
+        }
+
+        let mut indexer_args = args.to_vec();
+        indexer_args[0] = target_arg;
+
+        if let Ok(Some(typ)) = self.resolve_call(indexer, None, &indexer_args) {
+            return typ;
+        }
+
+        let t0 = self.engine.map_type_name(target.name);
+
+        let message = if is_setter {
+            format!("No writable property '{prop}' - a setter is not registered for type '{t0}'")
+        } else {
+            format!("Unknown property '{prop}' - a getter is not registered for type '{t0}'")
+        };
+
+        self.errors.push(ERR::ErrorDotExpr(message, pos).into());
+        None
+    }
+
+    /// Check the right-hand-side of a dot/index chain on a value of the `target` type.
+    ///
+    /// If `is_setter` is `true`, the chain is being assigned to.
+    fn check_chain(
+        &mut self,
+        target: Option<Type>,
+        expr: &'a Expr,
+        is_setter: bool,
+    ) -> Option<Type> {
+        match expr {
+            #[cfg(not(feature = "no_object"))]
+            Expr::Property(x, pos) => self.check_property(target, &x.2, is_setter, *pos),
+            Expr::MethodCall(x, pos) => match target {
+                // Function pointers in object map properties can also be called as methods
+                Some(t) if !t.is_map() => self.check_fn_call(x, Some(t), *pos),
+                _ => {
+                    x.args.iter().for_each(|e| {
+                        self.check_expr(e);
+                    });
+                    None
+                }
+            },
+            Expr::Dot(x, ..) => {
+                let target = self.check_chain(target, &x.lhs, false);
+                self.check_chain(target, &x.rhs, is_setter)
+            }
+            Expr::Index(x, ..) => {
+                self.check_chain(target, &x.lhs, false);
+                self.check_chain(None, &x.rhs, false);
+                None
+            }
+            _ => self.check_expr(expr),
+        }
+    }
+
+    /// Check an expression and infer its type.
+    fn check_expr(&mut self, expr: &'a Expr) -> Option<Type> {
+        match expr {
+            Expr::DynamicConstant(v, ..) => Some(Type::of(v)),
+            Expr::BoolConstant(..) => Some(Type::of(&Dynamic::FALSE)),
+            Expr::IntegerConstant(..) => Some(Type::of(&Dynamic::from_int(0))),
+            #[cfg(not(feature = "no_float"))]
+            Expr::FloatConstant(..) => Some(Type::of(&Dynamic::from_float(0.0))),
+            Expr::CharConstant(..) => Some(Type::of(&Dynamic::from_char(' '))),
+            Expr::StringConstant(..) => Some(Type::of(&Dynamic::from(ImmutableString::new()))),
+            Expr::Unit(..) => Some(Type::of(&Dynamic::UNIT)),
+            Expr::InterpolatedString(x, ..) => {
+                x.iter().for_each(|e| {
+                    self.check_expr(e);
+                });
+                Some(Type::of(&Dynamic::from(ImmutableString::new())))
+            }
+            #[cfg(not(feature = "no_index"))]
+            Expr::Array(x, ..) => {
+                x.iter().for_each(|e| {
+                    self.check_expr(e);
+                });
+                Some(Type::of(&Dynamic::from_array(crate::Array::new())))
+            }
+            #[cfg(not(feature = "no_index"))]
+            Expr::Set(x, ..) => {
+                x.iter().for_each(|e| {
+                    self.check_expr(e);
+                });
+                Some(Type::of(&Dynamic::from(crate::Set::new())))
+            }
+            #[cfg(not(feature = "no_index"))]
+            Expr::Tuple(x, ..) => {
+                x.iter().for_each(|e| {
+                    self.check_expr(e);
+                });
+                Some(Type::of(&Dynamic::from(crate::Tuple::default())))
+            }
+            #[cfg(not(feature = "no_object"))]
+            Expr::Map(x, ..) => {
+                x.0.iter().for_each(|(_, e)| {
+                    self.check_expr(e);
+                });
+                Some(Type::of(&Dynamic::from_map(crate::Map::new())))
+            }
+            #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+            Expr::Comprehension(x, ..) => {
+                let iter_type = self.check_expr(&x.iterable);
+
+                let orig_vars_len = self.vars.len();
+                self.vars.push((x.var.as_str(), None));
+                if let Some(ref counter) = x.counter {
+                    self.vars
+                        .push((counter.as_str(), Type::of_loop_counter(iter_type)));
+                }
+                self.check_expr(&x.condition);
+                if let Some(ref key) = x.key {
+                    self.check_expr(key);
+                }
+                self.check_expr(&x.value);
+                self.vars.truncate(orig_vars_len);
+
+                match x.key {
+                    #[cfg(not(feature = "no_object"))]
+                    Some(..) => Some(Type::of(&Dynamic::from_map(crate::Map::new()))),
+                    #[cfg(not(feature = "no_index"))]
+                    None => Some(Type::of(&Dynamic::from_array(crate::Array::new()))),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+            Expr::Variable(..) => expr
+                .get_variable_name(true)
+                .and_then(|name| self.find_var(name))
+                .flatten(),
+            Expr::Stmt(x) => {
+                self.check_block(x);
+                None
+            }
+            Expr::FnCall(x, pos) => self.check_fn_call(x, None, *pos),
+            Expr::Dot(x, ..) => {
+                let target = self.check_expr(&x.lhs);
+                self.check_chain(target, &x.rhs, false)
+            }
+            Expr::Index(x, ..) => {
+                self.check_expr(&x.lhs);
+                self.check_chain(None, &x.rhs, false);
+                None
+            }
+            Expr::And(x, ..) | Expr::Or(x, ..) => {
+                self.check_expr(&x.lhs);
+                self.check_expr(&x.rhs);
+                Some(Type::of(&Dynamic::FALSE))
+            }
+            Expr::Coalesce(x, ..) => {
+                self.check_expr(&x.lhs);
+                self.check_expr(&x.rhs);
+                None
+            }
+            Expr::Spread(x, ..) => {
+                self.check_expr(x);
+                None
+            }
+            #[cfg(not(feature = "no_custom_syntax"))]
+            Expr::Custom(x, ..) => {
+                x.inputs.iter().for_each(|e| {
+                    self.check_expr(e);
+                });
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Check a statements block.
+    fn check_block(&mut self, block: &'a StmtBlock) {
+        let orig_vars_len = self.vars.len();
+        block.iter().for_each(|stmt| self.check_stmt(stmt));
+        self.vars.truncate(orig_vars_len);
+    }
+
+    /// Check a value against a type annotation.
+    fn check_annotation(&mut self, typ: &str, value: Option<Type>, pos: Position) {
+        if let Some(value) = value {
+            if !self.engine.is_type_annotation_match(typ, value.name) {
+                let actual = self.engine.map_type_name(value.name);
+                self.errors
+                    .push(ERR::ErrorMismatchDataType(typ.into(), actual.into(), pos).into());
+            }
+        }
+    }
+
+    /// Check a statement.
+    fn check_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::Var(x, options, ..) => {
+                let (var_name, expr, _, typ) = &**x;
+                let value = self.check_expr(expr);
+
+                if let Some(ref typ) = typ {
+                    self.check_annotation(typ, value, expr.position());
+                }
+
+                let is_const = options.intersects(ASTFlags::CONSTANT);
+                let value = if is_const || !self.assigned.contains(var_name.as_str()) {
+                    value
+                } else {
+                    None
+                };
+                self.vars.push((var_name.as_str(), value));
+            }
+            #[cfg(not(feature = "no_index"))]
+            Stmt::VarTuple(x, ..) => {
+                self.check_expr(&x.1);
+                x.0.iter()
+                    .for_each(|name| self.vars.push((name.as_str(), None)));
+            }
+            Stmt::Assignment(x) => {
+                self.check_expr(&x.1.rhs);
+
+                match x.1.lhs {
+                    Expr::Dot(ref lhs, ..) => {
+                        let target = self.check_expr(&lhs.lhs);
+                        self.check_chain(target, &lhs.rhs, true);
+                    }
+                    Expr::Index(ref lhs, ..) => {
+                        self.check_expr(&lhs.lhs);
+                        self.check_chain(None, &lhs.rhs, true);
+                    }
+                    _ => (),
+                }
+            }
+            Stmt::FnCall(x, pos) => {
+                self.check_fn_call(x, None, *pos);
+            }
+            Stmt::Expr(e) => {
+                self.check_expr(e);
+            }
+            Stmt::Block(x) => self.check_block(x),
+            Stmt::If(x, ..) | Stmt::While(x, ..) | Stmt::Do(x, ..) => {
+                self.check_expr(&x.expr);
+                self.check_block(&x.body);
+                self.check_block(&x.branch);
+            }
+            Stmt::Switch(x, ..) => {
+                self.check_expr(&x.0);
+                for b in &x.1.expressions {
+                    self.check_expr(&b.lhs);
+                    self.check_expr(&b.rhs);
+                }
+            }
+            Stmt::For(x, ..) => {
+                let (var, counter, body) = &**x;
+                let iter_type = self.check_expr(&body.expr);
+
+                let orig_vars_len = self.vars.len();
+                self.vars.push((var.as_str(), None));
+                if let Some(counter) = counter {
+                    self.vars
+                        .push((counter.as_str(), Type::of_loop_counter(iter_type)));
+                }
+                self.check_block(&body.body);
+                self.vars.truncate(orig_vars_len);
+            }
+            Stmt::TryCatch(x, ..) => {
+                self.check_block(&x.body);
+
+                for clause in &x.catch_clauses {
+                    let orig_vars_len = self.vars.len();
+                    if let Some(name) = clause.var.get_variable_name(true) {
+                        self.vars.push((name, None));
+                    }
+                    self.check_expr(&clause.condition);
+                    self.check_block(&clause.body);
+                    self.vars.truncate(orig_vars_len);
+                }
+
+                self.check_block(&x.finally);
+            }
+            Stmt::Return(Some(e), options, ..) => {
+                let value = self.check_expr(e);
+
+                if !options.intersects(ASTFlags::BREAK) {
+                    if let Some(typ) = self.return_type {
+                        self.check_annotation(typ, value, e.position());
+                    }
+                }
+            }
+            Stmt::BreakLoop(Some(x), ..) => {
+                self.check_expr(&x.0);
+            }
+            #[cfg(not(feature = "no_module"))]
+            Stmt::Import(x, ..) => {
+                self.check_expr(&x.0);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Engine {
+    /// Statically check an [`AST`] for type errors, without running it.
+    ///
+    /// Types of expressions are inferred from literals, type annotations and the parameter and
+    /// return types of registered functions (return types are only available under the
+    /// `metadata` feature).
+    ///
+    /// The following errors are reported:
+    ///
+    /// * [`ErrorFunctionNotFound`][ERR::ErrorFunctionNotFound]: a function call with the wrong
+    ///   number of arguments, or with arguments that do not match any overload of the function.
+    /// * [`ErrorDotExpr`][ERR::ErrorDotExpr]: access to a property that is not defined for the type.
+    /// * [`ErrorMismatchDataType`][ERR::ErrorMismatchDataType]: a value that does not match its
+    ///   type annotation.
+    ///
+    /// Only calls to functions that are known to the [`Engine`] or defined in the [`AST`] are
+    /// checked. Expressions whose types cannot be inferred (e.g. variables that are re-assigned)
+    /// are never reported, so a script that passes may still fail at runtime.
+    ///
+    /// Functions in modules imported via `import` statements are not considered.
+    ///
+    /// Returns all errors found, in the order encountered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, EvalAltResult};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_fn("add", |x: i64, y: i64| x + y);
+    ///
+    /// let ast = engine.compile(r#"let x = add(40, 2); add(x, "hello")"#)?;
+    ///
+    /// let errors = engine.type_check(&ast);
+    ///
+    /// assert_eq!(errors.len(), 1);
+    /// assert!(matches!(*errors[0], EvalAltResult::ErrorFunctionNotFound(..)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn type_check(&self, ast: &AST) -> Vec<RhaiError> {
+        self.type_check_with_scope(&Scope::new(), ast)
+    }
+    /// Statically check an [`AST`] for type errors, without running it, using the types of
+    /// constants in a [`Scope`].
+    ///
+    /// Only constants in the [`Scope`] are used because variables may change type before the
+    /// [`AST`] is run.
+    ///
+    /// See [`type_check`][Engine::type_check] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no_object"))]
+    /// # {
+    /// use rhai::{Engine, EvalAltResult, Scope};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.push_constant("LIMIT", 42_i64);
+    ///
+    /// let ast = engine.compile("LIMIT.foo").unwrap();
+    ///
+    /// let errors = engine.type_check_with_scope(&scope, &ast);
+    ///
+    /// assert_eq!(errors.len(), 1);
+    /// assert!(matches!(*errors[0], EvalAltResult::ErrorDotExpr(..)));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn type_check_with_scope(&self, scope: &Scope, ast: &AST) -> Vec<RhaiError> {
+        // Variables that are assigned to anywhere in the script have no fixed type
+        let mut assigned = BTreeSet::new();
+
+        ast._walk(&mut |path: &[ASTNode]| {
+            if let Some(ASTNode::Stmt(Stmt::Assignment(x))) = path.last() {
+                if let Some(name) = x.1.lhs.get_variable_name(true) {
+                    assigned.insert(name.into());
+                }
+            }
+            true
+        });
+
+        #[allow(unused_mut)]
+        let mut global = self.new_global_runtime_state();
+        #[cfg(not(feature = "no_function"))]
+        global.lib.push(ast.shared_lib().clone());
+
+        let mut vars: Vec<_> = scope
+            .iter_raw()
+            .filter(|&(_, is_constant, ..)| is_constant)
+            .map(|(name, _, value)| (name, Some(Type::of(value))))
+            .collect();
+        vars.reverse();
+
+        let mut checker = TypeChecker {
+            engine: self,
+            global,
+            assigned,
+            vars,
+            return_type: None,
+            errors: Vec::new(),
+        };
+
+        // Check script-defined functions (which do not see the global scope)
+        #[cfg(not(feature = "no_function"))]
+        for fn_def in ast.iter_fn_def() {
+            let vars = std::mem::take(&mut checker.vars);
+
+            checker.vars = fn_def
+                .params
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    let typ = match fn_def.param_types.get(i) {
+                        Some(Some(t)) if !checker.assigned.contains(p.as_str()) => {
+                            Type::from_name(t)
+                        }
+                        _ => None,
+                    };
+                    (p.as_str(), typ)
+                })
+                .collect();
+            checker.return_type = fn_def.return_type.as_deref();
+
+            checker.check_block(&fn_def.body);
+
+            checker.return_type = None;
+            checker.vars = vars;
+        }
+
+        ast.statements()
+            .iter()
+            .for_each(|stmt| checker.check_stmt(stmt));
+
+        checker.errors
+    }
+}
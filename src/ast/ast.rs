@@ -199,6 +199,15 @@ impl AST {
     const fn shared_lib(&self) -> &crate::SharedModule {
         &self.lib
     }
+    /// Get a mutable reference to the internal [`Module`][crate::Module] containing all
+    /// script-defined functions.
+    #[cfg(not(feature = "no_module"))]
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn lib_mut(&mut self) -> &mut crate::Module {
+        crate::func::shared_make_mut(&mut self.lib)
+    }
     /// _(internals)_ Get the embedded [module resolver][crate::ModuleResolver].
     /// Exported under the `internals` feature only.
     ///
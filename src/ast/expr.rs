@@ -30,6 +30,27 @@ pub struct BinaryExpr {
     pub rhs: Expr,
 }
 
+/// _(internals)_ An array or object map comprehension.
+/// Exported under the `internals` feature only.
+///
+/// `[` value `for` var `in` expr `if` condition `]` or
+/// `#{` key `:` value `for` var `in` expr `if` condition `}`
+#[derive(Debug, Clone, Hash)]
+pub struct Comprehension {
+    /// Loop variable.
+    pub var: Ident,
+    /// Counter variable, if any.
+    pub counter: Option<Ident>,
+    /// Expression to iterate.
+    pub iterable: Expr,
+    /// Filter condition, or [`Expr::Unit`] if none.
+    pub condition: Expr,
+    /// Expression for the property name of each entry (object map comprehension only).
+    pub key: Option<Expr>,
+    /// Expression for the value of each item.
+    pub value: Expr,
+}
+
 /// _(internals)_ A custom syntax expression.
 /// Exported under the `internals` feature only.
 ///
@@ -192,6 +213,10 @@ pub struct FnCallExpr {
     pub hashes: FnCallHashes,
     /// List of function call argument expressions.
     pub args: FnArgsVec<Expr>,
+    /// Names of named arguments (if any), which always follow the positional arguments.
+    ///
+    /// The last name corresponds to the last argument in [`args`][FnCallExpr::args].
+    pub named_args: ThinVec<Ident>,
     /// Does this function call capture the parent scope?
     pub capture_parent_scope: bool,
     /// Is this function call a native operator?
@@ -210,6 +235,9 @@ impl fmt::Debug for FnCallExpr {
         ff.field("hash", &self.hashes)
             .field("name", &self.name)
             .field("args", &self.args);
+        if !self.named_args.is_empty() {
+            ff.field("named_args", &self.named_args);
+        }
         if self.is_operator_call() {
             ff.field("op_token", &self.op_token);
         }
@@ -242,6 +270,12 @@ impl FnCallExpr {
     pub fn into_fn_call_expr(self, pos: Position) -> Expr {
         Expr::FnCall(self.into(), pos)
     }
+    /// Does this function call contain any [spread][Expr::Spread] argument?
+    #[inline]
+    #[must_use]
+    pub fn has_spread_args(&self) -> bool {
+        self.args.iter().any(|e| matches!(e, Expr::Spread(..)))
+    }
     /// Are all arguments constant?
     #[inline]
     #[must_use]
@@ -278,6 +312,10 @@ pub enum Expr {
     InterpolatedString(ThinVec<Expr>, Position),
     /// [ expr, ... ]
     Array(ThinVec<Expr>, Position),
+    /// #[ expr, ... ]
+    Set(ThinVec<Expr>, Position),
+    /// ( expr, ... )
+    Tuple(ThinVec<Expr>, Position),
     /// #{ name:expr, ... }
     Map(
         Box<(StaticVec<(Ident, Expr)>, BTreeMap<Identifier, Dynamic>)>,
@@ -334,6 +372,13 @@ pub enum Expr {
     Or(Box<BinaryExpr>, Position),
     /// lhs `??` rhs
     Coalesce(Box<BinaryExpr>, Position),
+    /// `...` expr
+    ///
+    /// Only valid as a function call argument, an [array][Expr::Array] item or a
+    /// [object map][Expr::Map] entry.
+    Spread(Box<Expr>, Position),
+    /// `[` expr `for` var `in` expr `]` or `#{` expr `:` expr `for` var `in` expr `}`
+    Comprehension(Box<Comprehension>, Position),
     /// Custom syntax
     #[cfg(not(feature = "no_custom_syntax"))]
     Custom(Box<CustomExpr>, Position),
@@ -371,6 +416,14 @@ impl fmt::Debug for Expr {
                 f.write_str("Array")?;
                 f.debug_list().entries(x.iter()).finish()
             }
+            Self::Set(x, ..) => {
+                f.write_str("Set")?;
+                f.debug_list().entries(x.iter()).finish()
+            }
+            Self::Tuple(x, ..) => {
+                f.write_str("Tuple")?;
+                f.debug_list().entries(x.iter()).finish()
+            }
             Self::Map(x, ..) => {
                 f.write_str("Map")?;
                 f.debug_map()
@@ -410,6 +463,8 @@ impl fmt::Debug for Expr {
                 f.debug_list().entries(x.iter()).finish()
             }
             Self::FnCall(x, ..) => fmt::Debug::fmt(x, f),
+            Self::Spread(x, ..) => f.debug_tuple("Spread").field(x).finish(),
+            Self::Comprehension(x, ..) => fmt::Debug::fmt(x, f),
             Self::Index(x, options, pos) => {
                 if !pos.is_none() {
                     display_pos = *pos;
@@ -485,6 +540,20 @@ impl Expr {
                 Dynamic::from_array(arr)
             }
 
+            #[cfg(not(feature = "no_index"))]
+            Self::Set(x, ..) if self.is_constant() => {
+                crate::Set::try_from_iter(x.iter().map(|v| v.get_literal_value().unwrap()))
+                    .unwrap()
+                    .into()
+            }
+
+            #[cfg(not(feature = "no_index"))]
+            Self::Tuple(x, ..) if self.is_constant() => x
+                .iter()
+                .map(|v| v.get_literal_value().unwrap())
+                .collect::<crate::Tuple>()
+                .into(),
+
             #[cfg(not(feature = "no_object"))]
             Self::Map(x, ..) if self.is_constant() => {
                 let mut map = x.1.clone();
@@ -590,6 +659,7 @@ impl Expr {
                     hashes: FnCallHashes::from_hash(calc_fn_hash(None, f.fn_name(), 1)),
                     args: once(Self::StringConstant(f.fn_name().into(), pos)).collect(),
                     capture_parent_scope: false,
+                    named_args: ThinVec::new(),
                     op_token: None,
                 }
                 .into(),
@@ -629,12 +699,16 @@ impl Expr {
             | Self::Unit(..)
             | Self::StringConstant(..)
             | Self::Array(..)
+            | Self::Set(..)
+            | Self::Tuple(..)
             | Self::Map(..)
             | Self::Variable(..)
             | Self::ThisPtr(..)
             | Self::And(..)
             | Self::Or(..)
             | Self::Coalesce(..)
+            | Self::Spread(..)
+            | Self::Comprehension(..)
             | Self::FnCall(..)
             | Self::MethodCall(..)
             | Self::InterpolatedString(..)
@@ -660,12 +734,16 @@ impl Expr {
             | Self::Unit(pos)
             | Self::StringConstant(.., pos)
             | Self::Array(.., pos)
+            | Self::Set(.., pos)
+            | Self::Tuple(.., pos)
             | Self::Map(.., pos)
             | Self::Variable(.., pos)
             | Self::ThisPtr(pos)
             | Self::And(.., pos)
             | Self::Or(.., pos)
             | Self::Coalesce(.., pos)
+            | Self::Spread(.., pos)
+            | Self::Comprehension(.., pos)
             | Self::FnCall(.., pos)
             | Self::MethodCall(.., pos)
             | Self::Index(.., pos)
@@ -719,10 +797,14 @@ impl Expr {
             | Self::Unit(pos)
             | Self::StringConstant(.., pos)
             | Self::Array(.., pos)
+            | Self::Set(.., pos)
+            | Self::Tuple(.., pos)
             | Self::Map(.., pos)
             | Self::And(.., pos)
             | Self::Or(.., pos)
             | Self::Coalesce(.., pos)
+            | Self::Spread(.., pos)
+            | Self::Comprehension(.., pos)
             | Self::Dot(.., pos)
             | Self::Index(.., pos)
             | Self::Variable(.., pos)
@@ -747,7 +829,10 @@ impl Expr {
     #[must_use]
     pub fn is_pure(&self) -> bool {
         match self {
-            Self::InterpolatedString(x, ..) | Self::Array(x, ..) => x.iter().all(Self::is_pure),
+            Self::InterpolatedString(x, ..)
+            | Self::Array(x, ..)
+            | Self::Set(x, ..)
+            | Self::Tuple(x, ..) => x.iter().all(Self::is_pure),
 
             Self::Map(x, ..) => x.0.iter().map(|(.., v)| v).all(Self::is_pure),
 
@@ -757,6 +842,13 @@ impl Expr {
 
             Self::Stmt(x) => x.iter().all(Stmt::is_pure),
 
+            Self::Comprehension(x, ..) => {
+                x.iterable.is_pure()
+                    && x.condition.is_pure()
+                    && x.key.as_ref().map_or(true, Self::is_pure)
+                    && x.value.is_pure()
+            }
+
             Self::Variable(..) => true,
 
             _ => self.is_constant(),
@@ -783,7 +875,23 @@ impl Expr {
             | Self::StringConstant(..)
             | Self::Unit(..) => true,
 
-            Self::InterpolatedString(x, ..) | Self::Array(x, ..) => x.iter().all(Self::is_constant),
+            Self::InterpolatedString(x, ..) | Self::Array(x, ..) | Self::Tuple(x, ..) => {
+                x.iter().all(Self::is_constant)
+            }
+
+            // Only items that can be kept inside a set
+            Self::Set(x, ..) => x.iter().all(|e| match e {
+                #[cfg(not(feature = "no_float"))]
+                Self::FloatConstant(..) => true,
+
+                Self::BoolConstant(..)
+                | Self::IntegerConstant(..)
+                | Self::CharConstant(..)
+                | Self::StringConstant(..)
+                | Self::Unit(..) => true,
+
+                _ => false,
+            }),
 
             Self::Map(x, ..) => x.0.iter().map(|(.., expr)| expr).all(Self::is_constant),
 
@@ -812,6 +920,7 @@ impl Expr {
             | Self::And(..)
             | Self::Or(..)
             | Self::Coalesce(..)
+            | Self::Spread(..)
             | Self::Unit(..) => false,
 
             Self::IntegerConstant(..)
@@ -824,7 +933,10 @@ impl Expr {
             | Self::Dot(..)
             | Self::Index(..)
             | Self::Array(..)
-            | Self::Map(..) => false,
+            | Self::Set(..)
+            | Self::Tuple(..)
+            | Self::Map(..)
+            | Self::Comprehension(..) => false,
 
             #[cfg(not(feature = "no_custom_syntax"))]
             Self::Custom(..) => false,
@@ -865,7 +977,10 @@ impl Expr {
                     }
                 }
             }
-            Self::InterpolatedString(x, ..) | Self::Array(x, ..) => {
+            Self::InterpolatedString(x, ..)
+            | Self::Array(x, ..)
+            | Self::Set(x, ..)
+            | Self::Tuple(x, ..) => {
                 for e in &**x {
                     if !e.walk(path, on_node) {
                         return false;
@@ -898,6 +1013,20 @@ impl Expr {
                     }
                 }
             }
+            Self::Spread(x, ..) if !x.walk(path, on_node) => return false,
+            Self::Comprehension(x, ..) => {
+                if !x.iterable.walk(path, on_node) || !x.condition.walk(path, on_node) {
+                    return false;
+                }
+                if let Some(ref key) = x.key {
+                    if !key.walk(path, on_node) {
+                        return false;
+                    }
+                }
+                if !x.value.walk(path, on_node) {
+                    return false;
+                }
+            }
             #[cfg(not(feature = "no_custom_syntax"))]
             Self::Custom(x, ..) => {
                 for e in &*x.inputs {
@@ -10,6 +10,8 @@ pub mod script_fn;
 pub mod stmt;
 
 pub use ast::{ASTNode, EncapsulatedEnviron, AST};
+#[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+pub use expr::Comprehension;
 #[cfg(not(feature = "no_custom_syntax"))]
 pub use expr::CustomExpr;
 pub use expr::{BinaryExpr, Expr, FnCallExpr, FnCallHashes};
@@ -20,10 +22,13 @@ pub use namespace::Namespace;
 #[cfg(not(feature = "no_function"))]
 pub use script_fn::{ScriptFnMetadata, ScriptFuncDef};
 pub use stmt::{
-    CaseBlocksList, FlowControl, OpAssignment, RangeCase, Stmt, StmtBlock, StmtBlockContainer,
-    SwitchCasesCollection,
+    CaseBlocksList, CatchClause, FlowControl, OpAssignment, RangeCase, Stmt, StmtBlock,
+    StmtBlockContainer, SwitchCasesCollection, TryCatchBlock,
 };
 
+#[cfg(not(feature = "no_module"))]
+pub use stmt::ImportItems;
+
 /// _(internals)_ Empty placeholder for a script-defined function.
 /// Exported under the `internals` feature only.
 #[cfg(feature = "no_function")]
@@ -2,7 +2,7 @@
 #![cfg(not(feature = "no_function"))]
 
 use super::{FnAccess, StmtBlock};
-use crate::{FnArgsVec, ImmutableString};
+use crate::{Dynamic, FnArgsVec, ImmutableString};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{fmt, hash::Hash};
@@ -23,6 +23,19 @@ pub struct ScriptFuncDef {
     pub this_type: Option<ImmutableString>,
     /// Names of function parameters.
     pub params: FnArgsVec<ImmutableString>,
+    /// Type annotations of function parameters, if any.
+    ///
+    /// Empty if none of the parameters is annotated. Otherwise, this has the same length as
+    /// [`params`][ScriptFuncDef::params].
+    pub param_types: FnArgsVec<Option<ImmutableString>>,
+    /// Default values of trailing function parameters, if any.
+    ///
+    /// The last value corresponds to the last parameter in [`params`][ScriptFuncDef::params].
+    pub param_defaults: FnArgsVec<Dynamic>,
+    /// Does the last parameter collect all extra arguments into an array?
+    pub is_variadic: bool,
+    /// Type annotation of the return value, if any.
+    pub return_type: Option<ImmutableString>,
     /// _(metadata)_ Function doc-comments (if any). Exported under the `metadata` feature only.
     ///
     /// Doc-comments are comment lines beginning with `///` or comment blocks beginning with `/**`,
@@ -53,12 +66,47 @@ impl ScriptFuncDef {
             #[cfg(not(feature = "no_object"))]
             this_type: self.this_type.clone(),
             params: self.params.clone(),
+            param_types: self.param_types.clone(),
+            param_defaults: self.param_defaults.clone(),
+            is_variadic: self.is_variadic,
+            return_type: self.return_type.clone(),
             #[cfg(feature = "metadata")]
             comments: <_>::default(),
         }
     }
 }
 
+impl ScriptFuncDef {
+    /// Number of parameters, not counting the variadic parameter (if any).
+    #[inline(always)]
+    #[must_use]
+    pub fn num_fixed_params(&self) -> usize {
+        self.params.len() - usize::from(self.is_variadic)
+    }
+    /// Number of parameters that must be provided when calling this function, i.e. those without
+    /// default values.
+    #[inline(always)]
+    #[must_use]
+    pub fn num_required_params(&self) -> usize {
+        self.num_fixed_params() - self.param_defaults.len()
+    }
+    /// Can this function be called with the specified number of arguments?
+    #[inline(always)]
+    #[must_use]
+    pub fn accepts_num_args(&self, num_args: usize) -> bool {
+        num_args >= self.num_required_params()
+            && (self.is_variadic || num_args <= self.params.len())
+    }
+    /// Get the default value of the parameter at a particular position, if any.
+    #[inline]
+    #[must_use]
+    pub fn default_value(&self, index: usize) -> Option<&Dynamic> {
+        index
+            .checked_sub(self.num_required_params())
+            .and_then(|i| self.param_defaults.get(i))
+    }
+}
+
 impl fmt::Display for ScriptFuncDef {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         #[cfg(not(feature = "no_object"))]
@@ -81,10 +129,31 @@ impl fmt::Display for ScriptFuncDef {
             self.name,
             self.params
                 .iter()
-                .map(ImmutableString::as_str)
+                .enumerate()
+                .map(|(i, p)| {
+                    let p = if self.is_variadic && i == self.num_fixed_params() {
+                        format!("...{p}")
+                    } else {
+                        p.to_string()
+                    };
+                    let mut param = match self.param_types.get(i) {
+                        Some(Some(t)) => format!("{p}: {t}"),
+                        _ => p,
+                    };
+                    if let Some(value) = self.default_value(i) {
+                        param.push_str(&format!(" = {value:?}"));
+                    }
+                    param
+                })
                 .collect::<FnArgsVec<_>>()
                 .join(", ")
-        )
+        )?;
+
+        if let Some(ref t) = self.return_type {
+            write!(f, " -> {t}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -119,6 +188,20 @@ pub struct ScriptFnMetadata<'a> {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub this_type: Option<&'a str>,
+    /// Type annotations of function parameters (if any).
+    ///
+    /// Empty if none of the parameters is annotated.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub param_types: Vec<Option<&'a str>>,
+    /// Type annotation of the return value, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub return_type: Option<&'a str>,
     /// _(metadata)_ Function doc-comments (if any).
     /// Exported under the `metadata` feature only.
     ///
@@ -160,10 +243,20 @@ impl fmt::Display for ScriptFnMetadata<'_> {
             self.name,
             self.params
                 .iter()
-                .copied()
+                .enumerate()
+                .map(|(i, &p)| match self.param_types.get(i) {
+                    Some(Some(t)) => format!("{p}: {t}"),
+                    _ => p.to_string(),
+                })
                 .collect::<FnArgsVec<_>>()
                 .join(", ")
-        )
+        )?;
+
+        if let Some(t) = self.return_type {
+            write!(f, " -> {t}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -176,6 +269,12 @@ impl<'a> From<&'a ScriptFuncDef> for ScriptFnMetadata<'a> {
             access: value.access,
             #[cfg(not(feature = "no_object"))]
             this_type: value.this_type.as_deref(),
+            param_types: value
+                .param_types
+                .iter()
+                .map(|t| t.as_ref().map(ImmutableString::as_str))
+                .collect(),
+            return_type: value.return_type.as_deref(),
             #[cfg(feature = "metadata")]
             comments: value.comments.iter().map(<_>::as_ref).collect(),
         }
@@ -6,7 +6,7 @@ use crate::func::StraightHashMap;
 use crate::tokenizer::Token;
 use crate::types::dynamic::Union;
 use crate::types::Span;
-use crate::{calc_fn_hash, Dynamic, FnArgsVec, Position, StaticVec, INT};
+use crate::{calc_fn_hash, Dynamic, FnArgsVec, ImmutableString, Position, StaticVec, INT};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
@@ -580,6 +580,78 @@ pub struct FlowControl {
     pub body: StmtBlock,
     /// Branch body.
     pub branch: StmtBlock,
+    /// Label of the loop, if any.
+    ///
+    /// Always [`None`] for non-loop statements.
+    pub label: Option<ImmutableString>,
+}
+
+/// _(internals)_ A `catch` clause of a `try` statement.
+/// Exported under the `internals` feature only.
+#[derive(Debug, Clone, Hash)]
+pub struct CatchClause {
+    /// Variable holding the error value, or [`Expr::Unit`] if none.
+    pub var: Expr,
+    /// Type of the error to catch, if any.
+    ///
+    /// This matches either the kind of the error (e.g. `ErrorArithmetic`) or, for a thrown value,
+    /// the type of that value.
+    pub error_type: Option<ImmutableString>,
+    /// Condition for catching the error, or [`Expr::Unit`] if none.
+    pub condition: Expr,
+    /// Statements body.
+    pub body: StmtBlock,
+}
+
+/// _(internals)_ A `try` statement containing:
+/// * a statements body,
+/// * a list of `catch` clauses,
+/// * a `finally` statements body
+///
+/// Exported under the `internals` feature only.
+#[derive(Debug, Clone, Hash)]
+pub struct TryCatchBlock {
+    /// Statements body.
+    pub body: StmtBlock,
+    /// `catch` clauses, tried in order.
+    pub catch_clauses: StaticVec<CatchClause>,
+    /// `finally` statements body, which is empty if none.
+    pub finally: StmtBlock,
+}
+
+/// _(internals)_ Items of a [module][crate::Module] imported into the unqualified namespace by
+/// an `import` statement.
+/// Exported under the `internals` feature only.
+///
+/// Not available under `no_module`.
+#[cfg(not(feature = "no_module"))]
+#[derive(Debug, Clone, Hash, Default)]
+pub enum ImportItems {
+    /// No items are imported.
+    #[default]
+    None,
+    /// `for {` name `as` alias`,` ... `}`
+    ///
+    /// Each item holds its name and its optional alias.
+    Some(crate::ThinVec<(Ident, Option<Ident>)>),
+    /// `as *` - all items are imported.
+    All,
+}
+
+#[cfg(not(feature = "no_module"))]
+impl ImportItems {
+    /// Get the name that an item is imported as, or [`None`] if it is not imported.
+    #[must_use]
+    pub fn imported_name(&self, name: &str) -> Option<ImmutableString> {
+        match self {
+            Self::None => None,
+            Self::Some(list) => list
+                .iter()
+                .find(|(n, ..)| n.as_str() == name)
+                .map(|(n, alias)| alias.as_ref().unwrap_or(n).name.clone()),
+            Self::All => Some(name.into()),
+        }
+    }
 }
 
 /// _(internals)_ A statement.
@@ -613,13 +685,27 @@ pub enum Stmt {
     Do(Box<FlowControl>, ASTFlags, Position),
     /// `for` `(` id `,` counter `)` `in` expr `{` stmt `}`
     For(Box<(Ident, Option<Ident>, FlowControl)>, Position),
-    /// \[`export`\] `let`|`const` id `=` expr
+    /// \[`export`\] `let`|`const` id \[`:` type\] `=` expr
     ///
     /// ### Flags
     ///
     /// * [`EXPORTED`][ASTFlags::EXPORTED] = `export`  
     /// * [`CONSTANT`][ASTFlags::CONSTANT] = `const`
-    Var(Box<(Ident, Expr, Option<NonZeroUsize>)>, ASTFlags, Position),
+    Var(
+        Box<(Ident, Expr, Option<NonZeroUsize>, Option<ImmutableString>)>,
+        ASTFlags,
+        Position,
+    ),
+    /// \[`export`\] `let`|`const` `(` id `,` ... `)` `=` expr
+    ///
+    /// ### Flags
+    ///
+    /// * [`EXPORTED`][ASTFlags::EXPORTED] = `export`  
+    /// * [`CONSTANT`][ASTFlags::CONSTANT] = `const`
+    ///
+    /// Not available under `no_index`.
+    #[cfg(not(feature = "no_index"))]
+    VarTuple(Box<(crate::ThinVec<Ident>, Expr)>, ASTFlags, Position),
     /// expr op`=` expr
     Assignment(Box<(OpAssignment, BinaryExpr)>),
     /// func `(` expr `,` ... `)`
@@ -629,17 +715,23 @@ pub enum Stmt {
     FnCall(Box<FnCallExpr>, Position),
     /// `{` stmt`;` ... `}`
     Block(Box<StmtBlock>),
-    /// `try` `{` stmt; ... `}` `catch` `(` var `)` `{` stmt; ... `}`
-    TryCatch(Box<FlowControl>, Position),
+    /// `try` `{` stmt; ... `}` `catch` `(` var `:` type `if` expr `)` `{` stmt; ... `}` ... `finally` `{` stmt; ... `}`
+    TryCatch(Box<TryCatchBlock>, Position),
     /// [expression][Expr]
     Expr(Box<Expr>),
-    /// `continue`/`break` expr
+    /// `continue`/`break` `'`label expr
+    ///
+    /// The expression is [`Expr::Unit`] if there is only a label.
     ///
     /// ### Flags
     ///
     /// * [`NONE`][ASTFlags::NONE] = `continue`
     /// * [`BREAK`][ASTFlags::BREAK] = `break`
-    BreakLoop(Option<Box<Expr>>, ASTFlags, Position),
+    BreakLoop(
+        Option<Box<(Expr, Option<ImmutableString>)>>,
+        ASTFlags,
+        Position,
+    ),
     /// `return`/`throw` expr
     ///
     /// ### Flags
@@ -649,9 +741,13 @@ pub enum Stmt {
     Return(Option<Box<Expr>>, ASTFlags, Position),
     /// `import` expr `as` alias
     ///
+    /// `import` expr `for {` items `}`
+    ///
+    /// `import` expr `as *`
+    ///
     /// Not available under `no_module`.
     #[cfg(not(feature = "no_module"))]
-    Import(Box<(Expr, Ident)>, Position),
+    Import(Box<(Expr, Ident, ImportItems)>, Position),
     /// `export` var `as` alias
     ///
     /// Not available under `no_module`.
@@ -708,6 +804,9 @@ impl Stmt {
             #[cfg(not(feature = "no_module"))]
             Self::Import(..) | Self::Export(..) => ASTFlags::empty(),
 
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(_, options, _) => *options,
+
             #[cfg(not(feature = "no_closure"))]
             Self::Share(..) => ASTFlags::empty(),
         }
@@ -734,6 +833,8 @@ impl Stmt {
 
             Self::Expr(x) => x.start_position(),
 
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(.., pos) => *pos,
             #[cfg(not(feature = "no_module"))]
             Self::Import(.., pos) => *pos,
             #[cfg(not(feature = "no_module"))]
@@ -766,6 +867,8 @@ impl Stmt {
                 x.set_position(new_pos);
             }
 
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(.., pos) => *pos = new_pos,
             #[cfg(not(feature = "no_module"))]
             Self::Import(.., pos) => *pos = new_pos,
             #[cfg(not(feature = "no_module"))]
@@ -798,6 +901,9 @@ impl Stmt {
             #[cfg(not(feature = "no_module"))]
             Self::Import(..) | Self::Export(..) => false,
 
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(..) => false,
+
             #[cfg(not(feature = "no_closure"))]
             Self::Share(..) => false,
         }
@@ -832,6 +938,9 @@ impl Stmt {
             #[cfg(not(feature = "no_module"))]
             Self::Import(..) | Self::Export(..) => false,
 
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(..) => false,
+
             #[cfg(not(feature = "no_closure"))]
             Self::Share(..) => false,
         }
@@ -884,9 +993,11 @@ impl Stmt {
             Self::Block(block, ..) => block.iter().all(Self::is_pure),
             Self::BreakLoop(..) | Self::Return(..) => false,
             Self::TryCatch(x, ..) => {
-                x.expr.is_pure()
-                    && x.body.iter().all(Self::is_pure)
-                    && x.branch.iter().all(Self::is_pure)
+                x.body.iter().all(Self::is_pure)
+                    && x.catch_clauses
+                        .iter()
+                        .all(|c| c.condition.is_pure() && c.body.iter().all(Self::is_pure))
+                    && x.finally.iter().all(Self::is_pure)
             }
 
             #[cfg(not(feature = "no_module"))]
@@ -894,6 +1005,9 @@ impl Stmt {
             #[cfg(not(feature = "no_module"))]
             Self::Export(..) => false,
 
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(..) => false,
+
             #[cfg(not(feature = "no_closure"))]
             Self::Share(..) => false,
         }
@@ -910,6 +1024,8 @@ impl Stmt {
     pub fn is_block_dependent(&self) -> bool {
         match self {
             Self::Var(..) => true,
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(..) => true,
 
             Self::Expr(e) => match &**e {
                 Expr::Stmt(s) => s.iter().all(Self::is_block_dependent),
@@ -941,6 +1057,8 @@ impl Stmt {
     pub fn is_internally_pure(&self) -> bool {
         match self {
             Self::Var(x, ..) => x.1.is_pure(),
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(x, ..) => x.1.is_pure(),
 
             Self::Expr(e) => match &**e {
                 Expr::Stmt(s) => s.iter().all(Self::is_internally_pure),
@@ -991,6 +1109,12 @@ impl Stmt {
                     return false;
                 }
             }
+            #[cfg(not(feature = "no_index"))]
+            Self::VarTuple(x, ..) => {
+                if !x.1.walk(path, on_node) {
+                    return false;
+                }
+            }
             Self::If(x, ..) => {
                 if !x.expr.walk(path, on_node) {
                     return false;
@@ -1088,7 +1212,17 @@ impl Stmt {
                         return false;
                     }
                 }
-                for s in &x.branch {
+                for c in &x.catch_clauses {
+                    if !c.condition.walk(path, on_node) {
+                        return false;
+                    }
+                    for s in &c.body {
+                        if !s.walk(path, on_node) {
+                            return false;
+                        }
+                    }
+                }
+                for s in &x.finally {
                     if !s.walk(path, on_node) {
                         return false;
                     }
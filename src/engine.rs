@@ -62,6 +62,10 @@ pub const OP_NOT: &str = Token::Bang.literal_syntax();
 #[cfg(not(feature = "no_module"))]
 pub const NAMESPACE_SEPARATOR: &str = Token::DoubleColon.literal_syntax();
 
+/// Name of an import holding items imported into the unqualified namespace.
+#[cfg(not(feature = "no_module"))]
+pub const IMPORT_ITEMS: &str = Token::Multiply.literal_syntax();
+
 /// Rhai main scripting engine.
 ///
 /// # Thread Safety
@@ -98,9 +102,14 @@ pub struct Engine {
 
     /// Strings interner.
     pub(crate) interned_strings: Option<Locked<StringsInterner>>,
+    /// Cache of compiled regular expressions.
+    #[cfg(feature = "regex")]
+    pub(crate) regex_cache: Option<Locked<crate::packages::regex_basic::RegexCache>>,
 
     /// A set of symbols to disable.
     pub(crate) disabled_symbols: BTreeSet<Identifier>,
+    /// A map containing literal suffixes and the names of their constructor functions.
+    pub(crate) literal_suffixes: std::collections::BTreeMap<Identifier, Identifier>,
     /// A map containing custom keywords and precedence to recognize.
     #[cfg(not(feature = "no_custom_syntax"))]
     pub(crate) custom_keywords: std::collections::BTreeMap<Identifier, Option<Precedence>>,
@@ -166,7 +175,8 @@ impl fmt::Debug for Engine {
         #[cfg(not(feature = "no_module"))]
         f.field("global_sub_modules", &self.global_sub_modules);
 
-        f.field("disabled_symbols", &self.disabled_symbols);
+        f.field("disabled_symbols", &self.disabled_symbols)
+            .field("literal_suffixes", &self.literal_suffixes);
 
         #[cfg(not(feature = "no_custom_syntax"))]
         f.field("custom_keywords", &self.custom_keywords).field(
@@ -243,7 +253,10 @@ impl Engine {
         module_resolver: None,
 
         interned_strings: None,
+        #[cfg(feature = "regex")]
+        regex_cache: None,
         disabled_symbols: BTreeSet::new(),
+        literal_suffixes: std::collections::BTreeMap::new(),
         #[cfg(not(feature = "no_custom_syntax"))]
         custom_keywords: std::collections::BTreeMap::new(),
         #[cfg(not(feature = "no_custom_syntax"))]
@@ -298,6 +311,10 @@ impl Engine {
         // Turn on the strings interner
         engine.set_max_strings_interned(MAX_STRINGS_INTERNED);
 
+        // Turn on the regular expressions cache
+        #[cfg(feature = "regex")]
+        engine.set_max_regex_cached(crate::api::default_limits::MAX_REGEX_CACHED);
+
         // default print/debug implementations
         #[cfg(not(feature = "no_std"))]
         #[cfg(any(not(target_family = "wasm"), not(target_os = "unknown")))]
@@ -191,10 +191,16 @@ impl Engine {
                     map.insert(index.clone().into(), Dynamic::UNIT);
                 }
 
+                if self.fail_on_invalid_map_property() && !map.contains_key(index.as_str()) {
+                    let x = crate::eval::rank_suggestions(
+                        index.as_str(),
+                        map.keys().map(crate::Identifier::as_str),
+                    );
+                    return Err(ERR::ErrorPropertyNotFound(index.to_string(), x, idx_pos).into());
+                }
+
                 if let Some(value) = map.get_mut(index.as_str()) {
                     value.try_into()
-                } else if self.fail_on_invalid_map_property() {
-                    Err(ERR::ErrorPropertyNotFound(index.to_string(), idx_pos).into())
                 } else {
                     Ok(Target::from(Dynamic::UNIT))
                 }
@@ -864,19 +870,12 @@ impl Engine {
                         #[cfg(feature = "debugging")]
                         defer! { global if Some(reset) => move |g| g.debugger_mut().reset_status(reset) }
 
-                        let crate::ast::FnCallExpr {
-                            name, hashes, args, ..
-                        } = &**x;
-
                         // Truncate the index values upon exit
-                        defer! { idx_values => truncate; let offset = idx_values.len() - args.len(); }
+                        defer! { idx_values => truncate; let offset = idx_values.len() - x.args.len(); }
 
                         let call_args = &mut idx_values[offset..];
-                        let arg1_pos = args.first().map_or(Position::NONE, Expr::position);
 
-                        self.make_method_call(
-                            global, caches, name, *hashes, target, call_args, arg1_pos, *pos,
-                        )
+                        self.make_method_call_expr(global, caches, x, target, call_args, *pos)
                     }
                     // {xxx:map}.id op= ???
                     (Expr::Property(x, pos), Some((new_val, op_info)), true) => {
@@ -1074,18 +1073,13 @@ impl Engine {
                                 #[cfg(feature = "debugging")]
                                 defer! { global if Some(reset) => move |g| g.debugger_mut().reset_status(reset) }
 
-                                let crate::ast::FnCallExpr {
-                                    name, hashes, args, ..
-                                } = &**x;
-
                                 // Truncate the index values upon exit
-                                defer! { idx_values => truncate; let offset = idx_values.len() - args.len(); }
+                                defer! { idx_values => truncate; let offset = idx_values.len() - x.args.len(); }
 
                                 let call_args = &mut idx_values[offset..];
-                                let arg1_pos = args.first().map_or(Position::NONE, Expr::position);
 
-                                self.make_method_call(
-                                    global, caches, name, *hashes, target, call_args, arg1_pos, pos,
+                                self.make_method_call_expr(
+                                    global, caches, x, target, call_args, pos,
                                 )?
                                 .0
                                 .into()
@@ -1197,18 +1191,13 @@ impl Engine {
                                     #[cfg(feature = "debugging")]
                                     defer! { global if Some(reset) => move |g| g.debugger_mut().reset_status(reset) }
 
-                                    let crate::ast::FnCallExpr {
-                                        name, hashes, args, ..
-                                    } = &**f;
-
                                     // Truncate the index values upon exit
-                                    defer! { idx_values => truncate; let offset = idx_values.len() - args.len(); }
+                                    defer! { idx_values => truncate; let offset = idx_values.len() - f.args.len(); }
 
                                     let call_args = &mut idx_values[offset..];
-                                    let pos1 = args.first().map_or(Position::NONE, Expr::position);
 
-                                    self.make_method_call(
-                                        global, caches, name, *hashes, target, call_args, pos1, pos,
+                                    self.make_method_call_expr(
+                                        global, caches, f, target, call_args, pos,
                                     )?
                                     .0
                                 };
@@ -149,6 +149,21 @@ impl Engine {
         Ok(())
     }
 
+    /// Raise an error if the number of items in a [`Set`][crate::Set] exceeds limit.
+    #[cfg(not(feature = "no_index"))]
+    #[inline]
+    pub(crate) fn check_set_size(&self, set: &crate::Set, pos: Position) -> RhaiResultOf<()> {
+        if self
+            .limits
+            .set_size
+            .map_or(false, |max| set.len() > max.get())
+        {
+            return Err(ERR::ErrorDataTooLarge("Size of set".to_string(), pos).into());
+        }
+
+        Ok(())
+    }
+
     /// Check whether the size of a [`Dynamic`] is within limits.
     #[cfg(not(feature = "unchecked"))]
     #[inline]
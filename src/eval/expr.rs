@@ -35,10 +35,22 @@ impl Engine {
         }
 
         // Do a text-match search if the index doesn't work
-        global.find_import(root).map_or_else(
-            || self.global_sub_modules.get(root).cloned(),
-            |offset| global.get_shared_import(offset),
-        )
+        if let Some(offset) = global.find_import(root) {
+            return global.get_shared_import(offset);
+        }
+
+        // Search inline modules
+        #[cfg(not(feature = "no_function"))]
+        if let Some(m) = global
+            .lib
+            .iter()
+            .rev()
+            .find_map(|m| m.get_shared_sub_module(root))
+        {
+            return Some(m.clone());
+        }
+
+        self.global_sub_modules.get(root).cloned()
     }
     /// Search for a variable within the scope
     ///
@@ -122,6 +134,16 @@ impl Engine {
             match scope.search(var_name) {
                 Some(index) => index,
                 None => {
+                    // Items imported into the unqualified namespace
+                    #[cfg(not(feature = "no_module"))]
+                    if let Some(val) = global
+                        .iter_imports_raw()
+                        .filter(|&(name, ..)| name == crate::engine::IMPORT_ITEMS)
+                        .find_map(|(.., m)| m.get_var(var_name))
+                    {
+                        return Ok(val.into());
+                    }
+
                     return self
                         .global_modules
                         .iter()
@@ -130,12 +152,13 @@ impl Engine {
                             || {
                                 Err(ERR::ErrorVariableNotFound(
                                     var_name.to_string(),
+                                    self.suggest_var(global, scope, var_name),
                                     expr.position(),
                                 )
                                 .into())
                             },
                             |val| Ok(val.into()),
-                        )
+                        );
                 }
             }
         };
@@ -167,9 +190,14 @@ impl Engine {
                         return module.get_qualified_var(*hash_var).map_or_else(
                             || {
                                 let sep = crate::engine::NAMESPACE_SEPARATOR;
+                                let x = super::rank_suggestions(
+                                    var_name,
+                                    module.iter_var().map(|(name, ..)| name),
+                                );
 
                                 Err(ERR::ErrorVariableNotFound(
                                     format!("{ns}{sep}{var_name}"),
+                                    x.into_iter().map(|v| format!("{ns}{sep}{v}")).collect(),
                                     ns.position(),
                                 )
                                 .into())
@@ -198,9 +226,22 @@ impl Engine {
                         }
 
                         let sep = crate::engine::NAMESPACE_SEPARATOR;
+                        let x = global
+                            .constants
+                            .as_ref()
+                            .map_or_else(Vec::new, |constants| {
+                                super::rank_suggestions(
+                                    var_name,
+                                    crate::func::locked_read(constants)
+                                        .unwrap()
+                                        .keys()
+                                        .map(crate::ImmutableString::as_str),
+                                )
+                            });
 
                         return Err(ERR::ErrorVariableNotFound(
                             format!("{ns}{sep}{var_name}"),
+                            x.into_iter().map(|v| format!("{ns}{sep}{v}")).collect(),
                             ns.position(),
                         )
                         .into());
@@ -292,6 +333,30 @@ impl Engine {
                         .eval_expr(global, caches, scope, this_ptr.as_deref_mut(), item_expr)?
                         .flatten();
 
+                    // [ ..array ]
+                    if let Expr::Spread(.., pos) = item_expr {
+                        if !value.is_array() {
+                            let typ = value.type_name();
+                            return Err(self.make_type_mismatch_err::<crate::Array>(typ, *pos));
+                        }
+
+                        #[cfg(not(feature = "unchecked"))]
+                        if self.has_data_size_limit() {
+                            let val_sizes = crate::eval::calc_data_sizes(&value, true);
+
+                            total_data_sizes = (
+                                total_data_sizes.0 + val_sizes.0,
+                                total_data_sizes.1 + val_sizes.1,
+                                total_data_sizes.2 + val_sizes.2,
+                            );
+                            self.throw_on_size(total_data_sizes)
+                                .map_err(|err| err.fill_position(*pos))?;
+                        }
+
+                        array.extend(value.into_array().unwrap());
+                        continue;
+                    }
+
                     #[cfg(not(feature = "unchecked"))]
                     if self.has_data_size_limit() {
                         let val_sizes = crate::eval::calc_data_sizes(&value, true);
@@ -311,6 +376,40 @@ impl Engine {
                 Ok(Dynamic::from_array(array))
             }
 
+            #[cfg(not(feature = "no_index"))]
+            Expr::Set(x, ..) => {
+                let mut set = crate::Set::new();
+
+                for item_expr in &**x {
+                    let value = self
+                        .eval_expr(global, caches, scope, this_ptr.as_deref_mut(), item_expr)?
+                        .flatten();
+
+                    set.insert(value).map_err(|value| {
+                        crate::Set::make_item_err(self, &value, item_expr.position())
+                    })?;
+
+                    #[cfg(not(feature = "unchecked"))]
+                    self.check_set_size(&set, item_expr.position())?;
+                }
+
+                Ok(set.into())
+            }
+
+            #[cfg(not(feature = "no_index"))]
+            Expr::Tuple(x, ..) => {
+                let mut items = Vec::with_capacity(x.len());
+
+                for item_expr in &**x {
+                    items.push(
+                        self.eval_expr(global, caches, scope, this_ptr.as_deref_mut(), item_expr)?
+                            .flatten(),
+                    );
+                }
+
+                Ok(crate::Tuple::from(items).into())
+            }
+
             #[cfg(not(feature = "no_object"))]
             Expr::Map(x, ..) => {
                 let mut map = x.1.clone();
@@ -323,19 +422,33 @@ impl Engine {
                         .eval_expr(global, caches, scope, this_ptr.as_deref_mut(), value_expr)?
                         .flatten();
 
+                    // #{ ..map }
+                    let is_spread = matches!(value_expr, Expr::Spread(..));
+
+                    if is_spread && !value.is_map() {
+                        let typ = value.type_name();
+                        return Err(
+                            self.make_type_mismatch_err::<crate::Map>(typ, value_expr.position())
+                        );
+                    }
+
                     #[cfg(not(feature = "unchecked"))]
                     if self.has_data_size_limit() {
                         let delta = crate::eval::calc_data_sizes(&value, true);
                         total_data_sizes = (
                             total_data_sizes.0 + delta.0,
-                            total_data_sizes.1 + delta.1 + 1,
+                            total_data_sizes.1 + delta.1 + usize::from(!is_spread),
                             total_data_sizes.2 + delta.2,
                         );
                         self.throw_on_size(total_data_sizes)
                             .map_err(|err| err.fill_position(value_expr.position()))?;
                     }
 
-                    *map.get_mut(key.as_str()).unwrap() = value;
+                    if is_spread {
+                        map.extend(value.cast::<crate::Map>());
+                    } else {
+                        *map.get_mut(key.as_str()).unwrap() = value;
+                    }
                 }
 
                 Ok(Dynamic::from_map(map))
@@ -396,6 +509,14 @@ impl Engine {
                 self.eval_stmt_block(global, caches, scope, this_ptr, x.statements(), true)
             }
 
+            // The spread value is expanded by the enclosing expression
+            Expr::Spread(x, ..) => self.eval_expr(global, caches, scope, this_ptr, x),
+
+            #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+            Expr::Comprehension(x, ..) => {
+                self.eval_comprehension(global, caches, scope, this_ptr, x)
+            }
+
             #[cfg(not(feature = "no_index"))]
             Expr::Index(..) => {
                 self.eval_dot_index_chain(global, caches, scope, this_ptr, expr, None)
@@ -408,4 +529,124 @@ impl Engine {
             _ => unreachable!("expression cannot be evaluated: {:?}", expr),
         }
     }
+
+    /// Evaluate an array or object map comprehension.
+    ///
+    /// The loop variables are pushed into the [`Scope`] the same way as a `for` loop, so
+    /// variable indices computed by the parser remain valid.
+    #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+    fn eval_comprehension(
+        &self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        scope: &mut Scope,
+        mut this_ptr: Option<&mut Dynamic>,
+        comprehension: &crate::ast::Comprehension,
+    ) -> RhaiResult {
+        let crate::ast::Comprehension {
+            var,
+            counter,
+            iterable,
+            condition,
+            key,
+            value,
+        } = comprehension;
+
+        // Restore scope at end of expression
+        defer! { scope => rewind; let orig_scope_len = scope.len(); }
+
+        let (iter, index, counter_var) = self.begin_loop(
+            global,
+            caches,
+            scope,
+            this_ptr.as_deref_mut(),
+            var,
+            counter.as_ref(),
+            iterable,
+        )?;
+
+        #[cfg(not(feature = "no_index"))]
+        let mut array = crate::Array::new();
+        #[cfg(not(feature = "no_object"))]
+        let mut map = crate::Map::new();
+
+        #[cfg(not(feature = "unchecked"))]
+        let mut total_data_sizes = (0, 0, 0);
+
+        for (i, iter_value) in iter.enumerate() {
+            Self::set_loop_vars(scope, index, counter_var, i, iter_value)
+                .map_err(|err| err.fill_position(iterable.position()))?;
+
+            // Filter
+            if !condition.is_unit() {
+                let this_ptr = this_ptr.as_deref_mut();
+
+                let keep = self
+                    .eval_expr(global, caches, scope, this_ptr, condition)?
+                    .as_bool()
+                    .map_err(|typ| {
+                        self.make_type_mismatch_err::<bool>(typ, condition.position())
+                    })?;
+
+                if !keep {
+                    continue;
+                }
+            }
+
+            // The value (and key) are parsed before the comprehension's variables are known,
+            // so variables in them are searched by name
+            defer! { let orig_always_search_scope = global.always_search_scope; global.always_search_scope = true }
+
+            let value_expr = value;
+            let value = self
+                .eval_expr(global, caches, scope, this_ptr.as_deref_mut(), value_expr)?
+                .flatten();
+
+            #[cfg(not(feature = "unchecked"))]
+            if self.has_data_size_limit() {
+                let val_sizes = crate::eval::calc_data_sizes(&value, true);
+
+                total_data_sizes = (
+                    total_data_sizes.0 + val_sizes.0 + usize::from(key.is_none()),
+                    total_data_sizes.1 + val_sizes.1 + usize::from(key.is_some()),
+                    total_data_sizes.2 + val_sizes.2,
+                );
+                self.throw_on_size(total_data_sizes)
+                    .map_err(|err| err.fill_position(value_expr.position()))?;
+            }
+
+            match key {
+                #[cfg(not(feature = "no_object"))]
+                Some(key_expr) => {
+                    let this_ptr = this_ptr.as_deref_mut();
+
+                    let key = self
+                        .eval_expr(global, caches, scope, this_ptr, key_expr)?
+                        .flatten()
+                        .into_immutable_string()
+                        .map_err(|typ| {
+                            self.make_type_mismatch_err::<crate::ImmutableString>(
+                                typ,
+                                key_expr.position(),
+                            )
+                        })?;
+
+                    map.insert(key.into(), value);
+                }
+                #[cfg(not(feature = "no_index"))]
+                None => array.push(value),
+                #[allow(unreachable_patterns)]
+                _ => unreachable!("comprehension kind not supported: {:?}", comprehension),
+            }
+        }
+
+        Ok(match key {
+            #[cfg(not(feature = "no_object"))]
+            Some(..) => Dynamic::from_map(map),
+            #[cfg(not(feature = "no_index"))]
+            None => Dynamic::from_array(array),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("comprehension kind not supported: {:?}", comprehension),
+        })
+    }
 }
@@ -6,6 +6,7 @@ mod eval_context;
 mod expr;
 mod global_state;
 mod stmt;
+mod suggest;
 mod target;
 
 #[allow(unused_imports)]
@@ -31,6 +32,7 @@ pub use global_state::GlobalRuntimeState;
 #[cfg(not(feature = "no_module"))]
 #[cfg(not(feature = "no_function"))]
 pub use global_state::SharedGlobalConstants;
+pub use suggest::rank_suggestions;
 #[cfg(not(feature = "no_index"))]
 pub use target::calc_offset_len;
 pub use target::{calc_index, Target};
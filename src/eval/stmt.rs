@@ -2,7 +2,8 @@
 
 use super::{Caches, EvalContext, GlobalRuntimeState, Target};
 use crate::ast::{
-    ASTFlags, BinaryExpr, Expr, FlowControl, OpAssignment, Stmt, SwitchCasesCollection,
+    ASTFlags, BinaryExpr, CatchClause, Expr, FlowControl, FnCallHashes, Ident, OpAssignment, Stmt,
+    SwitchCasesCollection, TryCatchBlock,
 };
 use crate::func::{get_builtin_op_assignment_fn, get_hasher};
 use crate::tokenizer::Token;
@@ -15,6 +16,12 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+/// Iterator over the values of a `for` loop, each with an optional object map property value.
+type LoopIterator<'a> = Box<dyn Iterator<Item = RhaiResultOf<(Dynamic, Option<Dynamic>)>> + 'a>;
+
+/// Index in the [`Scope`] and position of the counter variable of a `for` loop, if any.
+type LoopCounter = Option<(usize, crate::Position)>;
+
 impl Engine {
     /// If the value is a string, intern it.
     #[inline(always)]
@@ -25,6 +32,64 @@ impl Engine {
         }
     }
 
+    /// Check whether a new variable can be defined, running the variable definition filter if any.
+    #[allow(clippy::too_many_arguments)]
+    fn check_var_def(
+        &self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        scope: &mut Scope,
+        this_ptr: Option<&mut Dynamic>,
+        name: &str,
+        access: AccessMode,
+        pos: crate::Position,
+    ) -> RhaiResultOf<()> {
+        if !self.allow_shadowing() && scope.contains(name) {
+            return Err(ERR::ErrorVariableExists(name.to_string(), pos).into());
+        }
+
+        // Check variable definition filter
+        if let Some(ref filter) = self.def_var_filter {
+            let will_shadow = scope.contains(name);
+            let is_const = access == AccessMode::ReadOnly;
+            let info = VarDefInfo::new(name, is_const, global.scope_level, will_shadow);
+            let orig_scope_len = scope.len();
+            let context = EvalContext::new(self, global, caches, scope, this_ptr);
+            let filter_result = filter(true, info, context);
+
+            if orig_scope_len != scope.len() {
+                // The scope is changed, always search from now on
+                global.always_search_scope = true;
+            }
+
+            if !filter_result? {
+                return Err(ERR::ErrorForbiddenVariable(name.to_string(), pos).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Put a constant defined at global level into the global constants, so that it is
+    /// visible to script-defined functions.
+    #[inline]
+    #[allow(unused_variables)]
+    fn add_global_constant(
+        global: &mut GlobalRuntimeState,
+        name: &crate::ImmutableString,
+        value: &Dynamic,
+    ) {
+        #[cfg(not(feature = "no_function"))]
+        #[cfg(not(feature = "no_module"))]
+        if global.scope_level == 0 && global.lib.iter().any(|m| !m.is_empty()) {
+            crate::func::locked_write(global.constants.get_or_insert_with(|| {
+                crate::Shared::new(crate::Locked::new(std::collections::BTreeMap::new()))
+            }))
+            .unwrap()
+            .insert(name.clone(), value.clone());
+        }
+    }
+
     /// Evaluate a statements block.
     pub(crate) fn eval_stmt_block(
         &self,
@@ -221,22 +286,36 @@ impl Engine {
                 let opx = Some(op_x);
                 let args = &mut [&mut *lock_guard, &mut new_val];
 
-                match self.exec_native_fn_call(
-                    global, caches, op_x_str, opx, hash_x, args, true, false, pos,
-                ) {
-                    Ok(_) => (),
-                    Err(err) if matches!(*err, ERR::ErrorFunctionNotFound(ref f, ..) if f.starts_with(op_x_str)) =>
-                    {
+                // Script-defined operator functions take precedence over native op-assignments
+                #[cfg(not(feature = "no_function"))]
+                let has_script_op = self.has_script_operator(global, caches, hash, args);
+                #[cfg(feature = "no_function")]
+                let has_script_op = false;
+
+                let result = if has_script_op {
+                    None
+                } else {
+                    Some(self.exec_native_fn_call(
+                        global, caches, op_x_str, opx, hash_x, args, true, false, pos,
+                    ))
+                };
+
+                match result {
+                    Some(Ok(_)) => (),
+                    Some(Err(err)) if !matches!(*err, ERR::ErrorFunctionNotFound(ref f, ..) if f.starts_with(op_x_str)) => {
+                        return Err(err)
+                    }
+                    _ => {
                         // Expand to `var = var op rhs`
                         let op = Some(op);
+                        let hashes = FnCallHashes::from_native_only(hash);
 
                         *args[0] = self
-                            .exec_native_fn_call(
-                                global, caches, op_str, op, hash, args, true, false, pos,
+                            .exec_fn_call(
+                                global, caches, None, op_str, op, hashes, args, true, false, pos,
                             )?
                             .0;
                     }
-                    Err(err) => return Err(err),
                 }
 
                 self.check_data_size(&*args[0], root.position())?;
@@ -384,12 +463,8 @@ impl Engine {
 
             // Variable definition
             Stmt::Var(x, options, pos) => {
-                if !self.allow_shadowing() && scope.contains(x.0.as_str()) {
-                    return Err(ERR::ErrorVariableExists(x.0.as_str().to_string(), *pos).into());
-                }
-
                 // Let/const statement
-                let (var_name, expr, index) = &**x;
+                let (var_name, expr, index, typ) = &**x;
 
                 let access = if options.intersects(ASTFlags::CONSTANT) {
                     AccessMode::ReadOnly
@@ -398,34 +473,15 @@ impl Engine {
                 };
                 let export = options.intersects(ASTFlags::EXPORTED);
 
-                // Check variable definition filter
-                if let Some(ref filter) = self.def_var_filter {
-                    let will_shadow = scope.contains(var_name.as_str());
-                    let is_const = access == AccessMode::ReadOnly;
-                    let info = VarDefInfo::new(
-                        var_name.as_str(),
-                        is_const,
-                        global.scope_level,
-                        will_shadow,
-                    );
-                    let orig_scope_len = scope.len();
-                    let context =
-                        EvalContext::new(self, global, caches, scope, this_ptr.as_deref_mut());
-                    let filter_result = filter(true, info, context);
-
-                    if orig_scope_len != scope.len() {
-                        // The scope is changed, always search from now on
-                        global.always_search_scope = true;
-                    }
-
-                    if !filter_result? {
-                        return Err(ERR::ErrorForbiddenVariable(
-                            var_name.as_str().to_string(),
-                            *pos,
-                        )
-                        .into());
-                    }
-                }
+                self.check_var_def(
+                    global,
+                    caches,
+                    scope,
+                    this_ptr.as_deref_mut(),
+                    var_name.as_str(),
+                    access,
+                    *pos,
+                )?;
 
                 // Guard against too many variables
                 #[cfg(not(feature = "unchecked"))]
@@ -439,21 +495,12 @@ impl Engine {
                     .flatten();
                 let mut value = self.intern_string(value);
 
+                // Check type annotation, if any
+                self.check_type_annotation(typ.as_deref(), &value, expr.position())?;
+
                 let _alias = if !rewind_scope {
-                    // Put global constants into global module
-                    #[cfg(not(feature = "no_function"))]
-                    #[cfg(not(feature = "no_module"))]
-                    if global.scope_level == 0
-                        && access == AccessMode::ReadOnly
-                        && global.lib.iter().any(|m| !m.is_empty())
-                    {
-                        crate::func::locked_write(global.constants.get_or_insert_with(|| {
-                            crate::Shared::new(
-                                crate::Locked::new(std::collections::BTreeMap::new()),
-                            )
-                        }))
-                        .unwrap()
-                        .insert(var_name.name.clone(), value.clone());
+                    if access == AccessMode::ReadOnly {
+                        Self::add_global_constant(global, &var_name.name, &value);
                     }
 
                     export.then_some(var_name)
@@ -481,9 +528,81 @@ impl Engine {
                 Ok(Dynamic::UNIT)
             }
 
+            // Tuple destructuring
+            #[cfg(not(feature = "no_index"))]
+            Stmt::VarTuple(x, options, ..) => {
+                let (names, expr) = &**x;
+
+                let access = if options.intersects(ASTFlags::CONSTANT) {
+                    AccessMode::ReadOnly
+                } else {
+                    AccessMode::ReadWrite
+                };
+                let export = options.intersects(ASTFlags::EXPORTED);
+
+                for name in names {
+                    self.check_var_def(
+                        global,
+                        caches,
+                        scope,
+                        this_ptr.as_deref_mut(),
+                        name.as_str(),
+                        access,
+                        name.pos,
+                    )?;
+                }
+
+                // Guard against too many variables
+                #[cfg(not(feature = "unchecked"))]
+                if scope.len() + names.len() > self.max_variables() {
+                    return Err(ERR::ErrorTooManyVariables(names[0].pos).into());
+                }
+
+                // Evaluate the tuple
+                let value = self
+                    .eval_expr(global, caches, scope, this_ptr, expr)?
+                    .flatten();
+                let typ = value.type_name();
+                let tuple = value.try_cast::<crate::Tuple>().ok_or_else(|| {
+                    self.make_type_mismatch_err::<crate::Tuple>(typ, expr.position())
+                })?;
+
+                if tuple.len() != names.len() {
+                    return Err(crate::Tuple::make_len_err(
+                        names.len(),
+                        tuple.len(),
+                        expr.position(),
+                    ));
+                }
+
+                debug_assert!(
+                    !rewind_scope || !export,
+                    "exported variable not on global level"
+                );
+
+                for (name, value) in names.iter().zip(tuple) {
+                    let value = self.intern_string(value);
+
+                    if !rewind_scope && access == AccessMode::ReadOnly {
+                        Self::add_global_constant(global, &name.name, &value);
+                    }
+
+                    scope.push_entry(name.name.clone(), access, value);
+
+                    #[cfg(not(feature = "no_module"))]
+                    if export && !rewind_scope {
+                        scope.add_alias_by_index(scope.len() - 1, name.name.clone());
+                    }
+                }
+
+                Ok(Dynamic::UNIT)
+            }
+
             // If statement
             Stmt::If(x, ..) => {
-                let FlowControl { expr, body, branch } = &**x;
+                let FlowControl {
+                    expr, body, branch, ..
+                } = &**x;
 
                 let guard_val = self
                     .eval_expr(global, caches, scope, this_ptr.as_deref_mut(), expr)?
@@ -576,7 +695,7 @@ impl Engine {
             Stmt::While(x, ..)
                 if matches!(x.expr, Expr::Unit(..) | Expr::BoolConstant(true, ..)) =>
             {
-                let FlowControl { body, .. } = &**x;
+                let FlowControl { body, label, .. } = &**x;
 
                 if body.is_empty() {
                     loop {
@@ -591,8 +710,10 @@ impl Engine {
                     match self.eval_stmt_block(global, caches, scope, this_ptr, statements, true) {
                         Ok(..) => (),
                         Err(err) => match *err {
-                            ERR::LoopBreak(false, ..) => (),
-                            ERR::LoopBreak(true, value, ..) => break Ok(value),
+                            ERR::LoopBreak(false, ref l, ..) if l.is_none() || l == label => (),
+                            ERR::LoopBreak(true, ref l, value, ..) if l.is_none() || l == label => {
+                                break Ok(value)
+                            }
                             _ => break Err(err),
                         },
                     }
@@ -601,7 +722,9 @@ impl Engine {
 
             // While loop
             Stmt::While(x, ..) => {
-                let FlowControl { expr, body, .. } = &**x;
+                let FlowControl {
+                    expr, body, label, ..
+                } = &**x;
 
                 loop {
                     let condition = self
@@ -623,8 +746,10 @@ impl Engine {
                     match self.eval_stmt_block(global, caches, scope, this_ptr, statements, true) {
                         Ok(..) => (),
                         Err(err) => match *err {
-                            ERR::LoopBreak(false, ..) => (),
-                            ERR::LoopBreak(true, value, ..) => break Ok(value),
+                            ERR::LoopBreak(false, ref l, ..) if l.is_none() || l == label => (),
+                            ERR::LoopBreak(true, ref l, value, ..) if l.is_none() || l == label => {
+                                break Ok(value)
+                            }
                             _ => break Err(err),
                         },
                     }
@@ -633,7 +758,9 @@ impl Engine {
 
             // Do loop
             Stmt::Do(x, options, ..) => {
-                let FlowControl { expr, body, .. } = &**x;
+                let FlowControl {
+                    expr, body, label, ..
+                } = &**x;
                 let is_while = !options.intersects(ASTFlags::NEGATED);
 
                 loop {
@@ -646,8 +773,14 @@ impl Engine {
                         {
                             Ok(..) => (),
                             Err(err) => match *err {
-                                ERR::LoopBreak(false, ..) => continue,
-                                ERR::LoopBreak(true, value, ..) => break Ok(value),
+                                ERR::LoopBreak(false, ref l, ..) if l.is_none() || l == label => {
+                                    continue
+                                }
+                                ERR::LoopBreak(true, ref l, value, ..)
+                                    if l.is_none() || l == label =>
+                                {
+                                    break Ok(value)
+                                }
                                 _ => break Err(err),
                             },
                         }
@@ -666,89 +799,40 @@ impl Engine {
 
             // For loop
             Stmt::For(x, ..) => {
-                let (var_name, counter, FlowControl { expr, body, .. }) = &**x;
-
-                // Guard against too many variables
-                #[cfg(not(feature = "unchecked"))]
-                if scope.len() >= self.max_variables() - usize::from(counter.is_some()) {
-                    return Err(ERR::ErrorTooManyVariables(var_name.pos).into());
-                }
-
-                let iter_obj = self
-                    .eval_expr(global, caches, scope, this_ptr.as_deref_mut(), expr)?
-                    .flatten();
-
-                let iter_type = iter_obj.type_id();
-
-                // lib should only contain scripts, so technically they cannot have iterators
-
-                // Search order:
-                // 1) Global namespace - functions registered via Engine::register_XXX
-                // 2) Global modules - packages
-                // 3) Imported modules - functions marked with global namespace
-                // 4) Global sub-modules - functions marked with global namespace
-                let iter_func = self
-                    .global_modules
-                    .iter()
-                    .find_map(|m| m.get_iter(iter_type));
-
-                #[cfg(not(feature = "no_module"))]
-                let iter_func = iter_func
-                    .or_else(|| global.get_iter(iter_type))
-                    .or_else(|| {
-                        self.global_sub_modules
-                            .values()
-                            .find_map(|m| m.get_qualified_iter(iter_type))
-                    });
-
-                let iter_func = iter_func.ok_or_else(|| ERR::ErrorFor(expr.start_position()))?;
+                let (
+                    var_name,
+                    counter,
+                    FlowControl {
+                        expr, body, label, ..
+                    },
+                ) = &**x;
 
                 // Restore scope at end of statement
                 defer! { scope => rewind; let orig_scope_len = scope.len(); }
 
-                // Add the loop variables
-                let counter_index = counter.as_ref().map(|counter| {
-                    scope.push(counter.name.clone(), 0 as INT);
-                    scope.len() - 1
-                });
-
-                scope.push(var_name.name.clone(), ());
-                let index = scope.len() - 1;
+                let (iter, index, counter_var) = self.begin_loop(
+                    global,
+                    caches,
+                    scope,
+                    this_ptr.as_deref_mut(),
+                    var_name,
+                    counter.as_ref(),
+                    expr,
+                )?;
 
                 let mut result = Dynamic::UNIT;
 
                 if body.is_empty() {
-                    for _ in iter_func(iter_obj) {
+                    for iter_value in iter {
+                        if let Err(err) = iter_value {
+                            return Err(err.fill_position(expr.position()));
+                        }
                         self.track_operation(global, body.position())?;
                     }
                 } else {
-                    for (i, iter_value) in iter_func(iter_obj).enumerate() {
-                        // Increment counter
-                        if let Some(counter_index) = counter_index {
-                            // As the variable increments from 0, this should always work
-                            // since any overflow will first be caught below.
-                            let index_value = i as INT;
-
-                            #[cfg(not(feature = "unchecked"))]
-                            #[allow(clippy::absurd_extreme_comparisons)]
-                            if index_value > crate::MAX_USIZE_INT {
-                                return Err(ERR::ErrorArithmetic(
-                                    format!("for-loop counter overflow: {i}"),
-                                    counter.as_ref().unwrap().pos,
-                                )
-                                .into());
-                            }
-
-                            *scope.get_mut_by_index(counter_index).write_lock().unwrap() =
-                                Dynamic::from_int(index_value);
-                        }
-
-                        // Set loop value
-                        let value = iter_value
-                            .map_err(|err| err.fill_position(expr.position()))?
-                            .flatten();
-
-                        *scope.get_mut_by_index(index).write_lock().unwrap() = value;
+                    for (i, iter_value) in iter.enumerate() {
+                        Self::set_loop_vars(scope, index, counter_var, i, iter_value)
+                            .map_err(|err| err.fill_position(expr.position()))?;
 
                         // Run block
                         let this_ptr = this_ptr.as_deref_mut();
@@ -759,8 +843,10 @@ impl Engine {
                         {
                             Ok(_) => (),
                             Err(err) => match *err {
-                                ERR::LoopBreak(false, ..) => (),
-                                ERR::LoopBreak(true, value, ..) => {
+                                ERR::LoopBreak(false, ref l, ..) if l.is_none() || l == label => (),
+                                ERR::LoopBreak(true, ref l, value, ..)
+                                    if l.is_none() || l == label =>
+                                {
                                     result = value;
                                     break;
                                 }
@@ -777,23 +863,27 @@ impl Engine {
             Stmt::BreakLoop(expr, options, pos) => {
                 let is_break = options.intersects(ASTFlags::BREAK);
 
-                let value = match expr {
-                    Some(ref expr) => self.eval_expr(global, caches, scope, this_ptr, expr)?,
-                    None => Dynamic::UNIT,
+                let (value, label) = match expr {
+                    Some(x) => {
+                        let (ref expr, ref label) = **x;
+                        let value = self.eval_expr(global, caches, scope, this_ptr, expr)?;
+                        (value, label.clone())
+                    }
+                    None => (Dynamic::UNIT, None),
                 };
 
-                Err(ERR::LoopBreak(is_break, value, *pos).into())
+                Err(ERR::LoopBreak(is_break, label, value, *pos).into())
             }
 
             // Try/Catch statement
             Stmt::TryCatch(x, ..) => {
-                let FlowControl {
+                let TryCatchBlock {
                     body,
-                    expr: catch_var,
-                    branch,
+                    catch_clauses,
+                    finally,
                 } = &**x;
 
-                match self.eval_stmt_block(
+                let result = match self.eval_stmt_block(
                     global,
                     caches,
                     scope,
@@ -804,70 +894,29 @@ impl Engine {
                     r @ Ok(_) => r,
                     Err(err) if err.is_pseudo_error() => Err(err),
                     Err(err) if !err.is_catchable() => Err(err),
-                    Err(mut err) => {
-                        let err_value = match err.unwrap_inner() {
-                            // No error variable
-                            _ if catch_var.is_unit() => Dynamic::UNIT,
-
-                            ERR::ErrorRuntime(x, ..) => x.clone(),
-
-                            #[cfg(feature = "no_object")]
-                            _ => {
-                                let _ = err.take_position();
-                                err.to_string().into()
-                            }
-                            #[cfg(not(feature = "no_object"))]
-                            _ => {
-                                let mut err_map = crate::Map::new();
-                                let err_pos = err.take_position();
-
-                                err_map.insert("message".into(), err.to_string().into());
-
-                                if let Some(ref source) = global.source {
-                                    err_map.insert("source".into(), source.into());
-                                }
-
-                                if !err_pos.is_none() {
-                                    err_map.insert(
-                                        "line".into(),
-                                        (err_pos.line().unwrap() as INT).into(),
-                                    );
-                                    err_map.insert(
-                                        "position".into(),
-                                        (err_pos.position().unwrap_or(0) as INT).into(),
-                                    );
-                                }
-
-                                err.dump_fields(&mut err_map);
-                                err_map.into()
-                            }
-                        };
-
-                        // Restore scope at end of block
-                        defer! { scope if !catch_var.is_unit() => rewind; let orig_scope_len = scope.len(); }
-
-                        if let Expr::Variable(x, ..) = catch_var {
-                            // Guard against too many variables
-                            #[cfg(not(feature = "unchecked"))]
-                            if scope.len() >= self.max_variables() {
-                                return Err(ERR::ErrorTooManyVariables(catch_var.position()).into());
-                            }
-                            scope.push(x.1.clone(), err_value);
-                        }
-
-                        let this_ptr = this_ptr.as_deref_mut();
-                        let statements = branch.statements();
+                    Err(err) => self.eval_catch_clauses(
+                        global,
+                        caches,
+                        scope,
+                        this_ptr.as_deref_mut(),
+                        catch_clauses,
+                        err,
+                    ),
+                };
 
+                match result {
+                    _ if finally.is_empty() => result,
+                    // Exit and uncatchable errors skip the finally block
+                    Err(ref err)
+                        if matches!(**err, ERR::Exit(..))
+                            || !(err.is_pseudo_error() || err.is_catchable()) =>
+                    {
+                        result
+                    }
+                    _ => {
+                        let statements = finally.statements();
                         self.eval_stmt_block(global, caches, scope, this_ptr, statements, true)
-                            .map(|_| Dynamic::UNIT)
-                            .map_err(|result_err| match *result_err {
-                                // Re-throw exception
-                                ERR::ErrorRuntime(v, pos) if v.is_unit() => {
-                                    err.set_position(pos);
-                                    err
-                                }
-                                _ => result_err,
-                            })
+                            .and(result)
                     }
                 }
             }
@@ -893,9 +942,10 @@ impl Engine {
             // Import statement
             #[cfg(not(feature = "no_module"))]
             Stmt::Import(x, _pos) => {
-                use crate::ModuleResolver;
+                use crate::ast::ImportItems;
+                use crate::{FnAccess, ModuleResolver};
 
-                let (expr, export) = &**x;
+                let (expr, export, items) = &**x;
 
                 // Guard against too many modules
                 #[cfg(not(feature = "unchecked"))]
@@ -934,6 +984,36 @@ impl Engine {
                         Err(ERR::ErrorModuleNotFound(path.to_string(), path_pos).into())
                     })?;
 
+                // Import items into the unqualified namespace
+                let module = match items {
+                    ImportItems::None => module,
+                    _ => {
+                        if let ImportItems::Some(list) = items {
+                            if let Some((name, ..)) = list.iter().find(|(name, ..)| {
+                                !module.contains_var(name.as_str())
+                                    && !module.iter_fn().any(|(_, f)| {
+                                        f.access != FnAccess::Private && f.name == name.as_str()
+                                    })
+                            }) {
+                                return Err(ERR::ErrorVariableNotFound(
+                                    format!(
+                                        "{path}{}{}",
+                                        crate::engine::NAMESPACE_SEPARATOR,
+                                        name.name
+                                    ),
+                                    Vec::new(),
+                                    name.pos,
+                                )
+                                .into());
+                            }
+                        }
+
+                        let m = module.extract_items(|name| items.imported_name(name));
+                        self.check_import_conflicts(global, &m, *_pos)?;
+                        m.into()
+                    }
+                };
+
                 let (export, must_be_indexed) = if export.is_empty() {
                     (self.const_empty_string(), false)
                 } else {
@@ -957,17 +1037,16 @@ impl Engine {
             // Export statement
             #[cfg(not(feature = "no_module"))]
             Stmt::Export(x, ..) => {
-                use crate::ast::Ident;
                 let (Ident { name, pos, .. }, Ident { name: alias, .. }) = &**x;
                 // Mark scope variables as public
-                scope.search(name).map_or_else(
-                    || Err(ERR::ErrorVariableNotFound(name.to_string(), *pos).into()),
-                    |index| {
-                        let alias = if alias.is_empty() { name } else { alias };
-                        scope.add_alias_by_index(index, alias.clone());
-                        Ok(Dynamic::UNIT)
-                    },
-                )
+                if let Some(index) = scope.search(name) {
+                    let alias = if alias.is_empty() { name } else { alias };
+                    scope.add_alias_by_index(index, alias.clone());
+                    Ok(Dynamic::UNIT)
+                } else {
+                    let x = self.suggest_var(global, scope, name);
+                    Err(ERR::ErrorVariableNotFound(name.to_string(), x, *pos).into())
+                }
             }
 
             // Share statement
@@ -1000,12 +1079,24 @@ impl Engine {
                     }
 
                     // Search the scope
-                    let index = index
+                    let Some(index) = index
+                        .filter(|_| !global.always_search_scope)
                         .map(|n| scope.len() - n.get())
                         .or_else(|| scope.search(&var.name))
-                        .ok_or_else(|| {
-                            Box::new(ERR::ErrorVariableNotFound(var.name.to_string(), var.pos))
-                        })?;
+                    else {
+                        // If imported into the unqualified namespace, skip it (because we cannot make it shared)
+                        #[cfg(not(feature = "no_module"))]
+                        if global.iter_imports_raw().any(|(name, m)| {
+                            name == crate::engine::IMPORT_ITEMS && m.contains_var(&var.name)
+                        }) {
+                            continue;
+                        }
+
+                        let x = self.suggest_var(global, scope, &var.name);
+                        return Err(
+                            ERR::ErrorVariableNotFound(var.name.to_string(), x, var.pos).into()
+                        );
+                    };
 
                     let val = scope.get_mut_by_index(index);
 
@@ -1023,6 +1114,307 @@ impl Engine {
         }
     }
 
+    /// Check that no item imported into the unqualified namespace conflicts with an item imported
+    /// earlier.
+    #[cfg(not(feature = "no_module"))]
+    fn check_import_conflicts(
+        &self,
+        global: &GlobalRuntimeState,
+        module: &crate::Module,
+        pos: crate::Position,
+    ) -> RhaiResultOf<()> {
+        global
+            .scan_imports_raw()
+            .filter(|&(name, ..)| name == crate::engine::IMPORT_ITEMS)
+            .try_for_each(|(.., m)| {
+                let conflict = module
+                    .iter_var()
+                    .map(|(name, ..)| name)
+                    .find(|&name| m.contains_var(name))
+                    .or_else(|| {
+                        module
+                            .iter_fn()
+                            .find(|(_, f)| m.contains_fn(f.hash))
+                            .map(|(_, f)| f.name.as_str())
+                    });
+
+                match conflict {
+                    Some(name) => Err(ERR::ErrorImportConflict(name.to_string(), pos).into()),
+                    None => Ok(()),
+                }
+            })
+    }
+
+    /// Get an iterator over a value, if its type has a registered type iterator.
+    ///
+    /// If `pairs` is `true` and the value is an object map, the iterator yields its properties as
+    /// name/value pairs. Otherwise, the second item of each pair is always [`None`].
+    ///
+    /// Returns [`None`] if the value cannot be iterated.
+    pub(crate) fn make_iterator<'a>(
+        &'a self,
+        global: &GlobalRuntimeState,
+        iter_obj: Dynamic,
+        pairs: bool,
+    ) -> Option<LoopIterator<'a>> {
+        // for (name, value) in map
+        #[cfg(not(feature = "no_object"))]
+        if pairs && iter_obj.is_map() {
+            let map = iter_obj.cast::<crate::Map>();
+
+            return Some(Box::new(map.into_iter().map(|(name, value)| {
+                let name: crate::ImmutableString = name.into();
+                Ok((name.into(), Some(value)))
+            })));
+        }
+        #[cfg(feature = "no_object")]
+        let _ = pairs;
+
+        // for x in iterator
+        #[cfg(not(feature = "no_index"))]
+        if iter_obj.is::<crate::LazyIterator>() {
+            let iter = iter_obj.cast::<crate::LazyIterator>();
+
+            return Some(Box::new(
+                iter.into_loop_iter(self, global)
+                    .map(|value| value.map(|value| (value, None))),
+            ));
+        }
+
+        self.get_type_iterator(global, iter_obj.type_id())
+            .map(|iter_func| -> LoopIterator {
+                Box::new(iter_func(iter_obj).map(|value| value.map(|value| (value, None))))
+            })
+    }
+
+    /// Evaluate the iterable expression of a `for` loop or comprehension, then push the loop
+    /// variables into the [`Scope`] in the same order as the parser does.
+    ///
+    /// Returns the iterator, the index of the loop variable in the [`Scope`], and the index and
+    /// position of the counter variable (if any).
+    ///
+    /// The caller is responsible for rewinding the [`Scope`] afterwards.
+    pub(crate) fn begin_loop<'a>(
+        &'a self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        scope: &mut Scope,
+        this_ptr: Option<&mut Dynamic>,
+        var: &Ident,
+        counter: Option<&Ident>,
+        expr: &Expr,
+    ) -> RhaiResultOf<(LoopIterator<'a>, usize, LoopCounter)> {
+        // Guard against too many variables
+        #[cfg(not(feature = "unchecked"))]
+        if scope.len() >= self.max_variables() - usize::from(counter.is_some()) {
+            return Err(ERR::ErrorTooManyVariables(var.pos).into());
+        }
+
+        let iter_obj = self
+            .eval_expr(global, caches, scope, this_ptr, expr)?
+            .flatten();
+
+        let iter = self
+            .make_iterator(global, iter_obj, counter.is_some())
+            .ok_or_else(|| ERR::ErrorFor(expr.start_position()))?;
+
+        // Add the loop variables
+        let counter_var = counter.map(|counter| {
+            scope.push(counter.name.clone(), 0 as INT);
+            (scope.len() - 1, counter.pos)
+        });
+
+        scope.push(var.name.clone(), ());
+
+        Ok((iter, scope.len() - 1, counter_var))
+    }
+
+    /// Set the loop variables of a `for` loop or comprehension for the `i`-th item yielded by
+    /// its iterator.
+    ///
+    /// The counter variable, if any, is set to the iteration count, or to the property value
+    /// when iterating through an object map.
+    pub(crate) fn set_loop_vars(
+        scope: &mut Scope,
+        index: usize,
+        counter_var: LoopCounter,
+        i: usize,
+        iter_value: RhaiResultOf<(Dynamic, Option<Dynamic>)>,
+    ) -> RhaiResultOf<()> {
+        let (value, map_value) = iter_value?;
+
+        // Increment counter, or set the property value for object maps
+        if let Some((counter_index, _counter_pos)) = counter_var {
+            let counter_value = match map_value {
+                Some(map_value) => map_value.flatten(),
+                None => {
+                    // As the variable increments from 0, this should always work
+                    // since any overflow will first be caught below.
+                    let index_value = i as INT;
+
+                    #[cfg(not(feature = "unchecked"))]
+                    #[allow(clippy::absurd_extreme_comparisons)]
+                    if index_value > crate::MAX_USIZE_INT {
+                        return Err(ERR::ErrorArithmetic(
+                            format!("for-loop counter overflow: {i}"),
+                            _counter_pos,
+                        )
+                        .into());
+                    }
+
+                    Dynamic::from_int(index_value)
+                }
+            };
+
+            *scope.get_mut_by_index(counter_index).write_lock().unwrap() = counter_value;
+        }
+
+        // Set loop value
+        *scope.get_mut_by_index(index).write_lock().unwrap() = value.flatten();
+
+        Ok(())
+    }
+
+    /// Get the type iterator registered for a type, if any.
+    pub(crate) fn get_type_iterator<'a>(
+        &'a self,
+        global: &'a GlobalRuntimeState,
+        iter_type: std::any::TypeId,
+    ) -> Option<&'a crate::func::FnIterator> {
+        // lib should only contain scripts, so technically they cannot have iterators
+
+        // Search order:
+        // 1) Global namespace - functions registered via Engine::register_XXX
+        // 2) Global modules - packages
+        // 3) Imported modules - functions marked with global namespace
+        // 4) Global sub-modules - functions marked with global namespace
+        let iter_func = self
+            .global_modules
+            .iter()
+            .find_map(|m| m.get_iter(iter_type));
+
+        #[cfg(not(feature = "no_module"))]
+        let iter_func = iter_func
+            .or_else(|| global.get_iter(iter_type))
+            .or_else(|| {
+                self.global_sub_modules
+                    .values()
+                    .find_map(|m| m.get_qualified_iter(iter_type))
+            });
+
+        #[cfg(feature = "no_module")]
+        let _ = global;
+
+        iter_func
+    }
+
+    /// Evaluate the `catch` clauses of a `try` statement for a caught error.
+    ///
+    /// The error is re-thrown if no clause matches it.
+    fn eval_catch_clauses(
+        &self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        scope: &mut Scope,
+        mut this_ptr: Option<&mut Dynamic>,
+        catch_clauses: &[CatchClause],
+        mut err: crate::RhaiError,
+    ) -> RhaiResult {
+        let err_pos = err.take_position();
+
+        let err_value = match err.unwrap_inner() {
+            // No error variable
+            _ if catch_clauses.iter().all(|c| c.var.is_unit()) => Dynamic::UNIT,
+
+            ERR::ErrorRuntime(x, ..) => x.clone(),
+
+            #[cfg(feature = "no_object")]
+            _ => err.to_string().into(),
+            #[cfg(not(feature = "no_object"))]
+            _ => {
+                let mut err_map = crate::Map::new();
+
+                err_map.insert("message".into(), err.to_string().into());
+
+                if let Some(ref source) = global.source {
+                    err_map.insert("source".into(), source.into());
+                }
+
+                if !err_pos.is_none() {
+                    err_map.insert("line".into(), (err_pos.line().unwrap() as INT).into());
+                    err_map.insert(
+                        "position".into(),
+                        (err_pos.position().unwrap_or(0) as INT).into(),
+                    );
+                }
+
+                err.dump_fields(&mut err_map);
+                err_map.into()
+            }
+        };
+
+        err.set_position(err_pos);
+
+        for clause in catch_clauses {
+            // Match the error kind, or the type of the thrown value
+            if let Some(ref typ) = clause.error_type {
+                let matched = match err.unwrap_inner() {
+                    ERR::ErrorRuntime(v, ..) => self.is_type_annotation_match(typ, v.type_name()),
+                    e => e.kind_name() == typ.as_str(),
+                };
+
+                if !matched {
+                    continue;
+                }
+            }
+
+            // Restore scope at end of clause
+            defer! { scope if !clause.var.is_unit() => rewind; let orig_scope_len = scope.len(); }
+
+            if let Expr::Variable(ref x, ..) = clause.var {
+                // Guard against too many variables
+                #[cfg(not(feature = "unchecked"))]
+                if scope.len() >= self.max_variables() {
+                    return Err(ERR::ErrorTooManyVariables(clause.var.position()).into());
+                }
+                scope.push(x.1.clone(), err_value.clone());
+            }
+
+            // Check the condition, if any
+            if !clause.condition.is_unit() {
+                let this_ptr = this_ptr.as_deref_mut();
+
+                let matched = self
+                    .eval_expr(global, caches, scope, this_ptr, &clause.condition)?
+                    .as_bool()
+                    .map_err(|typ| {
+                        self.make_type_mismatch_err::<bool>(typ, clause.condition.position())
+                    })?;
+
+                if !matched {
+                    continue;
+                }
+            }
+
+            let statements = clause.body.statements();
+
+            return self
+                .eval_stmt_block(global, caches, scope, this_ptr, statements, true)
+                .map(|_| Dynamic::UNIT)
+                .map_err(|result_err| match *result_err {
+                    // Re-throw exception
+                    ERR::ErrorRuntime(v, pos) if v.is_unit() => {
+                        err.set_position(pos);
+                        err
+                    }
+                    _ => result_err,
+                });
+        }
+
+        // No matching catch clause - re-throw exception
+        Err(err)
+    }
+
     /// Evaluate a list of statements with no `this` pointer.
     /// This is commonly used to evaluate a list of statements in an [`AST`][crate::AST] or a script function body.
     #[inline(always)]
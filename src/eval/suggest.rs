@@ -0,0 +1,265 @@
+//! Module containing "did you mean" suggestions for unknown functions, variables and properties.
+
+use super::GlobalRuntimeState;
+use crate::func::RhaiFunc;
+use crate::module::FuncMetadata;
+use crate::tokenizer::is_valid_identifier;
+use crate::{Engine, FnAccess, FnNamespace, Module, Scope, ERR};
+use std::cmp::Ordering;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// Maximum number of suggestions attached to an error.
+pub const MAX_SUGGESTIONS: usize = 5;
+
+/// Calculate the (case-insensitive) edit distance between two strings.
+///
+/// This is the Damerau-Levenshtein distance (optimal string alignment), so transposing two
+/// adjacent characters (a common typo) counts as a single edit.
+#[must_use]
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<_> = a.chars().flat_map(char::to_lowercase).collect();
+    let b: Vec<_> = b.chars().flat_map(char::to_lowercase).collect();
+
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            d[i][j] = (d[i - 1][j - 1] + cost)
+                .min(d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+/// Rank a list of candidate names by their similarity to `name`.
+///
+/// Only candidates within a reasonable edit distance (one third of the length of `name`, at
+/// least one) are returned, closest first, up to [`MAX_SUGGESTIONS`] entries.
+#[must_use]
+pub fn rank_suggestions<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    let mut ranked: Vec<_> = candidates
+        .into_iter()
+        .filter(|&s| s != name)
+        .filter_map(|s| {
+            let distance = edit_distance(name, s);
+            (distance <= max_distance).then_some((distance, s))
+        })
+        .collect();
+
+    ranked.sort_by(|(d1, s1), (d2, s2)| match d1.cmp(d2) {
+        Ordering::Equal => s1.cmp(s2),
+        r => r,
+    });
+    ranked.dedup_by(|(_, s1), (_, s2)| s1 == s2);
+
+    ranked
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, s)| s.to_string())
+        .collect()
+}
+
+impl Engine {
+    /// Generate a human-readable signature for a function, used when suggesting overloads.
+    #[must_use]
+    fn gen_suggestion_signature(&self, func: &RhaiFunc, metadata: &FuncMetadata) -> String {
+        #[cfg(not(feature = "no_function"))]
+        if let RhaiFunc::Script { fn_def, .. } = func {
+            return format!(
+                "{}({})",
+                fn_def.name,
+                fn_def
+                    .params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        let p = if fn_def.is_variadic && i == fn_def.num_fixed_params() {
+                            format!("...{p}")
+                        } else {
+                            p.to_string()
+                        };
+                        let mut param = match fn_def.param_types.get(i) {
+                            Some(Some(t)) => format!("{p}: {t}"),
+                            _ => p,
+                        };
+                        if let Some(value) = fn_def.default_value(i) {
+                            param.push_str(&format!(" = {value:?}"));
+                        }
+                        param
+                    })
+                    .collect::<crate::FnArgsVec<_>>()
+                    .join(", ")
+            );
+        }
+
+        let _ = func;
+
+        #[cfg(feature = "metadata")]
+        return metadata.gen_signature(|s| self.format_param_type(s));
+
+        #[cfg(not(feature = "metadata"))]
+        return format!(
+            "{}({})",
+            metadata.name,
+            vec!["_"; metadata.num_params].join(", ")
+        );
+    }
+
+    /// Find suggestions for a function that cannot be found.
+    ///
+    /// If there are functions with the same name (i.e. only the number or types of the arguments
+    /// are mismatched), their signatures are returned.
+    /// Otherwise, the names of similarly-named functions are returned.
+    ///
+    /// If `module` is [`Some`], only that module is searched (i.e. a namespace-qualified call).
+    /// Otherwise, the following are searched:
+    /// 1) AST - script functions in the AST
+    /// 2) Global registered modules - functions registered via `Engine::register_XXX` and packages
+    /// 3) Imported modules - functions marked with global namespace
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn suggest_fn(
+        &self,
+        _global: &GlobalRuntimeState,
+        module: Option<&Module>,
+        fn_name: &str,
+    ) -> Vec<String> {
+        // Skip operators and other special functions
+        if !is_valid_identifier(fn_name) {
+            return Vec::new();
+        }
+
+        // Functions in imported modules are only visible if they are in the global namespace
+        let mut modules: Vec<(&Module, bool)> = Vec::new();
+
+        if let Some(module) = module {
+            modules.push((module, false));
+        } else {
+            #[cfg(not(feature = "no_function"))]
+            modules.extend(_global.lib.iter().map(|m| (&**m, false)));
+            modules.extend(self.global_modules.iter().map(|m| (&**m, false)));
+            #[cfg(not(feature = "no_module"))]
+            modules.extend(_global.iter_imports_raw().map(|(_, m)| (&**m, true)));
+        }
+
+        let functions = || {
+            modules.iter().flat_map(|&(m, global_only)| {
+                m.iter_fn().filter(move |(_, f)| {
+                    f.access == FnAccess::Public
+                        && (!global_only || f.namespace == FnNamespace::Global)
+                })
+            })
+        };
+
+        // Same name - only the arguments are mismatched
+        let mut overloads: Vec<_> = functions()
+            .filter(|(_, m)| m.name == fn_name)
+            .map(|(f, m)| self.gen_suggestion_signature(f, m))
+            .collect();
+
+        if !overloads.is_empty() {
+            overloads.sort();
+            overloads.dedup();
+            overloads.truncate(MAX_SUGGESTIONS);
+            return overloads;
+        }
+
+        rank_suggestions(
+            fn_name,
+            functions()
+                .map(|(_, m)| m.name.as_str())
+                .filter(|&name| is_valid_identifier(name)),
+        )
+    }
+
+    /// Add suggestions to an [`ErrorFunctionNotFound`][ERR::ErrorFunctionNotFound] error for a
+    /// call to the function `fn_name`, if it does not already have any.
+    ///
+    /// Errors from other function calls are passed through unchanged.
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn fill_fn_suggestions(
+        &self,
+        global: &GlobalRuntimeState,
+        module: Option<&Module>,
+        fn_name: &str,
+        mut err: crate::RhaiError,
+    ) -> crate::RhaiError {
+        if let ERR::ErrorFunctionNotFound(ref sig, ref mut suggestions, ..) = *err {
+            // Strip the argument types and the namespace, if any
+            let name = sig.split(" (").next().unwrap_or(sig);
+            #[cfg(not(feature = "no_module"))]
+            let name = name
+                .rsplit(crate::engine::NAMESPACE_SEPARATOR)
+                .next()
+                .unwrap_or(name);
+
+            if suggestions.is_empty() && name == fn_name {
+                *suggestions = self.suggest_fn(global, module, fn_name);
+            }
+        }
+        err
+    }
+
+    /// Find suggestions for a variable that cannot be found.
+    ///
+    /// Variables in the [`Scope`], global constants, and variables in global registered modules
+    /// are searched.
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn suggest_var(
+        &self,
+        _global: &GlobalRuntimeState,
+        scope: &Scope,
+        var_name: &str,
+    ) -> Vec<String> {
+        #[cfg(not(feature = "no_module"))]
+        #[cfg(not(feature = "no_function"))]
+        let constants = _global
+            .constants
+            .as_ref()
+            .and_then(|c| crate::func::locked_read(c))
+            .map(|c| c.keys().map(|k| k.to_string()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        #[cfg(any(feature = "no_module", feature = "no_function"))]
+        let constants: Vec<String> = Vec::new();
+
+        rank_suggestions(
+            var_name,
+            scope
+                .iter_raw()
+                .map(|(name, ..)| name)
+                .chain(constants.iter().map(String::as_str))
+                .chain(
+                    self.global_modules
+                        .iter()
+                        .flat_map(|m| m.iter_var())
+                        .map(|(name, ..)| name),
+                ),
+        )
+    }
+}
@@ -24,6 +24,12 @@ use num_traits::Float;
 #[cfg(feature = "decimal")]
 use rust_decimal::Decimal;
 
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+
+#[cfg(feature = "rational")]
+use crate::Rational;
+
 /// The `unchecked` feature is not active.
 const CHECKED_BUILD: bool = cfg!(not(feature = "unchecked"));
 
@@ -56,6 +62,16 @@ fn is_numeric(typ: TypeId) -> bool {
         return true;
     }
 
+    #[cfg(feature = "bigint")]
+    if typ == TypeId::of::<BigInt>() {
+        return true;
+    }
+
+    #[cfg(feature = "rational")]
+    if typ == TypeId::of::<Rational>() {
+        return true;
+    }
+
     #[cfg(not(feature = "only_i32"))]
     #[cfg(not(feature = "only_i64"))]
     if typ == TypeId::of::<u8>()
@@ -80,6 +96,32 @@ fn is_numeric(typ: TypeId) -> bool {
     false
 }
 
+/// Get the two operands of a [`BigInt`] operation, promoting any `INT` operand.
+#[cfg(feature = "bigint")]
+#[inline]
+fn get_bigints(args: &FnCallArgs) -> (BigInt, BigInt) {
+    let get = |value: &Dynamic| {
+        value.as_int().map_or_else(
+            |_| value.read_lock::<BigInt>().unwrap().clone(),
+            BigInt::from,
+        )
+    };
+    (get(args[0]), get(args[1]))
+}
+
+/// Get the two operands of a [`Rational`] operation, promoting any `INT` operand.
+#[cfg(feature = "rational")]
+#[inline]
+fn get_rationals(args: &FnCallArgs) -> (Rational, Rational) {
+    let get = |value: &Dynamic| {
+        value.as_int().map_or_else(
+            |_| *value.read_lock::<Rational>().unwrap(),
+            Rational::from_integer,
+        )
+    };
+    (get(args[0]), get(args[1]))
+}
+
 /// Build in common binary operator implementations to avoid the cost of calling a registered function.
 ///
 /// The return function will be registered as a _method_, so the first parameter cannot be consumed.
@@ -89,6 +131,14 @@ pub fn get_builtin_binary_op_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Option<
     let type2 = y.type_id();
 
     macro_rules! impl_op {
+        ($get:ident => ctx $func:ident) => { Some((|ctx, args| {
+            let (x, y) = $get(args);
+            $func(ctx.unwrap(), x, y).map(Into::into)
+        }, true)) };
+        ($get:ident => $func:ident) => { Some((|_, args| {
+            let (x, y) = $get(args);
+            $func(x, y).map(Into::into)
+        }, false)) };
         ($xx:ident $op:tt $yy:ident) => { Some((|_, args| {
             let x = &*args[0].read_lock::<$xx>().unwrap();
             let y = &*args[1].read_lock::<$yy>().unwrap();
@@ -448,6 +498,61 @@ pub fn get_builtin_binary_op_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Option<
         impl_decimal!(INT, as_int, Decimal, as_decimal);
     }
 
+    #[cfg(feature = "bigint")]
+    if (type1 == TypeId::of::<BigInt>()
+        && (type2 == TypeId::of::<BigInt>() || type2 == TypeId::of::<INT>()))
+        || (type1 == TypeId::of::<INT>() && type2 == TypeId::of::<BigInt>())
+    {
+        #[allow(clippy::wildcard_imports)]
+        use crate::packages::arithmetic::bigint_functions::builtin::*;
+
+        return match op {
+            Plus => impl_op!(get_bigints(+)),
+            Minus => impl_op!(get_bigints(-)),
+            Multiply => impl_op!(get_bigints(*)),
+            Divide => impl_op!(get_bigints => divide),
+            Modulo => impl_op!(get_bigints => modulo),
+            PowerOf => impl_op!(get_bigints => ctx power),
+            LeftShift => impl_op!(get_bigints => ctx shift_left),
+            RightShift => impl_op!(get_bigints => ctx shift_right),
+            Ampersand => impl_op!(get_bigints(&)),
+            Pipe => impl_op!(get_bigints(|)),
+            XOr => impl_op!(get_bigints(^)),
+            EqualsTo => impl_op!(get_bigints(==)),
+            NotEqualsTo => impl_op!(get_bigints(!=)),
+            GreaterThan => impl_op!(get_bigints(>)),
+            GreaterThanEqualsTo => impl_op!(get_bigints(>=)),
+            LessThan => impl_op!(get_bigints(<)),
+            LessThanEqualsTo => impl_op!(get_bigints(<=)),
+            _ => None,
+        };
+    }
+
+    #[cfg(feature = "rational")]
+    if (type1 == TypeId::of::<Rational>()
+        && (type2 == TypeId::of::<Rational>() || type2 == TypeId::of::<INT>()))
+        || (type1 == TypeId::of::<INT>() && type2 == TypeId::of::<Rational>())
+    {
+        #[allow(clippy::wildcard_imports)]
+        use crate::packages::arithmetic::rational_functions::builtin::*;
+
+        return match op {
+            Plus => impl_op!(get_rationals => add),
+            Minus => impl_op!(get_rationals => subtract),
+            Multiply => impl_op!(get_rationals => multiply),
+            Divide => impl_op!(get_rationals => divide),
+            Modulo => impl_op!(get_rationals => modulo),
+            PowerOf => impl_op!(get_rationals => power),
+            EqualsTo => impl_op!(get_rationals(==)),
+            NotEqualsTo => impl_op!(get_rationals(!=)),
+            GreaterThan => impl_op!(get_rationals(>)),
+            GreaterThanEqualsTo => impl_op!(get_rationals(>=)),
+            LessThan => impl_op!(get_rationals(<)),
+            LessThanEqualsTo => impl_op!(get_rationals(<=)),
+            _ => None,
+        };
+    }
+
     // Ranges
     if *op == ExclusiveRange && type1 == TypeId::of::<INT>() && type2 == TypeId::of::<()>() {
         return Some((
@@ -644,6 +749,21 @@ pub fn get_builtin_op_assignment_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Opt
     let type2 = y.type_id();
 
     macro_rules! impl_op {
+        ($get:ident => ctx $func:ident) => { Some((|ctx, args| {
+            let (x, y) = $get(args);
+            let v: Dynamic = $func(ctx.unwrap(), x, y)?.into();
+            Ok((*args[0].write_lock().unwrap() = v).into())
+        }, true)) };
+        ($get:ident => $func:ident) => { Some((|_, args| {
+            let (x, y) = $get(args);
+            let v: Dynamic = $func(x, y)?.into();
+            Ok((*args[0].write_lock().unwrap() = v).into())
+        }, false)) };
+        ($get:ident ( $op:tt )) => { Some((|_, args| {
+            let (x, y) = $get(args);
+            let v: Dynamic = (x $op y).into();
+            Ok((*args[0].write_lock().unwrap() = v).into())
+        }, false)) };
         ($x:ty = x $op:tt $yy:ident) => { Some((|_, args| {
             let x = args[0].$yy().unwrap();
             let y = args[1].$yy().unwrap() as $x;
@@ -947,6 +1067,47 @@ pub fn get_builtin_op_assignment_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Opt
         impl_decimal!(Decimal, as_decimal, INT, as_int);
     }
 
+    #[cfg(feature = "bigint")]
+    if type1 == TypeId::of::<BigInt>()
+        && (type2 == TypeId::of::<BigInt>() || type2 == TypeId::of::<INT>())
+    {
+        #[allow(clippy::wildcard_imports)]
+        use crate::packages::arithmetic::bigint_functions::builtin::*;
+
+        return match op {
+            PlusAssign => impl_op!(get_bigints(+)),
+            MinusAssign => impl_op!(get_bigints(-)),
+            MultiplyAssign => impl_op!(get_bigints(*)),
+            DivideAssign => impl_op!(get_bigints => divide),
+            ModuloAssign => impl_op!(get_bigints => modulo),
+            PowerOfAssign => impl_op!(get_bigints => ctx power),
+            LeftShiftAssign => impl_op!(get_bigints => ctx shift_left),
+            RightShiftAssign => impl_op!(get_bigints => ctx shift_right),
+            AndAssign => impl_op!(get_bigints(&)),
+            OrAssign => impl_op!(get_bigints(|)),
+            XOrAssign => impl_op!(get_bigints(^)),
+            _ => None,
+        };
+    }
+
+    #[cfg(feature = "rational")]
+    if type1 == TypeId::of::<Rational>()
+        && (type2 == TypeId::of::<Rational>() || type2 == TypeId::of::<INT>())
+    {
+        #[allow(clippy::wildcard_imports)]
+        use crate::packages::arithmetic::rational_functions::builtin::*;
+
+        return match op {
+            PlusAssign => impl_op!(get_rationals => add),
+            MinusAssign => impl_op!(get_rationals => subtract),
+            MultiplyAssign => impl_op!(get_rationals => multiply),
+            DivideAssign => impl_op!(get_rationals => divide),
+            ModuloAssign => impl_op!(get_rationals => modulo),
+            PowerOfAssign => impl_op!(get_rationals => power),
+            _ => None,
+        };
+    }
+
     // string op= char
     if (type1, type2) == (TypeId::of::<ImmutableString>(), TypeId::of::<char>()) {
         return match op {
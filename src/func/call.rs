@@ -128,6 +128,23 @@ pub fn ensure_no_data_race(fn_name: &str, args: &FnCallArgs, is_ref_mut: bool) -
         })
 }
 
+/// Can a script-defined operator function be called with this operand?
+///
+/// Script-defined operator functions only apply when an operand is a value of a custom type or an
+/// object map tagged with a type (i.e. with a non-zero _tag_), so operators for all built-in types
+/// (including plain object maps) are never overridden by scripts.
+#[cfg(not(feature = "no_function"))]
+#[inline]
+#[must_use]
+pub fn is_script_operator_operand(value: &Dynamic) -> bool {
+    match value.0 {
+        Union::Variant(..) => true,
+        #[cfg(not(feature = "no_object"))]
+        Union::Map(_, tag, ..) => tag != 0,
+        _ => false,
+    }
+}
+
 /// Is a function name an anonymous function?
 #[cfg(not(feature = "no_function"))]
 #[inline]
@@ -363,7 +380,9 @@ impl Engine {
 
             if non_volatile_only && func.is_volatile() {
                 let gen_fn_call_signature = self.gen_fn_call_signature(name, args);
-                return Err(ERR::ErrorFunctionNotFound(gen_fn_call_signature, pos).into());
+                return Err(
+                    ERR::ErrorFunctionNotFound(gen_fn_call_signature, Vec::new(), pos).into(),
+                );
             }
 
             let is_method = func.is_method();
@@ -543,11 +562,67 @@ impl Engine {
 
             // Raise error
             _ => {
-                Err(ERR::ErrorFunctionNotFound(self.gen_fn_call_signature(name, args), pos).into())
+                let sig = self.gen_fn_call_signature(name, args);
+                Err(ERR::ErrorFunctionNotFound(sig, Vec::new(), pos).into())
             }
         }
     }
 
+    /// Resolve a script-defined function with default parameter values, or with a variadic
+    /// parameter, which can be called with the specified number of arguments.
+    ///
+    /// The resolution is cached under the hash of the function call, so that it is found directly
+    /// in subsequent look-ups.
+    #[cfg(not(feature = "no_function"))]
+    fn resolve_script_fn_by_arity(
+        &self,
+        global: &GlobalRuntimeState,
+        caches: &mut Caches,
+        fn_name: &str,
+        hash_script: u64,
+        args: &FnCallArgs,
+        is_method_call: bool,
+    ) -> Option<FnResolutionCacheEntry> {
+        let num_args = if is_method_call {
+            args.len().checked_sub(1)?
+        } else {
+            args.len()
+        };
+
+        #[cfg(not(feature = "no_object"))]
+        let this_type = is_method_call.then(|| self.map_type_name(args[0].type_name()));
+
+        let filter = |fn_def: &crate::ast::ScriptFuncDef| {
+            #[cfg(not(feature = "no_object"))]
+            match (fn_def.this_type.as_deref(), this_type) {
+                (None, _) => (),
+                (Some(t), Some(this_type)) if t == this_type => (),
+                _ => return false,
+            }
+
+            fn_def.accepts_num_args(num_args)
+        };
+
+        let (func, source) = global
+            .lib
+            .iter()
+            .rev()
+            .chain(self.global_modules.iter())
+            .find_map(|m| m.find_script_fn(fn_name, filter).map(|f| (f, m.id_raw())))?;
+
+        let entry = FnResolutionCacheEntry {
+            func: func.clone(),
+            source: source.cloned(),
+        };
+
+        caches
+            .fn_resolution_cache_mut()
+            .dict
+            .insert(hash_script, Some(entry.clone()));
+
+        Some(entry)
+    }
+
     /// # Main Entry-Point (By Name)
     ///
     /// Perform an actual function call, native Rust or scripted, by name, taking care of special functions.
@@ -597,7 +672,7 @@ impl Engine {
             }
         {
             let sig = self.gen_fn_call_signature(fn_name, args);
-            return Err(ERR::ErrorFunctionNotFound(sig, pos).into());
+            return Err(ERR::ErrorFunctionNotFound(sig, Vec::new(), pos).into());
         }
 
         // Check for data race.
@@ -606,6 +681,17 @@ impl Engine {
 
         defer! { let orig_level = global.level; global.level += 1 }
 
+        // Operator call which may be handled by a script-defined operator function?
+        #[cfg(not(feature = "no_function"))]
+        let hashes = if op_token.is_some()
+            && hashes.is_native_only()
+            && args.iter().any(|arg| is_script_operator_operand(arg))
+        {
+            FnCallHashes::from_hash(hashes.native())
+        } else {
+            hashes
+        };
+
         // Script-defined function call?
         #[cfg(not(feature = "no_function"))]
         if !hashes.is_native_only() {
@@ -617,15 +703,43 @@ impl Engine {
             if _is_method_call && !args.is_empty() {
                 let typed_hash =
                     crate::calc_typed_method_hash(hash, self.map_type_name(args[0].type_name()));
-                resolved =
-                    self.resolve_fn(global, caches, local_entry, None, typed_hash, None, false);
+                resolved = self
+                    .resolve_fn(global, caches, local_entry, None, typed_hash, None, false)
+                    .cloned();
             }
 
             if resolved.is_none() {
-                resolved = self.resolve_fn(global, caches, local_entry, None, hash, None, false);
+                resolved = self
+                    .resolve_fn(global, caches, local_entry, None, hash, None, false)
+                    .cloned();
             }
 
-            if let Some(FnResolutionCacheEntry { func, source }) = resolved.cloned() {
+            // Script-defined function with default parameter values,
+            // provided that there is no native function with the exact signature
+            if resolved.is_none()
+                && self
+                    .resolve_fn(
+                        global,
+                        caches,
+                        local_entry,
+                        op_token,
+                        hashes.native(),
+                        Some(args),
+                        true,
+                    )
+                    .is_none()
+            {
+                resolved = self.resolve_script_fn_by_arity(
+                    global,
+                    caches,
+                    fn_name,
+                    hash,
+                    args,
+                    _is_method_call,
+                );
+            }
+
+            if let Some(FnResolutionCacheEntry { func, source }) = resolved {
                 let RhaiFunc::Script { fn_def, environ } = func else {
                     unreachable!("Script function expected");
                 };
@@ -633,7 +747,10 @@ impl Engine {
                 let fn_def = &*fn_def;
                 let environ = environ.as_deref();
 
-                if fn_def.body.is_empty() {
+                if fn_def.body.is_empty()
+                    && fn_def.param_types.is_empty()
+                    && fn_def.return_type.is_none()
+                {
                     return Ok((Dynamic::UNIT, false));
                 }
 
@@ -684,6 +801,22 @@ impl Engine {
         )
     }
 
+    /// Is there a script-defined operator function that applies to the operands?
+    #[cfg(not(feature = "no_function"))]
+    #[must_use]
+    pub(crate) fn has_script_operator(
+        &self,
+        global: &GlobalRuntimeState,
+        caches: &mut Caches,
+        hash: u64,
+        args: &FnCallArgs,
+    ) -> bool {
+        args.iter().any(|arg| is_script_operator_operand(arg))
+            && self
+                .resolve_fn(global, caches, &mut None, None, hash, None, false)
+                .is_some()
+    }
+
     /// Evaluate an argument.
     #[inline]
     pub(crate) fn get_arg_value(
@@ -718,6 +851,94 @@ impl Engine {
             .map(|r| (r, arg_expr.start_position()))
     }
 
+    /// Expand spread arguments in a list of evaluated function call arguments.
+    ///
+    /// Each spread argument must evaluate to an array, whose items are passed as separate arguments.
+    #[cfg(not(feature = "no_index"))]
+    pub(crate) fn expand_spread_args(
+        &self,
+        args_expr: &[Expr],
+        arg_values: impl IntoIterator<Item = Dynamic>,
+    ) -> RhaiResultOf<FnArgsVec<Dynamic>> {
+        let mut values = FnArgsVec::with_capacity(args_expr.len());
+
+        for (expr, value) in args_expr.iter().zip(arg_values) {
+            match expr {
+                Expr::Spread(.., pos) => values.extend(
+                    value
+                        .into_array()
+                        .map_err(|typ| self.make_type_mismatch_err::<crate::Array>(typ, *pos))?,
+                ),
+                _ => values.push(value),
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Call a dot method from a method call expression, expanding spread arguments (if any).
+    #[cfg(not(feature = "no_object"))]
+    pub(crate) fn make_method_call_expr(
+        &self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        expr: &FnCallExpr,
+        target: &mut crate::eval::Target,
+        call_args: &mut [Dynamic],
+        pos: Position,
+    ) -> RhaiResultOf<(Dynamic, bool)> {
+        let FnCallExpr {
+            name, hashes, args, ..
+        } = expr;
+
+        let first_arg_pos = args.first().map_or(Position::NONE, Expr::position);
+
+        #[cfg(not(feature = "no_index"))]
+        if expr.has_spread_args() {
+            let mut values = self.expand_spread_args(args, call_args.iter_mut().map(mem::take))?;
+
+            // Recalculate hashes
+            let num_args = values.len() + 1;
+            let hashes = if hashes.is_native_only() {
+                FnCallHashes::from_native_only(calc_fn_hash(None, name, num_args))
+            } else {
+                #[cfg(not(feature = "no_function"))]
+                {
+                    FnCallHashes::from_script_and_native(
+                        calc_fn_hash(None, name, num_args - 1),
+                        calc_fn_hash(None, name, num_args),
+                    )
+                }
+                #[cfg(feature = "no_function")]
+                {
+                    FnCallHashes::from_native_only(calc_fn_hash(None, name, num_args))
+                }
+            };
+
+            return self.make_method_call(
+                global,
+                caches,
+                name,
+                hashes,
+                target,
+                &mut values,
+                first_arg_pos,
+                pos,
+            );
+        }
+
+        self.make_method_call(
+            global,
+            caches,
+            name,
+            *hashes,
+            target,
+            call_args,
+            first_arg_pos,
+            pos,
+        )
+    }
+
     /// Call a dot method.
     #[cfg(not(feature = "no_object"))]
     pub(crate) fn make_method_call(
@@ -750,7 +971,7 @@ impl Engine {
                 match _fn_def {
                     // Linked to scripted function - short-circuit
                     #[cfg(not(feature = "no_function"))]
-                    Some(fn_def) if fn_def.params.len() == args.len() => {
+                    Some(fn_def) if fn_def.accepts_num_args(args.len()) => {
                         let scope = &mut Scope::new();
                         let environ = fn_ptr.environ.as_ref().map(<_>::as_ref);
 
@@ -825,7 +1046,7 @@ impl Engine {
                 match fn_def {
                     // Linked to scripted function - short-circuit
                     #[cfg(not(feature = "no_function"))]
-                    Some(fn_def) if fn_def.params.len() == args.len() => {
+                    Some(fn_def) if fn_def.accepts_num_args(args.len()) => {
                         // Check for data race.
                         #[cfg(not(feature = "no_closure"))]
                         ensure_no_data_race(&fn_def.name, args, false)?;
@@ -932,7 +1153,7 @@ impl Engine {
                             match _fn_def {
                                 // Linked to scripted function
                                 #[cfg(not(feature = "no_function"))]
-                                Some(fn_def) if fn_def.params.len() == call_args.len() => {
+                                Some(fn_def) if fn_def.accepts_num_args(call_args.len()) => {
                                     _linked = Some((fn_def.clone(), fn_ptr.environ.clone()))
                                 }
                                 _ => {
@@ -1000,7 +1221,8 @@ impl Engine {
                     }
                 }
             }
-        }?;
+        }
+        .map_err(|err| self.fill_fn_suggestions(global, None, fn_name, err))?;
 
         // Propagate the changed value back to the source if necessary
         if updated {
@@ -1074,7 +1296,7 @@ impl Engine {
                 // Linked to scripted function - short-circuit
                 #[cfg(not(feature = "no_function"))]
                 if let Some(fn_def) = fn_def {
-                    if fn_def.params.len() == curry.len() + args_expr.len() {
+                    if fn_def.accepts_num_args(curry.len() + args_expr.len()) {
                         // Evaluate arguments
                         let mut arg_values =
                             FnArgsVec::with_capacity(curry.len() + args_expr.len());
@@ -1599,12 +1821,220 @@ impl Engine {
                         self.gen_fn_call_signature(fn_name, args)
                     )
                 },
+                self.suggest_fn(global, Some(&module), fn_name),
                 pos,
             )
             .into()),
         }
     }
 
+    /// Call a function with spread arguments in normal function-call style.
+    ///
+    /// The number of arguments is only known after the spread arguments are expanded, so the
+    /// function is resolved at runtime.
+    #[cfg(not(feature = "no_index"))]
+    fn make_spread_args_call(
+        &self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        scope: &mut Scope,
+        mut this_ptr: Option<&mut Dynamic>,
+        fn_name: &str,
+        hashes: FnCallHashes,
+        args_expr: &[Expr],
+        capture_scope: bool,
+        pos: Position,
+    ) -> RhaiResult {
+        let mut arg_values = FnArgsVec::with_capacity(args_expr.len());
+
+        for expr in args_expr {
+            let (value, ..) =
+                self.get_arg_value(global, caches, scope, this_ptr.as_deref_mut(), expr)?;
+            arg_values.push(value.flatten());
+        }
+
+        let mut arg_values = self.expand_spread_args(args_expr, arg_values)?;
+
+        let mut fn_name = fn_name;
+        let mut native_only = hashes.is_native_only();
+        let redirected;
+
+        match fn_name {
+            // Handle curry(fn_ptr, ...)
+            KEYWORD_FN_PTR_CURRY if arg_values.first().map_or(false, Dynamic::is_fnptr) => {
+                let mut fn_ptr = arg_values.remove(0).cast::<FnPtr>();
+
+                // Append the new curried arguments to the existing list.
+                for value in arg_values {
+                    fn_ptr.add_curry(value);
+                }
+
+                return Ok(fn_ptr.into());
+            }
+            // Handle call(fn_ptr, ...)
+            KEYWORD_FN_PTR_CALL if arg_values.first().map_or(false, Dynamic::is_fnptr) => {
+                let fn_ptr = arg_values.remove(0).cast::<FnPtr>();
+                #[cfg(not(feature = "no_function"))]
+                let is_anon = fn_ptr.is_anonymous();
+                #[cfg(feature = "no_function")]
+                let is_anon = false;
+
+                #[cfg(not(feature = "no_function"))]
+                let FnPtr {
+                    name,
+                    curry,
+                    environ,
+                    fn_def,
+                } = fn_ptr;
+                #[cfg(feature = "no_function")]
+                let FnPtr { name, curry, .. } = fn_ptr;
+
+                arg_values = curry.into_iter().chain(arg_values).collect();
+
+                // Linked to scripted function - short-circuit
+                #[cfg(not(feature = "no_function"))]
+                if let Some(fn_def) = fn_def {
+                    if fn_def.accepts_num_args(arg_values.len()) {
+                        let args = &mut arg_values.iter_mut().collect::<FnArgsVec<_>>();
+                        let scope = &mut Scope::new();
+                        let environ = environ.as_deref();
+
+                        defer! { let orig_level = global.level; global.level += 1 }
+
+                        return self.call_script_fn(
+                            global, caches, scope, None, environ, &fn_def, args, true, pos,
+                        );
+                    }
+                }
+
+                // Redirect function name
+                redirected = name;
+                fn_name = &redirected;
+
+                native_only = !is_anon && !is_valid_function_name(fn_name);
+            }
+            _ => (),
+        }
+
+        // Recalculate hashes
+        let hash = calc_fn_hash(None, fn_name, arg_values.len());
+        let hashes = if native_only {
+            FnCallHashes::from_native_only(hash)
+        } else {
+            FnCallHashes::from_hash(hash)
+        };
+        let args = &mut arg_values.iter_mut().collect::<FnArgsVec<_>>();
+        let scope = (capture_scope && !scope.is_empty()).then_some(scope);
+
+        self.exec_fn_call(
+            global, caches, scope, fn_name, None, hashes, args, false, false, pos,
+        )
+        .map(|(v, ..)| v)
+    }
+
+    /// Call a script-defined function with named arguments in normal function-call style.
+    ///
+    /// Named arguments are mapped to the parameters of the function, with default values filled
+    /// in for parameters that are not provided.
+    #[cfg(not(feature = "no_function"))]
+    fn make_named_args_call(
+        &self,
+        global: &mut GlobalRuntimeState,
+        caches: &mut Caches,
+        scope: &mut Scope,
+        mut this_ptr: Option<&mut Dynamic>,
+        fn_name: &str,
+        args_expr: &[Expr],
+        named_args: &[crate::ast::Ident],
+        capture_scope: bool,
+        pos: Position,
+    ) -> RhaiResult {
+        let mut arg_values = FnArgsVec::with_capacity(args_expr.len());
+
+        for expr in args_expr {
+            let (value, ..) =
+                self.get_arg_value(global, caches, scope, this_ptr.as_deref_mut(), expr)?;
+            arg_values.push(value.flatten());
+        }
+
+        #[cfg(not(feature = "no_index"))]
+        let mut arg_values = self.expand_spread_args(args_expr, arg_values)?;
+
+        let num_positional = arg_values.len() - named_args.len();
+
+        // Find a script-defined function that accepts all the named arguments
+        // and has default values for all parameters not provided
+        let filter = |fn_def: &crate::ast::ScriptFuncDef| {
+            #[cfg(not(feature = "no_object"))]
+            if fn_def.this_type.is_some() {
+                return false;
+            }
+
+            let num_fixed_params = fn_def.num_fixed_params();
+
+            num_positional <= num_fixed_params
+                && named_args
+                    .iter()
+                    .all(|n| fn_def.params[num_positional..num_fixed_params].contains(&n.name))
+                && (num_positional..fn_def.num_required_params())
+                    .all(|i| named_args.iter().any(|n| n.name == fn_def.params[i]))
+        };
+
+        let fn_def = global
+            .lib
+            .iter()
+            .rev()
+            .chain(self.global_modules.iter())
+            .find_map(|m| m.find_script_fn(fn_name, filter))
+            .and_then(RhaiFunc::get_script_fn_def)
+            .cloned()
+            .ok_or_else(|| {
+                let sig = format!(
+                    "{fn_name} ({})",
+                    arg_values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, a)| {
+                            let typ = self.map_type_name(a.type_name());
+                            match i.checked_sub(num_positional) {
+                                Some(n) => format!("{}: {typ}", named_args[n].name),
+                                None => typ.to_string(),
+                            }
+                        })
+                        .collect::<FnArgsVec<_>>()
+                        .join(", ")
+                );
+                ERR::ErrorFunctionNotFound(sig, Vec::new(), pos)
+            })?;
+
+        // Map arguments to parameters
+        let mut named_values: FnArgsVec<_> = arg_values.drain(num_positional..).collect();
+
+        for (i, param) in fn_def
+            .params
+            .iter()
+            .enumerate()
+            .take(fn_def.num_fixed_params())
+            .skip(num_positional)
+        {
+            let value = match named_args.iter().position(|n| n.name == *param) {
+                Some(n) => mem::take(&mut named_values[n]),
+                None => fn_def.default_value(i).unwrap().clone(),
+            };
+            arg_values.push(value);
+        }
+
+        let hash = calc_fn_hash(None, fn_name, arg_values.len());
+        let hashes = FnCallHashes::from_hash(hash);
+        let args = &mut arg_values.iter_mut().collect::<FnArgsVec<_>>();
+        let scope = (capture_scope && !scope.is_empty()).then_some(scope);
+
+        self.exec_fn_call(
+            global, caches, scope, fn_name, None, hashes, args, false, false, pos,
+        )
+        .map(|(v, ..)| v)
+    }
+
     /// Evaluate a text script in place - used primarily for 'eval'.
     pub(crate) fn eval_script_expr_in_place(
         &self,
@@ -1901,6 +2331,34 @@ impl Engine {
             );
         }
 
+        // Function call with named arguments
+        #[cfg(not(feature = "no_function"))]
+        if !expr.named_args.is_empty() {
+            return self
+                .make_named_args_call(
+                    global,
+                    caches,
+                    scope,
+                    this_ptr,
+                    name,
+                    args,
+                    &expr.named_args,
+                    *capture,
+                    pos,
+                )
+                .map_err(|err| self.fill_fn_suggestions(global, None, name, err));
+        }
+
+        // Function call with spread arguments
+        #[cfg(not(feature = "no_index"))]
+        if expr.has_spread_args() {
+            return self
+                .make_spread_args_call(
+                    global, caches, scope, this_ptr, name, *hashes, args, *capture, pos,
+                )
+                .map_err(|err| self.fill_fn_suggestions(global, None, name, err));
+        }
+
         // Normal function call
         let (first_arg, rest_args) = args.split_first().map_or_else(
             || (None, args.as_ref()),
@@ -1911,5 +2369,6 @@ impl Engine {
             global, caches, scope, this_ptr, name, op_token, first_arg, rest_args, *hashes,
             *capture, pos,
         )
+        .map_err(|err| self.fill_fn_suggestions(global, None, name, err))
     }
 }
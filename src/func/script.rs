@@ -33,7 +33,7 @@ impl Engine {
         rewind_scope: bool,
         pos: Position,
     ) -> RhaiResult {
-        debug_assert_eq!(fn_def.params.len(), args.len());
+        debug_assert!(fn_def.accepts_num_args(args.len()));
 
         self.track_operation(global, pos)?;
 
@@ -43,12 +43,32 @@ impl Engine {
             return Err(ERR::ErrorStackOverflow(pos).into());
         }
 
+        let num_fixed_params = fn_def.num_fixed_params();
+
+        // Check parameter type annotations, if any
+        for (i, typ) in fn_def.param_types.iter().enumerate().take(num_fixed_params) {
+            let arg = args
+                .get(i)
+                .map(|a| &**a)
+                .or_else(|| fn_def.default_value(i));
+            self.check_type_annotation(typ.as_deref(), arg.unwrap(), pos)?;
+        }
+        // The annotation of the variadic parameter applies to each extra argument
+        if let Some(typ) = fn_def.param_types.get(num_fixed_params) {
+            for arg in args.iter().skip(num_fixed_params) {
+                self.check_type_annotation(typ.as_deref(), arg, pos)?;
+            }
+        }
+
         #[cfg(feature = "debugging")]
-        if self.debugger_interface.is_none() && fn_def.body.is_empty() {
+        if self.debugger_interface.is_none()
+            && fn_def.body.is_empty()
+            && fn_def.return_type.is_none()
+        {
             return Ok(Dynamic::UNIT);
         }
         #[cfg(not(feature = "debugging"))]
-        if fn_def.body.is_empty() {
+        if fn_def.body.is_empty() && fn_def.return_type.is_none() {
             return Ok(Dynamic::UNIT);
         }
 
@@ -70,10 +90,34 @@ impl Engine {
         }
 
         // Put arguments into scope as variables
-        scope.extend(fn_def.params.iter().cloned().zip(args.iter_mut().map(|v| {
-            // Actually consume the arguments instead of cloning them
-            v.take()
-        })));
+        scope.extend(fn_def.params.iter().take(num_fixed_params).cloned().zip(
+            args.iter_mut().map(|v| {
+                // Actually consume the arguments instead of cloning them
+                v.take()
+            }),
+        ));
+
+        // Fill in default values for parameters not provided
+        scope.extend(
+            fn_def
+                .params
+                .iter()
+                .enumerate()
+                .take(num_fixed_params)
+                .skip(args.len())
+                .map(|(i, p)| (p.clone(), fn_def.default_value(i).unwrap().clone())),
+        );
+
+        // Collect extra arguments into the variadic parameter
+        #[cfg(not(feature = "no_index"))]
+        if fn_def.is_variadic {
+            let rest: crate::Array = args
+                .iter_mut()
+                .skip(num_fixed_params)
+                .map(|v| v.take())
+                .collect();
+            scope.push(fn_def.params[num_fixed_params].clone(), rest);
+        }
 
         // Push a new call stack frame
         #[cfg(feature = "debugging")]
@@ -188,9 +232,9 @@ impl Engine {
         // Remove all local variables and imported modules
         if rewind_scope {
             scope.rewind(orig_scope_len);
-        } else if !args.is_empty() {
+        } else if !fn_def.params.is_empty() {
             // Remove arguments only, leaving new variables in the scope
-            scope.remove_range(orig_scope_len, args.len());
+            scope.remove_range(orig_scope_len, fn_def.params.len());
         }
         global.lib.truncate(orig_lib_len);
         #[cfg(not(feature = "no_module"))]
@@ -205,7 +249,11 @@ impl Engine {
         // Restore state
         caches.rewind_fn_resolution_caches(orig_fn_resolution_caches_len);
 
-        _result
+        // Check return type annotation, if any
+        _result.and_then(|r| {
+            self.check_type_annotation(fn_def.return_type.as_deref(), &r, pos)?;
+            Ok(r)
+        })
     }
 
     // Does a script-defined function exist?
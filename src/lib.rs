@@ -214,6 +214,20 @@ pub type FLOAT = f32;
 #[cfg(not(feature = "no_index"))]
 const FLOAT_BYTES: usize = std::mem::size_of::<FLOAT>();
 
+/// The system rational number type, an exact fraction of two [`INT`]'s.
+/// It is defined as [`Ratio<INT>`][num_rational::Ratio].
+///
+/// Requires the `rational` feature.
+#[cfg(feature = "rational")]
+pub type Rational = num_rational::Ratio<INT>;
+
+/// The system regular expression type.
+/// It is defined as [`regex::Regex`].
+///
+/// Requires the `regex` feature.
+#[cfg(feature = "regex")]
+pub type Regex = regex::Regex;
+
 /// An exclusive integer range.
 type ExclusiveRange = std::ops::Range<INT>;
 
@@ -246,11 +260,15 @@ pub use module::{FnNamespace, FuncRegistration, Module};
 pub use packages::string_basic::{FUNC_TO_DEBUG, FUNC_TO_STRING};
 pub use rhai_codegen::*;
 #[cfg(not(feature = "no_time"))]
-pub use types::Instant;
+pub use types::{DateTime, Instant};
 pub use types::{
     Dynamic, EvalAltResult, FnPtr, ImmutableString, LexError, ParseError, ParseErrorType, Position,
     Scope, VarDefInfo,
 };
+#[cfg(not(feature = "no_index"))]
+pub use types::{LazyIterator, Set, Tuple};
+#[cfg(not(feature = "no_float"))]
+pub use types::{Mat, Vec2, Vec3, Vec4, Vector};
 
 /// _(debugging)_ Module containing types for debugging.
 /// Exported under the `debugging` feature only.
@@ -345,23 +363,32 @@ pub use tokenizer::{
 #[cfg(feature = "internals")]
 pub use parser::ParseState;
 
+#[cfg(feature = "internals")]
+#[cfg(not(feature = "no_module"))]
+#[cfg(not(feature = "no_function"))]
+pub use parser::ModuleDef;
+
 #[cfg(feature = "internals")]
 pub use api::default_limits;
 
 #[cfg(feature = "internals")]
 pub use ast::{
-    ASTFlags, ASTNode, BinaryExpr, EncapsulatedEnviron, Expr, FlowControl, FnCallExpr,
+    ASTFlags, ASTNode, BinaryExpr, CatchClause, EncapsulatedEnviron, Expr, FlowControl, FnCallExpr,
     FnCallHashes, Ident, OpAssignment, RangeCase, ScriptFuncDef, Stmt, StmtBlock,
-    SwitchCasesCollection,
+    SwitchCasesCollection, TryCatchBlock,
 };
 
+#[cfg(feature = "internals")]
+#[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+pub use ast::Comprehension;
+
 #[cfg(feature = "internals")]
 #[cfg(not(feature = "no_custom_syntax"))]
 pub use ast::CustomExpr;
 
 #[cfg(feature = "internals")]
 #[cfg(not(feature = "no_module"))]
-pub use ast::Namespace;
+pub use ast::{ImportItems, Namespace};
 
 #[cfg(feature = "internals")]
 pub use eval::{Caches, FnResolutionCache, FnResolutionCacheEntry, GlobalRuntimeState, Target};
@@ -1289,9 +1289,17 @@ impl Module {
             num_params,
             param_types: FnArgsVec::new_const(),
             #[cfg(feature = "metadata")]
-            params_info: fn_def.params.iter().map(Into::into).collect(),
+            params_info: fn_def
+                .params
+                .iter()
+                .enumerate()
+                .map(|(i, p)| match fn_def.param_types.get(i) {
+                    Some(Some(t)) => format!("{p}: {t}").into(),
+                    _ => p.into(),
+                })
+                .collect(),
             #[cfg(feature = "metadata")]
-            return_type: <_>::default(),
+            return_type: fn_def.return_type.as_deref().unwrap_or_default().into(),
             #[cfg(feature = "metadata")]
             comments: crate::StaticVec::new_const(),
         };
@@ -1306,6 +1314,32 @@ impl Module {
         hash_script
     }
 
+    /// Set a script-defined function into the [`Module`], encapsulated with an environment.
+    ///
+    /// If there is an existing function of the same name and number of arguments, it is replaced.
+    #[cfg(not(feature = "no_module"))]
+    #[cfg(not(feature = "no_function"))]
+    #[inline]
+    pub(crate) fn set_script_fn_with_environ(
+        &mut self,
+        fn_def: impl Into<Shared<crate::ast::ScriptFuncDef>>,
+        environ: Shared<crate::ast::EncapsulatedEnviron>,
+    ) -> u64 {
+        let hash = self.set_script_fn(fn_def);
+
+        if let (
+            RhaiFunc::Script {
+                environ: ref mut e, ..
+            },
+            _,
+        ) = self.functions.as_mut().unwrap().get_mut(&hash).unwrap()
+        {
+            *e = Some(environ);
+        }
+
+        hash
+    }
+
     /// Get a shared reference to the script-defined function in the [`Module`] based on name
     /// and number of parameters.
     #[cfg(not(feature = "no_function"))]
@@ -1325,6 +1359,27 @@ impl Module {
         })
     }
 
+    /// Find a script-defined function in the [`Module`] based on name and a filter predicate.
+    ///
+    /// If multiple functions match, the one with the fewest number of parameters is returned.
+    #[cfg(not(feature = "no_function"))]
+    #[inline]
+    #[must_use]
+    pub(crate) fn find_script_fn(
+        &self,
+        name: &str,
+        filter: impl Fn(&crate::ast::ScriptFuncDef) -> bool,
+    ) -> Option<&RhaiFunc> {
+        self.functions.as_ref().and_then(|lib| {
+            lib.values()
+                .filter(|(_, m)| m.name == name)
+                .filter_map(|(f, _)| f.get_script_fn_def().map(|fn_def| (f, fn_def)))
+                .filter(|(_, fn_def)| filter(fn_def))
+                .min_by_key(|(_, fn_def)| fn_def.params.len())
+                .map(|(f, _)| f)
+        })
+    }
+
     /// Get a mutable reference to the underlying [`BTreeMap`] of sub-modules,
     /// creating one if empty.
     ///
@@ -1380,6 +1435,12 @@ impl Module {
     pub fn get_sub_module(&self, name: &str) -> Option<&Self> {
         self.modules.get(name).map(|m| &**m)
     }
+    /// Get a shared sub-module in the [`Module`].
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn get_shared_sub_module(&self, name: &str) -> Option<&SharedModule> {
+        self.modules.get(name)
+    }
 
     /// Set a sub-module into the [`Module`].
     ///
@@ -2121,6 +2182,75 @@ impl Module {
         self
     }
 
+    /// Create a new [`Module`] containing the non-private functions and variables of this
+    /// [`Module`] selected by a mapping function, with all functions in the global namespace.
+    ///
+    /// The mapping function is called with the name of each item and returns the name to import
+    /// the item as, or [`None`] to skip the item.
+    #[cfg(not(feature = "no_module"))]
+    #[must_use]
+    pub(crate) fn extract_items(
+        &self,
+        mut map_name: impl FnMut(&str) -> Option<ImmutableString>,
+    ) -> Self {
+        let mut module = Self::new();
+        module.id.clone_from(&self.id);
+
+        for (name, value) in &self.variables {
+            if let Some(alias) = map_name(name) {
+                module
+                    .variables
+                    .insert(alias.as_str().into(), value.clone());
+            }
+        }
+
+        for (f, m) in self.functions.iter().flatten().map(|(_, (f, m))| (f, m)) {
+            if m.access == FnAccess::Private {
+                continue;
+            }
+            let Some(alias) = map_name(&m.name) else {
+                continue;
+            };
+
+            let hash_base = calc_fn_hash(None, &alias, m.num_params);
+
+            let hash = if f.is_script() {
+                #[cfg(not(feature = "no_function"))]
+                #[cfg(not(feature = "no_object"))]
+                let hash_base = f
+                    .get_script_fn_def()
+                    .unwrap()
+                    .this_type
+                    .as_ref()
+                    .map_or(hash_base, |this_type| {
+                        crate::calc_typed_method_hash(hash_base, this_type)
+                    });
+                hash_base
+            } else {
+                if m.param_types.iter().any(|&t| t == TypeId::of::<Dynamic>()) {
+                    module.dynamic_functions_filter.mark(hash_base);
+                }
+                calc_fn_hash_full(hash_base, m.param_types.iter().copied())
+            };
+
+            let mut metadata = m.clone();
+            metadata.hash = hash;
+            metadata.name = alias.as_str().into();
+            metadata.namespace = FnNamespace::Global;
+
+            module
+                .functions
+                .get_or_insert_with(|| new_hash_map(FN_MAP_SIZE))
+                .insert(hash, (f.clone(), metadata));
+        }
+
+        module
+            .flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        module.build_index();
+        module
+    }
+
     /// Get the number of variables, functions and type iterators in the [`Module`].
     #[inline(always)]
     #[must_use]
@@ -2392,17 +2522,8 @@ impl Module {
                 FnAccess::Private => false,
             })
             .for_each(|f| {
-                let hash = module.set_script_fn(f.clone());
-                if let (
-                    RhaiFunc::Script {
-                        environ: ref mut e, ..
-                    },
-                    _,
-                ) = module.functions.as_mut().unwrap().get_mut(&hash).unwrap()
-                {
-                    // Encapsulate AST environment
-                    *e = Some(environ.clone());
-                }
+                // Encapsulate AST environment
+                module.set_script_fn_with_environ(f.clone(), environ.clone());
             });
 
         module.id = ast.source_raw().cloned();
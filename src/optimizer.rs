@@ -231,6 +231,12 @@ fn optimize_stmt_block(
                     };
                     state.push_var(x.0.name.clone(), value);
                 }
+                #[cfg(not(feature = "no_index"))]
+                Stmt::VarTuple(x, ..) => {
+                    optimize_expr(&mut x.1, state, false);
+                    x.0.iter()
+                        .for_each(|name| state.push_var(name.name.clone(), None));
+                }
                 // Optimize the statement
                 _ => optimize_stmt(stmt, state, preserve_result),
             }
@@ -500,7 +506,9 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
         }
         // if expr { if_block } else { else_block }
         Stmt::If(x, ..) => {
-            let FlowControl { expr, body, branch } = &mut **x;
+            let FlowControl {
+                expr, body, branch, ..
+            } = &mut **x;
             optimize_expr(expr, state, false);
             *body.statements_mut() =
                 optimize_stmt_block(body.take_statements(), state, preserve_result, true, false);
@@ -556,7 +564,13 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
                             let expr = b.lhs.take();
 
                             *stmt = Stmt::If(
-                                FlowControl { expr, body, branch }.into(),
+                                FlowControl {
+                                    expr,
+                                    body,
+                                    branch,
+                                    label: None,
+                                }
+                                .into(),
                                 match_expr.start_position(),
                             );
                         }
@@ -613,7 +627,13 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
                             let body = Stmt::Expr(expressions[r.index()].rhs.take().into()).into();
 
                             *stmt = Stmt::If(
-                                FlowControl { expr, body, branch }.into(),
+                                FlowControl {
+                                    expr,
+                                    body,
+                                    branch,
+                                    label: None,
+                                }
+                                .into(),
                                 match_expr.start_position(),
                             );
                         }
@@ -773,6 +793,9 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
         Stmt::Var(x, options, ..) if !options.intersects(ASTFlags::CONSTANT) => {
             optimize_expr(&mut x.1, state, false);
         }
+        // let (id, ...) = expr;
+        #[cfg(not(feature = "no_index"))]
+        Stmt::VarTuple(x, ..) => optimize_expr(&mut x.1, state, false),
         // import expr as var;
         #[cfg(not(feature = "no_module"))]
         Stmt::Import(x, ..) => optimize_expr(&mut x.0, state, false),
@@ -795,7 +818,7 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
             }
         }
         // try { pure try_block } catch ( var ) { catch_block } -> try_block
-        Stmt::TryCatch(x, ..) if x.body.iter().all(Stmt::is_pure) => {
+        Stmt::TryCatch(x, ..) if x.body.iter().all(Stmt::is_pure) && x.finally.is_empty() => {
             // If try block is pure, there will never be any exceptions
             state.set_dirty();
             let statements = x.body.take_statements();
@@ -805,12 +828,17 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
             );
             *stmt = Stmt::Block(block.into());
         }
-        // try { try_block } catch ( var ) { catch_block }
+        // try { try_block } catch ( var ) { catch_block } finally { finally_block }
         Stmt::TryCatch(x, ..) => {
             *x.body.statements_mut() =
                 optimize_stmt_block(x.body.take_statements(), state, false, true, false);
-            *x.branch.statements_mut() =
-                optimize_stmt_block(x.branch.take_statements(), state, false, true, false);
+            for clause in &mut x.catch_clauses {
+                optimize_expr(&mut clause.condition, state, false);
+                *clause.body.statements_mut() =
+                    optimize_stmt_block(clause.body.take_statements(), state, false, true, false);
+            }
+            *x.finally.statements_mut() =
+                optimize_stmt_block(x.finally.take_statements(), state, false, true, false);
         }
 
         // expr(stmt)
@@ -853,7 +881,7 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
         },
 
         // break expr;
-        Stmt::BreakLoop(Some(ref mut expr), ..) => optimize_expr(expr, state, false),
+        Stmt::BreakLoop(Some(ref mut x), ..) => optimize_expr(&mut x.0, state, false),
 
         // return expr;
         Stmt::Return(Some(ref mut expr), ..) => optimize_expr(expr, state, false),
@@ -1089,6 +1117,24 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
         // [ items .. ]
         #[cfg(not(feature = "no_index"))]
         Expr::Array(x, ..) => x.iter_mut().for_each(|expr| optimize_expr(expr, state, false)),
+        // #[ constant .. ]
+        #[cfg(not(feature = "no_index"))]
+        Expr::Set(..) if expr.is_constant() => {
+            state.set_dirty();
+            *expr = Expr::DynamicConstant(expr.get_literal_value().unwrap().into(), expr.position());
+        }
+        // #[ items .. ]
+        #[cfg(not(feature = "no_index"))]
+        Expr::Set(x, ..) => x.iter_mut().for_each(|expr| optimize_expr(expr, state, false)),
+        // ( constant .. )
+        #[cfg(not(feature = "no_index"))]
+        Expr::Tuple(..) if expr.is_constant() => {
+            state.set_dirty();
+            *expr = Expr::DynamicConstant(expr.get_literal_value().unwrap().into(), expr.position());
+        }
+        // ( items .. )
+        #[cfg(not(feature = "no_index"))]
+        Expr::Tuple(x, ..) => x.iter_mut().for_each(|expr| optimize_expr(expr, state, false)),
         // #{ key:constant, .. }
         #[cfg(not(feature = "no_object"))]
         Expr::Map(..) if expr.is_constant() => {
@@ -1144,6 +1190,14 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
             }
         }
 
+        // id(args .., name: expr) or id(args .., ...expr) -> optimize function call arguments
+        Expr::FnCall(x, ..) if !x.named_args.is_empty() || x.has_spread_args() => x.args.iter_mut().for_each(|arg_expr| {
+            optimize_expr(arg_expr, state, false);
+
+            if move_constant_arg(arg_expr) {
+                state.set_dirty();
+            }
+        }),
         // nnn::id(args ..) -> optimize function call arguments
         #[cfg(not(feature = "no_module"))]
         Expr::FnCall(x, ..) if x.is_qualified() => x.args.iter_mut().for_each(|arg_expr| {
@@ -1239,7 +1293,8 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
             // First search for script-defined functions (can override built-in)
             let _has_script_fn = false;
             #[cfg(not(feature = "no_function"))]
-            let _has_script_fn = !x.hashes.is_native_only() && state.global.lib.iter().find_map(|m| m.get_script_fn(&x.name, x.args.len())).is_some();
+            let _has_script_fn = (!x.hashes.is_native_only() || x.is_operator_call() && x.args.iter().any(|a| a.get_literal_value().map_or(false, |v| crate::func::call::is_script_operator_operand(&v))))
+                                    && state.global.lib.iter().find_map(|m| m.get_script_fn(&x.name, x.args.len())).is_some();
 
             if !_has_script_fn {
                 let arg_values = &mut x.args.iter().map(Expr::get_literal_value).collect::<Option<FnArgsVec<_>>>().unwrap();
@@ -1278,6 +1333,29 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
             state.set_dirty();
         }
 
+        // ...expr
+        Expr::Spread(x, ..) => optimize_expr(x, state, false),
+
+        // [ expr for var in expr if expr ] or #{ key: expr for var in expr if expr }
+        Expr::Comprehension(x, ..) => {
+            optimize_expr(&mut x.iterable, state, false);
+
+            // The loop variables shadow any constants of the same names
+            let orig_len = state.variables.len();
+            if let Some(ref counter) = x.counter {
+                state.push_var(counter.name.clone(), None);
+            }
+            state.push_var(x.var.name.clone(), None);
+
+            optimize_expr(&mut x.condition, state, false);
+            if let Some(ref mut key) = x.key {
+                optimize_expr(key, state, false);
+            }
+            optimize_expr(&mut x.value, state, false);
+
+            state.rewind_var(orig_len);
+        }
+
         // Custom syntax
         #[cfg(not(feature = "no_custom_syntax"))]
         Expr::Custom(x, ..) => {
@@ -1,5 +1,6 @@
 use crate::plugin::*;
 use crate::{def_package, Position, RhaiError, RhaiResultOf, ERR, INT};
+use std::convert::TryFrom;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -13,6 +14,113 @@ pub fn make_err(msg: impl Into<String>) -> RhaiError {
     ERR::ErrorArithmetic(msg.into(), Position::NONE).into()
 }
 
+/// Create a reduced [`Rational`][crate::Rational] from a `numerator` and a `denominator`,
+/// returning [`None`] if the denominator is zero or the reduced form does not fit into [`INT`].
+///
+/// Unlike [`Rational::new`][crate::Rational::new], this never panics.
+#[cfg(feature = "rational")]
+pub fn make_rational(numer: INT, denom: INT) -> Option<crate::Rational> {
+    if denom == 0 {
+        return None;
+    }
+
+    let (mut numer, mut denom) = (i128::from(numer), i128::from(denom));
+    let (mut a, mut b) = (numer.unsigned_abs(), denom.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    let gcd = a as i128;
+    numer /= gcd;
+    denom /= gcd;
+
+    if denom < 0 {
+        numer = -numer;
+        denom = -denom;
+    }
+
+    Some(crate::Rational::new_raw(
+        INT::try_from(numer).ok()?,
+        INT::try_from(denom).ok()?,
+    ))
+}
+
+/// Convert an exponent into a [`u32`], raising an error if it is negative or too large.
+#[inline]
+fn to_exponent(y: INT) -> RhaiResultOf<u32> {
+    u32::try_from(y).map_err(|_| {
+        if y < 0 {
+            make_err(format!("Integer raised to a negative power: {y}"))
+        } else {
+            make_err(format!("Exponential overflow: {y}"))
+        }
+    })
+}
+
+/// Default maximum number of bits in a [`BigInt`][num_bigint::BigInt] produced by raising to a
+/// power or shifting left.
+#[cfg(feature = "bigint")]
+const MAX_BIGINT_BITS: u64 = 1 << 20;
+
+/// Raise an error if a [`BigInt`][num_bigint::BigInt] with an estimated number of `bits` is too
+/// large, to avoid hanging or running out of memory while computing it.
+#[cfg(feature = "bigint")]
+fn check_bigint_bits(_ctx: &NativeCallContext, bits: Option<u64>) -> RhaiResultOf<()> {
+    #[cfg(not(feature = "unchecked"))]
+    let max = match _ctx.engine().max_bigint_bits() {
+        0 => MAX_BIGINT_BITS,
+        n => n,
+    };
+    #[cfg(feature = "unchecked")]
+    let max = MAX_BIGINT_BITS;
+
+    if bits.map_or(true, |n| n > max) {
+        Err(ERR::ErrorDataTooLarge("Size of big integer".to_string(), Position::NONE).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Calculate `base ** exp % modulus` without overflow.
+///
+/// `base` must be less than `modulus`.
+fn pow_mod_u128(mut base: u128, mut exp: u64, modulus: u128) -> u128 {
+    // (a + b) % modulus, for a, b < modulus
+    let add_mod = |a: u128, b: u128| {
+        if a >= modulus - b {
+            a - (modulus - b)
+        } else {
+            a + b
+        }
+    };
+    // (a * b) % modulus by double-and-add, for a, b < modulus
+    let mul_mod = |mut a: u128, mut b: u128| {
+        let mut result = 0;
+        while b > 0 {
+            if b & 1 == 1 {
+                result = add_mod(result, a);
+            }
+            a = add_mod(a, a);
+            b >>= 1;
+        }
+        result
+    };
+
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base);
+        }
+        base = mul_mod(base, base);
+        exp >>= 1;
+    }
+
+    result
+}
+
 macro_rules! gen_arithmetic_functions {
     ($root:ident => $($arg_type:ident),+) => {
         #[allow(non_snake_case)]
@@ -187,6 +295,177 @@ macro_rules! gen_signed_functions {
     }
 }
 
+macro_rules! gen_integer_functions {
+    ($root:ident => $($arg_type:ident),+) => {
+        #[allow(non_snake_case)]
+        pub mod $root { $(pub mod $arg_type {
+            use super::super::*;
+
+            #[export_module]
+            pub mod functions {
+                /// Add two numbers, wrapping around at the boundary of the type.
+                pub const fn wrapping_add(x: $arg_type, y: $arg_type) -> $arg_type {
+                    x.wrapping_add(y)
+                }
+                /// Subtract two numbers, wrapping around at the boundary of the type.
+                pub const fn wrapping_sub(x: $arg_type, y: $arg_type) -> $arg_type {
+                    x.wrapping_sub(y)
+                }
+                /// Multiply two numbers, wrapping around at the boundary of the type.
+                pub const fn wrapping_mul(x: $arg_type, y: $arg_type) -> $arg_type {
+                    x.wrapping_mul(y)
+                }
+                /// Raise a number to the power of `y`, wrapping around at the boundary of the type.
+                ///
+                /// An error is raised if `y` is negative or too large.
+                #[rhai_fn(return_raw)]
+                pub fn wrapping_pow(x: $arg_type, y: INT) -> RhaiResultOf<$arg_type> {
+                    Ok(x.wrapping_pow(to_exponent(y)?))
+                }
+                /// Add two numbers, saturating at the boundary of the type instead of overflowing.
+                pub const fn saturating_add(x: $arg_type, y: $arg_type) -> $arg_type {
+                    x.saturating_add(y)
+                }
+                /// Subtract two numbers, saturating at the boundary of the type instead of overflowing.
+                pub const fn saturating_sub(x: $arg_type, y: $arg_type) -> $arg_type {
+                    x.saturating_sub(y)
+                }
+                /// Multiply two numbers, saturating at the boundary of the type instead of overflowing.
+                pub const fn saturating_mul(x: $arg_type, y: $arg_type) -> $arg_type {
+                    x.saturating_mul(y)
+                }
+                /// Raise a number to the power of `y`, saturating at the boundary of the type instead of overflowing.
+                ///
+                /// An error is raised if `y` is negative or too large.
+                #[rhai_fn(return_raw)]
+                pub fn saturating_pow(x: $arg_type, y: INT) -> RhaiResultOf<$arg_type> {
+                    Ok(x.saturating_pow(to_exponent(y)?))
+                }
+                /// Add two numbers, returning `()` on overflow.
+                pub fn checked_add(x: $arg_type, y: $arg_type) -> Dynamic {
+                    x.checked_add(y).map_or(Dynamic::UNIT, Dynamic::from)
+                }
+                /// Subtract two numbers, returning `()` on overflow.
+                pub fn checked_sub(x: $arg_type, y: $arg_type) -> Dynamic {
+                    x.checked_sub(y).map_or(Dynamic::UNIT, Dynamic::from)
+                }
+                /// Multiply two numbers, returning `()` on overflow.
+                pub fn checked_mul(x: $arg_type, y: $arg_type) -> Dynamic {
+                    x.checked_mul(y).map_or(Dynamic::UNIT, Dynamic::from)
+                }
+                /// Divide two numbers, returning `()` on division by zero or overflow.
+                pub fn checked_div(x: $arg_type, y: $arg_type) -> Dynamic {
+                    x.checked_div(y).map_or(Dynamic::UNIT, Dynamic::from)
+                }
+                /// Return the remainder of dividing two numbers, returning `()` on division by zero or overflow.
+                pub fn checked_rem(x: $arg_type, y: $arg_type) -> Dynamic {
+                    x.checked_rem(y).map_or(Dynamic::UNIT, Dynamic::from)
+                }
+                /// Raise a number to the power of `y`, returning `()` on overflow or if `y` is negative.
+                pub fn checked_pow(x: $arg_type, y: INT) -> Dynamic {
+                    u32::try_from(y)
+                        .ok()
+                        .and_then(|y| x.checked_pow(y))
+                        .map_or(Dynamic::UNIT, Dynamic::from)
+                }
+                /// Add two numbers, returning a tuple of the wrapped result and whether an overflow occurred.
+                ///
+                /// # Example
+                ///
+                /// ```rhai
+                /// let (sum, overflow) = overflowing_add(9223372036854775807, 1);
+                ///
+                /// print(overflow);    // prints true
+                /// ```
+                #[cfg(not(feature = "no_index"))]
+                pub fn overflowing_add(x: $arg_type, y: $arg_type) -> crate::Tuple {
+                    x.overflowing_add(y).into()
+                }
+                /// Subtract two numbers, returning a tuple of the wrapped result and whether an overflow occurred.
+                #[cfg(not(feature = "no_index"))]
+                pub fn overflowing_sub(x: $arg_type, y: $arg_type) -> crate::Tuple {
+                    x.overflowing_sub(y).into()
+                }
+                /// Multiply two numbers, returning a tuple of the wrapped result and whether an overflow occurred.
+                #[cfg(not(feature = "no_index"))]
+                pub fn overflowing_mul(x: $arg_type, y: $arg_type) -> crate::Tuple {
+                    x.overflowing_mul(y).into()
+                }
+                /// Raise a number to the power of `y`, returning a tuple of the wrapped result and whether an overflow occurred.
+                ///
+                /// An error is raised if `y` is negative or too large.
+                #[cfg(not(feature = "no_index"))]
+                #[rhai_fn(return_raw)]
+                pub fn overflowing_pow(x: $arg_type, y: INT) -> RhaiResultOf<crate::Tuple> {
+                    Ok(x.overflowing_pow(to_exponent(y)?).into())
+                }
+                /// Raise a number to the power of `y` modulo `modulus`, without any intermediate overflow.
+                ///
+                /// The result is always between zero and `modulus` (exclusive).
+                /// An error is raised if `y` is negative or `modulus` is not positive.
+                ///
+                /// # Example
+                ///
+                /// ```rhai
+                /// print(pow_mod(4, 13, 497));     // prints 445
+                /// ```
+                #[rhai_fn(return_raw)]
+                pub fn pow_mod(x: $arg_type, y: INT, modulus: $arg_type) -> RhaiResultOf<$arg_type> {
+                    if modulus < 1 {
+                        return Err(make_err(format!("Invalid modulus: pow_mod({x}, {y}, {modulus})")));
+                    }
+                    let exp = u64::try_from(y).map_err(|_| {
+                        make_err(format!("Integer raised to a negative power: pow_mod({x}, {y}, {modulus})"))
+                    })?;
+
+                    // The base is reduced to the range [0, modulus), so it always fits into u128
+                    let base = x.rem_euclid(modulus) as u128;
+
+                    Ok(pow_mod_u128(base, exp, modulus as u128) as $arg_type)
+                }
+                /// Rotate the bits of the number to the left by `n` positions,
+                /// wrapping the truncated bits to the end.
+                ///
+                /// A negative `n` rotates to the right instead.
+                pub fn rotate_left(x: $arg_type, n: INT) -> $arg_type {
+                    x.rotate_left(n.rem_euclid(<$arg_type>::BITS as INT) as u32)
+                }
+                /// Rotate the bits of the number to the right by `n` positions,
+                /// wrapping the truncated bits to the beginning.
+                ///
+                /// A negative `n` rotates to the left instead.
+                pub fn rotate_right(x: $arg_type, n: INT) -> $arg_type {
+                    x.rotate_right(n.rem_euclid(<$arg_type>::BITS as INT) as u32)
+                }
+                /// Return the number of ones in the binary representation of the number.
+                pub const fn count_ones(x: $arg_type) -> INT {
+                    x.count_ones() as INT
+                }
+                /// Return the number of zeros in the binary representation of the number.
+                pub const fn count_zeros(x: $arg_type) -> INT {
+                    x.count_zeros() as INT
+                }
+                /// Return the number of leading zeros in the binary representation of the number.
+                pub const fn leading_zeros(x: $arg_type) -> INT {
+                    x.leading_zeros() as INT
+                }
+                /// Return the number of trailing zeros in the binary representation of the number.
+                pub const fn trailing_zeros(x: $arg_type) -> INT {
+                    x.trailing_zeros() as INT
+                }
+                /// Reverse the byte order of the number.
+                pub const fn swap_bytes(x: $arg_type) -> $arg_type {
+                    x.swap_bytes()
+                }
+                /// Reverse the order of bits in the number.
+                pub const fn reverse_bits(x: $arg_type) -> $arg_type {
+                    x.reverse_bits()
+                }
+            }
+        })* }
+    }
+}
+
 macro_rules! reg_functions {
     ($mod_name:ident += $root:ident ; $($arg_type:ident),+ ) => { $(
         combine_with_exported_module!($mod_name, "arithmetic", $root::$arg_type::functions);
@@ -200,6 +479,7 @@ def_package! {
 
         combine_with_exported_module!(lib, "int", int_functions);
         reg_functions!(lib += signed_basic; INT);
+        reg_functions!(lib += int_ops_basic; INT);
 
         #[cfg(not(feature = "only_i32"))]
         #[cfg(not(feature = "only_i64"))]
@@ -208,6 +488,8 @@ def_package! {
             reg_functions!(lib += arith_numbers; i8, u8, i16, u16, i32, u32, u64);
             gen_signed_functions!(signed_numbers => i8, i16, i32);
             reg_functions!(lib += signed_numbers; i8, i16, i32);
+            gen_integer_functions!(int_ops_numbers => i8, u8, i16, u16, i32, u32, u64);
+            reg_functions!(lib += int_ops_numbers; i8, u8, i16, u16, i32, u32, u64);
 
             #[cfg(not(target_family = "wasm"))]
             {
@@ -215,6 +497,8 @@ def_package! {
                 reg_functions!(lib += arith_numbers; i128, u128);
                 gen_signed_functions!(signed_numbers => i128);
                 reg_functions!(lib += signed_numbers; i128);
+                gen_integer_functions!(int_ops_numbers => i128, u128);
+                reg_functions!(lib += int_ops_numbers; i128, u128);
             }
         }
 
@@ -228,6 +512,14 @@ def_package! {
         // Decimal functions
         #[cfg(feature = "decimal")]
         combine_with_exported_module!(lib, "decimal", decimal_functions);
+
+        // BigInt functions
+        #[cfg(feature = "bigint")]
+        combine_with_exported_module!(lib, "bigint", bigint_functions);
+
+        // Rational functions
+        #[cfg(feature = "rational")]
+        combine_with_exported_module!(lib, "rational", rational_functions);
     }
 }
 
@@ -252,6 +544,7 @@ mod int_functions {
 
 gen_arithmetic_functions!(arith_basic => INT);
 gen_signed_functions!(signed_basic => INT);
+gen_integer_functions!(int_ops_basic => INT);
 
 #[cfg(not(feature = "no_float"))]
 #[export_module]
@@ -555,3 +848,252 @@ pub mod decimal_functions {
         x.is_zero()
     }
 }
+
+#[cfg(feature = "bigint")]
+#[export_module]
+pub mod bigint_functions {
+    use num_bigint::{BigInt, Sign};
+    use num_traits::{ToPrimitive, Zero};
+
+    pub mod builtin {
+        #[rhai_fn(return_raw)]
+        pub fn divide(x: BigInt, y: BigInt) -> RhaiResultOf<BigInt> {
+            // Detect division by zero
+            if y.is_zero() {
+                Err(make_err(format!("Division by zero: {x} / {y}")))
+            } else {
+                Ok(x / y)
+            }
+        }
+        #[rhai_fn(return_raw)]
+        pub fn modulo(x: BigInt, y: BigInt) -> RhaiResultOf<BigInt> {
+            // Detect division by zero
+            if y.is_zero() {
+                Err(make_err(format!("Modulo division by zero: {x} % {y}")))
+            } else {
+                Ok(x % y)
+            }
+        }
+        #[rhai_fn(return_raw)]
+        pub fn power(ctx: NativeCallContext, x: BigInt, y: BigInt) -> RhaiResultOf<BigInt> {
+            if y.sign() == Sign::Minus {
+                return Err(make_err(format!(
+                    "Integer raised to a negative power: {x} ** {y}"
+                )));
+            }
+            let Some(n) = y.to_u32() else {
+                return Err(make_err(format!("Exponential overflow: {x} ** {y}")));
+            };
+            // The result has at least `(bits - 1) * n + 1` bits, except for zero, one and minus one
+            if x.bits() > 1 {
+                let bits = (x.bits() - 1)
+                    .checked_mul(u64::from(n))
+                    .and_then(|b| b.checked_add(1));
+                check_bigint_bits(&ctx, bits)?;
+            }
+            Ok(x.pow(n))
+        }
+        #[rhai_fn(return_raw)]
+        pub fn shift_left(ctx: NativeCallContext, x: BigInt, y: BigInt) -> RhaiResultOf<BigInt> {
+            if y.sign() == Sign::Minus {
+                return shift_right(ctx, x, -y);
+            }
+            let Some(n) = y.to_u32() else {
+                return Err(make_err(format!("Shift overflow: {x} << {y}")));
+            };
+            if !x.is_zero() {
+                check_bigint_bits(&ctx, x.bits().checked_add(u64::from(n)))?;
+            }
+            Ok(x << n)
+        }
+        #[rhai_fn(return_raw)]
+        pub fn shift_right(ctx: NativeCallContext, x: BigInt, y: BigInt) -> RhaiResultOf<BigInt> {
+            if y.sign() == Sign::Minus {
+                return shift_left(ctx, x, -y);
+            }
+            // Shifting out all the bits leaves only the sign
+            Ok(match y.to_u64() {
+                Some(n) => x >> n,
+                None if x.sign() == Sign::Minus => BigInt::from(-1),
+                None => BigInt::zero(),
+            })
+        }
+    }
+    #[rhai_fn(name = "-")]
+    pub fn neg(x: BigInt) -> BigInt {
+        -x
+    }
+    #[rhai_fn(name = "+")]
+    pub const fn plus(x: BigInt) -> BigInt {
+        x
+    }
+    /// Return the absolute value of the big integer.
+    pub fn abs(x: BigInt) -> BigInt {
+        if x.sign() == Sign::Minus {
+            -x
+        } else {
+            x
+        }
+    }
+    /// Return the sign (as an integer) of the big integer according to the following:
+    ///
+    /// * `0` if the number is zero
+    /// * `1` if the number is positive
+    /// * `-1` if the number is negative
+    pub fn sign(x: BigInt) -> INT {
+        match x.sign() {
+            Sign::Minus => -1,
+            Sign::NoSign => 0,
+            Sign::Plus => 1,
+        }
+    }
+    /// Return true if the big integer is zero.
+    #[rhai_fn(get = "is_zero", name = "is_zero")]
+    pub fn is_zero(x: BigInt) -> bool {
+        x.is_zero()
+    }
+    /// Return true if the big integer is odd.
+    #[rhai_fn(get = "is_odd", name = "is_odd")]
+    pub fn is_odd(x: BigInt) -> bool {
+        x.bit(0)
+    }
+    /// Return true if the big integer is even.
+    #[rhai_fn(get = "is_even", name = "is_even")]
+    pub fn is_even(x: BigInt) -> bool {
+        !x.bit(0)
+    }
+}
+
+#[cfg(feature = "rational")]
+#[export_module]
+pub mod rational_functions {
+    use crate::Rational;
+    use num_traits::{Signed, Zero};
+
+    pub mod builtin {
+        use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, ToPrimitive};
+
+        #[rhai_fn(return_raw)]
+        pub fn add(x: Rational, y: Rational) -> RhaiResultOf<Rational> {
+            if cfg!(not(feature = "unchecked")) {
+                x.checked_add(&y)
+                    .ok_or_else(|| make_err(format!("Addition overflow: {x} + {y}")))
+            } else {
+                Ok(x + y)
+            }
+        }
+        #[rhai_fn(return_raw)]
+        pub fn subtract(x: Rational, y: Rational) -> RhaiResultOf<Rational> {
+            if cfg!(not(feature = "unchecked")) {
+                x.checked_sub(&y)
+                    .ok_or_else(|| make_err(format!("Subtraction overflow: {x} - {y}")))
+            } else {
+                Ok(x - y)
+            }
+        }
+        #[rhai_fn(return_raw)]
+        pub fn multiply(x: Rational, y: Rational) -> RhaiResultOf<Rational> {
+            if cfg!(not(feature = "unchecked")) {
+                x.checked_mul(&y)
+                    .ok_or_else(|| make_err(format!("Multiplication overflow: {x} * {y}")))
+            } else {
+                Ok(x * y)
+            }
+        }
+        #[rhai_fn(return_raw)]
+        pub fn divide(x: Rational, y: Rational) -> RhaiResultOf<Rational> {
+            // Detect division by zero
+            if y.is_zero() {
+                Err(make_err(format!("Division by zero: {x} / {y}")))
+            } else if cfg!(not(feature = "unchecked")) {
+                x.checked_div(&y)
+                    .ok_or_else(|| make_err(format!("Division overflow: {x} / {y}")))
+            } else {
+                Ok(x / y)
+            }
+        }
+        #[rhai_fn(return_raw)]
+        pub fn modulo(x: Rational, y: Rational) -> RhaiResultOf<Rational> {
+            // Detect division by zero
+            if y.is_zero() {
+                return Err(make_err(format!("Modulo division by zero: {x} % {y}")));
+            }
+
+            // x % y = x - y * trunc(x / y)
+            divide(x, y)
+                .map(|q| q.trunc())
+                .and_then(|q| multiply(y, q))
+                .and_then(|v| subtract(x, v))
+        }
+        #[rhai_fn(return_raw)]
+        pub fn power(x: Rational, y: Rational) -> RhaiResultOf<Rational> {
+            if !y.is_integer() {
+                return Err(make_err(format!(
+                    "Rational number raised to a fractional power: {x} ** {y}"
+                )));
+            }
+            if x.is_zero() && *y.numer() < 0 {
+                return Err(make_err(format!("Division by zero: {x} ** {y}")));
+            }
+
+            let Some(n) = y.numer().unsigned_abs().to_u32() else {
+                return Err(make_err(format!("Exponential overflow: {x} ** {y}")));
+            };
+            let (numer, denom) = if cfg!(not(feature = "unchecked")) {
+                match (x.numer().checked_pow(n), x.denom().checked_pow(n)) {
+                    (Some(numer), Some(denom)) => (numer, denom),
+                    _ => return Err(make_err(format!("Exponential overflow: {x} ** {y}"))),
+                }
+            } else {
+                (x.numer().pow(n), x.denom().pow(n))
+            };
+
+            let (numer, denom) = if *y.numer() < 0 {
+                (denom, numer)
+            } else {
+                (numer, denom)
+            };
+            super::super::make_rational(numer, denom)
+                .ok_or_else(|| make_err(format!("Exponential overflow: {x} ** {y}")))
+        }
+    }
+    #[rhai_fn(name = "-", return_raw)]
+    pub fn neg(x: Rational) -> RhaiResultOf<Rational> {
+        x.numer()
+            .checked_neg()
+            .map(|numer| Rational::new_raw(numer, *x.denom()))
+            .ok_or_else(|| make_err(format!("Negation overflow: -{x}")))
+    }
+    #[rhai_fn(name = "+")]
+    pub const fn plus(x: Rational) -> Rational {
+        x
+    }
+    /// Return the absolute value of the rational number.
+    #[rhai_fn(return_raw)]
+    pub fn abs(x: Rational) -> RhaiResultOf<Rational> {
+        if x.is_negative() {
+            neg(x)
+        } else {
+            Ok(x)
+        }
+    }
+    /// Return the sign (as an integer) of the rational number according to the following:
+    ///
+    /// * `0` if the number is zero
+    /// * `1` if the number is positive
+    /// * `-1` if the number is negative
+    pub fn sign(x: Rational) -> INT {
+        if x.is_zero() {
+            0
+        } else if x.is_negative() {
+            -1
+        } else {
+            1
+        }
+    }
+    /// Return true if the rational number is zero.
+    #[rhai_fn(get = "is_zero", name = "is_zero")]
+    pub fn is_zero(x: Rational) -> bool {
+        x.is_zero()
+    }
+}
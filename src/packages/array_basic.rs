@@ -18,6 +18,7 @@ def_package! {
         lib.set_standard_lib(true);
 
         combine_with_exported_module!(lib, "array", array_functions);
+        combine_with_exported_module!(lib, "array_stats", array_stats_functions);
         combine_with_exported_module!(lib, "deprecated_array", deprecated_array_functions);
 
         // Register array iterator
@@ -25,6 +26,48 @@ def_package! {
     }
 }
 
+/// Find the first element in an array that compares as the most `extreme` (i.e. [`Ordering::Less`]
+/// for the smallest and [`Ordering::Greater`] for the largest) according to a `comparer` function.
+fn find_extreme_by(
+    ctx: &NativeCallContext,
+    array: &Array,
+    comparer: &FnPtr,
+    extreme: Ordering,
+) -> RhaiResult {
+    let mut result: Option<&Dynamic> = None;
+
+    for item in array {
+        let Some(current) = result else {
+            result = Some(item);
+            continue;
+        };
+
+        let value = comparer.call_raw(ctx, None, [item.clone(), current.clone()])?;
+
+        let ordering = match value.as_int() {
+            Ok(v) => v.cmp(&0),
+            Err(_) => match value.as_bool() {
+                Ok(true) => Ordering::Less,
+                Ok(false) => Ordering::Greater,
+                Err(typ) => {
+                    return Err(ERR::ErrorMismatchOutputType(
+                        "integer".to_string(),
+                        ctx.engine().map_type_name(typ).to_string(),
+                        Position::NONE,
+                    )
+                    .into())
+                }
+            },
+        };
+
+        if ordering == extreme {
+            result = Some(item);
+        }
+    }
+
+    Ok(result.cloned().unwrap_or(Dynamic::UNIT))
+}
+
 #[export_module]
 pub mod array_functions {
     /// Number of elements in the array.
@@ -1606,6 +1649,7 @@ pub mod array_functions {
         if array.iter().any(|a| a.type_id() != type_id) {
             return Err(ERR::ErrorFunctionNotFound(
                 "sort() cannot be called with elements of different types".into(),
+                Vec::new(),
                 Position::NONE,
             )
             .into());
@@ -1667,6 +1711,56 @@ pub mod array_functions {
 
         Ok(())
     }
+    /// Return the first element in the array that is the smallest according to a `comparer`
+    /// function, or `()` if the array is empty.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element1`: copy of the current array element to compare
+    /// * `element2`: copy of the next array element to compare
+    ///
+    /// ## Return Value
+    ///
+    /// * Any integer > 0 if `element1 > element2`
+    /// * Zero if `element1 == element2`
+    /// * Any integer < 0 if `element1 < element2`
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = ["apple", "fig", "banana", "kiwi"];
+    ///
+    /// print(x.min_by(|a, b| a.len() - b.len()));      // prints "fig"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn min_by(ctx: NativeCallContext, array: &mut Array, comparer: FnPtr) -> RhaiResult {
+        find_extreme_by(&ctx, array, &comparer, Ordering::Less)
+    }
+    /// Return the first element in the array that is the largest according to a `comparer`
+    /// function, or `()` if the array is empty.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element1`: copy of the current array element to compare
+    /// * `element2`: copy of the next array element to compare
+    ///
+    /// ## Return Value
+    ///
+    /// * Any integer > 0 if `element1 > element2`
+    /// * Zero if `element1 == element2`
+    /// * Any integer < 0 if `element1 < element2`
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = ["apple", "fig", "banana", "kiwi"];
+    ///
+    /// print(x.max_by(|a, b| a.len() - b.len()));      // prints "banana"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn max_by(ctx: NativeCallContext, array: &mut Array, comparer: FnPtr) -> RhaiResult {
+        find_extreme_by(&ctx, array, &comparer, Ordering::Greater)
+    }
     /// Remove all elements in the array that returns `true` when applied the `filter` function and
     /// return them as a new array.
     ///
@@ -2039,3 +2133,648 @@ pub mod array_functions {
         equals(ctx, array1, array2).map(|r| !r)
     }
 }
+
+/// Numeric elements of an array, promoted to a common type.
+enum Numbers {
+    Int(Vec<INT>),
+    #[cfg(not(feature = "no_float"))]
+    Float(Vec<crate::FLOAT>),
+    #[cfg(feature = "decimal")]
+    Decimal(Vec<rust_decimal::Decimal>),
+}
+
+impl Numbers {
+    /// Collect the elements of an array.
+    ///
+    /// Integers are promoted to floating-point or decimal numbers if the array contains any.
+    /// Floating-point and decimal numbers cannot be mixed.
+    fn from_array(ctx: &NativeCallContext, array: &Array) -> RhaiResultOf<Self> {
+        #[cfg(not(feature = "no_float"))]
+        let mut has_float = false;
+        #[cfg(feature = "decimal")]
+        let mut has_decimal = false;
+
+        for item in array {
+            if item.is_int() {
+                continue;
+            }
+            #[cfg(not(feature = "no_float"))]
+            if item.is_float() {
+                has_float = true;
+                continue;
+            }
+            #[cfg(feature = "decimal")]
+            if item.is_decimal() {
+                has_decimal = true;
+                continue;
+            }
+
+            return Err(ERR::ErrorMismatchDataType(
+                "number".to_string(),
+                ctx.engine().map_type_name(item.type_name()).to_string(),
+                Position::NONE,
+            )
+            .into());
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        #[cfg(feature = "decimal")]
+        if has_float && has_decimal {
+            return Err(ERR::ErrorMismatchDataType(
+                "decimal".to_string(),
+                "float".to_string(),
+                Position::NONE,
+            )
+            .into());
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        if has_float {
+            return Ok(Self::Float(
+                array
+                    .iter()
+                    .map(|v| v.as_float().unwrap_or_else(|_| v.as_int().unwrap() as _))
+                    .collect(),
+            ));
+        }
+
+        #[cfg(feature = "decimal")]
+        if has_decimal {
+            return Ok(Self::Decimal(
+                array
+                    .iter()
+                    .map(|v| {
+                        v.as_decimal()
+                            .unwrap_or_else(|_| v.as_int().unwrap().into())
+                    })
+                    .collect(),
+            ));
+        }
+
+        Ok(Self::Int(
+            array.iter().map(|v| v.as_int().unwrap()).collect(),
+        ))
+    }
+}
+
+/// Maximum number of bins in a histogram, regardless of the limit on array sizes.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+const MAX_HISTOGRAM_BINS: INT = 1 << 20;
+
+/// Make an error for arithmetic overflow in a statistics function.
+#[cold]
+#[inline(never)]
+fn stats_overflow_err(fn_name: &str) -> crate::RhaiError {
+    ERR::ErrorArithmetic(format!("Arithmetic overflow in {fn_name}"), Position::NONE).into()
+}
+
+/// Make an error for a NaN or infinite number in a statistics function.
+#[cold]
+#[inline(never)]
+fn stats_non_finite_err(fn_name: &str) -> crate::RhaiError {
+    ERR::ErrorArithmetic(format!("Non-finite number in {fn_name}"), Position::NONE).into()
+}
+
+/// A number type supporting fractions, used in statistics functions.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+trait Real: Copy {
+    fn from_count(n: usize) -> Self;
+    fn checked_add(self, y: Self) -> Option<Self>;
+    fn checked_sub(self, y: Self) -> Option<Self>;
+    fn checked_mul(self, y: Self) -> Option<Self>;
+    fn checked_div(self, y: Self) -> Option<Self>;
+    fn square_root(self) -> Self;
+    /// Is the number neither NaN nor infinite?
+    fn is_finite_number(self) -> bool;
+    /// Truncate a non-negative number into an index.
+    fn to_index(self) -> usize;
+    fn compare(&self, y: &Self) -> Ordering;
+    fn into_dynamic(self) -> Dynamic;
+}
+
+#[cfg(not(feature = "no_float"))]
+impl Real for crate::FLOAT {
+    #[inline(always)]
+    fn from_count(n: usize) -> Self {
+        n as Self
+    }
+    #[inline(always)]
+    fn checked_add(self, y: Self) -> Option<Self> {
+        Some(self + y)
+    }
+    #[inline(always)]
+    fn checked_sub(self, y: Self) -> Option<Self> {
+        Some(self - y)
+    }
+    #[inline(always)]
+    fn checked_mul(self, y: Self) -> Option<Self> {
+        Some(self * y)
+    }
+    #[inline(always)]
+    fn checked_div(self, y: Self) -> Option<Self> {
+        Some(self / y)
+    }
+    #[inline(always)]
+    fn square_root(self) -> Self {
+        #[cfg(feature = "no_std")]
+        use num_traits::Float;
+
+        self.sqrt()
+    }
+    #[inline(always)]
+    fn is_finite_number(self) -> bool {
+        self.is_finite()
+    }
+    #[inline(always)]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn to_index(self) -> usize {
+        self as usize
+    }
+    #[inline(always)]
+    fn compare(&self, y: &Self) -> Ordering {
+        self.total_cmp(y)
+    }
+    #[inline(always)]
+    fn into_dynamic(self) -> Dynamic {
+        Dynamic::from_float(self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Real for rust_decimal::Decimal {
+    #[inline(always)]
+    fn from_count(n: usize) -> Self {
+        n.into()
+    }
+    #[inline(always)]
+    fn checked_add(self, y: Self) -> Option<Self> {
+        Self::checked_add(self, y)
+    }
+    #[inline(always)]
+    fn checked_sub(self, y: Self) -> Option<Self> {
+        Self::checked_sub(self, y)
+    }
+    #[inline(always)]
+    fn checked_mul(self, y: Self) -> Option<Self> {
+        Self::checked_mul(self, y)
+    }
+    #[inline(always)]
+    fn checked_div(self, y: Self) -> Option<Self> {
+        Self::checked_div(self, y)
+    }
+    #[inline(always)]
+    fn square_root(self) -> Self {
+        rust_decimal::MathematicalOps::sqrt(&self).unwrap_or_default()
+    }
+    #[inline(always)]
+    fn is_finite_number(self) -> bool {
+        true
+    }
+    #[inline(always)]
+    fn to_index(self) -> usize {
+        num_traits::ToPrimitive::to_usize(&self.trunc()).unwrap_or(usize::MAX)
+    }
+    #[inline(always)]
+    fn compare(&self, y: &Self) -> Ordering {
+        self.cmp(y)
+    }
+    #[inline(always)]
+    fn into_dynamic(self) -> Dynamic {
+        Dynamic::from_decimal(self)
+    }
+}
+
+/// Numeric elements of an array, promoted to a common type supporting fractions.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+enum Reals {
+    #[cfg(not(feature = "no_float"))]
+    Float(Vec<crate::FLOAT>),
+    #[cfg(feature = "decimal")]
+    Decimal(Vec<rust_decimal::Decimal>),
+}
+
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+impl Reals {
+    /// Collect the elements of an array.
+    ///
+    /// Integers are promoted to floating-point numbers, or to decimal numbers if the array
+    /// contains any (or under `no_float`).
+    fn from_array(ctx: &NativeCallContext, array: &Array) -> RhaiResultOf<Self> {
+        Ok(match Numbers::from_array(ctx, array)? {
+            #[cfg(not(feature = "no_float"))]
+            Numbers::Int(values) => Self::Float(values.into_iter().map(|v| v as _).collect()),
+            #[cfg(feature = "no_float")]
+            Numbers::Int(values) => Self::Decimal(values.into_iter().map(Into::into).collect()),
+            #[cfg(not(feature = "no_float"))]
+            Numbers::Float(values) => Self::Float(values),
+            #[cfg(feature = "decimal")]
+            Numbers::Decimal(values) => Self::Decimal(values),
+        })
+    }
+}
+
+/// Calculate the arithmetic mean of non-empty numbers.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+fn mean_of<T: Real>(values: &[T]) -> RhaiResultOf<T> {
+    let mut sum = values[0];
+
+    for &v in &values[1..] {
+        sum = sum
+            .checked_add(v)
+            .ok_or_else(|| stats_overflow_err("mean"))?;
+    }
+
+    sum.checked_div(T::from_count(values.len()))
+        .ok_or_else(|| stats_overflow_err("mean"))
+}
+
+/// Calculate the population variance of non-empty numbers.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+fn variance_of<T: Real>(values: &[T]) -> RhaiResultOf<T> {
+    let mean = mean_of(values)?;
+
+    let squares = values
+        .iter()
+        .map(|&v| {
+            let d = v.checked_sub(mean)?;
+            d.checked_mul(d)
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| stats_overflow_err("variance"))?;
+
+    mean_of(&squares)
+}
+
+/// Calculate the `p`-th percentile of non-empty numbers, interpolating linearly between the
+/// closest elements.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+fn percentile_of<T: Real>(mut values: Vec<T>, p: T) -> RhaiResultOf<T> {
+    if !values.iter().all(|v| v.is_finite_number()) {
+        return Err(stats_non_finite_err("percentile"));
+    }
+
+    values.sort_by(T::compare);
+
+    let last = values.len() - 1;
+    let rank = p
+        .checked_mul(T::from_count(last))
+        .and_then(|r| r.checked_div(T::from_count(100)))
+        .ok_or_else(|| stats_overflow_err("percentile"))?;
+    let index = rank.to_index().min(last);
+
+    if index == last {
+        return Ok(values[last]);
+    }
+
+    let (lo, hi) = (values[index], values[index + 1]);
+
+    rank.checked_sub(T::from_count(index))
+        .and_then(|frac| hi.checked_sub(lo)?.checked_mul(frac))
+        .and_then(|delta| lo.checked_add(delta))
+        .ok_or_else(|| stats_overflow_err("percentile"))
+}
+
+/// Count non-empty numbers in `bins` equal-width bins between their minimum and maximum.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+fn histogram_of<T: Real>(values: &[T], bins: usize) -> RhaiResultOf<Array> {
+    if !values.iter().all(|v| v.is_finite_number()) {
+        return Err(stats_non_finite_err("histogram"));
+    }
+
+    let mut counts = vec![0 as INT; bins];
+
+    let min = *values.iter().min_by(|x, y| x.compare(y)).unwrap();
+    let max = *values.iter().max_by(|x, y| x.compare(y)).unwrap();
+    let range = max
+        .checked_sub(min)
+        .ok_or_else(|| stats_overflow_err("histogram"))?;
+
+    for &v in values {
+        let offset = v
+            .checked_sub(min)
+            .ok_or_else(|| stats_overflow_err("histogram"))?;
+
+        let index = if range.compare(&T::from_count(0)) == Ordering::Equal {
+            0
+        } else {
+            offset
+                .checked_mul(T::from_count(bins))
+                .and_then(|x| x.checked_div(range))
+                .ok_or_else(|| stats_overflow_err("histogram"))?
+                .to_index()
+                .min(bins - 1)
+        };
+
+        counts[index] += 1;
+    }
+
+    Ok(counts.into_iter().map(Dynamic::from_int).collect())
+}
+
+#[export_module]
+mod array_stats_functions {
+    /// Return the sum of all the numbers in the array.
+    ///
+    /// The result is an integer if all the numbers are integers, otherwise integers are promoted
+    /// to floating-point or decimal numbers. An empty array returns zero.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// print(x.sum());     // prints 15
+    ///
+    /// let y = [1, 2.5, 3];
+    ///
+    /// print(y.sum());     // prints 6.5
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn sum(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        match Numbers::from_array(&ctx, array)? {
+            Numbers::Int(values) => values
+                .into_iter()
+                .try_fold(0 as INT, INT::checked_add)
+                .map(Dynamic::from_int)
+                .ok_or_else(|| stats_overflow_err("sum")),
+            #[cfg(not(feature = "no_float"))]
+            Numbers::Float(values) => Ok(Dynamic::from_float(values.into_iter().sum())),
+            #[cfg(feature = "decimal")]
+            Numbers::Decimal(values) => values
+                .into_iter()
+                .try_fold(
+                    rust_decimal::Decimal::ZERO,
+                    rust_decimal::Decimal::checked_add,
+                )
+                .map(Dynamic::from_decimal)
+                .ok_or_else(|| stats_overflow_err("sum")),
+        }
+    }
+    /// Return the product of all the numbers in the array.
+    ///
+    /// The result is an integer if all the numbers are integers, otherwise integers are promoted
+    /// to floating-point or decimal numbers. An empty array returns one.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// print(x.product());     // prints 120
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn product(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        match Numbers::from_array(&ctx, array)? {
+            Numbers::Int(values) => values
+                .into_iter()
+                .try_fold(1 as INT, INT::checked_mul)
+                .map(Dynamic::from_int)
+                .ok_or_else(|| stats_overflow_err("product")),
+            #[cfg(not(feature = "no_float"))]
+            Numbers::Float(values) => Ok(Dynamic::from_float(values.into_iter().product())),
+            #[cfg(feature = "decimal")]
+            Numbers::Decimal(values) => values
+                .into_iter()
+                .try_fold(
+                    rust_decimal::Decimal::ONE,
+                    rust_decimal::Decimal::checked_mul,
+                )
+                .map(Dynamic::from_decimal)
+                .ok_or_else(|| stats_overflow_err("product")),
+        }
+    }
+    /// Return the arithmetic mean of all the numbers in the array, or `()` if the array is empty.
+    ///
+    /// The result is a floating-point number, or a decimal number if the array contains any.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4];
+    ///
+    /// print(x.mean());        // prints 2.5
+    /// ```
+    #[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn mean(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        match Reals::from_array(&ctx, array)? {
+            #[cfg(not(feature = "no_float"))]
+            Reals::Float(values) => mean_of(&values).map(Real::into_dynamic),
+            #[cfg(feature = "decimal")]
+            Reals::Decimal(values) => mean_of(&values).map(Real::into_dynamic),
+        }
+    }
+    /// Return the median of all the numbers in the array, or `()` if the array is empty.
+    ///
+    /// For an even number of elements, the median is the mean of the two middle elements.
+    ///
+    /// An error is raised if any number is NaN or infinite.
+    ///
+    /// The result is a floating-point number, or a decimal number if the array contains any.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [3, 1, 4, 1, 5, 9];
+    ///
+    /// print(x.median());      // prints 3.5
+    /// ```
+    #[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn median(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        percentile(ctx, array, 50)
+    }
+    /// Return the `p`-th percentile (between 0 and 100) of all the numbers in the array, or `()`
+    /// if the array is empty.
+    ///
+    /// Values between elements are interpolated linearly.
+    ///
+    /// An error is raised if any number is NaN or infinite.
+    ///
+    /// The result is a floating-point number, or a decimal number if the array contains any.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [10, 20, 30, 40, 50];
+    ///
+    /// print(x.percentile(25));        // prints 20.0
+    ///
+    /// print(x.percentile(90));        // prints 46.0
+    /// ```
+    #[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn percentile(ctx: NativeCallContext, array: &mut Array, p: INT) -> RhaiResult {
+        if !(0..=100).contains(&p) {
+            return Err(ERR::ErrorArithmetic(
+                format!("Percentile must be between 0 and 100: {p}"),
+                Position::NONE,
+            )
+            .into());
+        }
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        match Reals::from_array(&ctx, array)? {
+            #[cfg(not(feature = "no_float"))]
+            Reals::Float(values) => percentile_of(values, p as _).map(Real::into_dynamic),
+            #[cfg(feature = "decimal")]
+            Reals::Decimal(values) => percentile_of(values, p.into()).map(Real::into_dynamic),
+        }
+    }
+    /// Return the `p`-th percentile (between 0.0 and 100.0) of all the numbers in the array, or
+    /// `()` if the array is empty.
+    ///
+    /// Values between elements are interpolated linearly.
+    ///
+    /// An error is raised if any number is NaN or infinite.
+    ///
+    /// The result is a floating-point number, or a decimal number if the array contains any.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [10, 20, 30, 40, 50];
+    ///
+    /// print(x.percentile(62.5));      // prints 35.0
+    /// ```
+    #[cfg(not(feature = "no_float"))]
+    #[rhai_fn(name = "percentile", return_raw, pure)]
+    pub fn percentile_float(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        p: crate::FLOAT,
+    ) -> RhaiResult {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(ERR::ErrorArithmetic(
+                format!("Percentile must be between 0 and 100: {p}"),
+                Position::NONE,
+            )
+            .into());
+        }
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        match Reals::from_array(&ctx, array)? {
+            Reals::Float(values) => percentile_of(values, p).map(Real::into_dynamic),
+            #[cfg(feature = "decimal")]
+            Reals::Decimal(values) => {
+                use std::convert::TryFrom;
+
+                let p = rust_decimal::Decimal::try_from(p).map_err(|_| {
+                    ERR::ErrorArithmetic(format!("Cannot convert to Decimal: {p}"), Position::NONE)
+                })?;
+                percentile_of(values, p).map(Real::into_dynamic)
+            }
+        }
+    }
+    /// Return the population variance of all the numbers in the array, or `()` if the array is
+    /// empty.
+    ///
+    /// The result is a floating-point number, or a decimal number if the array contains any.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [2, 4, 4, 4, 5, 5, 7, 9];
+    ///
+    /// print(x.variance());        // prints 4.0
+    /// ```
+    #[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn variance(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        match Reals::from_array(&ctx, array)? {
+            #[cfg(not(feature = "no_float"))]
+            Reals::Float(values) => variance_of(&values).map(Real::into_dynamic),
+            #[cfg(feature = "decimal")]
+            Reals::Decimal(values) => variance_of(&values).map(Real::into_dynamic),
+        }
+    }
+    /// Return the population standard deviation of all the numbers in the array, or `()` if the
+    /// array is empty.
+    ///
+    /// The result is a floating-point number, or a decimal number if the array contains any.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [2, 4, 4, 4, 5, 5, 7, 9];
+    ///
+    /// print(x.stddev());      // prints 2.0
+    /// ```
+    #[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn stddev(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        match Reals::from_array(&ctx, array)? {
+            #[cfg(not(feature = "no_float"))]
+            Reals::Float(values) => variance_of(&values).map(|v| v.square_root().into_dynamic()),
+            #[cfg(feature = "decimal")]
+            Reals::Decimal(values) => variance_of(&values).map(|v| v.square_root().into_dynamic()),
+        }
+    }
+    /// Count all the numbers in the array in `bins` equal-width bins between the smallest and the
+    /// largest number, and return the counts as an array.
+    ///
+    /// The largest number is counted in the last bin.
+    ///
+    /// An error is raised if any number is NaN or infinite, or if there are more than 1,048,576
+    /// bins.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 2, 3, 3, 3, 4, 4, 5];
+    ///
+    /// print(x.histogram(4));      // prints "[1, 2, 3, 3]"
+    /// ```
+    #[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn histogram(ctx: NativeCallContext, array: &mut Array, bins: INT) -> RhaiResultOf<Array> {
+        if bins <= 0 {
+            return Err(ERR::ErrorArithmetic(
+                format!("Number of bins must be positive: {bins}"),
+                Position::NONE,
+            )
+            .into());
+        }
+
+        if bins > MAX_HISTOGRAM_BINS {
+            return Err(ERR::ErrorDataTooLarge(
+                "Number of histogram bins".to_string(),
+                Position::NONE,
+            )
+            .into());
+        }
+
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let bins = bins as usize;
+
+        #[cfg(not(feature = "unchecked"))]
+        ctx.engine().throw_on_size((bins, 0, 0))?;
+
+        if array.is_empty() {
+            return Ok(vec![Dynamic::from_int(0); bins]);
+        }
+
+        match Reals::from_array(&ctx, array)? {
+            #[cfg(not(feature = "no_float"))]
+            Reals::Float(values) => histogram_of(&values, bins),
+            #[cfg(feature = "decimal")]
+            Reals::Decimal(values) => histogram_of(&values, bins),
+        }
+    }
+}
@@ -394,6 +394,10 @@ def_package! {
         // Register iterator functions
         combine_with_exported_module!(lib, "iterator", iterator_functions);
         combine_with_exported_module!(lib, "range", range_functions);
+
+        // Register lazy iterator functions
+        #[cfg(not(feature = "no_index"))]
+        combine_with_exported_module!(lib, "lazy_iterator", lazy_iterator_functions);
     }
 }
 
@@ -603,6 +607,210 @@ mod iterator_functions {
     }
 }
 
+#[cfg(not(feature = "no_index"))]
+#[export_module]
+mod lazy_iterator_functions {
+    use crate::{Array, FnPtr, LazyIterator};
+
+    /// Return a lazy iterator over the values of an iterable, such as an array, a range, a string
+    /// (over its characters) or an object map (over `(name, value)` tuples).
+    ///
+    /// Adapters such as `map` and `filter` can be chained onto the iterator. No values are
+    /// produced until the iterator is consumed by `collect`, `count` or a `for` loop.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = (1..1000).iter().filter(|v| v % 7 == 0).map(|v| v * v).take(3);
+    ///
+    /// print(x.collect());     // prints "[49, 196, 441]"
+    ///
+    /// for v in [1, 2, 3].iter().map(|v| v * 10) {
+    ///     print(v);
+    /// }
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn iter(ctx: NativeCallContext, value: &mut Dynamic) -> RhaiResultOf<LazyIterator> {
+        LazyIterator::from_iterable(&ctx, value.clone())
+    }
+    /// Return a lazy iterator that applies a `mapper` function to each value in turn.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `value`: value produced by the iterator
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3].iter().map(|v| v * v);
+    ///
+    /// print(x.collect());     // prints "[1, 4, 9]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn map(iter: &mut LazyIterator, mapper: FnPtr) -> LazyIterator {
+        iter.map(mapper)
+    }
+    /// Return a lazy iterator over the values for which a `filter` function returns `true`.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `value`: value produced by the iterator
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = (1..=10).iter().filter(|v| v % 3 == 0);
+    ///
+    /// print(x.collect());     // prints "[3, 6, 9]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn filter(iter: &mut LazyIterator, filter: FnPtr) -> LazyIterator {
+        iter.filter(filter)
+    }
+    /// Return a lazy iterator that applies a `mapper` function, which returns an iterable, to
+    /// each value in turn, and produces the values of the results.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `value`: value produced by the iterator
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3].iter().flat_map(|v| 0..v);
+    ///
+    /// print(x.collect());     // prints "[0, 0, 1, 0, 1, 2]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn flat_map(iter: &mut LazyIterator, mapper: FnPtr) -> LazyIterator {
+        iter.flat_map(mapper)
+    }
+    /// Return a lazy iterator over the first `n` values.
+    ///
+    /// If `n` ≤ 0, the iterator produces no values.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = (1..).iter().take(3);
+    ///
+    /// print(x.collect());     // prints "[1, 2, 3]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn take(iter: &mut LazyIterator, n: INT) -> LazyIterator {
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        iter.take(n.clamp(0, MAX_USIZE_INT) as usize)
+    }
+    /// Return a lazy iterator that skips the first `n` values.
+    ///
+    /// If `n` ≤ 0, no values are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5].iter().skip(3);
+    ///
+    /// print(x.collect());     // prints "[4, 5]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn skip(iter: &mut LazyIterator, n: INT) -> LazyIterator {
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        iter.skip(n.clamp(0, MAX_USIZE_INT) as usize)
+    }
+    /// Return a lazy iterator that pairs each value with its zero-based index, as `(index, value)`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = ['a', 'b'].iter().enumerate();
+    ///
+    /// print(x.collect());     // prints "[(0, 'a'), (1, 'b')]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn enumerate(iter: &mut LazyIterator) -> LazyIterator {
+        iter.enumerate()
+    }
+    /// Return a lazy iterator that pairs each value with a value of another iterable, as
+    /// `(value, other)`, stopping when either runs out.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3].iter().zip("ab");
+    ///
+    /// print(x.collect());     // prints "[(1, 'a'), (2, 'b')]"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn zip(
+        ctx: NativeCallContext,
+        iter: &mut LazyIterator,
+        other: Dynamic,
+    ) -> RhaiResultOf<LazyIterator> {
+        Ok(iter.zip(LazyIterator::from_iterable(&ctx, other)?))
+    }
+    /// Return a lazy iterator over the values followed by those of another iterable.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2].iter().chain(5..7);
+    ///
+    /// print(x.collect());     // prints "[1, 2, 5, 6]"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn chain(
+        ctx: NativeCallContext,
+        iter: &mut LazyIterator,
+        other: Dynamic,
+    ) -> RhaiResultOf<LazyIterator> {
+        Ok(iter.chain(LazyIterator::from_iterable(&ctx, other)?))
+    }
+    /// Consume the iterator and return its values as an array.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = "hello".iter().skip(1).collect();
+    ///
+    /// print(x);       // prints "['e', 'l', 'l', 'o']"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn collect(ctx: NativeCallContext, iter: &mut LazyIterator) -> RhaiResultOf<Array> {
+        let mut array = Array::new();
+
+        iter.for_each(&ctx, |value| {
+            array.push(value);
+
+            #[cfg(not(feature = "unchecked"))]
+            ctx.engine().throw_on_size((array.len(), 0, 0))?;
+
+            Ok(true)
+        })?;
+
+        Ok(array)
+    }
+    /// Consume the iterator and return the number of values it produces.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = (1..100).iter().filter(|v| v % 10 == 0);
+    ///
+    /// print(x.count());       // prints 9
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn count(ctx: NativeCallContext, iter: &mut LazyIterator) -> RhaiResultOf<INT> {
+        let mut count: INT = 0;
+
+        iter.for_each(&ctx, |_| {
+            count += 1;
+            Ok(true)
+        })?;
+
+        Ok(count)
+    }
+}
+
 #[export_module]
 mod range_functions {
     /// Return the start of the exclusive range.
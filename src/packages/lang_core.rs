@@ -206,7 +206,8 @@ mod reflection_functions {
         collect_fn_metadata(&ctx, |_, _, n, _, _| n == name)
     }
     /// Return an array of object maps containing metadata of all script-defined functions
-    /// matching the specified name and arity (number of parameters).
+    /// matching the specified name and callable with the specified number of arguments
+    /// (taking default parameter values and variadic parameters into account).
     #[rhai_fn(name = "get_fn_metadata_list")]
     #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
     pub fn get_fn_metadata2(ctx: NativeCallContext, name: &str, params: INT) -> Array {
@@ -214,7 +215,9 @@ mod reflection_functions {
             return Array::new();
         }
 
-        collect_fn_metadata(&ctx, |_, _, n, p, _| p == (params as usize) && n == name)
+        collect_fn_metadata(&ctx, |_, _, n, _, f| {
+            n == name && f.accepts_num_args(params as usize)
+        })
     }
 }
 
@@ -274,6 +277,34 @@ fn collect_fn_metadata(
                 .collect::<Array>()
                 .into(),
         );
+        if !func.param_types.is_empty() {
+            map.insert(
+                "param_types".into(),
+                func.param_types
+                    .iter()
+                    .map(|t| t.as_ref().map_or(Dynamic::UNIT, |t| t.clone().into()))
+                    .collect::<Array>()
+                    .into(),
+            );
+        }
+        if !func.param_defaults.is_empty() {
+            map.insert(
+                "param_defaults".into(),
+                func.params
+                    .iter()
+                    .skip(func.num_required_params())
+                    .zip(func.param_defaults.iter())
+                    .map(|(p, v)| (p.as_str().into(), v.clone()))
+                    .collect::<Map>()
+                    .into(),
+            );
+        }
+        if func.is_variadic {
+            map.insert("is_variadic".into(), true.into());
+        }
+        if let Some(ref return_type) = func.return_type {
+            map.insert("return_type".into(), return_type.into());
+        }
         #[cfg(feature = "metadata")]
         if !func.comments.is_empty() {
             map.insert(
@@ -5,6 +5,7 @@ use crate::plugin::*;
 use crate::{
     def_package, Dynamic, FnPtr, ImmutableString, Map, NativeCallContext, RhaiResultOf, INT,
 };
+use std::any::TypeId;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -17,6 +18,11 @@ def_package! {
         lib.set_standard_lib(true);
 
         combine_with_exported_module!(lib, "map", map_functions);
+
+        // Register object map iterator (over property names)
+        lib.set_iter(TypeId::of::<Map>(), |value| Box::new(
+            value.cast::<Map>().into_keys().map(|name| ImmutableString::from(name).into())
+        ));
     }
 }
 
@@ -79,6 +79,37 @@ def_package! {
             #[cfg(not(feature = "only_i64"))]
             gen_conv_functions!(lib => to_decimal(i8, u8, i16, u16, i32, u32, i64, u64).into() -> Decimal);
         }
+
+        // BigInt functions
+        #[cfg(feature = "bigint")]
+        {
+            use num_bigint::BigInt;
+
+            combine_with_exported_module!(lib, "bigint", bigint_functions);
+
+            gen_conv_functions!(lib => to_bigint(BigInt) -> BigInt);
+            gen_conv_functions!(lib => to_bigint(INT).into() -> BigInt);
+
+            #[cfg(not(feature = "only_i32"))]
+            #[cfg(not(feature = "only_i64"))]
+            {
+                gen_conv_functions!(lib => to_bigint(i8, u8, i16, u16, i32, u32, i64, u64).into() -> BigInt);
+
+                #[cfg(not(target_family = "wasm"))]
+                gen_conv_functions!(lib => to_bigint(i128, u128).into() -> BigInt);
+            }
+        }
+
+        // Rational functions
+        #[cfg(feature = "rational")]
+        {
+            use crate::Rational;
+
+            combine_with_exported_module!(lib, "rational", rational_functions);
+
+            gen_conv_functions!(lib => to_rational(Rational) -> Rational);
+            gen_conv_functions!(lib => to_rational(INT).into() -> Rational);
+        }
     }
 }
 
@@ -626,3 +657,307 @@ mod decimal_functions {
         })
     }
 }
+
+#[cfg(feature = "bigint")]
+#[export_module]
+mod bigint_functions {
+    use num_bigint::BigInt;
+    #[cfg(not(feature = "no_float"))]
+    use num_traits::FromPrimitive;
+    use num_traits::ToPrimitive;
+
+    /// Parse a string into a big integer.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = parse_bigint("123456789012345678901234567890");
+    ///
+    /// print(x);       // prints 123456789012345678901234567890
+    /// ```
+    #[rhai_fn(name = "parse_bigint", return_raw)]
+    pub fn parse_bigint(string: &str) -> RhaiResultOf<BigInt> {
+        parse_bigint_radix(string, 10)
+    }
+    /// Parse a string into a big integer of the specified `radix`.
+    ///
+    /// `radix` must be between 2 and 36.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = parse_bigint("ffffffffffffffffffff", 16);
+    ///
+    /// print(x);       // prints 1208925819614629174706175
+    /// ```
+    #[rhai_fn(name = "parse_bigint", return_raw)]
+    pub fn parse_bigint_radix(string: &str, radix: INT) -> RhaiResultOf<BigInt> {
+        if !(2..=36).contains(&radix) {
+            return Err(
+                ERR::ErrorArithmetic(format!("Invalid radix: '{radix}'"), Position::NONE).into(),
+            );
+        }
+
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        BigInt::parse_bytes(string.trim().as_bytes(), radix as u32).ok_or_else(|| {
+            ERR::ErrorArithmetic(
+                format!("Error parsing big integer '{string}'"),
+                Position::NONE,
+            )
+            .into()
+        })
+    }
+    /// Convert the big integer into a string of the specified `radix`.
+    ///
+    /// `radix` must be between 2 and 36.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = 255n;
+    ///
+    /// print(x.to_string(16));     // prints "ff"
+    /// ```
+    #[rhai_fn(name = "to_string", return_raw, pure)]
+    pub fn to_string_radix(x: &mut BigInt, radix: INT) -> RhaiResultOf<ImmutableString> {
+        if !(2..=36).contains(&radix) {
+            return Err(
+                ERR::ErrorArithmetic(format!("Invalid radix: '{radix}'"), Position::NONE).into(),
+            );
+        }
+
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        Ok(x.to_str_radix(radix as u32).into())
+    }
+    /// Convert the big integer into an integer.
+    #[rhai_fn(return_raw)]
+    pub fn to_int(x: BigInt) -> RhaiResultOf<INT> {
+        #[cfg(not(feature = "only_i32"))]
+        let n = x.to_i64();
+        #[cfg(feature = "only_i32")]
+        let n = x.to_i32();
+
+        n.ok_or_else(|| {
+            ERR::ErrorArithmetic(format!("Integer overflow: to_int({x})"), Position::NONE).into()
+        })
+    }
+    /// Convert the big integer to floating-point.
+    #[cfg(not(feature = "no_float"))]
+    #[rhai_fn(return_raw)]
+    pub fn to_float(x: BigInt) -> RhaiResultOf<FLOAT> {
+        #[cfg(not(feature = "f32_float"))]
+        let n = x.to_f64();
+        #[cfg(feature = "f32_float")]
+        let n = x.to_f32();
+
+        n.filter(|n| n.is_finite()).ok_or_else(|| {
+            ERR::ErrorArithmetic(
+                format!("Cannot convert to floating-point: to_float({x})"),
+                Position::NONE,
+            )
+            .into()
+        })
+    }
+    /// Convert the floating-point number to a big integer, truncating any fractional part.
+    #[cfg(not(feature = "no_float"))]
+    #[rhai_fn(name = "to_bigint", return_raw)]
+    pub fn float_to_bigint(x: FLOAT) -> RhaiResultOf<BigInt> {
+        #[cfg(not(feature = "f32_float"))]
+        let n = BigInt::from_f64(x);
+        #[cfg(feature = "f32_float")]
+        let n = BigInt::from_f32(x);
+
+        n.ok_or_else(|| {
+            ERR::ErrorArithmetic(
+                format!("Cannot convert to BigInt: to_bigint({x})"),
+                Position::NONE,
+            )
+            .into()
+        })
+    }
+}
+
+#[cfg(feature = "rational")]
+#[export_module]
+mod rational_functions {
+    use super::super::arithmetic::{make_err, make_rational};
+    use crate::Rational;
+    use std::str::FromStr;
+
+    /// Create a rational number from a `numerator` and a `denominator`.
+    ///
+    /// The rational number is always kept in its reduced form.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = rational(6, 8);
+    ///
+    /// print(x);       // prints 3/4
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn rational(numerator: INT, denominator: INT) -> RhaiResultOf<Rational> {
+        if denominator == 0 {
+            return Err(make_err(format!(
+                "Division by zero: rational({numerator}, {denominator})"
+            )));
+        }
+        make_rational(numerator, denominator).ok_or_else(|| {
+            make_err(format!(
+                "Rational overflow: rational({numerator}, {denominator})"
+            ))
+        })
+    }
+    /// Parse a string into a rational number.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = parse_rational("2/6");
+    ///
+    /// print(x);       // prints 1/3
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn parse_rational(string: &str) -> RhaiResultOf<Rational> {
+        let make_parse_err = |err: &dyn std::fmt::Display| {
+            make_err(format!("Error parsing rational number '{string}': {err}"))
+        };
+
+        // Parse the parts separately, because `Rational::from_str` panics on overflow
+        let (numer, denom) = string
+            .trim()
+            .split_once('/')
+            .unwrap_or((string.trim(), "1"));
+        let numer = INT::from_str(numer).map_err(|err| make_parse_err(&err))?;
+        let denom = INT::from_str(denom).map_err(|err| make_parse_err(&err))?;
+
+        if denom == 0 {
+            return Err(make_parse_err(&"division by zero"));
+        }
+        make_rational(numer, denom).ok_or_else(|| make_parse_err(&"overflow"))
+    }
+    /// Return the numerator of the rational number.
+    #[rhai_fn(name = "numer", get = "numer")]
+    pub fn numer(x: Rational) -> INT {
+        *x.numer()
+    }
+    /// Return the denominator of the rational number, which is always positive.
+    #[rhai_fn(name = "denom", get = "denom")]
+    pub fn denom(x: Rational) -> INT {
+        *x.denom()
+    }
+    /// Return the reciprocal of the rational number.
+    #[rhai_fn(return_raw)]
+    pub fn recip(x: Rational) -> RhaiResultOf<Rational> {
+        if x.numer() == &0 {
+            Err(make_err(format!("Division by zero: recip({x})")))
+        } else {
+            make_rational(*x.denom(), *x.numer())
+                .ok_or_else(|| make_err(format!("Rational overflow: recip({x})")))
+        }
+    }
+    /// Return `true` if the rational number is a whole number.
+    #[rhai_fn(name = "is_integer", get = "is_integer")]
+    pub fn is_integer(x: Rational) -> bool {
+        x.is_integer()
+    }
+    /// Round the rational number to a whole number via `adjust`, which is given the truncated
+    /// integral part and the remainder.
+    ///
+    /// `Rational::floor`, `Rational::ceil` and `Rational::round` panic on overflow.
+    fn round_with(
+        x: Rational,
+        fn_name: &str,
+        adjust: impl FnOnce(INT, INT) -> Option<INT>,
+    ) -> RhaiResultOf<Rational> {
+        let (numer, denom) = (*x.numer(), *x.denom());
+
+        numer
+            .checked_div(denom)
+            .zip(numer.checked_rem(denom))
+            .and_then(|(n, r)| adjust(n, r))
+            .map(Rational::from_integer)
+            .ok_or_else(|| make_err(format!("Rational overflow: {fn_name}({x})")))
+    }
+    /// Return the largest whole number less than or equals to the rational number.
+    #[rhai_fn(name = "floor", get = "floor", return_raw)]
+    pub fn floor(x: Rational) -> RhaiResultOf<Rational> {
+        round_with(
+            x,
+            "floor",
+            |n, r| if r < 0 { n.checked_sub(1) } else { Some(n) },
+        )
+    }
+    /// Return the smallest whole number larger than or equals to the rational number.
+    #[rhai_fn(name = "ceiling", get = "ceiling", return_raw)]
+    pub fn ceiling(x: Rational) -> RhaiResultOf<Rational> {
+        round_with(
+            x,
+            "ceiling",
+            |n, r| if r > 0 { n.checked_add(1) } else { Some(n) },
+        )
+    }
+    /// Return the nearest whole number closest to the rational number.
+    /// Rounds away from zero.
+    #[rhai_fn(name = "round", get = "round", return_raw)]
+    pub fn round(x: Rational) -> RhaiResultOf<Rational> {
+        let denom = x.denom().unsigned_abs();
+
+        round_with(x, "round", |n, r| {
+            // Round away from zero when the remainder is at least half of the denominator
+            if r.unsigned_abs() < denom - r.unsigned_abs() {
+                Some(n)
+            } else if r < 0 {
+                n.checked_sub(1)
+            } else {
+                n.checked_add(1)
+            }
+        })
+    }
+    /// Return the integral part of the rational number.
+    #[rhai_fn(name = "int", get = "int")]
+    pub fn int(x: Rational) -> Rational {
+        x.trunc()
+    }
+    /// Return the fractional part of the rational number.
+    #[rhai_fn(name = "fraction", get = "fraction")]
+    pub fn fraction(x: Rational) -> Rational {
+        x.fract()
+    }
+    /// Convert the rational number into an integer, truncating any fractional part.
+    pub fn to_int(x: Rational) -> INT {
+        x.to_integer()
+    }
+    /// Convert the floating-point number to the closest rational number.
+    #[cfg(not(feature = "no_float"))]
+    #[rhai_fn(name = "to_rational", return_raw)]
+    pub fn float_to_rational(x: FLOAT) -> RhaiResultOf<Rational> {
+        Rational::approximate_float(x).ok_or_else(|| {
+            ERR::ErrorArithmetic(
+                format!("Cannot convert to Rational: to_rational({x})"),
+                Position::NONE,
+            )
+            .into()
+        })
+    }
+    /// Convert the rational number to floating-point.
+    #[cfg(not(feature = "no_float"))]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_float(x: Rational) -> FLOAT {
+        *x.numer() as FLOAT / *x.denom() as FLOAT
+    }
+    /// Convert the rational number to decimal.
+    #[cfg(feature = "decimal")]
+    #[rhai_fn(return_raw)]
+    pub fn to_decimal(x: Rational) -> RhaiResultOf<rust_decimal::Decimal> {
+        rust_decimal::Decimal::from(*x.numer())
+            .checked_div(rust_decimal::Decimal::from(*x.denom()))
+            .ok_or_else(|| {
+                ERR::ErrorArithmetic(
+                    format!("Cannot convert to Decimal: to_decimal({x})"),
+                    Position::NONE,
+                )
+                .into()
+            })
+    }
+}
@@ -15,9 +15,14 @@ pub(crate) mod map_basic;
 pub(crate) mod math_basic;
 pub(crate) mod pkg_core;
 pub(crate) mod pkg_std;
+pub(crate) mod regex_basic;
+pub(crate) mod set_basic;
 pub(crate) mod string_basic;
+pub(crate) mod string_format;
 pub(crate) mod string_more;
 pub(crate) mod time_basic;
+pub(crate) mod tuple_basic;
+pub(crate) mod vector_math;
 
 pub use arithmetic::ArithmeticPackage;
 #[cfg(not(feature = "no_index"))]
@@ -36,10 +41,18 @@ pub use map_basic::BasicMapPackage;
 pub use math_basic::BasicMathPackage;
 pub use pkg_core::CorePackage;
 pub use pkg_std::StandardPackage;
+#[cfg(feature = "regex")]
+pub use regex_basic::BasicRegexPackage;
+#[cfg(not(feature = "no_index"))]
+pub use set_basic::BasicSetPackage;
 pub use string_basic::BasicStringPackage;
 pub use string_more::MoreStringPackage;
 #[cfg(not(feature = "no_time"))]
 pub use time_basic::BasicTimePackage;
+#[cfg(not(feature = "no_index"))]
+pub use tuple_basic::BasicTuplePackage;
+#[cfg(not(feature = "no_float"))]
+pub use vector_math::VectorMathPackage;
 
 /// Trait that all packages must implement.
 pub trait Package {
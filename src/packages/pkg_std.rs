@@ -15,8 +15,11 @@ def_package! {
     /// * [`BasicMathPackage`][super::BasicMathPackage]
     /// * [`BasicArrayPackage`][super::BasicArrayPackage]
     /// * [`BasicBlobPackage`][super::BasicBlobPackage]
+    /// * [`BasicSetPackage`][super::BasicSetPackage]
+    /// * [`BasicTuplePackage`][super::BasicTuplePackage]
     /// * [`BasicMapPackage`][super::BasicMapPackage]
     /// * [`BasicTimePackage`][super::BasicTimePackage]
+    /// * [`BasicRegexPackage`][super::BasicRegexPackage]
     /// * [`MoreStringPackage`][super::MoreStringPackage]
     pub StandardPackage(lib) :
             CorePackage,
@@ -25,8 +28,11 @@ def_package! {
             BasicMathPackage,
             #[cfg(not(feature = "no_index"))] BasicArrayPackage,
             #[cfg(not(feature = "no_index"))] BasicBlobPackage,
+            #[cfg(not(feature = "no_index"))] BasicSetPackage,
+            #[cfg(not(feature = "no_index"))] BasicTuplePackage,
             #[cfg(not(feature = "no_object"))] BasicMapPackage,
             #[cfg(not(feature = "no_time"))] BasicTimePackage,
+            #[cfg(feature = "regex")] BasicRegexPackage,
             MoreStringPackage
     {
         lib.set_standard_lib(true);
@@ -0,0 +1,410 @@
+#![cfg(feature = "regex")]
+
+use crate::plugin::*;
+use crate::{
+    def_package, Dynamic, Engine, FnPtr, ImmutableString, Regex, RhaiError, RhaiResultOf,
+    SmartString, ERR,
+};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{collections::BTreeMap, fmt};
+
+#[cfg(not(feature = "unchecked"))]
+use std::convert::TryFrom;
+
+#[cfg(not(feature = "no_object"))]
+use crate::Map;
+
+def_package! {
+    /// Package of basic regular expression utilities.
+    pub BasicRegexPackage(lib) {
+        lib.set_standard_lib(true);
+
+        combine_with_exported_module!(lib, "regex", regex_functions);
+    }
+}
+
+/// A cache for compiled regular expressions.
+#[derive(Clone)]
+pub struct RegexCache {
+    /// Maximum number of regular expressions to be cached.
+    max_regex_cached: usize,
+    /// Size and nesting limits that the cached regular expressions were compiled with.
+    limits: (usize, u32),
+    /// Cached regular expressions, keyed by pattern.
+    cache: BTreeMap<SmartString, Regex>,
+}
+
+impl fmt::Debug for RegexCache {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.cache.keys()).finish()
+    }
+}
+
+impl RegexCache {
+    /// Create a new [`RegexCache`] holding up to a maximum number of compiled regular expressions.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(max_regex_cached: usize) -> Self {
+        Self {
+            max_regex_cached,
+            limits: (0, 0),
+            cache: BTreeMap::new(),
+        }
+    }
+    /// Get the maximum number of regular expressions to be cached.
+    #[inline(always)]
+    #[must_use]
+    pub const fn max(&self) -> usize {
+        self.max_regex_cached
+    }
+    /// Set the maximum number of regular expressions to be cached.
+    #[inline]
+    pub fn set_max(&mut self, max: usize) {
+        self.max_regex_cached = max;
+
+        while self.cache.len() > max {
+            self.cache.pop_first();
+        }
+    }
+    /// Clear all the cached regular expressions.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Compile a regular expression pattern subject to the [`Engine`]'s limits.
+///
+/// Compiled regular expressions are kept in the [`Engine`]'s cache, if any.
+pub fn compile_regex(engine: &Engine, pattern: &str) -> Result<Regex, regex::Error> {
+    let mut builder = regex::RegexBuilder::new(pattern);
+
+    #[cfg(not(feature = "unchecked"))]
+    let limits = {
+        if engine.max_regex_size() > 0 {
+            builder.size_limit(engine.max_regex_size());
+        }
+        let depth = u32::try_from(engine.max_expr_depth()).unwrap_or(u32::MAX);
+        if depth > 0 {
+            builder.nest_limit(depth);
+        }
+        (engine.max_regex_size(), depth)
+    };
+    #[cfg(feature = "unchecked")]
+    let limits = (0, 0);
+
+    let Some(mut cache) = engine
+        .regex_cache
+        .as_ref()
+        .and_then(crate::func::locked_write)
+    else {
+        return builder.build();
+    };
+
+    // Limits have changed since the cached regular expressions were compiled
+    if cache.limits != limits {
+        cache.clear();
+        cache.limits = limits;
+    }
+
+    if let Some(regex) = cache.cache.get(pattern) {
+        return Ok(regex.clone());
+    }
+
+    let regex = builder.build()?;
+
+    if cache.cache.len() >= cache.max() {
+        cache.cache.pop_first();
+    }
+    cache.cache.insert(pattern.into(), regex.clone());
+
+    Ok(regex)
+}
+
+/// Make an error for a regular expression that failed to compile.
+fn make_err(err: &regex::Error) -> RhaiError {
+    match err {
+        regex::Error::CompiledTooBig(..) => {
+            ERR::ErrorDataTooLarge("Size of regular expression".to_string(), Position::NONE).into()
+        }
+        _ => ERR::ErrorRuntime(
+            format!("Invalid regular expression: {err}").into(),
+            Position::NONE,
+        )
+        .into(),
+    }
+}
+
+/// Collect the capture groups of a match into an object map.
+///
+/// Groups are keyed by their index as well as their name (if any).
+/// Groups that did not participate in the match are `()`.
+#[cfg(not(feature = "no_object"))]
+fn captures_to_map(regex: &Regex, captures: &regex::Captures) -> Map {
+    let mut map = Map::new();
+
+    for (i, name) in regex.capture_names().enumerate() {
+        let value = captures.get(i).map_or(Dynamic::UNIT, |m| m.as_str().into());
+
+        if let Some(name) = name {
+            map.insert(name.into(), value.clone());
+        }
+        map.insert(i.to_string().into(), value);
+    }
+
+    map
+}
+
+#[export_module]
+mod regex_functions {
+    use super::compile_regex;
+
+    /// Compile a regular expression pattern.
+    ///
+    /// Compiled regular expressions are cached, so it is cheap to call this function
+    /// repeatedly with the same pattern.
+    ///
+    /// An error is raised if the pattern is not a valid regular expression.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("[a-z]+([0-9]+)");
+    ///
+    /// print(re.is_match("abc123"));   // prints true
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn regex(ctx: NativeCallContext, pattern: &str) -> RhaiResultOf<Regex> {
+        compile_regex(ctx.engine(), pattern).map_err(|err| make_err(&err))
+    }
+    /// Return the pattern of the regular expression.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("[a-z]+");
+    ///
+    /// print(re.pattern);              // prints "[a-z]+"
+    /// ```
+    #[rhai_fn(get = "pattern", pure)]
+    pub fn pattern(regex: &mut Regex) -> ImmutableString {
+        regex.as_str().into()
+    }
+    /// Return `true` if the regular expression matches anywhere in the `text`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("^[0-9]+$");
+    ///
+    /// print(re.is_match("12345"));    // prints true
+    ///
+    /// print(re.is_match("123x5"));    // prints false
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn is_match(regex: &mut Regex, text: &str) -> bool {
+        regex.is_match(text)
+    }
+    /// Return the first match of the regular expression in the `text`, or `()` if there is none.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("[0-9]+");
+    ///
+    /// print(re.find("abc 123 def 456"));  // prints "123"
+    ///
+    /// print(re.find("abc def"));          // prints ""
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn find(regex: &mut Regex, text: &str) -> Dynamic {
+        regex
+            .find(text)
+            .map_or(Dynamic::UNIT, |m| m.as_str().into())
+    }
+    /// Replace the first match of the regular expression in the `text` with the `replacement`
+    /// string, returning a new string.
+    ///
+    /// Capture groups can be referenced in `replacement` via `$1`, `$name` or `${name}`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("(?P<word>[a-z]+)");
+    ///
+    /// print(re.replace("abc def", "<$word>"));    // prints "<abc> def"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn replace(regex: &mut Regex, text: &str, replacement: &str) -> ImmutableString {
+        regex.replace(text, replacement).into_owned().into()
+    }
+    /// Replace all matches of the regular expression in the `text` with the `replacement`
+    /// string, returning a new string.
+    ///
+    /// Capture groups can be referenced in `replacement` via `$1`, `$name` or `${name}`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("(?P<word>[a-z]+)");
+    ///
+    /// print(re.replace_all("abc def", "<$word>"));    // prints "<abc> <def>"
+    /// ```
+    #[rhai_fn(name = "replace_all", pure)]
+    pub fn replace_all(regex: &mut Regex, text: &str, replacement: &str) -> ImmutableString {
+        regex.replace_all(text, replacement).into_owned().into()
+    }
+    /// Replace all matches of the regular expression in the `text` with the results of calling
+    /// the `replacer` function on each match, returning a new string.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `matched`: text of the match
+    /// * `captures` _(optional)_: object map of the capture groups, keyed by both index and name
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let re = regex("[0-9]+");
+    ///
+    /// print(re.replace_all("a1 b22 c333", |n| n.len())); // prints "a1 b2 c3"
+    /// ```
+    #[rhai_fn(name = "replace_all", return_raw, pure)]
+    pub fn replace_all_with_fn(
+        ctx: NativeCallContext,
+        regex: &mut Regex,
+        text: &str,
+        replacer: FnPtr,
+    ) -> RhaiResultOf<ImmutableString> {
+        let mut result = SmartString::new_const();
+        let mut last = 0;
+
+        for captures in regex.captures_iter(text) {
+            let m = captures.get(0).unwrap();
+
+            #[cfg(not(feature = "no_object"))]
+            let ex = [captures_to_map(regex, &captures).into()];
+            #[cfg(feature = "no_object")]
+            let ex = [];
+
+            let args = [m.as_str().into()];
+            let mut value =
+                replacer.call_raw_with_extra_args("replace_all", &ctx, None, args, ex, None)?;
+
+            result.push_str(&text[last..m.start()]);
+            result.push_str(&super::super::string_basic::print_with_func(
+                super::super::string_basic::FUNC_TO_STRING,
+                &ctx,
+                &mut value,
+            ));
+            last = m.end();
+        }
+
+        result.push_str(&text[last..]);
+
+        Ok(result.into())
+    }
+
+    #[cfg(not(feature = "no_index"))]
+    pub mod arrays {
+        use crate::{Array, Regex};
+
+        /// Return an array containing all the non-overlapping matches of the regular expression
+        /// in the `text`.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let re = regex("[0-9]+");
+        ///
+        /// print(re.find_all("abc 123 def 456"));  // prints ["123", "456"]
+        /// ```
+        #[rhai_fn(pure)]
+        pub fn find_all(regex: &mut Regex, text: &str) -> Array {
+            regex.find_iter(text).map(|m| m.as_str().into()).collect()
+        }
+        /// Split the `text` into segments separated by matches of the regular expression,
+        /// returning an array of the segments.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let re = regex("[, ]+");
+        ///
+        /// print(re.split("a, b,c  d"));       // prints ["a", "b", "c", "d"]
+        /// ```
+        #[rhai_fn(name = "split", pure)]
+        pub fn split(regex: &mut Regex, text: &str) -> Array {
+            regex.split(text).map(Into::into).collect()
+        }
+        /// Split the string into segments separated by matches of the regular expression,
+        /// returning an array of the segments.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let text = "a, b,c  d";
+        ///
+        /// print(text.split(regex("[, ]+")));  // prints ["a", "b", "c", "d"]
+        /// ```
+        #[rhai_fn(name = "split")]
+        pub fn split_string(string: &str, regex: Regex) -> Array {
+            regex.split(string).map(Into::into).collect()
+        }
+    }
+
+    #[cfg(not(feature = "no_object"))]
+    pub mod maps {
+        use super::super::captures_to_map;
+        use crate::{Dynamic, Regex};
+
+        /// Return an object map of the capture groups of the first match of the regular expression
+        /// in the `text`, or `()` if there is no match.
+        ///
+        /// Capture groups are keyed by both index and name (if any). Groups that did not
+        /// participate in the match are `()`.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let re = regex("(?P<key>[a-z]+)=(?P<value>[0-9]+)");
+        ///
+        /// let m = re.captures("x = 1, abc=42");
+        ///
+        /// print(m.key);       // prints "abc"
+        /// print(m.value);     // prints "42"
+        /// print(m["0"]);      // prints "abc=42"
+        /// ```
+        #[rhai_fn(pure)]
+        pub fn captures(regex: &mut Regex, text: &str) -> Dynamic {
+            regex
+                .captures(text)
+                .map_or(Dynamic::UNIT, |c| captures_to_map(regex, &c).into())
+        }
+        /// Return an array of object maps, one for each non-overlapping match of the regular
+        /// expression in the `text`, containing the capture groups of the match.
+        ///
+        /// Not available under `no_index`.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let re = regex("(?P<key>[a-z]+)=(?P<value>[0-9]+)");
+        ///
+        /// for m in re.captures_all("a=1, b=2") {
+        ///     print(`${m.key} is ${m.value}`);
+        /// }
+        /// ```
+        #[cfg(not(feature = "no_index"))]
+        #[rhai_fn(pure)]
+        pub fn captures_all(regex: &mut Regex, text: &str) -> crate::Array {
+            regex
+                .captures_iter(text)
+                .map(|c| captures_to_map(regex, &c).into())
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,236 @@
+#![cfg(not(feature = "no_index"))]
+
+use crate::plugin::*;
+use crate::{def_package, Array, Dynamic, NativeCallContext, Position, RhaiResultOf, Set, INT};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+def_package! {
+    /// Package of basic set utilities.
+    pub BasicSetPackage(lib) {
+        lib.set_standard_lib(true);
+
+        combine_with_exported_module!(lib, "set", set_functions);
+
+        // Register set iterator
+        lib.set_iterable::<Set>();
+    }
+}
+
+#[export_module]
+mod set_functions {
+    /// Number of items in the set.
+    #[rhai_fn(name = "len", get = "len", pure)]
+    pub fn len(set: &mut Set) -> INT {
+        set.len() as INT
+    }
+    /// Return true if the set is empty.
+    #[rhai_fn(name = "is_empty", get = "is_empty", pure)]
+    pub fn is_empty(set: &mut Set) -> bool {
+        set.is_empty()
+    }
+    /// Return `true` if the set contains a specified value.
+    ///
+    /// This function also drives the `in` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.contains(2));       // prints true
+    ///
+    /// print(42 in s);             // prints false
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn contains(set: &mut Set, value: Dynamic) -> bool {
+        set.contains(&value)
+    }
+    /// Add a value to the set.
+    ///
+    /// Return `true` if the value was not already in the set.
+    ///
+    /// Only `()`, booleans, integers, floating-point numbers, characters and strings can be
+    /// kept in a set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.insert(4));         // prints true
+    ///
+    /// print(s.insert(1));         // prints false
+    ///
+    /// print(s);                   // prints "#[1, 2, 3, 4]"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn insert(ctx: NativeCallContext, set: &mut Set, value: Dynamic) -> RhaiResultOf<bool> {
+        let added = set
+            .insert(value)
+            .map_err(|v| Set::make_item_err(ctx.engine(), &v, Position::NONE))?;
+
+        #[cfg(not(feature = "unchecked"))]
+        if added {
+            ctx.engine().check_set_size(set, Position::NONE)?;
+        }
+
+        Ok(added)
+    }
+    /// Remove a value from the set.
+    ///
+    /// Return `true` if the value was in the set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.remove(2));         // prints true
+    ///
+    /// print(s.remove(42));        // prints false
+    ///
+    /// print(s);                   // prints "#[1, 3]"
+    /// ```
+    pub fn remove(set: &mut Set, value: Dynamic) -> bool {
+        set.remove(&value)
+    }
+    /// Clear the set.
+    pub fn clear(set: &mut Set) {
+        set.clear();
+    }
+    /// Return a new set containing all the values in either set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.union(#[3, 4, 5]));     // prints "#[1, 2, 3, 4, 5]"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn union(_ctx: NativeCallContext, set: &mut Set, other: Set) -> RhaiResultOf<Set> {
+        let result = set.union(&other);
+
+        #[cfg(not(feature = "unchecked"))]
+        _ctx.engine().check_set_size(&result, Position::NONE)?;
+
+        Ok(result)
+    }
+    /// Return a new set containing only the values in both sets.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.intersection(#[3, 4, 5]));  // prints "#[3]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn intersection(set: &mut Set, other: Set) -> Set {
+        set.intersection(&other)
+    }
+    /// Return a new set containing the values in the set that are not in the `other` set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.difference(#[3, 4, 5]));    // prints "#[1, 2]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn difference(set: &mut Set, other: Set) -> Set {
+        set.difference(&other)
+    }
+    /// Return `true` if every value in the set is also in the `other` set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2];
+    ///
+    /// print(s.is_subset(#[1, 2, 3]));     // prints true
+    ///
+    /// print(s.is_subset(#[2, 3]));        // prints false
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn is_subset(set: &mut Set, other: Set) -> bool {
+        set.is_subset(&other)
+    }
+    /// Return `true` if every value in the `other` set is also in the set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.is_superset(#[1, 2]));      // prints true
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn is_superset(set: &mut Set, other: Set) -> bool {
+        set.is_superset(&other)
+    }
+    /// Return `true` if two sets contain the same values.
+    #[rhai_fn(name = "==", pure)]
+    pub fn equals(set1: &mut Set, set2: Set) -> bool {
+        *set1 == set2
+    }
+    /// Return `true` if two sets do not contain the same values.
+    #[rhai_fn(name = "!=", pure)]
+    pub fn not_equals(set1: &mut Set, set2: Set) -> bool {
+        *set1 != set2
+    }
+    /// Return an array with all the values in the set, in sorted order.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #["b", "a", "b"];
+    ///
+    /// print(s.to_array());        // prints "[a, b]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn to_array(set: &mut Set) -> Array {
+        set.iter().collect()
+    }
+    /// Return a new set containing the unique elements of the array.
+    ///
+    /// Only `()`, booleans, integers, floating-point numbers, characters and strings can be
+    /// kept in a set.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [3, 1, 2, 1, 3];
+    ///
+    /// print(x.to_set());          // prints "#[1, 2, 3]"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn to_set(ctx: NativeCallContext, array: Array) -> RhaiResultOf<Set> {
+        let set = Set::try_from_iter(array)
+            .map_err(|v| Set::make_item_err(ctx.engine(), &v, Position::NONE))?;
+
+        #[cfg(not(feature = "unchecked"))]
+        ctx.engine().check_set_size(&set, Position::NONE)?;
+
+        Ok(set)
+    }
+    /// Return the JSON representation of the set, which is an array.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let s = #[1, 2, 3];
+    ///
+    /// print(s.to_json());         // prints [1,2,3]
+    /// ```
+    #[cfg(not(feature = "no_object"))]
+    #[rhai_fn(pure)]
+    pub fn to_json(set: &mut Set) -> String {
+        #[cfg(feature = "metadata")]
+        return serde_json::to_string(set).unwrap_or_else(|_| "ERROR".into());
+        #[cfg(not(feature = "metadata"))]
+        return crate::api::json::format_set_as_json(set);
+    }
+}
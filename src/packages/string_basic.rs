@@ -31,6 +31,9 @@ def_package! {
         combine_with_exported_module!(lib, "number_formatting", number_formatting);
         combine_with_exported_module!(lib, "char", char_functions);
 
+        // Register the `format` function
+        super::string_format::register_format_functions(lib);
+
         // Register characters iterator
         lib.set_iterator::<CharsStream>();
 
@@ -0,0 +1,488 @@
+//! Module implementing the `format` function and format specifiers for interpolated strings.
+
+use super::string_basic::{print_with_func, FUNC_TO_DEBUG, FUNC_TO_STRING};
+use crate::func::{FnCallArgs, RhaiFunc};
+use crate::plugin::*;
+use crate::{Dynamic, FuncRegistration, ImmutableString, Module, RhaiResultOf, Shared, ERR, INT};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{
+    any::TypeId,
+    fmt::{Binary, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
+    iter::repeat,
+    str::FromStr,
+};
+
+/// Standard string formatting function.
+///
+/// This function is called to format values according to a format string,
+/// including values with format specifiers in interpolated strings (e.g. `` `${x:.2}` ``).
+pub const FUNC_FORMAT: &str = "format";
+
+/// Maximum number of values (excluding the format string) that can be passed to [`FUNC_FORMAT`].
+pub const MAX_FORMAT_ARGS: usize = 10;
+
+/// Alignment of a formatted value within its width.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Alignment {
+    /// Align to the left (`<`).
+    Left,
+    /// Align to the right (`>`).
+    Right,
+    /// Align to the center (`^`).
+    Center,
+}
+
+/// Type of formatting to apply to a value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum FormatKind {
+    /// Display format via `to_string` (no type character).
+    Display,
+    /// Debug format via `to_debug` (`?`).
+    Debug,
+    /// Lower-case hexadecimal (`x`).
+    LowerHex,
+    /// Upper-case hexadecimal (`X`).
+    UpperHex,
+    /// Binary (`b`).
+    Binary,
+    /// Octal (`o`).
+    Octal,
+    /// Lower-case scientific notation (`e`).
+    LowerExp,
+    /// Upper-case scientific notation (`E`).
+    UpperExp,
+}
+
+/// A format specifier, e.g. `>8.2` or `#010x`.
+///
+/// The syntax follows Rust's format specifiers: `[[fill]align][+]['#']['0'][width]['.'precision][type]`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FormatSpec {
+    /// Character used for padding.
+    pub fill: char,
+    /// Alignment within the width, if any.
+    pub align: Option<Alignment>,
+    /// Always show the sign of a number?
+    pub sign_plus: bool,
+    /// Alternate form (e.g. `0x` prefix for hexadecimal)?
+    pub alternate: bool,
+    /// Pad numbers with zeros after the sign?
+    pub zero_pad: bool,
+    /// Minimum width, if any.
+    pub width: Option<usize>,
+    /// Precision (number of decimal places for numbers, maximum length for text), if any.
+    pub precision: Option<usize>,
+    /// Type of formatting.
+    pub kind: FormatKind,
+}
+
+impl Default for FormatSpec {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: None,
+            sign_plus: false,
+            alternate: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+            kind: FormatKind::Display,
+        }
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        fn to_align(ch: char) -> Option<Alignment> {
+            match ch {
+                '<' => Some(Alignment::Left),
+                '>' => Some(Alignment::Right),
+                '^' => Some(Alignment::Center),
+                _ => None,
+            }
+        }
+        fn number(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<usize> {
+            let mut value = None::<usize>;
+
+            while let Some(d) = chars.peek().and_then(|ch| ch.to_digit(10)) {
+                chars.next();
+                value = Some(
+                    value
+                        .unwrap_or(0)
+                        .saturating_mul(10)
+                        .saturating_add(d as usize),
+                );
+            }
+
+            value
+        }
+
+        let mut result = Self::default();
+        let mut chars = spec.chars().peekable();
+
+        // [[fill]align]
+        let mut lookahead = spec.chars();
+        match (lookahead.next(), lookahead.next().and_then(to_align)) {
+            (Some(fill), Some(align)) => {
+                result.fill = fill;
+                result.align = Some(align);
+                chars.next();
+                chars.next();
+            }
+            (Some(ch), _) if to_align(ch).is_some() => {
+                result.align = to_align(ch);
+                chars.next();
+            }
+            _ => (),
+        }
+
+        // [+]
+        if chars.next_if_eq(&'+').is_some() {
+            result.sign_plus = true;
+        }
+        // ['#']
+        if chars.next_if_eq(&'#').is_some() {
+            result.alternate = true;
+        }
+        // ['0']
+        if chars.next_if_eq(&'0').is_some() {
+            result.zero_pad = true;
+        }
+        // [width]
+        result.width = number(&mut chars);
+        // ['.' precision]
+        if chars.next_if_eq(&'.').is_some() {
+            result.precision = number(&mut chars);
+
+            if result.precision.is_none() {
+                return Err(format!("missing precision in format specifier '{spec}'"));
+            }
+        }
+        // [type]
+        result.kind = match chars.next() {
+            None => return Ok(result),
+            Some('?') => FormatKind::Debug,
+            Some('x') => FormatKind::LowerHex,
+            Some('X') => FormatKind::UpperHex,
+            Some('b') => FormatKind::Binary,
+            Some('o') => FormatKind::Octal,
+            Some('e') => FormatKind::LowerExp,
+            Some('E') => FormatKind::UpperExp,
+            Some(ch) => return Err(format!("unknown format type '{ch}' in '{spec}'")),
+        };
+
+        match chars.next() {
+            None => Ok(result),
+            Some(..) => Err(format!("invalid format specifier '{spec}'")),
+        }
+    }
+}
+
+/// A segment of a parsed format string.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum FormatSegment {
+    /// Literal text.
+    Text(String),
+    /// An argument (by zero-based index) formatted according to a [`FormatSpec`].
+    Arg(usize, FormatSpec),
+}
+
+/// Parse a format string (e.g. `"{:>8.2} {0:x}"`) into segments.
+///
+/// Arguments are referenced via `{}` (the next argument in turn) or `{n}` (by zero-based index),
+/// each optionally followed by `:` and a format specifier. Literal braces are written as `{{`
+/// and `}}`.
+pub fn parse_format_string(format: &str) -> Result<Vec<FormatSegment>, String> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut next_arg = 0;
+    let mut chars = format.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                text.push('}');
+            }
+            '}' => return Err("unmatched '}' in format string".to_string()),
+            '{' => {
+                let rest = chars.as_str();
+                let Some(end) = rest.find('}') else {
+                    return Err("unterminated '{' in format string".to_string());
+                };
+                let placeholder = &rest[..end];
+                chars = rest[end + 1..].chars();
+
+                let (arg, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+
+                let index = match arg.trim() {
+                    "" => {
+                        next_arg += 1;
+                        next_arg - 1
+                    }
+                    arg => arg
+                        .parse::<usize>()
+                        .map_err(|_| format!("invalid argument '{arg}' in format string"))?,
+                };
+
+                if !text.is_empty() {
+                    segments.push(FormatSegment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(FormatSegment::Arg(index, spec.parse()?));
+            }
+            ch => text.push(ch),
+        }
+    }
+
+    if !text.is_empty() {
+        segments.push(FormatSegment::Text(text));
+    }
+
+    Ok(segments)
+}
+
+/// Check a constant format string against the number of arguments it is called with.
+pub fn validate_format_string(format: &str, num_args: usize) -> Result<(), String> {
+    parse_format_string(format)?
+        .iter()
+        .try_for_each(|segment| match segment {
+            FormatSegment::Arg(index, ..) if *index >= num_args => Err(format!(
+                "format string references argument {index} but only {num_args} given"
+            )),
+            _ => Ok(()),
+        })
+}
+
+/// Apply sign, prefix, zero-padding and alignment to the digits of a formatted number.
+fn pad_number(digits: &str, prefix: &str, spec: &FormatSpec) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None if spec.sign_plus => ("+", digits),
+        None => ("", digits),
+    };
+    let len = sign.len() + prefix.len() + digits.chars().count();
+
+    match spec.width {
+        Some(width) if spec.zero_pad && width > len => {
+            let zeros = "0".repeat(width - len);
+            format!("{sign}{prefix}{zeros}{digits}")
+        }
+        _ => pad_text(&format!("{sign}{prefix}{digits}"), spec, Alignment::Right),
+    }
+}
+
+/// Apply alignment to formatted text within the width of a [`FormatSpec`].
+fn pad_text(text: &str, spec: &FormatSpec, default_align: Alignment) -> String {
+    let len = text.chars().count();
+
+    let Some(padding) = spec.width.and_then(|width| width.checked_sub(len)) else {
+        return text.to_string();
+    };
+
+    let (left, right) = match spec.align.unwrap_or(default_align) {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+
+    let mut result = String::with_capacity(text.len() + padding * spec.fill.len_utf8());
+    result.extend(repeat(spec.fill).take(left));
+    result.push_str(text);
+    result.extend(repeat(spec.fill).take(right));
+    result
+}
+
+/// Format an integer according to a [`FormatSpec`].
+fn format_integer<T>(value: T, spec: &FormatSpec) -> String
+where
+    T: Display + LowerHex + UpperHex + Binary + Octal + LowerExp + UpperExp,
+{
+    let (digits, prefix) = match (spec.kind, spec.precision) {
+        (FormatKind::LowerHex, ..) => (format!("{value:x}"), "0x"),
+        (FormatKind::UpperHex, ..) => (format!("{value:X}"), "0x"),
+        (FormatKind::Binary, ..) => (format!("{value:b}"), "0b"),
+        (FormatKind::Octal, ..) => (format!("{value:o}"), "0o"),
+        (FormatKind::LowerExp, Some(p)) => (format!("{value:.p$e}"), ""),
+        (FormatKind::LowerExp, None) => (format!("{value:e}"), ""),
+        (FormatKind::UpperExp, Some(p)) => (format!("{value:.p$E}"), ""),
+        (FormatKind::UpperExp, None) => (format!("{value:E}"), ""),
+        (FormatKind::Display | FormatKind::Debug, ..) => (value.to_string(), ""),
+    };
+
+    pad_number(&digits, if spec.alternate { prefix } else { "" }, spec)
+}
+
+/// Format a floating-point or decimal number according to a [`FormatSpec`].
+///
+/// Returns [`None`] if the [`FormatSpec`] does not apply to non-integers.
+#[cfg(any(not(feature = "no_float"), feature = "decimal"))]
+fn format_real<T: Display + LowerExp + UpperExp>(
+    value: T,
+    text: impl FnOnce() -> String,
+    spec: &FormatSpec,
+) -> Option<String> {
+    let digits = match (spec.kind, spec.precision) {
+        (FormatKind::Display | FormatKind::Debug, Some(p)) => format!("{value:.p$}"),
+        (FormatKind::Display | FormatKind::Debug, None) => text(),
+        (FormatKind::LowerExp, Some(p)) => format!("{value:.p$e}"),
+        (FormatKind::LowerExp, None) => format!("{value:e}"),
+        (FormatKind::UpperExp, Some(p)) => format!("{value:.p$E}"),
+        (FormatKind::UpperExp, None) => format!("{value:E}"),
+        _ => return None,
+    };
+
+    Some(pad_number(&digits, "", spec))
+}
+
+/// Format a value according to a [`FormatSpec`].
+///
+/// Numbers are formatted natively. Other values are converted via `to_string` (or `to_debug`
+/// for the `?` format type), so custom types registering these functions are honored.
+///
+/// The value must not be _shared_.
+pub fn format_value(
+    ctx: &NativeCallContext,
+    value: &mut Dynamic,
+    spec: &FormatSpec,
+) -> RhaiResultOf<String> {
+    #[cfg(not(feature = "unchecked"))]
+    if let Some(width) = spec.width {
+        let max = ctx.engine().max_string_size();
+
+        if max > 0 && width > max {
+            return Err(
+                ERR::ErrorDataTooLarge("Length of string".to_string(), Position::NONE).into(),
+            );
+        }
+    }
+
+    if spec.kind != FormatKind::Debug {
+        macro_rules! format_integers {
+            ($($t:ty),*) => {$(
+                if let Some(&v) = value.downcast_ref::<$t>() {
+                    return Ok(format_integer(v, spec));
+                }
+            )*};
+        }
+
+        format_integers!(INT);
+
+        #[cfg(not(feature = "only_i32"))]
+        #[cfg(not(feature = "only_i64"))]
+        {
+            format_integers!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+            #[cfg(not(target_family = "wasm"))]
+            format_integers!(u128, i128);
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        if let Some(&v) = value.downcast_ref::<crate::FLOAT>() {
+            if let Some(text) = format_real(v, || value.to_string(), spec) {
+                return Ok(text);
+            }
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some(&v) = value.downcast_ref::<rust_decimal::Decimal>() {
+            if let Some(text) = format_real(v, || v.to_string(), spec) {
+                return Ok(text);
+            }
+        }
+    }
+
+    let text = match spec.kind {
+        FormatKind::Display => print_with_func(FUNC_TO_STRING, ctx, value),
+        FormatKind::Debug => print_with_func(FUNC_TO_DEBUG, ctx, value),
+        _ => {
+            return Err(ERR::ErrorMismatchDataType(
+                "number".to_string(),
+                ctx.engine().map_type_name(value.type_name()).to_string(),
+                Position::NONE,
+            )
+            .into())
+        }
+    };
+
+    let text = match spec.precision {
+        Some(max) if spec.kind == FormatKind::Display => text.chars().take(max).collect(),
+        _ => text.to_string(),
+    };
+
+    Ok(pad_text(&text, spec, Alignment::Left))
+}
+
+/// Format values according to a format string.
+pub fn format_string(
+    ctx: &NativeCallContext,
+    format: &str,
+    args: &mut [&mut Dynamic],
+) -> RhaiResultOf<ImmutableString> {
+    let segments = parse_format_string(format).map_err(|err| {
+        ERR::ErrorRuntime(
+            format!("Invalid format string: {err}").into(),
+            Position::NONE,
+        )
+    })?;
+
+    let mut result = String::new();
+
+    for segment in segments {
+        match segment {
+            FormatSegment::Text(text) => result.push_str(&text),
+            FormatSegment::Arg(index, spec) => {
+                let Some(value) = args.get_mut(index) else {
+                    return Err(ERR::ErrorRuntime(
+                        format!(
+                            "Invalid format string: argument {index} is referenced but only {} given",
+                            args.len()
+                        )
+                        .into(),
+                        Position::NONE,
+                    )
+                    .into());
+                };
+                // Keep the original argument intact for other references to it
+                let mut value = value.flatten_clone();
+                result.push_str(&format_value(ctx, &mut value, &spec)?);
+            }
+        }
+    }
+
+    Ok(result.into())
+}
+
+/// Register the `format` function, taking a format string followed by up to
+/// [`MAX_FORMAT_ARGS`] values, into a [`Module`].
+pub fn register_format_functions(lib: &mut Module) {
+    for n in 0..=MAX_FORMAT_ARGS {
+        let arg_types: Vec<_> = std::iter::once(TypeId::of::<ImmutableString>())
+            .chain(repeat(TypeId::of::<Dynamic>()).take(n))
+            .collect();
+
+        let func = |ctx: Option<NativeCallContext>, args: &mut FnCallArgs| {
+            let (format, rest) = args.split_first_mut().unwrap();
+            let format = format.clone().into_immutable_string().unwrap();
+            format_string(&ctx.unwrap(), &format, rest).map(Into::into)
+        };
+
+        FuncRegistration::new(FUNC_FORMAT).set_into_module_raw(
+            lib,
+            arg_types,
+            RhaiFunc::Method {
+                func: Shared::new(func),
+                has_context: true,
+                is_pure: true,
+                is_volatile: false,
+            },
+        );
+    }
+}
@@ -2,7 +2,8 @@
 
 use super::arithmetic::make_err as make_arithmetic_err;
 use crate::plugin::*;
-use crate::{def_package, Dynamic, RhaiResult, RhaiResultOf, INT};
+use crate::{def_package, DateTime, Dynamic, RhaiResult, RhaiResultOf, INT};
+use std::convert::TryFrom;
 
 #[cfg(not(feature = "no_float"))]
 use crate::FLOAT;
@@ -20,9 +21,52 @@ def_package! {
 
         // Register date/time functions
         combine_with_exported_module!(lib, "time", time_functions);
+        combine_with_exported_module!(lib, "duration", duration_functions);
+        combine_with_exported_module!(lib, "datetime", datetime_functions);
     }
 }
 
+/// Number of nanoseconds in a second.
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Create a [`Duration`] of `value` units, each `unit_nanos` nanoseconds long.
+fn make_duration(value: INT, unit_nanos: u64) -> RhaiResultOf<Duration> {
+    let Ok(n) = u64::try_from(value) else {
+        return Err(make_arithmetic_err(format!(
+            "Duration cannot be negative: {value}"
+        )));
+    };
+
+    let nanos = u128::from(n) * u128::from(unit_nanos);
+
+    u64::try_from(nanos / NANOS_PER_SECOND)
+        .map(|secs| {
+            #[allow(clippy::cast_possible_truncation)]
+            Duration::new(secs, (nanos % NANOS_PER_SECOND) as u32)
+        })
+        .map_err(|_| make_arithmetic_err(format!("Duration overflow: {value}")))
+}
+
+/// Create a [`Duration`] from a number of seconds.
+#[cfg(not(feature = "no_float"))]
+#[allow(clippy::useless_conversion)]
+fn make_duration_float(seconds: FLOAT) -> RhaiResultOf<Duration> {
+    if seconds.is_nan() || seconds < 0.0 {
+        return Err(make_arithmetic_err(format!(
+            "Duration cannot be negative: {seconds}"
+        )));
+    }
+
+    Duration::try_from_secs_f64(seconds.into())
+        .map_err(|_| make_arithmetic_err(format!("Duration overflow: {seconds}")))
+}
+
+/// Convert a whole number of units in a [`Duration`] into an [`INT`].
+fn duration_to_int(value: u128) -> RhaiResultOf<INT> {
+    INT::try_from(value)
+        .map_err(|_| make_arithmetic_err(format!("Integer overflow for duration: {value}")))
+}
+
 #[export_module]
 mod time_functions {
     /// Create a timestamp containing the current system time.
@@ -287,4 +331,517 @@ mod time_functions {
     pub fn gte(timestamp1: Instant, timestamp2: Instant) -> bool {
         timestamp1 >= timestamp2
     }
+
+    /// Add the specified `duration` to the timestamp and return it as a new timestamp.
+    #[rhai_fn(return_raw, name = "+")]
+    pub fn add_duration(timestamp: Instant, duration: Duration) -> RhaiResultOf<Instant> {
+        timestamp.checked_add(duration).ok_or_else(|| {
+            make_arithmetic_err(format!("Timestamp overflow when adding {duration:?}"))
+        })
+    }
+    /// Subtract the specified `duration` from the timestamp and return it as a new timestamp.
+    #[rhai_fn(return_raw, name = "-")]
+    pub fn subtract_duration(timestamp: Instant, duration: Duration) -> RhaiResultOf<Instant> {
+        timestamp.checked_sub(duration).ok_or_else(|| {
+            make_arithmetic_err(format!("Timestamp overflow when subtracting {duration:?}"))
+        })
+    }
+}
+
+#[export_module]
+mod duration_functions {
+    /// Create a duration of the specified number of `nanoseconds`.
+    #[rhai_fn(return_raw)]
+    pub fn nanoseconds(nanoseconds: INT) -> RhaiResultOf<Duration> {
+        make_duration(nanoseconds, 1)
+    }
+    /// Create a duration of the specified number of `microseconds`.
+    #[rhai_fn(return_raw)]
+    pub fn microseconds(microseconds: INT) -> RhaiResultOf<Duration> {
+        make_duration(microseconds, 1_000)
+    }
+    /// Create a duration of the specified number of `milliseconds`.
+    #[rhai_fn(return_raw)]
+    pub fn milliseconds(milliseconds: INT) -> RhaiResultOf<Duration> {
+        make_duration(milliseconds, 1_000_000)
+    }
+    /// Create a duration of the specified number of `seconds`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let d = seconds(90);
+    ///
+    /// print(d.as_minutes());      // prints 1
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn seconds(seconds: INT) -> RhaiResultOf<Duration> {
+        make_duration(seconds, 1_000_000_000)
+    }
+    /// Create a duration of the specified number of `minutes`.
+    #[rhai_fn(return_raw)]
+    pub fn minutes(minutes: INT) -> RhaiResultOf<Duration> {
+        make_duration(minutes, 60 * 1_000_000_000)
+    }
+    /// Create a duration of the specified number of `hours`.
+    #[rhai_fn(return_raw)]
+    pub fn hours(hours: INT) -> RhaiResultOf<Duration> {
+        make_duration(hours, 3_600 * 1_000_000_000)
+    }
+    /// Create a duration of the specified number of `days` (each exactly 24 hours long).
+    #[rhai_fn(return_raw)]
+    pub fn days(days: INT) -> RhaiResultOf<Duration> {
+        make_duration(days, 86_400 * 1_000_000_000)
+    }
+    /// Create a duration of the specified number of `weeks` (each exactly 7 days long).
+    #[rhai_fn(return_raw)]
+    pub fn weeks(weeks: INT) -> RhaiResultOf<Duration> {
+        make_duration(weeks, 7 * 86_400 * 1_000_000_000)
+    }
+
+    /// Return the total number of seconds in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_seconds(duration: Duration) -> RhaiResult {
+        #[cfg(not(feature = "no_float"))]
+        return Ok((duration.as_secs_f64() as FLOAT).into());
+
+        #[cfg(feature = "no_float")]
+        return duration_to_int(duration.as_secs().into()).map(Into::into);
+    }
+    /// Return the total number of whole milliseconds in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_millis(duration: Duration) -> RhaiResultOf<INT> {
+        duration_to_int(duration.as_millis())
+    }
+    /// Return the total number of whole microseconds in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_micros(duration: Duration) -> RhaiResultOf<INT> {
+        duration_to_int(duration.as_micros())
+    }
+    /// Return the total number of nanoseconds in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_nanos(duration: Duration) -> RhaiResultOf<INT> {
+        duration_to_int(duration.as_nanos())
+    }
+    /// Return the total number of whole minutes in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_minutes(duration: Duration) -> RhaiResultOf<INT> {
+        duration_to_int((duration.as_secs() / 60).into())
+    }
+    /// Return the total number of whole hours in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_hours(duration: Duration) -> RhaiResultOf<INT> {
+        duration_to_int((duration.as_secs() / 3_600).into())
+    }
+    /// Return the total number of whole days in the duration.
+    #[rhai_fn(return_raw)]
+    pub fn as_days(duration: Duration) -> RhaiResultOf<INT> {
+        duration_to_int((duration.as_secs() / 86_400).into())
+    }
+    /// Return `true` if the duration is zero.
+    #[rhai_fn(name = "is_zero", get = "is_zero")]
+    pub const fn is_zero(duration: Duration) -> bool {
+        duration.is_zero()
+    }
+
+    /// Add two durations.
+    #[rhai_fn(return_raw, name = "+")]
+    pub fn add(duration1: Duration, duration2: Duration) -> RhaiResultOf<Duration> {
+        duration1.checked_add(duration2).ok_or_else(|| {
+            make_arithmetic_err(format!("Duration overflow: {duration1:?} + {duration2:?}"))
+        })
+    }
+    /// Subtract the second duration from the first.
+    ///
+    /// An error is raised if the second duration is longer than the first.
+    #[rhai_fn(return_raw, name = "-")]
+    pub fn subtract(duration1: Duration, duration2: Duration) -> RhaiResultOf<Duration> {
+        duration1.checked_sub(duration2).ok_or_else(|| {
+            make_arithmetic_err(format!(
+                "Duration cannot be negative: {duration1:?} - {duration2:?}"
+            ))
+        })
+    }
+    /// Multiply the duration by a number.
+    #[rhai_fn(return_raw, name = "*")]
+    pub fn multiply(duration: Duration, n: INT) -> RhaiResultOf<Duration> {
+        u32::try_from(n)
+            .ok()
+            .and_then(|n| duration.checked_mul(n))
+            .ok_or_else(|| make_arithmetic_err(format!("Invalid duration: {duration:?} * {n}")))
+    }
+    /// Multiply the duration by a number.
+    #[rhai_fn(return_raw, name = "*")]
+    pub fn multiply_rev(n: INT, duration: Duration) -> RhaiResultOf<Duration> {
+        multiply(duration, n)
+    }
+    /// Divide the duration by a number.
+    #[rhai_fn(return_raw, name = "/")]
+    pub fn divide(duration: Duration, n: INT) -> RhaiResultOf<Duration> {
+        if n == 0 {
+            return Err(make_arithmetic_err(format!(
+                "Division by zero: {duration:?} / {n}"
+            )));
+        }
+
+        u32::try_from(n)
+            .ok()
+            .and_then(|n| duration.checked_div(n))
+            .ok_or_else(|| make_arithmetic_err(format!("Invalid duration: {duration:?} / {n}")))
+    }
+    /// Return `true` if two durations are equal.
+    #[rhai_fn(name = "==")]
+    pub fn eq(duration1: Duration, duration2: Duration) -> bool {
+        duration1 == duration2
+    }
+    /// Return `true` if two durations are not equal.
+    #[rhai_fn(name = "!=")]
+    pub fn ne(duration1: Duration, duration2: Duration) -> bool {
+        duration1 != duration2
+    }
+    /// Return `true` if the first duration is shorter than the second.
+    #[rhai_fn(name = "<")]
+    pub fn lt(duration1: Duration, duration2: Duration) -> bool {
+        duration1 < duration2
+    }
+    /// Return `true` if the first duration is shorter than or equals to the second.
+    #[rhai_fn(name = "<=")]
+    pub fn lte(duration1: Duration, duration2: Duration) -> bool {
+        duration1 <= duration2
+    }
+    /// Return `true` if the first duration is longer than the second.
+    #[rhai_fn(name = ">")]
+    pub fn gt(duration1: Duration, duration2: Duration) -> bool {
+        duration1 > duration2
+    }
+    /// Return `true` if the first duration is longer than or equals to the second.
+    #[rhai_fn(name = ">=")]
+    pub fn gte(duration1: Duration, duration2: Duration) -> bool {
+        duration1 >= duration2
+    }
+
+    #[cfg(not(feature = "no_float"))]
+    pub mod float_functions {
+        /// Create a duration of the specified number of `seconds`.
+        #[rhai_fn(name = "seconds", return_raw)]
+        pub fn seconds_float(seconds: FLOAT) -> RhaiResultOf<Duration> {
+            make_duration_float(seconds)
+        }
+        /// Multiply the duration by a floating-point number.
+        #[rhai_fn(return_raw, name = "*")]
+        pub fn multiply_float(duration: Duration, x: FLOAT) -> RhaiResultOf<Duration> {
+            make_duration_float(duration.as_secs_f64() as FLOAT * x)
+        }
+        /// Multiply the duration by a floating-point number.
+        #[rhai_fn(return_raw, name = "*")]
+        pub fn multiply_float_rev(x: FLOAT, duration: Duration) -> RhaiResultOf<Duration> {
+            make_duration_float(duration.as_secs_f64() as FLOAT * x)
+        }
+        /// Return the ratio between two durations.
+        #[rhai_fn(return_raw, name = "/")]
+        pub fn divide_duration(duration1: Duration, duration2: Duration) -> RhaiResultOf<FLOAT> {
+            if duration2.is_zero() {
+                return Err(make_arithmetic_err(format!(
+                    "Division by zero: {duration1:?} / {duration2:?}"
+                )));
+            }
+            Ok((duration1.as_secs_f64() / duration2.as_secs_f64()) as FLOAT)
+        }
+    }
+}
+
+#[export_module]
+mod datetime_functions {
+    /// Create a date/time containing the current system time, in UTC.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let now = now();
+    ///
+    /// print(now.year);        // prints the current year
+    /// ```
+    #[cfg(any(not(target_family = "wasm"), not(target_os = "unknown")))]
+    #[rhai_fn(volatile)]
+    pub fn now() -> DateTime {
+        DateTime::now()
+    }
+    /// Create a date/time at midnight UTC on the specified date.
+    ///
+    /// An error is raised if the date is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let d = datetime(2024, 2, 29);
+    ///
+    /// print(d);               // prints "2024-02-29T00:00:00Z"
+    /// ```
+    #[rhai_fn(name = "datetime", return_raw)]
+    pub fn datetime_date(year: INT, month: INT, day: INT) -> RhaiResultOf<DateTime> {
+        datetime(year, month, day, 0, 0, 0)
+    }
+    /// Create a date/time in UTC from the specified date and time of day.
+    ///
+    /// An error is raised if the date or time is not valid.
+    #[rhai_fn(return_raw)]
+    #[allow(clippy::useless_conversion)]
+    pub fn datetime(
+        year: INT,
+        month: INT,
+        day: INT,
+        hour: INT,
+        minute: INT,
+        second: INT,
+    ) -> RhaiResultOf<DateTime> {
+        let to_u32 = |value: INT| u32::try_from(value).unwrap_or(u32::MAX);
+
+        DateTime::from_parts(
+            year.into(),
+            to_u32(month),
+            to_u32(day),
+            to_u32(hour),
+            to_u32(minute),
+            to_u32(second),
+            0,
+            0,
+        )
+        .ok_or_else(|| {
+            make_arithmetic_err(format!(
+                "Invalid date/time: {year}-{month}-{day} {hour}:{minute}:{second}"
+            ))
+        })
+    }
+    /// Create a date/time in UTC from the number of seconds since the UNIX epoch
+    /// (1970-01-01T00:00:00Z).
+    #[rhai_fn(return_raw)]
+    #[allow(clippy::useless_conversion)]
+    pub fn from_unix(seconds: INT) -> RhaiResultOf<DateTime> {
+        DateTime::from_unix(seconds.into(), 0)
+            .ok_or_else(|| make_arithmetic_err(format!("Date/time out of range: {seconds}")))
+    }
+    /// Create a date/time in UTC from the number of milliseconds since the UNIX epoch
+    /// (1970-01-01T00:00:00Z).
+    #[rhai_fn(return_raw)]
+    #[allow(clippy::useless_conversion)]
+    pub fn from_unix_millis(milliseconds: INT) -> RhaiResultOf<DateTime> {
+        let milliseconds = i64::from(milliseconds);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nanos = milliseconds.rem_euclid(1_000) as u32 * 1_000_000;
+
+        DateTime::from_unix(milliseconds.div_euclid(1_000), nanos)
+            .ok_or_else(|| make_arithmetic_err(format!("Date/time out of range: {milliseconds}")))
+    }
+    /// Parse a date/time in ISO-8601 format.
+    ///
+    /// Accepted formats are `YYYY-MM-DD`, optionally followed by `T` (or a space) and a time
+    /// of day (`HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff`), optionally followed by `Z` or an
+    /// offset from UTC (`±HH:MM`). A missing offset is taken as UTC.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let d = parse_datetime("2024-02-29T13:45:00+05:30");
+    ///
+    /// print(d.hour);          // prints 13
+    ///
+    /// print(d.to_utc());      // prints "2024-02-29T08:15:00Z"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn parse_datetime(string: &str) -> RhaiResultOf<DateTime> {
+        string.parse().map_err(make_arithmetic_err)
+    }
+
+    /// Return the year of the date/time.
+    #[rhai_fn(name = "year", get = "year")]
+    pub fn year(datetime: DateTime) -> INT {
+        #[allow(clippy::cast_possible_truncation)]
+        return datetime.date().0 as INT;
+    }
+    /// Return the month (1 to 12) of the date/time.
+    #[rhai_fn(name = "month", get = "month")]
+    pub fn month(datetime: DateTime) -> INT {
+        datetime.date().1 as INT
+    }
+    /// Return the day of the month (1 to 31) of the date/time.
+    #[rhai_fn(name = "day", get = "day")]
+    pub fn day(datetime: DateTime) -> INT {
+        datetime.date().2 as INT
+    }
+    /// Return the hour (0 to 23) of the date/time.
+    #[rhai_fn(name = "hour", get = "hour")]
+    pub fn hour(datetime: DateTime) -> INT {
+        datetime.time_of_day().0 as INT
+    }
+    /// Return the minute (0 to 59) of the date/time.
+    #[rhai_fn(name = "minute", get = "minute")]
+    pub fn minute(datetime: DateTime) -> INT {
+        datetime.time_of_day().1 as INT
+    }
+    /// Return the second (0 to 59) of the date/time.
+    #[rhai_fn(name = "second", get = "second")]
+    pub fn second(datetime: DateTime) -> INT {
+        datetime.time_of_day().2 as INT
+    }
+    /// Return the fraction of the second of the date/time, in nanoseconds.
+    #[rhai_fn(name = "nanosecond", get = "nanosecond")]
+    pub fn nanosecond(datetime: DateTime) -> INT {
+        datetime.time_of_day().3 as INT
+    }
+    /// Return the day of the week of the date/time, from 1 (Monday) to 7 (Sunday).
+    #[rhai_fn(name = "weekday", get = "weekday")]
+    pub fn weekday(datetime: DateTime) -> INT {
+        datetime.weekday() as INT
+    }
+    /// Return the day of the year (1 to 366) of the date/time.
+    #[rhai_fn(name = "day_of_year", get = "day_of_year")]
+    pub fn day_of_year(datetime: DateTime) -> INT {
+        datetime.day_of_year() as INT
+    }
+    /// Return the offset from UTC of the date/time, in seconds.
+    #[rhai_fn(name = "offset", get = "offset")]
+    pub fn offset(datetime: DateTime) -> INT {
+        datetime.offset() as INT
+    }
+    /// Return the number of whole seconds since the UNIX epoch (1970-01-01T00:00:00Z).
+    #[rhai_fn(name = "unix", get = "unix", return_raw)]
+    pub fn unix(datetime: DateTime) -> RhaiResultOf<INT> {
+        let seconds = datetime.unix().0;
+
+        INT::try_from(seconds).map_err(|_| {
+            make_arithmetic_err(format!("Integer overflow for datetime.unix: {seconds}"))
+        })
+    }
+    /// Return the number of whole milliseconds since the UNIX epoch (1970-01-01T00:00:00Z).
+    #[rhai_fn(name = "unix_millis", get = "unix_millis", return_raw)]
+    pub fn unix_millis(datetime: DateTime) -> RhaiResultOf<INT> {
+        let (seconds, nanos) = datetime.unix();
+
+        seconds
+            .checked_mul(1_000)
+            .and_then(|ms| ms.checked_add((nanos / 1_000_000).into()))
+            .and_then(|ms| INT::try_from(ms).ok())
+            .ok_or_else(|| {
+                make_arithmetic_err(format!(
+                    "Integer overflow for datetime.unix_millis: {seconds}"
+                ))
+            })
+    }
+
+    /// Return the same point in time in UTC.
+    pub fn to_utc(datetime: DateTime) -> DateTime {
+        DateTime::from_system_time(datetime.system_time())
+    }
+    /// Return the same point in time at a fixed offset from UTC, in seconds.
+    ///
+    /// An error is raised if the offset is not within ±24 hours.
+    #[rhai_fn(return_raw)]
+    #[allow(clippy::useless_conversion)]
+    pub fn with_offset(datetime: DateTime, seconds: INT) -> RhaiResultOf<DateTime> {
+        i32::try_from(seconds)
+            .ok()
+            .and_then(|offset| datetime.with_offset(offset))
+            .ok_or_else(|| make_arithmetic_err(format!("Invalid offset: {seconds}")))
+    }
+    /// Return the same point in time at a fixed offset from UTC, in the form `Z`, `±HH`,
+    /// `±HH:MM` or `±HHMM`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let d = datetime(2024, 1, 1, 12, 0, 0);
+    ///
+    /// print(d.with_offset("-08:00"));     // prints "2024-01-01T04:00:00-08:00"
+    /// ```
+    #[rhai_fn(name = "with_offset", return_raw)]
+    pub fn with_offset_str(datetime: DateTime, offset: &str) -> RhaiResultOf<DateTime> {
+        DateTime::parse_offset(offset)
+            .and_then(|offset| datetime.with_offset(offset))
+            .ok_or_else(|| make_arithmetic_err(format!("Invalid offset: {offset}")))
+    }
+    /// Move the date/time by a number of calendar months, keeping the time of day.
+    ///
+    /// If the day does not exist in the target month, the last day of that month is used.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let d = datetime(2024, 1, 31);
+    ///
+    /// print(d.add_months(1));     // prints "2024-02-29T00:00:00Z"
+    /// ```
+    #[rhai_fn(return_raw)]
+    #[allow(clippy::useless_conversion)]
+    pub fn add_months(datetime: DateTime, months: INT) -> RhaiResultOf<DateTime> {
+        datetime.add_months(months.into()).ok_or_else(|| {
+            make_arithmetic_err(format!("Date/time overflow when adding {months} month(s)"))
+        })
+    }
+    /// Move the date/time by a number of calendar years, keeping the time of day.
+    ///
+    /// If the day does not exist in the target year (i.e. February 29), February 28 is used.
+    #[rhai_fn(return_raw)]
+    #[allow(clippy::useless_conversion)]
+    pub fn add_years(datetime: DateTime, years: INT) -> RhaiResultOf<DateTime> {
+        i64::from(years)
+            .checked_mul(12)
+            .and_then(|months| datetime.add_months(months))
+            .ok_or_else(|| {
+                make_arithmetic_err(format!("Date/time overflow when adding {years} year(s)"))
+            })
+    }
+
+    /// Add the specified `duration` to the date/time and return it as a new date/time.
+    #[rhai_fn(return_raw, name = "+")]
+    pub fn add(datetime: DateTime, duration: Duration) -> RhaiResultOf<DateTime> {
+        datetime.checked_add(duration).ok_or_else(|| {
+            make_arithmetic_err(format!("Date/time overflow when adding {duration:?}"))
+        })
+    }
+    /// Subtract the specified `duration` from the date/time and return it as a new date/time.
+    #[rhai_fn(return_raw, name = "-")]
+    pub fn subtract(datetime: DateTime, duration: Duration) -> RhaiResultOf<DateTime> {
+        datetime.checked_sub(duration).ok_or_else(|| {
+            make_arithmetic_err(format!("Date/time overflow when subtracting {duration:?}"))
+        })
+    }
+    /// Return the duration between two date/times.
+    ///
+    /// An error is raised if the second date/time is later than the first.
+    #[rhai_fn(return_raw, name = "-")]
+    pub fn diff(datetime1: DateTime, datetime2: DateTime) -> RhaiResultOf<Duration> {
+        datetime1.duration_since(&datetime2).ok_or_else(|| {
+            make_arithmetic_err(format!("Date/time is earlier: {datetime1} - {datetime2}"))
+        })
+    }
+    /// Return `true` if two date/times refer to the same point in time.
+    #[rhai_fn(name = "==")]
+    pub fn eq(datetime1: DateTime, datetime2: DateTime) -> bool {
+        datetime1 == datetime2
+    }
+    /// Return `true` if two date/times do not refer to the same point in time.
+    #[rhai_fn(name = "!=")]
+    pub fn ne(datetime1: DateTime, datetime2: DateTime) -> bool {
+        datetime1 != datetime2
+    }
+    /// Return `true` if the first date/time is earlier than the second.
+    #[rhai_fn(name = "<")]
+    pub fn lt(datetime1: DateTime, datetime2: DateTime) -> bool {
+        datetime1 < datetime2
+    }
+    /// Return `true` if the first date/time is earlier than or equals to the second.
+    #[rhai_fn(name = "<=")]
+    pub fn lte(datetime1: DateTime, datetime2: DateTime) -> bool {
+        datetime1 <= datetime2
+    }
+    /// Return `true` if the first date/time is later than the second.
+    #[rhai_fn(name = ">")]
+    pub fn gt(datetime1: DateTime, datetime2: DateTime) -> bool {
+        datetime1 > datetime2
+    }
+    /// Return `true` if the first date/time is later than or equals to the second.
+    #[rhai_fn(name = ">=")]
+    pub fn gte(datetime1: DateTime, datetime2: DateTime) -> bool {
+        datetime1 >= datetime2
+    }
 }
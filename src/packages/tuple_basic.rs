@@ -0,0 +1,113 @@
+#![cfg(not(feature = "no_index"))]
+
+use super::array_basic::array_functions;
+use crate::eval::calc_index;
+use crate::plugin::*;
+use crate::{
+    def_package, Array, Dynamic, NativeCallContext, Position, RhaiResultOf, Tuple, ERR, INT,
+};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+def_package! {
+    /// Package of basic tuple utilities.
+    pub BasicTuplePackage(lib) {
+        lib.set_standard_lib(true);
+
+        combine_with_exported_module!(lib, "tuple", tuple_functions);
+
+        // Register tuple iterator
+        lib.set_iterable::<Tuple>();
+    }
+}
+
+#[export_module]
+mod tuple_functions {
+    /// Number of items in the tuple.
+    #[rhai_fn(name = "len", get = "len", pure)]
+    pub fn len(tuple: &mut Tuple) -> INT {
+        tuple.len() as INT
+    }
+    /// Get a copy of the item at the `index` position in the tuple.
+    ///
+    /// * If `index` < 0, position counts from the end of the tuple (`-1` is the last item).
+    /// * If `index` is out of bounds, an error is raised.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let t = (1, "hello", true);
+    ///
+    /// print(t[1]);            // prints "hello"
+    ///
+    /// print(t[-1]);           // prints true
+    /// ```
+    #[rhai_fn(index_get, return_raw, pure)]
+    pub fn get(tuple: &mut Tuple, index: INT) -> RhaiResultOf<Dynamic> {
+        let len = tuple.len();
+
+        calc_index(len, index, true, || {
+            Err(ERR::ErrorArrayBounds(len, index, Position::NONE).into())
+        })
+        .map(|index| tuple[index].clone())
+    }
+    /// Return an array with all the items in the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let t = (1, 2, 3);
+    ///
+    /// print(t.to_array());    // prints "[1, 2, 3]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn to_array(tuple: &mut Tuple) -> Array {
+        tuple.to_vec()
+    }
+    /// Return a tuple with all the elements of the array.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3];
+    ///
+    /// print(x.to_tuple());    // prints "(1, 2, 3)"
+    /// ```
+    pub fn to_tuple(array: Array) -> Tuple {
+        array.into()
+    }
+    /// Return `true` if two tuples are equal (i.e. all items are equal and in the same order).
+    ///
+    /// The operator `==` is used to compare items and must be defined,
+    /// otherwise `false` is assumed.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let t = (1, "x");
+    ///
+    /// print(t == (1, "x"));   // prints true
+    ///
+    /// print(t == (1, "y"));   // prints false
+    /// ```
+    #[rhai_fn(name = "==", return_raw, pure)]
+    pub fn equals(ctx: NativeCallContext, tuple1: &mut Tuple, tuple2: Tuple) -> RhaiResultOf<bool> {
+        if tuple1.len() != tuple2.len() {
+            return Ok(false);
+        }
+
+        array_functions::equals(ctx, &mut tuple1.to_vec(), tuple2.into())
+    }
+    /// Return `true` if two tuples are not-equal (i.e. any item not equal or not in the same order).
+    ///
+    /// The operator `==` is used to compare items and must be defined,
+    /// otherwise `false` is assumed.
+    #[rhai_fn(name = "!=", return_raw, pure)]
+    pub fn not_equals(
+        ctx: NativeCallContext,
+        tuple1: &mut Tuple,
+        tuple2: Tuple,
+    ) -> RhaiResultOf<bool> {
+        equals(ctx, tuple1, tuple2).map(|r| !r)
+    }
+}
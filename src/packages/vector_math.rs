@@ -0,0 +1,543 @@
+#![cfg(not(feature = "no_float"))]
+
+use crate::eval::calc_index;
+use crate::plugin::*;
+use crate::types::vector::{Mat, Vec2, Vec3, Vec4, Vector};
+use crate::{
+    def_package, Dynamic, NativeCallContext, Position, RhaiError, RhaiResultOf, ERR, FLOAT, INT,
+};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+#[cfg(not(feature = "no_index"))]
+use crate::Array;
+
+def_package! {
+    /// Package of vector and matrix math, with the `Vec2`, `Vec3`, `Vec4` and `Mat` types.
+    ///
+    /// This package is not part of the [`StandardPackage`][super::StandardPackage] and must be
+    /// registered explicitly.
+    pub VectorMathPackage(lib) {
+        lib.set_standard_lib(true);
+
+        combine_with_exported_module!(lib, "vector", vector_functions);
+        combine_with_exported_module!(lib, "vector", vec_n::vec2::functions);
+        combine_with_exported_module!(lib, "vector", vec_n::vec3::functions);
+        combine_with_exported_module!(lib, "vector", vec_n::vec4::functions);
+        combine_with_exported_module!(lib, "matrix", matrix_functions);
+
+        #[cfg(not(feature = "no_object"))]
+        {
+            reg_components::<2>(lib, &["x", "y"]);
+            reg_components::<3>(lib, &["x", "y", "z"]);
+            reg_components::<4>(lib, &["x", "y", "z", "w"]);
+        }
+    }
+}
+
+/// Register property getters and setters for the components of a [`Vector`].
+#[cfg(not(feature = "no_object"))]
+fn reg_components<const N: usize>(lib: &mut Module, names: &[&str; N]) {
+    for (i, name) in names.iter().enumerate() {
+        FuncRegistration::new_getter(name).set_into_module(lib, move |v: &mut Vector<N>| v.0[i]);
+        FuncRegistration::new_setter(name)
+            .set_into_module(lib, move |v: &mut Vector<N>, value: FLOAT| v.0[i] = value);
+        FuncRegistration::new_setter(name)
+            .set_into_module(lib, move |v: &mut Vector<N>, value: INT| {
+                v.0[i] = value as FLOAT
+            });
+    }
+}
+
+/// Convert a number into a [`FLOAT`] vector component or matrix element.
+fn to_float(value: &Dynamic) -> RhaiResultOf<FLOAT> {
+    value
+        .as_float()
+        .or_else(|_| value.as_int().map(|v| v as FLOAT))
+        .map_err(|typ| {
+            ERR::ErrorMismatchDataType("float".into(), typ.into(), Position::NONE).into()
+        })
+}
+
+/// Create an error for a dimensions mismatch.
+fn make_dims_err(msg: impl Into<String>) -> RhaiError {
+    ERR::ErrorArithmetic(msg.into(), Position::NONE).into()
+}
+
+/// Maximum number of elements in a matrix created from its dimensions, regardless of the limit on
+/// array sizes.
+const MAX_MATRIX_SIZE: INT = 1 << 20;
+
+/// Check that matrix dimensions are positive and not too large, and return them as `usize`.
+fn make_dims(ctx: &NativeCallContext, rows: INT, cols: INT) -> RhaiResultOf<(usize, usize)> {
+    if rows <= 0 || cols <= 0 {
+        return Err(make_dims_err(format!(
+            "Matrix dimensions must be positive: {rows}x{cols}"
+        )));
+    }
+    if rows.checked_mul(cols).map_or(true, |n| n > MAX_MATRIX_SIZE) {
+        return Err(ERR::ErrorDataTooLarge("Size of matrix".to_string(), Position::NONE).into());
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let (rows, cols) = (rows as usize, cols as usize);
+
+    #[cfg(not(feature = "unchecked"))]
+    ctx.engine().throw_on_size((rows * cols, 0, 0))?;
+    #[cfg(feature = "unchecked")]
+    let _ = ctx;
+
+    Ok((rows, cols))
+}
+
+/// Create an error for an out-of-bounds index.
+fn make_bounds_err<T>(len: usize, index: INT) -> RhaiResultOf<T> {
+    Err(ERR::ErrorArrayBounds(len, index, Position::NONE).into())
+}
+
+/// Resolve a (possibly negative) row and column into a position in the matrix.
+fn locate(m: &Mat, row: INT, col: INT) -> RhaiResultOf<(usize, usize)> {
+    let r = calc_index(m.rows(), row, true, || make_bounds_err(m.rows(), row))?;
+    let c = calc_index(m.cols(), col, true, || make_bounds_err(m.cols(), col))?;
+    Ok((r, c))
+}
+/// Create an error for mismatched matrix dimensions in a binary operation.
+fn dims_mismatch(m1: &Mat, op: &str, m2: &Mat) -> RhaiError {
+    make_dims_err(format!(
+        "Matrix dimensions mismatch: {}x{} {op} {}x{}",
+        m1.rows(),
+        m1.cols(),
+        m2.rows(),
+        m2.cols()
+    ))
+}
+
+#[export_module]
+mod vector_functions {
+    /// Create a two-dimensional vector from its components.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let v = vec2(1, 2.5);
+    ///
+    /// print(v.y);         // prints 2.5
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn vec2(x: Dynamic, y: Dynamic) -> RhaiResultOf<Vec2> {
+        Ok(Vector([to_float(&x)?, to_float(&y)?]))
+    }
+    /// Create a three-dimensional vector from its components.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let v = vec3(1, 2, 3);
+    ///
+    /// print(v);           // prints "vec3(1.0, 2.0, 3.0)"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn vec3(x: Dynamic, y: Dynamic, z: Dynamic) -> RhaiResultOf<Vec3> {
+        Ok(Vector([to_float(&x)?, to_float(&y)?, to_float(&z)?]))
+    }
+    /// Create a four-dimensional vector from its components.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let v = vec4(1, 2, 3, 1);
+    ///
+    /// print(v.w);         // prints 1.0
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn vec4(x: Dynamic, y: Dynamic, z: Dynamic, w: Dynamic) -> RhaiResultOf<Vec4> {
+        Ok(Vector([
+            to_float(&x)?,
+            to_float(&y)?,
+            to_float(&z)?,
+            to_float(&w)?,
+        ]))
+    }
+    /// Return the cross product of two three-dimensional vectors.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let v = vec3(1, 0, 0).cross(vec3(0, 1, 0));
+    ///
+    /// print(v);           // prints "vec3(0.0, 0.0, 1.0)"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn cross(v1: &mut Vec3, v2: Vec3) -> Vec3 {
+        v1.cross(&v2)
+    }
+}
+
+macro_rules! gen_vector_functions {
+    ($root:ident => $($mod_name:ident : $arg_type:ident),+) => {
+        pub mod $root { $(pub mod $mod_name {
+            use super::super::*;
+
+            type VecN = $arg_type;
+
+            #[export_module]
+            pub mod functions {
+                #[rhai_fn(name = "+")]
+                pub fn add(v1: VecN, v2: VecN) -> VecN {
+                    v1.zip_with(&v2, |x, y| x + y)
+                }
+                #[rhai_fn(name = "-")]
+                pub fn subtract(v1: VecN, v2: VecN) -> VecN {
+                    v1.zip_with(&v2, |x, y| x - y)
+                }
+                #[rhai_fn(name = "-")]
+                pub fn neg(v: VecN) -> VecN {
+                    v.map(|x| -x)
+                }
+                #[rhai_fn(name = "*")]
+                pub fn multiply(v1: VecN, v2: VecN) -> VecN {
+                    v1.zip_with(&v2, |x, y| x * y)
+                }
+                #[rhai_fn(name = "*")]
+                pub fn scale(v: VecN, s: FLOAT) -> VecN {
+                    v.map(|x| x * s)
+                }
+                #[rhai_fn(name = "*")]
+                pub fn scale_int(v: VecN, s: INT) -> VecN {
+                    scale(v, s as FLOAT)
+                }
+                #[rhai_fn(name = "*")]
+                pub fn scale_rev(s: FLOAT, v: VecN) -> VecN {
+                    scale(v, s)
+                }
+                #[rhai_fn(name = "*")]
+                pub fn scale_int_rev(s: INT, v: VecN) -> VecN {
+                    scale(v, s as FLOAT)
+                }
+                #[rhai_fn(name = "/")]
+                pub fn divide(v: VecN, s: FLOAT) -> VecN {
+                    v.map(|x| x / s)
+                }
+                #[rhai_fn(name = "/")]
+                pub fn divide_int(v: VecN, s: INT) -> VecN {
+                    divide(v, s as FLOAT)
+                }
+                #[rhai_fn(name = "==")]
+                pub fn eq(v1: VecN, v2: VecN) -> bool {
+                    v1 == v2
+                }
+                #[rhai_fn(name = "!=")]
+                pub fn ne(v1: VecN, v2: VecN) -> bool {
+                    v1 != v2
+                }
+                #[rhai_fn(pure)]
+                pub fn dot(v1: &mut VecN, v2: VecN) -> FLOAT {
+                    v1.dot(&v2)
+                }
+                #[rhai_fn(name = "length", get = "length", pure)]
+                pub fn length(v: &mut VecN) -> FLOAT {
+                    v.length()
+                }
+                #[rhai_fn(name = "length_squared", get = "length_squared", pure)]
+                pub fn length_squared(v: &mut VecN) -> FLOAT {
+                    v.dot(v)
+                }
+                #[rhai_fn(pure)]
+                pub fn distance(v1: &mut VecN, v2: VecN) -> FLOAT {
+                    v1.zip_with(&v2, |x, y| x - y).length()
+                }
+                #[rhai_fn(pure, return_raw)]
+                pub fn normalize(v: &mut VecN) -> RhaiResultOf<VecN> {
+                    v.normalize().ok_or_else(|| make_dims_err(format!("Cannot normalize a zero-length vector: {v}")))
+                }
+                #[rhai_fn(name = "transform", pure, return_raw)]
+                pub fn transform(m: &mut Mat, v: VecN) -> RhaiResultOf<VecN> {
+                    m.transform(&v).ok_or_else(|| make_dims_err(format!(
+                        "Cannot transform a {}-dimensional vector by a {}x{} matrix",
+                        v.0.len(), m.rows(), m.cols()
+                    )))
+                }
+                #[rhai_fn(name = "*", return_raw)]
+                pub fn mat_multiply(m: Mat, v: VecN) -> RhaiResultOf<VecN> {
+                    m.mul_vector(&v).ok_or_else(|| make_dims_err(format!(
+                        "Matrix dimensions mismatch: {}x{} * {}-dimensional vector",
+                        m.rows(), m.cols(), v.0.len()
+                    )))
+                }
+                #[rhai_fn(index_get, return_raw, pure)]
+                pub fn get(v: &mut VecN, index: INT) -> RhaiResultOf<FLOAT> {
+                    let len = v.0.len();
+                    let index = calc_index(len, index, true, || make_bounds_err(len, index))?;
+                    Ok(v.0[index])
+                }
+                #[rhai_fn(index_set, return_raw)]
+                pub fn set(v: &mut VecN, index: INT, value: Dynamic) -> RhaiResultOf<()> {
+                    let len = v.0.len();
+                    let index = calc_index(len, index, true, || make_bounds_err(len, index))?;
+                    v.0[index] = to_float(&value)?;
+                    Ok(())
+                }
+                #[cfg(not(feature = "no_index"))]
+                #[rhai_fn(pure)]
+                pub fn to_array(v: &mut VecN) -> Array {
+                    v.0.iter().map(|&x| x.into()).collect()
+                }
+            }
+        })* }
+    }
+}
+
+gen_vector_functions!(vec_n => vec2: Vec2, vec3: Vec3, vec4: Vec4);
+
+#[export_module]
+mod matrix_functions {
+    /// Create a matrix from an array of rows, each of which is an array of numbers.
+    ///
+    /// All rows must be of the same, non-zero, length.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat([[1, 2], [3, 4]]);
+    ///
+    /// print(m.determinant()); // prints -2.0
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[rhai_fn(return_raw)]
+    pub fn mat(rows: Array) -> RhaiResultOf<Mat> {
+        let num_rows = rows.len();
+        let mut num_cols = 0;
+        let mut data = Vec::new();
+
+        for (r, row) in rows.into_iter().enumerate() {
+            let typ = row.type_name();
+            let row = row.try_cast::<Array>().ok_or_else(|| {
+                ERR::ErrorMismatchDataType("array".into(), typ.into(), Position::NONE)
+            })?;
+
+            if r == 0 {
+                num_cols = row.len();
+            } else if row.len() != num_cols {
+                return Err(make_dims_err(format!(
+                    "Matrix rows must have the same length: {} != {num_cols}",
+                    row.len()
+                )));
+            }
+
+            for value in row {
+                data.push(to_float(&value)?);
+            }
+        }
+
+        if num_rows == 0 || num_cols == 0 {
+            return Err(make_dims_err("Matrix cannot be empty"));
+        }
+
+        Ok(Mat::from_vec(num_rows, num_cols, data).unwrap())
+    }
+    /// Create a matrix of the specified dimensions with all elements zero.
+    ///
+    /// An error is raised if the matrix would have more than 1,048,576 elements.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat_zeros(2, 3);
+    ///
+    /// print(m);           // prints "mat([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn mat_zeros(ctx: NativeCallContext, rows: INT, cols: INT) -> RhaiResultOf<Mat> {
+        let (rows, cols) = make_dims(&ctx, rows, cols)?;
+        Ok(Mat::zeros(rows, cols))
+    }
+    /// Create a square identity matrix of the specified size.
+    ///
+    /// An error is raised if the matrix would have more than 1,048,576 elements.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat_identity(2);
+    ///
+    /// print(m);           // prints "mat([[1.0, 0.0], [0.0, 1.0]])"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn mat_identity(ctx: NativeCallContext, size: INT) -> RhaiResultOf<Mat> {
+        let (size, _) = make_dims(&ctx, size, size)?;
+        Ok(Mat::identity(size))
+    }
+    /// Number of rows in the matrix.
+    #[rhai_fn(name = "rows", get = "rows", pure)]
+    pub fn rows(m: &mut Mat) -> INT {
+        m.rows() as INT
+    }
+    /// Number of columns in the matrix.
+    #[rhai_fn(name = "cols", get = "cols", pure)]
+    pub fn cols(m: &mut Mat) -> INT {
+        m.cols() as INT
+    }
+    /// Get the element at the specified `row` and `col`.
+    ///
+    /// * If `row` < 0, position counts from the last row.
+    /// * If `col` < 0, position counts from the last column.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat([[1, 2], [3, 4]]);
+    ///
+    /// print(m.get(1, 0)); // prints 3.0
+    ///
+    /// print(m.get(-1, -1)); // prints 4.0
+    /// ```
+    #[rhai_fn(pure, return_raw)]
+    pub fn get(m: &mut Mat, row: INT, col: INT) -> RhaiResultOf<FLOAT> {
+        let (r, c) = locate(m, row, col)?;
+        Ok(m.get(r, c))
+    }
+    /// Set the element at the specified `row` and `col` to a new value.
+    ///
+    /// * If `row` < 0, position counts from the last row.
+    /// * If `col` < 0, position counts from the last column.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat_zeros(2, 2);
+    ///
+    /// m.set(0, 1, 42);
+    ///
+    /// print(m);           // prints "mat([[0.0, 42.0], [0.0, 0.0]])"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn set(m: &mut Mat, row: INT, col: INT, value: Dynamic) -> RhaiResultOf<()> {
+        let (r, c) = locate(m, row, col)?;
+        m.set(r, c, to_float(&value)?);
+        Ok(())
+    }
+    /// Return the matrix as an array of rows, each of which is an array of elements.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat_identity(2);
+    ///
+    /// print(m.to_array()); // prints "[[1.0, 0.0], [0.0, 1.0]]"
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[rhai_fn(pure)]
+    pub fn to_array(m: &mut Mat) -> Array {
+        (0..m.rows())
+            .map(|r| {
+                let row: Array = m.row(r).iter().map(|&x| x.into()).collect();
+                row.into()
+            })
+            .collect()
+    }
+    /// Return the transpose of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat([[1, 2, 3]]);
+    ///
+    /// print(m.transpose()); // prints "mat([[1.0], [2.0], [3.0]])"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn transpose(m: &mut Mat) -> Mat {
+        m.transpose()
+    }
+    /// Return the determinant of a square matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat([[2, 0], [0, 3]]);
+    ///
+    /// print(m.determinant()); // prints 6.0
+    /// ```
+    #[rhai_fn(pure, return_raw)]
+    pub fn determinant(m: &mut Mat) -> RhaiResultOf<FLOAT> {
+        m.determinant().ok_or_else(|| {
+            make_dims_err(format!(
+                "Determinant requires a square matrix: {}x{}",
+                m.rows(),
+                m.cols()
+            ))
+        })
+    }
+    /// Return the inverse of a square matrix.
+    ///
+    /// An error is thrown if the matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = mat([[2, 0], [0, 4]]);
+    ///
+    /// print(m.inverse());     // prints "mat([[0.5, 0.0], [0.0, 0.25]])"
+    /// ```
+    #[rhai_fn(pure, return_raw)]
+    pub fn inverse(m: &mut Mat) -> RhaiResultOf<Mat> {
+        if !m.is_square() {
+            return Err(make_dims_err(format!(
+                "Inverse requires a square matrix: {}x{}",
+                m.rows(),
+                m.cols()
+            )));
+        }
+        m.inverse()
+            .ok_or_else(|| make_dims_err("Cannot invert a singular matrix"))
+    }
+    #[rhai_fn(name = "+", return_raw)]
+    pub fn add(m1: Mat, m2: Mat) -> RhaiResultOf<Mat> {
+        m1.zip_with(&m2, |x, y| x + y)
+            .ok_or_else(|| dims_mismatch(&m1, "+", &m2))
+    }
+    #[rhai_fn(name = "-", return_raw)]
+    pub fn subtract(m1: Mat, m2: Mat) -> RhaiResultOf<Mat> {
+        m1.zip_with(&m2, |x, y| x - y)
+            .ok_or_else(|| dims_mismatch(&m1, "-", &m2))
+    }
+    #[rhai_fn(name = "-")]
+    pub fn neg(m: Mat) -> Mat {
+        m.map(|x| -x)
+    }
+    #[rhai_fn(name = "*", return_raw)]
+    pub fn multiply(m1: Mat, m2: Mat) -> RhaiResultOf<Mat> {
+        m1.mul(&m2).ok_or_else(|| dims_mismatch(&m1, "*", &m2))
+    }
+    #[rhai_fn(name = "*")]
+    pub fn scale(m: Mat, s: FLOAT) -> Mat {
+        m.map(|x| x * s)
+    }
+    #[rhai_fn(name = "*")]
+    pub fn scale_int(m: Mat, s: INT) -> Mat {
+        m.map(|x| x * s as FLOAT)
+    }
+    #[rhai_fn(name = "*")]
+    pub fn scale_rev(s: FLOAT, m: Mat) -> Mat {
+        m.map(|x| x * s)
+    }
+    #[rhai_fn(name = "*")]
+    pub fn scale_int_rev(s: INT, m: Mat) -> Mat {
+        m.map(|x| x * s as FLOAT)
+    }
+    #[rhai_fn(name = "/")]
+    pub fn divide(m: Mat, s: FLOAT) -> Mat {
+        m.map(|x| x / s)
+    }
+    #[rhai_fn(name = "/")]
+    pub fn divide_int(m: Mat, s: INT) -> Mat {
+        m.map(|x| x / s as FLOAT)
+    }
+    #[rhai_fn(name = "==", pure)]
+    pub fn equals(m1: &mut Mat, m2: Mat) -> bool {
+        *m1 == m2
+    }
+    #[rhai_fn(name = "!=", pure)]
+    pub fn not_equals(m1: &mut Mat, m2: Mat) -> bool {
+        *m1 != m2
+    }
+}
@@ -2,13 +2,14 @@
 
 use crate::api::options::LangOptions;
 use crate::ast::{
-    ASTFlags, BinaryExpr, CaseBlocksList, Expr, FlowControl, FnCallExpr, FnCallHashes, Ident,
-    OpAssignment, RangeCase, ScriptFuncDef, Stmt, StmtBlock, StmtBlockContainer,
-    SwitchCasesCollection,
+    ASTFlags, BinaryExpr, CaseBlocksList, CatchClause, Expr, FlowControl, FnCallExpr, FnCallHashes,
+    Ident, OpAssignment, RangeCase, ScriptFuncDef, Stmt, StmtBlock, StmtBlockContainer,
+    SwitchCasesCollection, TryCatchBlock,
 };
 use crate::engine::{Precedence, OP_CONTAINS, OP_NOT};
 use crate::eval::{Caches, GlobalRuntimeState};
 use crate::func::{hashing::get_hasher, StraightHashMap};
+use crate::packages::string_format::{validate_format_string, FormatSpec, FUNC_FORMAT};
 use crate::tokenizer::{
     is_reserved_keyword_or_symbol, is_valid_function_name, is_valid_identifier, Token, TokenStream,
     TokenizerControl,
@@ -34,6 +35,26 @@ pub type ParseResult<T> = Result<T, ParseError>;
 #[cfg(not(feature = "no_function"))]
 type FnLib = StraightHashMap<Shared<ScriptFuncDef>>;
 
+/// _(internals)_ Definition of an inline `module` block.
+/// Exported under the `internals` feature only.
+///
+/// It is compiled into a [module][crate::Module] when parsing is complete.
+#[cfg(not(feature = "no_module"))]
+#[cfg(not(feature = "no_function"))]
+#[derive(Debug, Clone)]
+pub struct ModuleDef {
+    /// Name of the module.
+    pub name: ImmutableString,
+    /// Functions defined in the module.
+    pub functions: Vec<Shared<ScriptFuncDef>>,
+    /// Variables defined in the module.
+    ///
+    /// Each entry is the name, the value, whether it is a constant and whether it is exported.
+    pub variables: ThinVec<(ImmutableString, Dynamic, bool, bool)>,
+    /// Inline modules nested in the module.
+    pub modules: ThinVec<ModuleDef>,
+}
+
 /// Invalid variable name that acts as a search barrier in a [`Scope`].
 const SCOPE_SEARCH_BARRIER_MARKER: &str = "$ BARRIER $";
 
@@ -77,12 +98,28 @@ pub struct ParseState<'a, 't, 'f> {
     ///
     /// All consequent calls to [`access_var`][ParseState::access_var] will not be affected.
     pub allow_capture: bool,
+    /// Labels of the enclosing loops.
+    pub loop_labels: ThinVec<ImmutableString>,
+    /// Undefined variables (under Strict Variables mode) pending to be checked, if any.
+    ///
+    /// This is used when parsing an item that may turn out to be the value of a comprehension,
+    /// which refers to variables that are only defined afterwards.
+    pub pending_vars: Option<ThinVec<Ident>>,
     /// Encapsulates a local stack with imported [module][crate::Module] names.
     #[cfg(not(feature = "no_module"))]
     pub imports: ThinVec<ImmutableString>,
     /// List of globally-imported [module][crate::Module] names.
     #[cfg(not(feature = "no_module"))]
     pub global_imports: ThinVec<ImmutableString>,
+    /// Encapsulates a local stack with names of items imported into the unqualified namespace.
+    ///
+    /// A wildcard import is recorded as `*`.
+    #[cfg(not(feature = "no_module"))]
+    pub imported_items: ThinVec<ImmutableString>,
+    /// Inline `module` blocks defined.
+    #[cfg(not(feature = "no_module"))]
+    #[cfg(not(feature = "no_function"))]
+    pub modules: ThinVec<ModuleDef>,
     /// Unused dummy field.
     #[cfg(feature = "no_function")]
     pub dummy: &'f (),
@@ -98,7 +135,9 @@ impl fmt::Debug for ParseState<'_, '_, '_> {
             .field("external_constants_scope", &self.external_constants)
             .field("global", &self.global)
             .field("stack", &self.stack)
-            .field("frame_pointer", &self.frame_pointer);
+            .field("frame_pointer", &self.frame_pointer)
+            .field("loop_labels", &self.loop_labels)
+            .field("pending_vars", &self.pending_vars);
 
         #[cfg(not(feature = "no_closure"))]
         f.field("external_vars", &self.external_vars)
@@ -106,7 +145,12 @@ impl fmt::Debug for ParseState<'_, '_, '_> {
 
         #[cfg(not(feature = "no_module"))]
         f.field("imports", &self.imports)
-            .field("global_imports", &self.global_imports);
+            .field("global_imports", &self.global_imports)
+            .field("imported_items", &self.imported_items);
+
+        #[cfg(not(feature = "no_module"))]
+        #[cfg(not(feature = "no_function"))]
+        f.field("modules", &self.modules);
 
         f.finish()
     }
@@ -138,10 +182,17 @@ impl<'a, 't, 'f> ParseState<'a, 't, 'f> {
             global: None,
             stack: Scope::new(),
             frame_pointer: 0,
+            loop_labels: ThinVec::new(),
+            pending_vars: None,
             #[cfg(not(feature = "no_module"))]
             imports: ThinVec::new(),
             #[cfg(not(feature = "no_module"))]
             global_imports: ThinVec::new(),
+            #[cfg(not(feature = "no_module"))]
+            imported_items: ThinVec::new(),
+            #[cfg(not(feature = "no_module"))]
+            #[cfg(not(feature = "no_function"))]
+            modules: ThinVec::new(),
         }
     }
 
@@ -174,6 +225,72 @@ impl<'a, 't, 'f> ParseState<'a, 't, 'f> {
         (index, hit_barrier)
     }
 
+    /// Report an undefined variable under Strict Variables mode.
+    ///
+    /// The variable is kept pending if undefined variables are being collected.
+    fn undefined_var(&mut self, name: &str, pos: Position) -> ParseResult<()> {
+        // Items imported into the unqualified namespace are only known at runtime
+        #[cfg(not(feature = "no_module"))]
+        if self
+            .imported_items
+            .iter()
+            .any(|n| n == name || n == crate::engine::IMPORT_ITEMS)
+        {
+            return Ok(());
+        }
+
+        match self.pending_vars {
+            Some(ref mut vars) => {
+                vars.push(Ident {
+                    name: name.into(),
+                    pos,
+                });
+                Ok(())
+            }
+            None => Err(PERR::VariableUndefined(name.to_string()).into_err(pos)),
+        }
+    }
+
+    /// Start collecting undefined variables instead of reporting them.
+    ///
+    /// Returns the previous collection (if any) together with the current number of external
+    /// variables, to be passed to [`end_pending_vars`][ParseState::end_pending_vars].
+    #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+    fn begin_pending_vars(&mut self) -> (Option<ThinVec<Ident>>, usize) {
+        #[cfg(not(feature = "no_closure"))]
+        let num_externals = self.external_vars.len();
+        #[cfg(feature = "no_closure")]
+        let num_externals = 0;
+
+        (self.pending_vars.replace(ThinVec::new()), num_externals)
+    }
+
+    /// Stop collecting undefined variables, then report those that are not defined afterwards.
+    #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+    fn end_pending_vars(
+        &mut self,
+        (orig_pending_vars, _num_externals): (Option<ThinVec<Ident>>, usize),
+        is_defined: impl Fn(&str) -> bool,
+    ) -> ParseResult<()> {
+        let pending_vars = std::mem::replace(&mut self.pending_vars, orig_pending_vars);
+
+        // Variables defined afterwards are not captured by closures
+        #[cfg(not(feature = "no_closure"))]
+        {
+            let mut index = 0;
+            self.external_vars.retain(|var| {
+                index += 1;
+                index <= _num_externals || !is_defined(&var.name)
+            });
+        }
+
+        pending_vars
+            .into_iter()
+            .flatten()
+            .filter(|var| !is_defined(&var.name))
+            .try_for_each(|var| self.undefined_var(&var.name, var.pos))
+    }
+
     /// Find a module by name in the [`ParseState`], searching in reverse.
     ///
     /// Returns the offset to be deducted from `Stack::len`,
@@ -212,6 +329,10 @@ bitflags! {
         const DISALLOW_STATEMENTS_IN_BLOCKS = 0b0001_0000;
         /// Disallow unquoted map properties?
         const DISALLOW_UNQUOTED_MAP_PROPERTIES = 0b0010_0000;
+        /// Allow a format specifier to terminate the block (e.g. `${x:.2}` in interpolated strings)?
+        const ALLOW_FORMAT_SPEC = 0b0100_0000;
+        /// Is the construct being parsed a function call argument that may be a named argument?
+        const ALLOW_NAMED_ARG = 0b1000_0000;
     }
 }
 
@@ -277,6 +398,14 @@ impl ParseSettings {
     }
 }
 
+/// Consume the `:` before a format specifier within an interpolated string (e.g. `` `${x:.2}` ``),
+/// switching the tokenizer to read the format specifier as raw text.
+fn eat_format_spec_colon(state: &mut ParseState) -> Position {
+    let pos = eat_token(state.input, &Token::Colon);
+    state.tokenizer_control.borrow_mut().is_within_format_spec = true;
+    pos
+}
+
 /// Make an anonymous function.
 #[cfg(not(feature = "no_function"))]
 #[inline]
@@ -310,6 +439,8 @@ impl Expr {
             Self::StringConstant(..) => "a string",
             Self::InterpolatedString(..) => "a string",
             Self::Array(..) => "an array",
+            Self::Set(..) => "a set",
+            Self::Tuple(..) => "a tuple",
             Self::Map(..) => "an object map",
             _ => return Ok(self),
         };
@@ -328,7 +459,6 @@ impl Expr {
             #[cfg(not(feature = "no_float"))]
             Self::FloatConstant(..) => "a floating-point number",
             Self::CharConstant(..) => "a character",
-            Self::Map(..) => "an object map",
             _ => return Ok(self),
         };
 
@@ -442,6 +572,22 @@ fn parse_var_name(input: &mut TokenStream) -> ParseResult<(SmartString, Position
     }
 }
 
+/// Parse a type name in a type annotation.
+fn parse_type_name(input: &mut TokenStream) -> ParseResult<(SmartString, Position)> {
+    match input.next().unwrap() {
+        // Type name
+        (Token::Identifier(s), pos) => Ok((*s, pos)),
+        // Type name in string form
+        (Token::StringConstant(s), pos) => Ok((*s, pos)),
+        // ()
+        (Token::Unit, pos) => Ok((Token::Unit.literal_syntax().into(), pos)),
+        // Bad identifier
+        (Token::LexError(err), pos) => Err(err.into_err(pos)),
+        // Not a type name
+        (.., pos) => Err(PERR::MissingSymbol("Expecting a type name".into()).into_err(pos)),
+    }
+}
+
 /// Optimize the structure of a chained expression where the root expression is another chained expression.
 ///
 /// # Panics
@@ -669,6 +815,7 @@ impl Engine {
                     namespace,
                     hashes,
                     args,
+                    named_args: ThinVec::new(),
                 }
                 .into_fn_call_expr(settings.pos));
             }
@@ -677,12 +824,77 @@ impl Engine {
         }
 
         let settings = settings.level_up()?;
+        let mut named_args = ThinVec::<Ident>::new();
 
         loop {
             match state.input.peek().unwrap() {
                 // id(...args, ) - handle trailing comma
                 (Token::RightParen, ..) => (),
-                _ => args.push(self.parse_expr(state, settings)?),
+                // id(...args, ...expr)
+                #[cfg(not(feature = "no_index"))]
+                (Token::Ellipsis, ..) => {
+                    let pos = eat_token(state.input, &Token::Ellipsis);
+
+                    #[cfg(not(feature = "no_module"))]
+                    if !namespace.is_empty() {
+                        return Err(LexError::ImproperSymbol(
+                            Token::Ellipsis.literal_syntax().into(),
+                            "Spread arguments are not supported in namespace-qualified function calls".into(),
+                        )
+                        .into_err(pos));
+                    }
+                    #[cfg(not(feature = "no_function"))]
+                    if !named_args.is_empty() {
+                        return Err(PERR::MalformedNamedArg(format!(
+                            "Positional argument cannot follow named arguments in call to function '{id}'"
+                        ))
+                        .into_err(pos));
+                    }
+
+                    let expr = self.parse_expr(state, settings)?;
+                    args.push(Expr::Spread(expr.into(), pos));
+                }
+                _ => {
+                    let mut arg_settings = settings;
+                    arg_settings.flags |= ParseSettingFlags::ALLOW_NAMED_ARG;
+                    let expr = self.parse_expr(state, arg_settings)?;
+
+                    match (expr, state.input.peek().unwrap()) {
+                        // id(...args, name: expr)
+                        #[cfg(not(feature = "no_function"))]
+                        (Expr::Variable(x, None, pos), (Token::Colon, ..)) => {
+                            #[cfg(not(feature = "no_module"))]
+                            if !namespace.is_empty() || !x.2.is_empty() {
+                                return Err(PERR::MalformedNamedArg(
+                                    "Named arguments are not supported in namespace-qualified function calls".into(),
+                                )
+                                .into_err(pos));
+                            }
+
+                            let name = x.1.clone();
+
+                            if named_args.iter().any(|n| n.name == name) {
+                                return Err(PERR::MalformedNamedArg(format!(
+                                    "Duplicated named argument '{name}' in call to function '{id}'"
+                                ))
+                                .into_err(pos));
+                            }
+
+                            eat_token(state.input, &Token::Colon);
+                            args.push(self.parse_expr(state, settings)?);
+                            named_args.push(Ident { name, pos });
+                        }
+                        // id(...name: expr, expr)
+                        #[cfg(not(feature = "no_function"))]
+                        (expr, ..) if !named_args.is_empty() => {
+                            return Err(PERR::MalformedNamedArg(format!(
+                                "Positional argument cannot follow named arguments in call to function '{id}'"
+                            ))
+                            .into_err(expr.start_position()));
+                        }
+                        (expr, ..) => args.push(expr),
+                    }
+                }
             }
 
             match state.input.peek().unwrap() {
@@ -721,6 +933,27 @@ impl Engine {
                     #[cfg(feature = "no_module")]
                     let hash = calc_fn_hash(None, &id, args.len());
 
+                    // Validate constant format strings
+                    #[cfg(not(feature = "no_module"))]
+                    let is_format = id == FUNC_FORMAT && namespace.is_empty();
+                    #[cfg(feature = "no_module")]
+                    let is_format = id == FUNC_FORMAT;
+                    #[cfg(not(feature = "no_index"))]
+                    let is_format =
+                        is_format && !args.iter().any(|arg| matches!(arg, Expr::Spread(..)));
+
+                    if let (true, Some(Expr::StringConstant(format, pos))) =
+                        (is_format && named_args.is_empty(), args.first())
+                    {
+                        validate_format_string(format, args.len() - 1).map_err(|err| {
+                            LexError::ImproperSymbol(
+                                format.to_string(),
+                                format!("Invalid format string: {err}"),
+                            )
+                            .into_err(*pos)
+                        })?;
+                    }
+
                     let hashes = if is_valid_function_name(&id) {
                         FnCallHashes::from_hash(hash)
                     } else {
@@ -728,6 +961,7 @@ impl Engine {
                     };
 
                     args.shrink_to_fit();
+                    named_args.shrink_to_fit();
 
                     return Ok(FnCallExpr {
                         name: self.get_interned_string(id),
@@ -737,6 +971,7 @@ impl Engine {
                         namespace,
                         hashes,
                         args,
+                        named_args,
                     }
                     .into_fn_call_expr(settings.pos));
                 }
@@ -948,6 +1183,24 @@ impl Engine {
                     )
                     .into_err(*pos))
                 }
+                // [ ..expr ] or [ ...expr ]
+                (Token::ExclusiveRange | Token::Ellipsis, ..) => {
+                    let pos = state.input.next().unwrap().1;
+                    let expr = self.parse_expr(state, settings.level_up()?)?;
+                    array.push(Expr::Spread(expr.into(), pos));
+                }
+                // [ expr for var in expr if expr ]
+                _ if array.is_empty() => {
+                    let pending = state.begin_pending_vars();
+                    let expr = self.parse_expr(state, settings.level_up()?)?;
+
+                    if state.input.peek().unwrap().0 == Token::For {
+                        return self.parse_comprehension(state, settings, pending, None, expr);
+                    }
+
+                    state.end_pending_vars(pending, |_| false)?;
+                    array.push(expr);
+                }
                 _ => array.push(self.parse_expr(state, settings.level_up()?)?),
             }
 
@@ -979,6 +1232,127 @@ impl Engine {
         Ok(Expr::Array(array, settings.pos))
     }
 
+    /// Parse a set literal.
+    #[cfg(not(feature = "no_index"))]
+    fn parse_set_literal(
+        &self,
+        state: &mut ParseState,
+        mut settings: ParseSettings,
+    ) -> ParseResult<Expr> {
+        // #[ ...
+        settings.pos = eat_token(state.input, &Token::SetStart);
+
+        let mut items = ThinVec::new();
+
+        loop {
+            const MISSING_RBRACKET: &str = "to end this set literal";
+
+            match state.input.peek().unwrap() {
+                (Token::RightBracket, ..) => {
+                    eat_token(state.input, &Token::RightBracket);
+                    break;
+                }
+                (Token::EOF, pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::RightBracket.into(),
+                        MISSING_RBRACKET.into(),
+                    )
+                    .into_err(*pos))
+                }
+                #[cfg(not(feature = "unchecked"))]
+                (.., pos) if self.max_set_size() > 0 && items.len() >= self.max_set_size() => {
+                    return Err(PERR::LiteralTooLarge(
+                        "Size of set literal".into(),
+                        self.max_set_size(),
+                    )
+                    .into_err(*pos))
+                }
+                _ => items.push(self.parse_expr(state, settings.level_up()?)?),
+            }
+
+            match state.input.peek().unwrap() {
+                (Token::Comma, ..) => {
+                    eat_token(state.input, &Token::Comma);
+                }
+                (Token::RightBracket, ..) => (),
+                (Token::EOF, pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::RightBracket.into(),
+                        MISSING_RBRACKET.into(),
+                    )
+                    .into_err(*pos))
+                }
+                (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                (.., pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::Comma.into(),
+                        "to separate the items of this set literal".into(),
+                    )
+                    .into_err(*pos))
+                }
+            };
+        }
+
+        items.shrink_to_fit();
+
+        Ok(Expr::Set(items, settings.pos))
+    }
+
+    /// Parse the rest of a tuple literal, after the first item and comma.
+    #[cfg(not(feature = "no_index"))]
+    fn parse_tuple_literal(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+        first: Expr,
+    ) -> ParseResult<Expr> {
+        let mut items = ThinVec::new();
+        items.push(first);
+
+        loop {
+            const MISSING_RPAREN: &str = "to end this tuple literal";
+
+            match state.input.peek().unwrap() {
+                (Token::RightParen, ..) => {
+                    eat_token(state.input, &Token::RightParen);
+                    break;
+                }
+                (Token::EOF, pos) => {
+                    return Err(
+                        PERR::MissingToken(Token::RightParen.into(), MISSING_RPAREN.into())
+                            .into_err(*pos),
+                    )
+                }
+                _ => items.push(self.parse_expr(state, settings.level_up()?)?),
+            }
+
+            match state.input.peek().unwrap() {
+                (Token::Comma, ..) => {
+                    eat_token(state.input, &Token::Comma);
+                }
+                (Token::RightParen, ..) => (),
+                (Token::EOF, pos) => {
+                    return Err(
+                        PERR::MissingToken(Token::RightParen.into(), MISSING_RPAREN.into())
+                            .into_err(*pos),
+                    )
+                }
+                (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                (.., pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::Comma.into(),
+                        "to separate the items of this tuple literal".into(),
+                    )
+                    .into_err(*pos))
+                }
+            };
+        }
+
+        items.shrink_to_fit();
+
+        Ok(Expr::Tuple(items, settings.pos))
+    }
+
     /// Parse a map literal.
     #[cfg(not(feature = "no_object"))]
     fn parse_map_literal(
@@ -1006,9 +1380,34 @@ impl Engine {
                             .into_err(*pos),
                     )
                 }
+                // #{ ..expr } or #{ ...expr }
+                (Token::ExclusiveRange | Token::Ellipsis, ..) => {
+                    let pos = state.input.next().unwrap().1;
+                    let expr = self.parse_expr(state, settings.level_up()?)?;
+                    let name = self.get_interned_string("");
+                    map.push((Ident { name, pos }, Expr::Spread(expr.into(), pos)));
+
+                    match state.input.peek().unwrap() {
+                        (Token::Comma, ..) => {
+                            eat_token(state.input, &Token::Comma);
+                        }
+                        (Token::RightBrace, ..) => (),
+                        (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                        (.., pos) => {
+                            return Err(PERR::MissingToken(
+                                Token::RightBrace.into(),
+                                MISSING_RBRACE.into(),
+                            )
+                            .into_err(*pos))
+                        }
+                    }
+                    continue;
+                }
                 _ => (),
             }
 
+            let is_ident = matches!(state.input.peek().unwrap().0, Token::Identifier(..));
+
             let (name, pos) = match state.input.next().unwrap() {
                 (Token::Identifier(..), pos)
                     if settings.has_flag(ParseSettingFlags::DISALLOW_UNQUOTED_MAP_PROPERTIES) =>
@@ -1066,7 +1465,42 @@ impl Engine {
                 .into_err(state.input.peek().unwrap().1));
             }
 
+            let pending = map.is_empty().then(|| state.begin_pending_vars());
             let expr = self.parse_expr(state, settings.level_up()?)?;
+
+            if let Some(pending) = pending {
+                // #{ key: expr for var in expr if expr }
+                if state.input.peek().unwrap().0 == Token::For {
+                    let name = self.get_interned_string(name);
+
+                    let key = if is_ident {
+                        if settings.has_option(LangOptions::STRICT_VAR)
+                            && state.find_var(&name).0 == 0
+                            && !state
+                                .external_constants
+                                .map_or(false, |scope| scope.contains(&name))
+                        {
+                            state.undefined_var(&name, pos)?;
+                        }
+
+                        Expr::Variable(
+                            #[cfg(not(feature = "no_module"))]
+                            (None, name, crate::ast::Namespace::NONE, 0).into(),
+                            #[cfg(feature = "no_module")]
+                            (None, name).into(),
+                            None,
+                            pos,
+                        )
+                    } else {
+                        Expr::StringConstant(name, pos)
+                    };
+
+                    return self.parse_comprehension(state, settings, pending, Some(key), expr);
+                }
+
+                state.end_pending_vars(pending, |_| false)?;
+            }
+
             template.insert(name.clone(), crate::Dynamic::UNIT);
 
             let name = self.get_interned_string(name);
@@ -1318,6 +1752,10 @@ impl Engine {
 
         settings.pos = *next_token_pos;
 
+        // Only the root of a function call argument can be the name of a named argument
+        let _allow_named_arg = settings.has_flag(ParseSettingFlags::ALLOW_NAMED_ARG);
+        settings.flags.remove(ParseSettingFlags::ALLOW_NAMED_ARG);
+
         let root_expr = match next_token {
             _ if !(state.expr_filter)(next_token) => {
                 return Err(LexError::UnexpectedInput(next_token.to_string()).into_err(settings.pos))
@@ -1357,6 +1795,46 @@ impl Engine {
                 state.input.next();
                 Expr::DynamicConstant(Box::new(x.into()), settings.pos)
             }
+            #[cfg(feature = "bigint")]
+            Token::BigIntConstant(..) => match state.input.next().unwrap().0 {
+                Token::BigIntConstant(x) => {
+                    Expr::DynamicConstant(Box::new((*x).into()), settings.pos)
+                }
+                token => unreachable!("token is {:?}", token),
+            },
+            Token::SuffixedConstant(..) => match state.input.next().unwrap().0 {
+                Token::SuffixedConstant(x) => {
+                    let (token, suffix) = *x;
+
+                    let value = match token {
+                        Token::IntegerConstant(n) => Dynamic::from_int(n),
+                        #[cfg(not(feature = "no_float"))]
+                        Token::FloatConstant(x) => Dynamic::from_float(*x.0),
+                        #[cfg(feature = "decimal")]
+                        Token::DecimalConstant(x) => x.0.into(),
+                        token => unreachable!("number is {:?}", token),
+                    };
+
+                    self.make_suffixed_constant(state, value, &suffix, settings.pos)
+                }
+                token => unreachable!("token is {:?}", token),
+            },
+            #[cfg(feature = "regex")]
+            Token::RegexConstant(..) => match state.input.next().unwrap().0 {
+                Token::RegexConstant(x) => {
+                    match crate::packages::regex_basic::compile_regex(self, &x) {
+                        Ok(regex) => Expr::DynamicConstant(Box::new(regex.into()), settings.pos),
+                        Err(err) => {
+                            return Err(LexError::ImproperSymbol(
+                                format!("r/{x}/"),
+                                format!("Invalid regular expression: {err}"),
+                            )
+                            .into_err(settings.pos))
+                        }
+                    }
+                }
+                token => unreachable!("token is {:?}", token),
+            },
 
             // { - block statement as expression
             Token::LeftBrace if settings.has_option(LangOptions::STMT_EXPR) => {
@@ -1375,6 +1853,9 @@ impl Engine {
                 match state.input.next().unwrap() {
                     // ( ... )
                     (Token::RightParen, ..) => expr,
+                    // ( ... , - tuple literal
+                    #[cfg(not(feature = "no_index"))]
+                    (Token::Comma, ..) => self.parse_tuple_literal(state, settings, expr)?,
                     // ( <error>
                     (Token::LexError(err), pos) => return Err(err.into_err(pos)),
                     // ( ... ???
@@ -1397,15 +1878,25 @@ impl Engine {
                 if self.allow_looping() && settings.has_option(LangOptions::LOOP_EXPR) =>
             {
                 Expr::Stmt(Box::new(
-                    self.parse_while_loop(state, settings.level_up()?)?.into(),
+                    self.parse_while_loop(state, settings.level_up()?, None)?
+                        .into(),
                 ))
             }
             Token::Do if self.allow_looping() && settings.has_option(LangOptions::LOOP_EXPR) => {
-                Expr::Stmt(Box::new(self.parse_do(state, settings.level_up()?)?.into()))
+                Expr::Stmt(Box::new(
+                    self.parse_do(state, settings.level_up()?, None)?.into(),
+                ))
             }
             Token::For if self.allow_looping() && settings.has_option(LangOptions::LOOP_EXPR) => {
                 Expr::Stmt(Box::new(
-                    self.parse_for(state, settings.level_up()?)?.into(),
+                    self.parse_for(state, settings.level_up()?, None)?.into(),
+                ))
+            }
+            Token::Label(..)
+                if self.allow_looping() && settings.has_option(LangOptions::LOOP_EXPR) =>
+            {
+                Expr::Stmt(Box::new(
+                    self.parse_labeled_loop(state, settings.level_up()?)?.into(),
                 ))
             }
             // Switch statement is allowed to act as expressions
@@ -1442,11 +1933,31 @@ impl Engine {
                     }
                 }
 
+                let mut block_settings = settings;
+                block_settings.flags |= ParseSettingFlags::ALLOW_FORMAT_SPEC;
+
                 loop {
-                    let expr = match self.parse_block(state, settings)? {
+                    let expr = match self.parse_block(state, block_settings)? {
                         block @ Stmt::Block(..) => Expr::Stmt(Box::new(block.into())),
                         stmt => unreachable!("Stmt::Block expected but gets {:?}", stmt),
                     };
+
+                    // `... ${expr:spec} ...`
+                    let expr = if state.tokenizer_control.borrow().is_within_format_spec {
+                        match state.input.next().unwrap() {
+                            (Token::StringConstant(spec), pos) => {
+                                self.make_format_spec_call(expr, &spec, pos)?
+                            }
+                            (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                            (token, ..) => unreachable!(
+                                "format specifier within an interpolated string literal expected but gets {:?}",
+                                token
+                            ),
+                        }
+                    } else {
+                        expr
+                    };
+
                     match expr {
                         Expr::StringConstant(s, ..) if s.is_empty() => (),
                         _ => segments.push(expr),
@@ -1495,6 +2006,10 @@ impl Engine {
             #[cfg(not(feature = "no_index"))]
             Token::LeftBracket => self.parse_array_literal(state, settings.level_up()?)?,
 
+            // Set literal
+            #[cfg(not(feature = "no_index"))]
+            Token::SetStart => self.parse_set_literal(state, settings.level_up()?)?,
+
             // Map literal
             #[cfg(not(feature = "no_object"))]
             Token::MapStart => self.parse_map_literal(state, settings.level_up()?)?,
@@ -1552,9 +2067,19 @@ impl Engine {
                         let name = self.get_interned_string(*s);
                         Expr::Variable((None, name, ns, 0).into(), None, settings.pos)
                     }
-                    // Normal variable access
-                    _ => {
-                        let (index, is_func) = self.access_var(state, &s, settings.pos);
+                    // Named argument in function call
+                    #[cfg(not(feature = "no_function"))]
+                    (Token::Colon, _) if _allow_named_arg => Expr::Variable(
+                        #[cfg(not(feature = "no_module"))]
+                        (None, self.get_interned_string(*s), ns, 0).into(),
+                        #[cfg(feature = "no_module")]
+                        (None, self.get_interned_string(*s)).into(),
+                        None,
+                        settings.pos,
+                    ),
+                    // Normal variable access
+                    _ => {
+                        let (index, is_func) = self.access_var(state, &s, settings.pos);
 
                         if !options.intersects(ChainingFlags::PROPERTY)
                             && !is_func
@@ -1564,9 +2089,7 @@ impl Engine {
                                 .external_constants
                                 .map_or(false, |scope| scope.contains(&s))
                         {
-                            return Err(
-                                PERR::VariableUndefined(s.to_string()).into_err(settings.pos)
-                            );
+                            state.undefined_var(&s, settings.pos)?;
                         }
 
                         let short_index = index
@@ -1904,6 +2427,7 @@ impl Engine {
                         args: IntoIterator::into_iter([expr]).collect(),
                         op_token: Some(token),
                         capture_parent_scope: false,
+                        named_args: ThinVec::new(),
                     }
                     .into_fn_call_expr(pos)),
                 }
@@ -1927,6 +2451,7 @@ impl Engine {
                         args: IntoIterator::into_iter([expr]).collect(),
                         op_token: Some(token),
                         capture_parent_scope: false,
+                        named_args: ThinVec::new(),
                     }
                     .into_fn_call_expr(pos)),
                 }
@@ -1947,6 +2472,7 @@ impl Engine {
                     },
                     op_token: Some(token),
                     capture_parent_scope: false,
+                    named_args: ThinVec::new(),
                 }
                 .into_fn_call_expr(pos))
             }
@@ -2124,6 +2650,11 @@ impl Engine {
                 )
                 .into_err(func_pos))
             }
+            // lhs.func(..., name: expr)
+            (.., Expr::FnCall(f, ..)) if !f.named_args.is_empty() => Err(PERR::MalformedNamedArg(
+                "Named arguments are not supported in method calls".into(),
+            )
+            .into_err(f.named_args[0].pos)),
             // lhs.func(...)
             (lhs, Expr::FnCall(mut f, func_pos)) => {
                 // Recalculate hash
@@ -2179,6 +2710,13 @@ impl Engine {
                         };
                         Ok(Expr::Dot(BinaryExpr { lhs, rhs }.into(), op_flags, op_pos))
                     }
+                    // lhs.func(name: expr).dot_rhs or lhs.func(name: expr)[idx_rhs]
+                    Expr::FnCall(f, ..) if !f.named_args.is_empty() => {
+                        Err(PERR::MalformedNamedArg(
+                            "Named arguments are not supported in method calls".into(),
+                        )
+                        .into_err(f.named_args[0].pos))
+                    }
                     // lhs.func().dot_rhs or lhs.func()[idx_rhs]
                     Expr::FnCall(mut f, func_pos) => {
                         // Recalculate hash
@@ -2222,6 +2760,37 @@ impl Engine {
         }
     }
 
+    /// Make a pipeline expression `lhs |> func(...)` into the function call `func(lhs, ...)`.
+    fn make_pipeline_call(&self, lhs: Expr, rhs: Expr) -> ParseResult<Expr> {
+        match rhs {
+            // lhs |> func(...)
+            Expr::FnCall(mut f, pos) if !f.is_operator_call() => {
+                f.args.insert(0, lhs);
+
+                // Recalculate hash
+                let args_len = f.args.len();
+                #[cfg(not(feature = "no_module"))]
+                let hash = calc_fn_hash(
+                    f.namespace.path.iter().map(Ident::as_str),
+                    &f.name,
+                    args_len,
+                );
+                #[cfg(feature = "no_module")]
+                let hash = calc_fn_hash(None, &f.name, args_len);
+
+                f.hashes = if is_valid_function_name(&f.name) {
+                    FnCallHashes::from_hash(hash)
+                } else {
+                    FnCallHashes::from_native_only(hash)
+                };
+
+                Ok(Expr::FnCall(f, pos))
+            }
+            // lhs |> rhs
+            rhs => Err(PERR::ExprExpected("a function call".into()).into_err(rhs.start_position())),
+        }
+    }
+
     /// Parse a binary expression (if any).
     fn parse_binary_op(
         &self,
@@ -2310,13 +2879,15 @@ impl Engine {
 
             // Bind to right if the next operator has higher precedence
             // If same precedence, then check if the operator binds right
-            let rhs =
-                if (precedence == next_precedence && bind_right) || precedence < next_precedence {
-                    self.parse_binary_op(state, settings.level_up()?, precedence, rhs)?
-                } else {
-                    // Otherwise bind to left (even if next operator has the same precedence)
-                    rhs
-                };
+            // The function call of a pipeline never binds to the right
+            let rhs = if op_token != Token::PipeForward
+                && ((precedence == next_precedence && bind_right) || precedence < next_precedence)
+            {
+                self.parse_binary_op(state, settings.level_up()?, precedence, rhs)?
+            } else {
+                // Otherwise bind to left (even if next operator has the same precedence)
+                rhs
+            };
 
             settings = settings.level_up()?;
             settings.pos = pos;
@@ -2333,6 +2904,7 @@ impl Engine {
                 args: IntoIterator::into_iter([root, rhs]).collect(),
                 op_token: native_only.then(|| op_token.clone()),
                 capture_parent_scope: false,
+                named_args: ThinVec::new(),
             };
 
             root = match op_token {
@@ -2373,11 +2945,18 @@ impl Engine {
                             args: IntoIterator::into_iter([fn_call]).collect(),
                             op_token: Some(Token::Bang),
                             capture_parent_scope: false,
+                            named_args: ThinVec::new(),
                         };
                         not_base.into_fn_call_expr(pos)
                     }
                 }
                 Token::ExclusiveRange | Token::InclusiveRange => op_base.into_fn_call_expr(pos),
+                // xxx |> func(...)
+                Token::PipeForward => {
+                    let rhs = op_base.args[1].take();
+                    let lhs = op_base.args[0].take();
+                    self.make_pipeline_call(lhs, rhs)?
+                }
 
                 #[cfg(not(feature = "no_custom_syntax"))]
                 Token::Custom(s) if self.custom_keywords.contains_key(&*s) => {
@@ -2620,8 +3199,9 @@ impl Engine {
 
         // Parse expression normally.
         let precedence = Precedence::new(1);
-        let settings = settings.level_up()?;
+        let mut settings = settings.level_up()?;
         let lhs = self.parse_unary(state, settings)?;
+        settings.flags.remove(ParseSettingFlags::ALLOW_NAMED_ARG);
         self.parse_binary_op(state, settings, precedence, lhs)
     }
 
@@ -2650,16 +3230,97 @@ impl Engine {
         .into();
 
         Ok(Stmt::If(
-            FlowControl { expr, body, branch }.into(),
+            FlowControl {
+                expr,
+                body,
+                branch,
+                label: None,
+            }
+            .into(),
             settings.pos,
         ))
     }
 
+    /// Parse a loop with a label: `'label: loop`.
+    fn parse_labeled_loop(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+    ) -> ParseResult<Stmt> {
+        // 'label ...
+        let (label, label_pos) = match state.input.next().unwrap() {
+            (Token::Label(s), pos) => (self.get_interned_string(*s), pos),
+            token => unreachable!("Token::Label expected but gets {:?}", token),
+        };
+
+        // 'label : ...
+        let (has_colon, pos) = match_token(state.input, &Token::Colon);
+        if !has_colon {
+            return Err(PERR::MissingToken(
+                Token::Colon.into(),
+                format!("after the loop label '{label}"),
+            )
+            .into_err(pos));
+        }
+
+        // 'label : loop ...
+        match state.input.peek().unwrap() {
+            (Token::While | Token::Loop, ..) => {
+                self.parse_while_loop(state, settings.level_up()?, Some(label))
+            }
+            (Token::Do, ..) => self.parse_do(state, settings.level_up()?, Some(label)),
+            (Token::For, ..) => self.parse_for(state, settings.level_up()?, Some(label)),
+            _ => Err(LexError::ImproperSymbol(
+                format!("'{label}"),
+                "A label can only be placed on a loop".into(),
+            )
+            .into_err(label_pos)),
+        }
+    }
+
+    /// Parse the body of a loop, making its label (if any) available to `break` and `continue`.
+    fn parse_loop_body(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+        label: Option<&ImmutableString>,
+    ) -> ParseResult<StmtBlock> {
+        if let Some(label) = label {
+            state.loop_labels.push(label.clone());
+        }
+
+        let body = self.parse_block(state, settings)?.into();
+
+        if label.is_some() {
+            state.loop_labels.pop();
+        }
+
+        Ok(body)
+    }
+
+    /// Parse the optional label of a `break` or `continue` statement.
+    fn parse_break_label(&self, state: &mut ParseState) -> ParseResult<Option<ImmutableString>> {
+        if !matches!(state.input.peek().unwrap().0, Token::Label(..)) {
+            return Ok(None);
+        }
+
+        match state.input.next().unwrap() {
+            (Token::Label(s), pos) => {
+                if !state.loop_labels.iter().any(|label| *label == *s) {
+                    return Err(PERR::LabelUndefined(s.to_string()).into_err(pos));
+                }
+                Ok(Some(self.get_interned_string(*s)))
+            }
+            token => unreachable!("Token::Label expected but gets {:?}", token),
+        }
+    }
+
     /// Parse a while loop.
     fn parse_while_loop(
         &self,
         state: &mut ParseState,
         settings: ParseSettings,
+        label: Option<ImmutableString>,
     ) -> ParseResult<Stmt> {
         let mut settings = settings.level_up()?;
 
@@ -2677,17 +3338,28 @@ impl Engine {
         settings.pos = token_pos;
         settings.flags |= ParseSettingFlags::BREAKABLE;
 
-        let body = self.parse_block(state, settings)?.into();
+        let body = self.parse_loop_body(state, settings, label.as_ref())?;
         let branch = StmtBlock::NONE;
 
         Ok(Stmt::While(
-            FlowControl { expr, body, branch }.into(),
+            FlowControl {
+                expr,
+                body,
+                branch,
+                label,
+            }
+            .into(),
             settings.pos,
         ))
     }
 
     /// Parse a do loop.
-    fn parse_do(&self, state: &mut ParseState, settings: ParseSettings) -> ParseResult<Stmt> {
+    fn parse_do(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+        label: Option<ImmutableString>,
+    ) -> ParseResult<Stmt> {
         // do ...
         let mut settings = settings.level_up_with_position(eat_token(state.input, &Token::Do))?;
         let orig_breakable = settings.has_flag(ParseSettingFlags::BREAKABLE);
@@ -2695,7 +3367,7 @@ impl Engine {
 
         // do { body } [while|until] guard
 
-        let body = self.parse_block(state, settings)?.into();
+        let body = self.parse_loop_body(state, settings, label.as_ref())?;
 
         let negated = match state.input.next().unwrap() {
             (Token::While, ..) => ASTFlags::empty(),
@@ -2719,18 +3391,71 @@ impl Engine {
         let branch = StmtBlock::NONE;
 
         Ok(Stmt::Do(
-            FlowControl { expr, body, branch }.into(),
+            FlowControl {
+                expr,
+                body,
+                branch,
+                label,
+            }
+            .into(),
             negated,
             settings.pos,
         ))
     }
 
     /// Parse a for loop.
-    fn parse_for(&self, state: &mut ParseState, settings: ParseSettings) -> ParseResult<Stmt> {
+    fn parse_for(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+        label: Option<ImmutableString>,
+    ) -> ParseResult<Stmt> {
         // for ...
         let mut settings = settings.level_up_with_position(eat_token(state.input, &Token::For))?;
 
-        // for name ...
+        // for name in ...
+        let (loop_var, counter_var) = self.parse_for_vars(state)?;
+
+        // for name in expr { body }
+        ensure_not_statement_expr(state.input, "a boolean")?;
+        let expr = self.parse_expr(state, settings)?.ensure_iterable()?;
+
+        let prev_stack_len = {
+            let prev_stack_len = state.stack.len();
+
+            if let Some(ref counter_var) = counter_var {
+                state.stack.push(counter_var.name.clone(), ());
+            }
+            state.stack.push(&loop_var.name, ());
+
+            prev_stack_len
+        };
+
+        settings.flags |= ParseSettingFlags::BREAKABLE;
+        let body = self.parse_loop_body(state, settings, label.as_ref())?;
+
+        state.stack.rewind(prev_stack_len);
+
+        let branch = StmtBlock::NONE;
+
+        Ok(Stmt::For(
+            Box::new((
+                loop_var,
+                counter_var,
+                FlowControl {
+                    expr,
+                    body,
+                    branch,
+                    label,
+                },
+            )),
+            settings.pos,
+        ))
+    }
+
+    /// Parse the variables of a `for` loop or comprehension, up to the `in` keyword.
+    fn parse_for_vars(&self, state: &mut ParseState) -> ParseResult<(Ident, Option<Ident>)> {
+        // name ...
         let (name, name_pos, counter_name, counter_pos) =
             if match_token(state.input, &Token::LeftParen).0 {
                 // ( name, counter )
@@ -2764,7 +3489,7 @@ impl Engine {
                 (name, name_pos, None, Position::NONE)
             };
 
-        // for name in ...
+        // name in ...
         match state.input.next().unwrap() {
             (Token::In, ..) => (),
             (Token::LexError(err), pos) => return Err(err.into_err(pos)),
@@ -2777,10 +3502,6 @@ impl Engine {
             }
         }
 
-        // for name in expr { body }
-        ensure_not_statement_expr(state.input, "a boolean")?;
-        let expr = self.parse_expr(state, settings)?.ensure_iterable()?;
-
         let counter_var = counter_name.map(|counter_name| Ident {
             name: self.get_interned_string(counter_name),
             pos: counter_pos,
@@ -2791,26 +3512,76 @@ impl Engine {
             pos: name_pos,
         };
 
-        let prev_stack_len = {
-            let prev_stack_len = state.stack.len();
+        Ok((loop_var, counter_var))
+    }
 
-            if let Some(ref counter_var) = counter_var {
-                state.stack.push(counter_var.name.clone(), ());
-            }
-            state.stack.push(&loop_var.name, ());
+    /// Parse the rest of an array or object map comprehension, starting from the `for` keyword.
+    ///
+    /// `pending` holds the undefined variables collection started before parsing the value
+    /// (and key) of the comprehension, which may refer to the comprehension's variables.
+    #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+    fn parse_comprehension(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+        pending: (Option<ThinVec<Ident>>, usize),
+        key: Option<Expr>,
+        value: Expr,
+    ) -> ParseResult<Expr> {
+        // ... for
+        eat_token(state.input, &Token::For);
 
-            prev_stack_len
-        };
+        // ... for name in
+        let (var, counter) = self.parse_for_vars(state)?;
 
-        settings.flags |= ParseSettingFlags::BREAKABLE;
-        let body = self.parse_block(state, settings)?.into();
+        state.end_pending_vars(pending, |name| {
+            name == var.name || counter.as_ref().map_or(false, |c| name == c.name)
+        })?;
+
+        // ... for name in expr
+        let iterable = self
+            .parse_expr(state, settings.level_up()?)?
+            .ensure_iterable()?;
+
+        // ... for name in expr if expr
+        let prev_stack_len = state.stack.len();
+
+        if let Some(ref counter) = counter {
+            state.stack.push(counter.name.clone(), ());
+        }
+        state.stack.push(&var.name, ());
+
+        let condition = if match_token(state.input, &Token::If).0 {
+            self.parse_expr(state, settings.level_up()?)?
+                .ensure_bool_expr()?
+        } else {
+            Expr::Unit(Position::NONE)
+        };
 
         state.stack.rewind(prev_stack_len);
 
-        let branch = StmtBlock::NONE;
+        // ... ] or ... }
+        let (close_token, description) = if key.is_some() {
+            (Token::RightBrace, "to end this object map comprehension")
+        } else {
+            (Token::RightBracket, "to end this array comprehension")
+        };
 
-        Ok(Stmt::For(
-            Box::new((loop_var, counter_var, FlowControl { expr, body, branch })),
+        let (has_close, pos) = match_token(state.input, &close_token);
+        if !has_close {
+            return Err(PERR::MissingToken(close_token.into(), description.into()).into_err(pos));
+        }
+
+        Ok(Expr::Comprehension(
+            crate::ast::Comprehension {
+                var,
+                counter,
+                iterable,
+                condition,
+                key,
+                value,
+            }
+            .into(),
             settings.pos,
         ))
     }
@@ -2826,46 +3597,50 @@ impl Engine {
         // let/const... (specified in `var_type`)
         settings.pos = state.input.next().unwrap().1;
 
+        // let ( name, ... ) ...
+        #[cfg(not(feature = "no_index"))]
+        if matches!(state.input.peek().unwrap().0, Token::LeftParen) {
+            return self.parse_let_tuple(state, settings, access, is_export);
+        }
+
         // let name ...
         let (name, pos) = parse_var_name(state.input)?;
 
-        if !self.allow_shadowing() && state.stack.get(&name).is_some() {
-            return Err(PERR::VariableExists(name.into()).into_err(pos));
-        }
+        self.check_new_var(state, settings.level, &name, access, pos)?;
 
-        if let Some(ref filter) = self.def_var_filter {
-            let will_shadow = state.stack.get(&name).is_some();
+        let name = self.get_interned_string(name);
 
-            let global = state
-                .global
-                .get_or_insert_with(|| self.new_global_runtime_state().into());
+        // let name: type ...
+        let typ = if match_token(state.input, &Token::Colon).0 {
+            Some(parse_type_name(state.input)?.0)
+        } else {
+            None
+        };
 
-            global.level = settings.level;
-            let is_const = access == AccessMode::ReadOnly;
-            let info = VarDefInfo::new(&name, is_const, settings.level, will_shadow);
-            let caches = &mut Caches::new();
-            let context = EvalContext::new(self, global, caches, &mut state.stack, None);
+        // let name = ...
+        let (expr, typ) = if match_token(state.input, &Token::Equals).0 {
+            // let name = expr
+            let expr = self.parse_expr(state, settings.level_up()?)?;
 
-            match filter(false, info, context) {
-                Ok(true) => (),
-                Ok(false) => return Err(PERR::ForbiddenVariable(name.into()).into_err(pos)),
-                Err(err) => {
-                    return Err(match *err {
-                        EvalAltResult::ErrorParsing(e, pos) => e.into_err(pos),
-                        _ => PERR::ForbiddenVariable(name.into()).into_err(pos),
-                    })
+            // Check the type annotation against a constant initial value
+            if let (Some(ref typ), Some(value)) = (&typ, expr.get_literal_value()) {
+                if !self.is_type_annotation_match(typ, value.type_name()) {
+                    let actual = self.map_type_name(value.type_name());
+                    return Err(PERR::MismatchedType(typ.to_string(), actual.to_string())
+                        .into_err(expr.start_position()));
                 }
             }
-        }
-
-        let name = self.get_interned_string(name);
 
-        // let name = ...
-        let expr = if match_token(state.input, &Token::Equals).0 {
-            // let name = expr
-            self.parse_expr(state, settings.level_up()?)?
+            (expr, typ.map(|t| self.get_interned_string(t)))
+        } else if typ.is_some() {
+            // let name: type - the type annotation applies to the initial value
+            return Err(PERR::MissingToken(
+                Token::Equals.into(),
+                "to initialize the variable with a type annotation".into(),
+            )
+            .into_err(state.input.peek().unwrap().1));
         } else {
-            Expr::Unit(Position::NONE)
+            (Expr::Unit(Position::NONE), None)
         };
 
         let export = if is_export {
@@ -2904,7 +3679,7 @@ impl Engine {
                 .add_alias_by_index(state.stack.len() - 1, name.clone());
         }
 
-        let var_def = (Ident { name, pos }, expr, idx).into();
+        let var_def = (Ident { name, pos }, expr, idx, typ).into();
 
         Ok(match access {
             // let name = expr
@@ -2914,52 +3689,369 @@ impl Engine {
         })
     }
 
-    /// Parse an import statement.
-    #[cfg(not(feature = "no_module"))]
-    fn parse_import(&self, state: &mut ParseState, settings: ParseSettings) -> ParseResult<Stmt> {
-        // import ...
-        let settings = settings.level_up_with_position(eat_token(state.input, &Token::Import))?;
+    /// Check whether a new variable can be defined.
+    fn check_new_var(
+        &self,
+        state: &mut ParseState,
+        level: usize,
+        name: &str,
+        access: AccessMode,
+        pos: Position,
+    ) -> ParseResult<()> {
+        if !self.allow_shadowing() && state.stack.get(name).is_some() {
+            return Err(PERR::VariableExists(name.into()).into_err(pos));
+        }
 
-        // import expr ...
-        let expr = self.parse_expr(state, settings)?;
+        if let Some(ref filter) = self.def_var_filter {
+            let will_shadow = state.stack.get(name).is_some();
 
-        let export = if match_token(state.input, &Token::As).0 {
-            // import expr as name ...
-            let (name, pos) = parse_var_name(state.input)?;
-            Ident {
-                name: self.get_interned_string(name),
-                pos,
-            }
-        } else {
-            // import expr;
-            Ident {
-                name: self.get_interned_string(""),
-                pos: Position::NONE,
-            }
-        };
+            let global = state
+                .global
+                .get_or_insert_with(|| self.new_global_runtime_state().into());
 
-        state.imports.push(export.name.clone());
+            global.level = level;
+            let is_const = access == AccessMode::ReadOnly;
+            let info = VarDefInfo::new(name, is_const, level, will_shadow);
+            let caches = &mut Caches::new();
+            let context = EvalContext::new(self, global, caches, &mut state.stack, None);
+
+            match filter(false, info, context) {
+                Ok(true) => (),
+                Ok(false) => return Err(PERR::ForbiddenVariable(name.into()).into_err(pos)),
+                Err(err) => {
+                    return Err(match *err {
+                        EvalAltResult::ErrorParsing(e, pos) => e.into_err(pos),
+                        _ => PERR::ForbiddenVariable(name.into()).into_err(pos),
+                    })
+                }
+            }
+        }
 
-        Ok(Stmt::Import((expr, export).into(), settings.pos))
+        Ok(())
     }
 
-    /// Parse an export statement.
-    #[cfg(not(feature = "no_module"))]
-    fn parse_export(
+    /// Convert a numeric constant with a literal suffix into a call to the suffix's
+    /// constructor function.
+    ///
+    /// The constructor function is called at parse time, if possible, to yield a constant.
+    fn make_suffixed_constant(
         &self,
         state: &mut ParseState,
-        mut settings: ParseSettings,
-    ) -> ParseResult<Stmt> {
-        settings.pos = eat_token(state.input, &Token::Export);
+        value: Dynamic,
+        suffix: &str,
+        pos: Position,
+    ) -> Expr {
+        let fn_name = self
+            .literal_suffixes
+            .get(suffix)
+            .map_or(suffix, SmartString::as_str);
+        let hash = calc_fn_hash(None, fn_name, 1);
 
-        match state.input.peek().unwrap() {
-            (Token::Let, pos) => {
-                let pos = *pos;
-                let settings = settings.level_up()?;
-                let mut stmt = self.parse_let(state, settings, AccessMode::ReadWrite, true)?;
-                stmt.set_position(pos);
-                return Ok(stmt);
-            }
+        #[cfg(not(feature = "no_optimize"))]
+        let can_fold = self.optimization_level() != crate::OptimizationLevel::None;
+        #[cfg(feature = "no_optimize")]
+        let can_fold = true;
+
+        if can_fold {
+            let global = state
+                .global
+                .get_or_insert_with(|| self.new_global_runtime_state().into());
+            let caches = &mut Caches::new();
+            let arg = &mut value.clone();
+
+            if let Ok((result, ..)) = self.exec_native_fn_call(
+                global,
+                caches,
+                fn_name,
+                None,
+                hash,
+                &mut [arg],
+                false,
+                true,
+                pos,
+            ) {
+                return Expr::from_dynamic(result, pos);
+            }
+        }
+
+        FnCallExpr {
+            #[cfg(not(feature = "no_module"))]
+            namespace: crate::ast::Namespace::NONE,
+            name: self.get_interned_string(fn_name),
+            hashes: FnCallHashes::from_hash(hash),
+            args: IntoIterator::into_iter([Expr::from_dynamic(value, pos)]).collect(),
+            op_token: None,
+            capture_parent_scope: false,
+            named_args: ThinVec::new(),
+        }
+        .into_fn_call_expr(pos)
+    }
+
+    /// Convert a value with a format specifier within an interpolated string (e.g. `` `${x:.2}` ``)
+    /// into a call to the standard `format` function.
+    fn make_format_spec_call(&self, expr: Expr, spec: &str, pos: Position) -> ParseResult<Expr> {
+        if let Err(err) = spec.parse::<FormatSpec>() {
+            return Err(LexError::ImproperSymbol(
+                spec.to_string(),
+                format!("Invalid format specifier: {err}"),
+            )
+            .into_err(pos));
+        }
+
+        let expr_pos = expr.start_position();
+        let format = format!("{{:{spec}}}");
+
+        Ok(FnCallExpr {
+            #[cfg(not(feature = "no_module"))]
+            namespace: crate::ast::Namespace::NONE,
+            name: self.get_interned_string(FUNC_FORMAT),
+            hashes: FnCallHashes::from_hash(calc_fn_hash(None, FUNC_FORMAT, 2)),
+            args: IntoIterator::into_iter([
+                Expr::StringConstant(self.get_interned_string(format), pos),
+                expr,
+            ])
+            .collect(),
+            op_token: None,
+            capture_parent_scope: false,
+            named_args: ThinVec::new(),
+        }
+        .into_fn_call_expr(expr_pos))
+    }
+
+    /// Parse a variable definition statement that destructures a tuple.
+    #[cfg(not(feature = "no_index"))]
+    fn parse_let_tuple(
+        &self,
+        state: &mut ParseState,
+        settings: ParseSettings,
+        access: AccessMode,
+        is_export: bool,
+    ) -> ParseResult<Stmt> {
+        // let ( ...
+        eat_token(state.input, &Token::LeftParen);
+
+        let mut names = ThinVec::<Ident>::new();
+
+        loop {
+            // let ( name ...
+            let (name, pos) = parse_var_name(state.input)?;
+
+            if names.iter().any(|n| n.name == name) {
+                return Err(PERR::DuplicatedVariable(name.into()).into_err(pos));
+            }
+
+            self.check_new_var(state, settings.level, &name, access, pos)?;
+
+            let name = self.get_interned_string(name);
+            names.push(Ident { name, pos });
+
+            match state.input.next().unwrap() {
+                (Token::Comma, ..) => (),
+                (Token::RightParen, ..) => break,
+                (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                (.., pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::RightParen.into(),
+                        "to close the list of variables".into(),
+                    )
+                    .into_err(pos))
+                }
+            }
+        }
+
+        // let ( name, ... ) = expr
+        match state.input.next().unwrap() {
+            (Token::Equals, ..) => (),
+            (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+            (.., pos) => {
+                return Err(PERR::MissingToken(
+                    Token::Equals.into(),
+                    "to destructure a tuple".into(),
+                )
+                .into_err(pos))
+            }
+        }
+
+        let expr = self.parse_expr(state, settings.level_up()?)?;
+
+        for Ident { name, .. } in &names {
+            state.stack.push_entry(name.clone(), access, Dynamic::UNIT);
+
+            #[cfg(not(feature = "no_module"))]
+            if is_export {
+                state
+                    .stack
+                    .add_alias_by_index(state.stack.len() - 1, name.clone());
+            }
+        }
+
+        names.shrink_to_fit();
+
+        let mut options = match access {
+            AccessMode::ReadWrite => ASTFlags::empty(),
+            AccessMode::ReadOnly => ASTFlags::CONSTANT,
+        };
+        if is_export {
+            options |= ASTFlags::EXPORTED;
+        }
+
+        Ok(Stmt::VarTuple((names, expr).into(), options, settings.pos))
+    }
+
+    /// Parse an import statement.
+    #[cfg(not(feature = "no_module"))]
+    fn parse_import(&self, state: &mut ParseState, settings: ParseSettings) -> ParseResult<Stmt> {
+        // import ...
+        let settings = settings.level_up_with_position(eat_token(state.input, &Token::Import))?;
+
+        // import expr ...
+        let expr = self.parse_expr(state, settings)?;
+
+        let mut items = crate::ast::ImportItems::None;
+
+        let export = if match_token(state.input, &Token::As).0 {
+            if match_token(state.input, &Token::Multiply).0 {
+                // import expr as * ...
+                items = crate::ast::ImportItems::All;
+                state
+                    .imported_items
+                    .push(self.get_interned_string(crate::engine::IMPORT_ITEMS));
+                Ident {
+                    name: self.get_interned_string(crate::engine::IMPORT_ITEMS),
+                    pos: Position::NONE,
+                }
+            } else {
+                // import expr as name ...
+                let (name, pos) = parse_var_name(state.input)?;
+                Ident {
+                    name: self.get_interned_string(name),
+                    pos,
+                }
+            }
+        } else if match_token(state.input, &Token::For).0 {
+            // import expr for { ... }
+            items = crate::ast::ImportItems::Some(self.parse_import_items(state)?);
+            Ident {
+                name: self.get_interned_string(crate::engine::IMPORT_ITEMS),
+                pos: Position::NONE,
+            }
+        } else {
+            // import expr;
+            Ident {
+                name: self.get_interned_string(""),
+                pos: Position::NONE,
+            }
+        };
+
+        state.imports.push(export.name.clone());
+
+        Ok(Stmt::Import((expr, export, items).into(), settings.pos))
+    }
+
+    /// Parse the list of items in an import statement.
+    #[cfg(not(feature = "no_module"))]
+    fn parse_import_items(
+        &self,
+        state: &mut ParseState,
+    ) -> ParseResult<ThinVec<(Ident, Option<Ident>)>> {
+        // import expr for { ...
+        match state.input.next().unwrap() {
+            (Token::LeftBrace, ..) => (),
+            (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+            (.., pos) => {
+                return Err(PERR::MissingToken(
+                    Token::LeftBrace.into(),
+                    "to start the list of imported items".into(),
+                )
+                .into_err(pos))
+            }
+        }
+
+        let mut items = ThinVec::<(Ident, Option<Ident>)>::new();
+
+        loop {
+            match state.input.peek().unwrap() {
+                (Token::RightBrace, ..) => {
+                    eat_token(state.input, &Token::RightBrace);
+                    break;
+                }
+                (Token::EOF, pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::RightBrace.into(),
+                        "to end the list of imported items".into(),
+                    )
+                    .into_err(*pos))
+                }
+                _ => (),
+            }
+
+            // import expr for { name ...
+            let (name, pos) = parse_var_name(state.input)?;
+            let name = Ident {
+                name: self.get_interned_string(name),
+                pos,
+            };
+
+            // import expr for { name as alias ...
+            let alias = if match_token(state.input, &Token::As).0 {
+                let (alias, pos) = parse_var_name(state.input)?;
+                Some(Ident {
+                    name: self.get_interned_string(alias),
+                    pos,
+                })
+            } else {
+                None
+            };
+
+            let imported_name = alias.as_ref().unwrap_or(&name);
+
+            if items
+                .iter()
+                .any(|(n, a)| a.as_ref().unwrap_or(n).name == imported_name.name)
+            {
+                return Err(PERR::DuplicatedVariable(imported_name.name.to_string())
+                    .into_err(imported_name.pos));
+            }
+
+            state.imported_items.push(imported_name.name.clone());
+            items.push((name, alias));
+
+            match state.input.peek().unwrap() {
+                (Token::Comma, ..) => {
+                    eat_token(state.input, &Token::Comma);
+                }
+                (Token::RightBrace, ..) => (),
+                (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                (.., pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::Comma.into(),
+                        "to separate the list of imported items".into(),
+                    )
+                    .into_err(*pos))
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Parse an export statement.
+    #[cfg(not(feature = "no_module"))]
+    fn parse_export(
+        &self,
+        state: &mut ParseState,
+        mut settings: ParseSettings,
+    ) -> ParseResult<Stmt> {
+        settings.pos = eat_token(state.input, &Token::Export);
+
+        match state.input.peek().unwrap() {
+            (Token::Let, pos) => {
+                let pos = *pos;
+                let settings = settings.level_up()?;
+                let mut stmt = self.parse_let(state, settings, AccessMode::ReadWrite, true)?;
+                stmt.set_position(pos);
+                return Ok(stmt);
+            }
             (Token::Const, pos) => {
                 let pos = *pos;
                 let settings = settings.level_up()?;
@@ -3016,6 +4108,10 @@ impl Engine {
         };
         let mut settings = settings.level_up_with_position(brace_start_pos)?;
 
+        // Nested blocks cannot be terminated by format specifiers
+        let allow_format_spec = settings.has_flag(ParseSettingFlags::ALLOW_FORMAT_SPEC);
+        settings.flags.remove(ParseSettingFlags::ALLOW_FORMAT_SPEC);
+
         let mut block = StmtBlock::empty(settings.pos);
 
         if settings.has_flag(ParseSettingFlags::DISALLOW_STATEMENTS_IN_BLOCKS) {
@@ -3023,16 +4119,23 @@ impl Engine {
             block.statements_mut().push(stmt);
 
             // Must end with }
-            return match state.input.next().unwrap() {
-                (Token::RightBrace, pos) => {
+            return match state.input.peek().unwrap() {
+                // { stmt :spec
+                (Token::Colon, ..) if allow_format_spec => {
+                    let pos = eat_format_spec_colon(state);
                     Ok(Stmt::Block(StmtBlock::new(block, settings.pos, pos).into()))
                 }
-                (Token::LexError(err), pos) => Err(err.into_err(pos)),
-                (.., pos) => Err(PERR::MissingToken(
-                    Token::LeftBrace.into(),
-                    "to start a statement block".into(),
-                )
-                .into_err(pos)),
+                _ => match state.input.next().unwrap() {
+                    (Token::RightBrace, pos) => {
+                        Ok(Stmt::Block(StmtBlock::new(block, settings.pos, pos).into()))
+                    }
+                    (Token::LexError(err), pos) => Err(err.into_err(pos)),
+                    (.., pos) => Err(PERR::MissingToken(
+                        Token::LeftBrace.into(),
+                        "to start a statement block".into(),
+                    )
+                    .into_err(pos)),
+                },
             };
         }
 
@@ -3041,6 +4144,8 @@ impl Engine {
 
         #[cfg(not(feature = "no_module"))]
         let orig_imports_len = state.imports.len();
+        #[cfg(not(feature = "no_module"))]
+        let orig_imported_items_len = state.imported_items.len();
 
         let end_pos = loop {
             // Terminated?
@@ -3073,6 +4178,8 @@ impl Engine {
             match state.input.peek().unwrap() {
                 // { ... stmt }
                 (Token::RightBrace, ..) => break eat_token(state.input, &Token::RightBrace),
+                // { ... stmt :spec
+                (Token::Colon, ..) if allow_format_spec => break eat_format_spec_colon(state),
                 // { ... stmt;
                 (Token::SemiColon, ..) if need_semicolon => {
                     eat_token(state.input, &Token::SemiColon);
@@ -3101,7 +4208,10 @@ impl Engine {
         state.frame_pointer = prev_frame_pointer;
 
         #[cfg(not(feature = "no_module"))]
-        state.imports.truncate(orig_imports_len);
+        {
+            state.imports.truncate(orig_imports_len);
+            state.imported_items.truncate(orig_imported_items_len);
+        }
 
         Ok(Stmt::Block(
             StmtBlock::new(block, settings.pos, end_pos).into(),
@@ -3253,6 +4363,7 @@ impl Engine {
                             // mode will not complain.
                             new_state.global_imports.clone_from(&state.global_imports);
                             new_state.global_imports.extend(state.imports.clone());
+                            new_state.imported_items.clone_from(&state.imported_items);
                         }
 
                         // Brand new options
@@ -3312,29 +4423,40 @@ impl Engine {
             Token::If => self.parse_if(state, settings.level_up()?),
             Token::Switch => self.parse_switch(state, settings.level_up()?),
             Token::While | Token::Loop if self.allow_looping() => {
-                self.parse_while_loop(state, settings.level_up()?)
+                self.parse_while_loop(state, settings.level_up()?, None)
+            }
+            Token::Do if self.allow_looping() => self.parse_do(state, settings.level_up()?, None),
+            Token::For if self.allow_looping() => self.parse_for(state, settings.level_up()?, None),
+            Token::Label(..) if self.allow_looping() => {
+                self.parse_labeled_loop(state, settings.level_up()?)
             }
-            Token::Do if self.allow_looping() => self.parse_do(state, settings.level_up()?),
-            Token::For if self.allow_looping() => self.parse_for(state, settings.level_up()?),
 
             Token::Continue
                 if self.allow_looping() && settings.has_flag(ParseSettingFlags::BREAKABLE) =>
             {
                 let pos = eat_token(state.input, &Token::Continue);
-                Ok(Stmt::BreakLoop(None, ASTFlags::empty(), pos))
+                let label = self.parse_break_label(state)?;
+                let target = label.map(|label| (Expr::Unit(pos), Some(label)).into());
+                Ok(Stmt::BreakLoop(target, ASTFlags::empty(), pos))
             }
             Token::Break
                 if self.allow_looping() && settings.has_flag(ParseSettingFlags::BREAKABLE) =>
             {
                 let pos = eat_token(state.input, &Token::Break);
+                let label = self.parse_break_label(state)?;
 
                 let current_pos = state.input.peek().unwrap().1;
 
                 match self.parse_expr(state, settings.level_up()?) {
-                    Ok(expr) => Ok(Stmt::BreakLoop(Some(expr.into()), ASTFlags::BREAK, pos)),
+                    Ok(expr) => Ok(Stmt::BreakLoop(
+                        Some((expr, label).into()),
+                        ASTFlags::BREAK,
+                        pos,
+                    )),
                     Err(err) => {
                         if state.input.peek().unwrap().1 == current_pos {
-                            Ok(Stmt::BreakLoop(None, ASTFlags::BREAK, pos))
+                            let target = label.map(|label| (Expr::Unit(pos), Some(label)).into());
+                            Ok(Stmt::BreakLoop(target, ASTFlags::BREAK, pos))
                         } else {
                             return Err(err);
                         }
@@ -3392,10 +4514,136 @@ impl Engine {
             #[cfg(not(feature = "no_module"))]
             Token::Export => self.parse_export(state, settings.level_up()?),
 
+            #[cfg(not(feature = "no_module"))]
+            #[cfg(not(feature = "no_function"))]
+            Token::Module if !settings.has_flag(ParseSettingFlags::GLOBAL_LEVEL) => {
+                Err(PERR::WrongModuleDefinition.into_err(token_pos))
+            }
+
+            #[cfg(not(feature = "no_module"))]
+            #[cfg(not(feature = "no_function"))]
+            Token::Module => self.parse_module(state, settings.level_up()?),
+
             _ => self.parse_expr_stmt(state, settings.level_up()?),
         }
     }
 
+    /// Parse an inline `module` block.
+    #[cfg(not(feature = "no_module"))]
+    #[cfg(not(feature = "no_function"))]
+    fn parse_module(&self, state: &mut ParseState, settings: ParseSettings) -> ParseResult<Stmt> {
+        // module ...
+        let settings = settings.level_up_with_position(eat_token(state.input, &Token::Module))?;
+
+        // module name ...
+        let (name, _) = parse_var_name(state.input)?;
+        let name = self.get_interned_string(name);
+
+        // module name { ...
+        match state.input.next().unwrap() {
+            (Token::LeftBrace, ..) => (),
+            (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+            (.., pos) => {
+                return Err(PERR::MissingToken(
+                    Token::LeftBrace.into(),
+                    format!("to start the body of module '{name}'"),
+                )
+                .into_err(pos))
+            }
+        }
+
+        // Build new parse state
+        let mut lib = FnLib::default();
+        let new_state = &mut ParseState::new(
+            state.external_constants,
+            state.input,
+            state.tokenizer_control.clone(),
+            &mut lib,
+        );
+
+        new_state.global_imports.clone_from(&state.global_imports);
+        new_state.global_imports.extend(state.imports.clone());
+        new_state.imported_items.clone_from(&state.imported_items);
+
+        let mut variables = ThinVec::new();
+
+        loop {
+            // Terminated?
+            match new_state.input.peek().unwrap() {
+                (Token::RightBrace, ..) => {
+                    eat_token(new_state.input, &Token::RightBrace);
+                    break;
+                }
+                (Token::EOF, pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::RightBrace.into(),
+                        format!("to terminate the body of module '{name}'"),
+                    )
+                    .into_err(*pos));
+                }
+                _ => (),
+            }
+
+            let stmt = self.parse_stmt(new_state, settings)?;
+
+            let need_semicolon = match stmt {
+                // Function definitions and nested modules
+                Stmt::Noop(..) => false,
+                // Variables must have constant values
+                Stmt::Var(x, options, ..) => {
+                    let (ident, expr, ..) = *x;
+                    let value = expr.get_literal_value().ok_or_else(|| {
+                        PERR::ExprExpected("a constant".into()).into_err(expr.start_position())
+                    })?;
+                    variables.push((
+                        ident.name,
+                        value,
+                        options.intersects(ASTFlags::CONSTANT),
+                        options.intersects(ASTFlags::EXPORTED),
+                    ));
+                    true
+                }
+                stmt => return Err(PERR::WrongModuleStatement.into_err(stmt.position())),
+            };
+
+            match new_state.input.peek().unwrap() {
+                // { ... stmt }
+                (Token::RightBrace, ..) => (),
+                // { ... stmt;
+                (Token::SemiColon, ..) => {
+                    eat_token(new_state.input, &Token::SemiColon);
+                }
+                // { ... fn f() { } ???
+                _ if !need_semicolon => (),
+                // { ... stmt <error>
+                (Token::LexError(err), err_pos) => return Err(err.clone().into_err(*err_pos)),
+                // { ... stmt ???
+                (.., pos) => {
+                    // Semicolons are not optional between statements
+                    return Err(PERR::MissingToken(
+                        Token::SemiColon.into(),
+                        "to terminate this statement".into(),
+                    )
+                    .into_err(*pos));
+                }
+            }
+        }
+
+        let modules = std::mem::take(&mut new_state.modules);
+
+        // Keep the module name in `global_imports` so that strict variables mode will not complain
+        state.global_imports.push(name.clone());
+
+        state.modules.push(ModuleDef {
+            name,
+            functions: lib.into_values().collect(),
+            variables,
+            modules,
+        });
+
+        Ok(Stmt::Noop(settings.pos))
+    }
+
     /// Parse a try/catch statement.
     fn parse_try_catch(
         &self,
@@ -3408,60 +4656,99 @@ impl Engine {
         // try { try_block }
         let body = self.parse_block(state, settings)?.into();
 
+        let mut catch_clauses = StaticVec::new_const();
+
         // try { try_block } catch
-        let (matched, catch_pos) = match_token(state.input, &Token::Catch);
+        while match_token(state.input, &Token::Catch).0 {
+            let mut error_type = None;
+            let mut condition = Expr::Unit(Position::NONE);
 
-        if !matched {
-            return Err(
-                PERR::MissingToken(Token::Catch.into(), "for the 'try' statement".into())
-                    .into_err(catch_pos),
-            );
-        }
+            // try { try_block } catch (
+            let catch_var = if match_token(state.input, &Token::LeftParen).0 {
+                let (name, pos) = parse_var_name(state.input)?;
+                let name = self.get_interned_string(name);
+                state.stack.push(name.clone(), ());
 
-        // try { try_block } catch (
-        let catch_var = if match_token(state.input, &Token::LeftParen).0 {
-            let (name, pos) = parse_var_name(state.input)?;
-            let (matched, err_pos) = match_token(state.input, &Token::RightParen);
+                // try { try_block } catch ( var : type
+                if match_token(state.input, &Token::Colon).0 {
+                    error_type = Some(self.get_interned_string(parse_type_name(state.input)?.0));
+                }
 
-            if !matched {
-                return Err(PERR::MissingToken(
-                    Token::RightParen.into(),
-                    "to enclose the catch variable".into(),
+                // try { try_block } catch ( var if expr
+                if match_token(state.input, &Token::If).0 {
+                    condition = self
+                        .parse_expr(state, settings.level_up()?)?
+                        .ensure_bool_expr()?;
+                }
+
+                let (matched, err_pos) = match_token(state.input, &Token::RightParen);
+
+                if !matched {
+                    return Err(PERR::MissingToken(
+                        Token::RightParen.into(),
+                        "to enclose the catch variable".into(),
+                    )
+                    .into_err(err_pos));
+                }
+
+                Ident { name, pos }
+            } else {
+                Ident {
+                    name: self.get_interned_string(""),
+                    pos: Position::NONE,
+                }
+            };
+
+            // try { try_block } catch ( var ) { catch_block }
+            let catch_body = self.parse_block(state, settings)?.into();
+
+            let var = if catch_var.is_empty() {
+                Expr::Unit(catch_var.pos)
+            } else {
+                // Remove the error variable from the stack
+                state.stack.pop();
+
+                Expr::Variable(
+                    #[cfg(not(feature = "no_module"))]
+                    (None, catch_var.name, <_>::default(), 0).into(),
+                    #[cfg(feature = "no_module")]
+                    (None, catch_var.name).into(),
+                    None,
+                    catch_var.pos,
                 )
-                .into_err(err_pos));
-            }
+            };
 
-            let name = self.get_interned_string(name);
-            state.stack.push(name.clone(), ());
-            Ident { name, pos }
-        } else {
-            Ident {
-                name: self.get_interned_string(""),
-                pos: Position::NONE,
-            }
-        };
+            catch_clauses.push(CatchClause {
+                var,
+                error_type,
+                condition,
+                body: catch_body,
+            });
+        }
 
-        // try { try_block } catch ( var ) { catch_block }
-        let branch = self.parse_block(state, settings)?.into();
+        // try { try_block } catch ( var ) { catch_block } finally { finally_block }
+        let (has_finally, finally_pos) = match_token(state.input, &Token::Finally);
 
-        let expr = if catch_var.is_empty() {
-            Expr::Unit(catch_var.pos)
+        let finally = if has_finally {
+            self.parse_block(state, settings)?.into()
+        } else if catch_clauses.is_empty() {
+            return Err(
+                PERR::MissingToken(Token::Catch.into(), "for the 'try' statement".into())
+                    .into_err(finally_pos),
+            );
         } else {
-            // Remove the error variable from the stack
-            state.stack.pop();
-
-            Expr::Variable(
-                #[cfg(not(feature = "no_module"))]
-                (None, catch_var.name, <_>::default(), 0).into(),
-                #[cfg(feature = "no_module")]
-                (None, catch_var.name).into(),
-                None,
-                catch_var.pos,
-            )
+            StmtBlock::NONE
         };
 
+        catch_clauses.shrink_to_fit();
+
         Ok(Stmt::TryCatch(
-            FlowControl { expr, body, branch }.into(),
+            TryCatchBlock {
+                body,
+                catch_clauses,
+                finally,
+            }
+            .into(),
             settings.pos,
         ))
     }
@@ -3516,10 +4803,14 @@ impl Engine {
             }
         };
 
+        let is_operator = token.is_overloadable_operator();
+
         let name = match token {
             #[cfg(not(feature = "no_custom_syntax"))]
             Token::Custom(s) if is_valid_function_name(&s) => *s,
             Token::Identifier(s) if is_valid_function_name(&s) => *s,
+            // fn +(x, y)
+            _ if is_operator => token.literal_syntax().into(),
             Token::Reserved(s) => return Err(PERR::Reserved(s.to_string()).into_err(pos)),
             _ => return Err(PERR::FnMissingName.into_err(pos)),
         };
@@ -3536,25 +4827,24 @@ impl Engine {
             (.., pos) => return Err(PERR::FnMissingParams(name.into()).into_err(*pos)),
         };
 
-        let mut params = StaticVec::<(ImmutableString, _)>::new_const();
+        let mut params = StaticVec::<(ImmutableString, Option<ImmutableString>, _)>::new_const();
+        let mut param_defaults = FnArgsVec::new_const();
+        let mut is_variadic = false;
 
         if !no_params {
             let sep_err = format!("to separate the parameters of function '{name}'");
 
             loop {
-                match state.input.next().unwrap() {
+                let (s, pos, is_rest) = match state.input.next().unwrap() {
                     (Token::RightParen, ..) => break,
-                    (Token::Identifier(s), pos) => {
-                        if params.iter().any(|(p, _)| p == &*s) {
-                            return Err(
-                                PERR::FnDuplicatedParam(name.into(), s.to_string()).into_err(pos)
-                            );
-                        }
-
-                        let s = self.get_interned_string(*s);
-                        state.stack.push(s.clone(), ());
-                        params.push((s, pos));
-                    }
+                    // ...param
+                    #[cfg(not(feature = "no_index"))]
+                    (Token::Ellipsis, ..) => match state.input.next().unwrap() {
+                        (Token::Identifier(s), pos) => (s, pos, true),
+                        (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                        (.., pos) => return Err(PERR::VariableExpected.into_err(pos)),
+                    },
+                    (Token::Identifier(s), pos) => (s, pos, false),
                     (Token::LexError(err), pos) => return Err(err.into_err(pos)),
                     (.., pos) => {
                         return Err(PERR::MissingToken(
@@ -3563,6 +4853,53 @@ impl Engine {
                         )
                         .into_err(pos))
                     }
+                };
+
+                if params.iter().any(|(p, ..)| p == &*s) {
+                    return Err(PERR::FnDuplicatedParam(name.into(), s.to_string()).into_err(pos));
+                }
+
+                let s = self.get_interned_string(*s);
+                state.stack.push(s.clone(), ());
+
+                // param: type
+                let typ = if match_token(state.input, &Token::Colon).0 {
+                    Some(self.get_interned_string(parse_type_name(state.input)?.0))
+                } else {
+                    None
+                };
+
+                // param = default
+                if !is_rest && match_token(state.input, &Token::Equals).0 {
+                    let expr = self.parse_expr(state, settings.level_up()?)?;
+                    let value = expr.get_literal_value().ok_or_else(|| {
+                        PERR::FnNonConstantParamDefault(name.to_string(), s.to_string())
+                            .into_err(expr.start_position())
+                    })?;
+                    param_defaults.push(value);
+                } else if !is_rest && !param_defaults.is_empty() {
+                    return Err(
+                        PERR::FnMissingParamDefault(name.to_string(), s.to_string()).into_err(pos)
+                    );
+                }
+
+                params.push((s, typ, pos));
+
+                // The variadic parameter must be the last
+                if is_rest {
+                    is_variadic = true;
+
+                    match state.input.next().unwrap() {
+                        (Token::RightParen, ..) => break,
+                        (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                        (.., pos) => {
+                            return Err(PERR::MissingToken(
+                                Token::RightParen.into(),
+                                format!("after the variadic parameter of function '{name}'"),
+                            )
+                            .into_err(pos))
+                        }
+                    }
                 }
 
                 match state.input.next().unwrap() {
@@ -3576,6 +4913,26 @@ impl Engine {
             }
         }
 
+        // Operator functions must be unary or binary
+        if is_operator {
+            let num_params_ok = match name.as_str() {
+                "+" | "-" => params.len() == 1 || params.len() == 2,
+                "!" => params.len() == 1,
+                _ => params.len() == 2,
+            };
+
+            if !num_params_ok || is_variadic || !param_defaults.is_empty() {
+                return Err(PERR::FnWrongOperatorParams(name.into()).into_err(pos));
+            }
+        }
+
+        // -> type
+        let return_type = if match_token(state.input, &Token::Arrow).0 {
+            Some(self.get_interned_string(parse_type_name(state.input)?.0))
+        } else {
+            None
+        };
+
         // Parse function body
         let body = match state.input.peek().unwrap() {
             (Token::LeftBrace, ..) => self.parse_block(state, settings)?,
@@ -3583,6 +4940,13 @@ impl Engine {
         }
         .into();
 
+        let mut param_types: FnArgsVec<_> = if params.iter().any(|(_, t, ..)| t.is_some()) {
+            params.iter().map(|(_, t, ..)| t.clone()).collect()
+        } else {
+            FnArgsVec::new_const()
+        };
+        param_types.shrink_to_fit();
+
         let mut params: FnArgsVec<_> = params.into_iter().map(|(p, ..)| p).collect();
         params.shrink_to_fit();
 
@@ -3592,6 +4956,10 @@ impl Engine {
             #[cfg(not(feature = "no_object"))]
             this_type,
             params,
+            param_types,
+            param_defaults,
+            is_variadic,
+            return_type,
             body,
             #[cfg(feature = "metadata")]
             comments: comments.into_iter().collect(),
@@ -3648,6 +5016,7 @@ impl Engine {
             args,
             op_token: None,
             capture_parent_scope: false,
+            named_args: ThinVec::new(),
         }
         .into_fn_call_expr(pos);
 
@@ -3800,6 +5169,10 @@ impl Engine {
             #[cfg(not(feature = "no_object"))]
             this_type: None,
             params,
+            param_types: FnArgsVec::new_const(),
+            param_defaults: FnArgsVec::new_const(),
+            is_variadic: false,
+            return_type: None,
             body: body.into(),
             #[cfg(not(feature = "no_function"))]
             #[cfg(feature = "metadata")]
@@ -3834,7 +5207,7 @@ impl Engine {
                 // If the parent scope is not inside another capturing closure
                 // then we can conclude that the captured variable doesn't exist.
                 // Under Strict Variables mode, this is not allowed.
-                return Err(PERR::VariableUndefined(name.to_string()).into_err(*pos));
+                state.undefined_var(name, *pos)?;
             }
         }
 
@@ -3967,16 +5340,16 @@ impl Engine {
         let (statements, _lib) = self.parse_global_level(&mut state, |_| {})?;
 
         #[cfg(not(feature = "no_optimize"))]
-        return Ok(self.optimize_into_ast(
+        let mut _ast = self.optimize_into_ast(
             state.external_constants,
             statements,
             #[cfg(not(feature = "no_function"))]
             _lib,
             optimization_level,
-        ));
+        );
 
         #[cfg(feature = "no_optimize")]
-        return Ok(AST::new(
+        let mut _ast = AST::new(
             statements,
             #[cfg(not(feature = "no_function"))]
             {
@@ -3984,6 +5357,107 @@ impl Engine {
                 new_lib.extend(_lib);
                 new_lib
             },
-        ));
+        );
+
+        // Compile inline modules
+        #[cfg(not(feature = "no_module"))]
+        #[cfg(not(feature = "no_function"))]
+        for def in state.modules {
+            let name = def.name.clone();
+            let module = self.build_module(
+                def,
+                state.external_constants,
+                #[cfg(not(feature = "no_optimize"))]
+                optimization_level,
+            );
+            _ast.lib_mut().set_sub_module(name, module);
+        }
+
+        Ok(_ast)
+    }
+
+    /// Compile an inline `module` block into a [module][crate::Module].
+    #[cfg(not(feature = "no_module"))]
+    #[cfg(not(feature = "no_function"))]
+    fn build_module(
+        &self,
+        def: ModuleDef,
+        external_constants: Option<&Scope>,
+        #[cfg(not(feature = "no_optimize"))] optimization_level: crate::OptimizationLevel,
+    ) -> crate::SharedModule {
+        let ModuleDef {
+            name,
+            functions,
+            variables,
+            modules,
+        } = def;
+
+        let imports = modules
+            .into_iter()
+            .map(|def| {
+                let name = def.name.clone();
+                let module = self.build_module(
+                    def,
+                    external_constants,
+                    #[cfg(not(feature = "no_optimize"))]
+                    optimization_level,
+                );
+                (name, module)
+            })
+            .collect::<ThinVec<_>>();
+
+        // All functions, including private ones, are visible within the module
+        #[cfg(not(feature = "no_optimize"))]
+        let mut lib = self
+            .optimize_into_ast(
+                external_constants,
+                StmtBlockContainer::new_const(),
+                functions,
+                optimization_level,
+            )
+            .shared_lib()
+            .as_ref()
+            .clone();
+        #[cfg(feature = "no_optimize")]
+        let mut lib = crate::Module::from(functions);
+
+        lib.set_id(name.clone());
+        let lib = crate::SharedModule::from(lib);
+
+        let constants = variables
+            .iter()
+            .filter(|(.., is_const, _)| *is_const)
+            .map(|(name, value, ..)| (name.clone(), value.clone()))
+            .collect();
+
+        // Encapsulated environment
+        let environ = Shared::new(crate::ast::EncapsulatedEnviron {
+            lib: lib.clone(),
+            imports: imports.clone(),
+            constants: Some(crate::Locked::new(constants).into()),
+        });
+
+        let mut module = crate::Module::new();
+        module.set_id(name);
+
+        // Non-private functions become module functions
+        for (.., access, _, _, fn_def) in lib.iter_script_fn() {
+            if access == crate::FnAccess::Public {
+                module.set_script_fn_with_environ(fn_def.clone(), environ.clone());
+            }
+        }
+
+        for (name, value, .., is_exported) in variables {
+            if is_exported {
+                module.set_var(name, value);
+            }
+        }
+
+        for (name, sub_module) in imports {
+            module.set_sub_module(name, sub_module);
+        }
+
+        module.build_index();
+        module.into()
     }
 }
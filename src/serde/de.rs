@@ -52,6 +52,25 @@ impl<'de> DynamicDeserializer<'de> {
         #[cfg(feature = "only_i32")]
         return visitor.visit_i32(v);
     }
+    /// Deserialize a [`BigInt`][num_bigint::BigInt] into the smallest primitive integer type
+    /// that can hold it, falling back to its string representation.
+    #[cfg(feature = "bigint")]
+    fn deserialize_bigint<V: Visitor<'de>>(
+        x: &num_bigint::BigInt,
+        visitor: V,
+    ) -> RhaiResultOf<V::Value> {
+        use num_traits::ToPrimitive;
+
+        if let Some(v) = x.to_i64() {
+            visitor.visit_i64(v)
+        } else if let Some(v) = x.to_i128() {
+            visitor.visit_i128(v)
+        } else if let Some(v) = x.to_u128() {
+            visitor.visit_u128(v)
+        } else {
+            visitor.visit_string(x.to_string())
+        }
+    }
 }
 
 /// Deserialize a [`Dynamic`][crate::Dynamic] value into a Rust type that implements [`serde::Deserialize`].
@@ -172,6 +191,10 @@ impl<'de> Deserializer<'de> for DynamicDeserializer<'de> {
             Union::Variant(ref value, ..) if value.is::<u32>() => self.deserialize_u32(visitor),
             Union::Variant(ref value, ..) if value.is::<u64>() => self.deserialize_u64(visitor),
             Union::Variant(ref value, ..) if value.is::<u128>() => self.deserialize_u128(visitor),
+            #[cfg(feature = "bigint")]
+            Union::Variant(ref value, ..) if value.is::<num_bigint::BigInt>() => {
+                Self::deserialize_bigint(self.0.downcast_ref().unwrap(), visitor)
+            }
 
             Union::Variant(..) => self.type_error(),
 
@@ -216,6 +239,11 @@ impl<'de> Deserializer<'de> for DynamicDeserializer<'de> {
     }
 
     fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> RhaiResultOf<V::Value> {
+        #[cfg(feature = "bigint")]
+        if let Some(x) = self.0.downcast_ref::<num_bigint::BigInt>() {
+            return Self::deserialize_bigint(x, visitor);
+        }
+
         match self.0.as_int() {
             Ok(v) => Self::deserialize_int(v, visitor),
             _ if cfg!(not(feature = "only_i32")) => self.type_error(),
@@ -227,6 +255,11 @@ impl<'de> Deserializer<'de> for DynamicDeserializer<'de> {
     }
 
     fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> RhaiResultOf<V::Value> {
+        #[cfg(feature = "bigint")]
+        if let Some(x) = self.0.downcast_ref::<num_bigint::BigInt>() {
+            return Self::deserialize_bigint(x, visitor);
+        }
+
         match self.0.as_int() {
             Ok(v) => Self::deserialize_int(v, visitor),
             _ if cfg!(not(feature = "only_i32")) => self.type_error(),
@@ -268,6 +301,11 @@ impl<'de> Deserializer<'de> for DynamicDeserializer<'de> {
     }
 
     fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> RhaiResultOf<V::Value> {
+        #[cfg(feature = "bigint")]
+        if let Some(x) = self.0.downcast_ref::<num_bigint::BigInt>() {
+            return Self::deserialize_bigint(x, visitor);
+        }
+
         match self.0.as_int() {
             Ok(v) => Self::deserialize_int(v, visitor),
             Err(_) => self
@@ -278,6 +316,11 @@ impl<'de> Deserializer<'de> for DynamicDeserializer<'de> {
     }
 
     fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> RhaiResultOf<V::Value> {
+        #[cfg(feature = "bigint")]
+        if let Some(x) = self.0.downcast_ref::<num_bigint::BigInt>() {
+            return Self::deserialize_bigint(x, visitor);
+        }
+
         match self.0.as_int() {
             Ok(v) => Self::deserialize_int(v, visitor),
             Err(_) => self
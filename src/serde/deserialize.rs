@@ -50,6 +50,9 @@ impl<'de> Visitor<'de> for DynamicVisitor {
 
         #[allow(unreachable_code)]
         {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
+
             #[cfg(feature = "decimal")]
             if let Some(n) = rust_decimal::Decimal::from_i64(v) {
                 return Ok(Dynamic::from_decimal(n));
@@ -69,6 +72,9 @@ impl<'de> Visitor<'de> for DynamicVisitor {
 
         #[allow(unreachable_code)]
         {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
+
             #[cfg(feature = "decimal")]
             if let Some(n) = rust_decimal::Decimal::from_i128(v) {
                 return Ok(Dynamic::from_decimal(n));
@@ -100,6 +106,9 @@ impl<'de> Visitor<'de> for DynamicVisitor {
 
         #[allow(unreachable_code)]
         {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
+
             #[cfg(feature = "decimal")]
             if let Some(n) = rust_decimal::Decimal::from_u32(v) {
                 return Ok(Dynamic::from_decimal(n));
@@ -117,16 +126,21 @@ impl<'de> Visitor<'de> for DynamicVisitor {
             return Ok(Dynamic::from(v as INT));
         }
 
-        #[cfg(feature = "decimal")]
-        if let Some(n) = rust_decimal::Decimal::from_u64(v) {
-            return Ok(Dynamic::from_decimal(n));
-        }
+        #[allow(unreachable_code)]
+        {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
 
-        #[cfg(not(feature = "no_float"))]
-        return Ok(Dynamic::from_float(v as crate::FLOAT));
+            #[cfg(feature = "decimal")]
+            if let Some(n) = rust_decimal::Decimal::from_u64(v) {
+                return Ok(Dynamic::from_decimal(n));
+            }
 
-        #[allow(unreachable_code)]
-        Err(Error::custom(format!("integer number too large: {v}")))
+            #[cfg(not(feature = "no_float"))]
+            return Ok(Dynamic::from_float(v as crate::FLOAT));
+
+            Err(Error::custom(format!("integer number too large: {v}")))
+        }
     }
     #[inline]
     fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
@@ -134,16 +148,21 @@ impl<'de> Visitor<'de> for DynamicVisitor {
             return Ok(Dynamic::from(v as INT));
         }
 
-        #[cfg(feature = "decimal")]
-        if let Some(n) = rust_decimal::Decimal::from_u128(v) {
-            return Ok(Dynamic::from_decimal(n));
-        }
+        #[allow(unreachable_code)]
+        {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
 
-        #[cfg(not(feature = "no_float"))]
-        return Ok(Dynamic::from_float(v as crate::FLOAT));
+            #[cfg(feature = "decimal")]
+            if let Some(n) = rust_decimal::Decimal::from_u128(v) {
+                return Ok(Dynamic::from_decimal(n));
+            }
 
-        #[allow(unreachable_code)]
-        Err(Error::custom(format!("integer number too large: {v}")))
+            #[cfg(not(feature = "no_float"))]
+            return Ok(Dynamic::from_float(v as crate::FLOAT));
+
+            Err(Error::custom(format!("integer number too large: {v}")))
+        }
     }
 
     #[cfg(not(feature = "no_float"))]
@@ -270,6 +289,27 @@ impl<'de> Deserialize<'de> for ImmutableString {
     }
 }
 
+#[cfg(not(feature = "no_index"))]
+impl<'de> Deserialize<'de> for crate::Set {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items: Vec<Dynamic> = Deserialize::deserialize(deserializer)?;
+
+        Self::try_from_iter(items).map_err(|value| {
+            Error::custom(format!("{} cannot be kept in a set", value.type_name()))
+        })
+    }
+}
+
+#[cfg(not(feature = "no_index"))]
+impl<'de> Deserialize<'de> for crate::Tuple {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items: Vec<Dynamic> = Deserialize::deserialize(deserializer)?;
+        Ok(items.into())
+    }
+}
+
 impl<'de> Deserialize<'de> for Scope<'de> {
     #[inline(always)]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -141,6 +141,9 @@ impl Serializer for &mut DynamicSerializer {
 
         #[allow(unreachable_code)]
         {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
+
             #[cfg(feature = "decimal")]
             if let Some(n) = rust_decimal::Decimal::from_i64(v) {
                 return Ok(Dynamic::from_decimal(n));
@@ -161,6 +164,9 @@ impl Serializer for &mut DynamicSerializer {
 
         #[allow(unreachable_code)]
         {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
+
             #[cfg(feature = "decimal")]
             if let Some(n) = rust_decimal::Decimal::from_i128(v) {
                 return Ok(Dynamic::from_decimal(n));
@@ -195,6 +201,9 @@ impl Serializer for &mut DynamicSerializer {
 
         #[allow(unreachable_code)]
         {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
+
             #[cfg(feature = "decimal")]
             if let Some(n) = rust_decimal::Decimal::from_u32(v) {
                 return Ok(Dynamic::from_decimal(n));
@@ -213,16 +222,21 @@ impl Serializer for &mut DynamicSerializer {
             return Ok(Dynamic::from(v as INT));
         }
 
-        #[cfg(feature = "decimal")]
-        if let Some(n) = rust_decimal::Decimal::from_u64(v) {
-            return Ok(Dynamic::from_decimal(n));
-        }
+        #[allow(unreachable_code)]
+        {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
 
-        #[cfg(not(feature = "no_float"))]
-        return Ok(Dynamic::from_float(v as crate::FLOAT));
+            #[cfg(feature = "decimal")]
+            if let Some(n) = rust_decimal::Decimal::from_u64(v) {
+                return Ok(Dynamic::from_decimal(n));
+            }
 
-        #[allow(unreachable_code)]
-        Err(Error::custom(format!("integer number too large: {v}")))
+            #[cfg(not(feature = "no_float"))]
+            return Ok(Dynamic::from_float(v as crate::FLOAT));
+
+            Err(Error::custom(format!("integer number too large: {v}")))
+        }
     }
 
     #[inline]
@@ -231,16 +245,21 @@ impl Serializer for &mut DynamicSerializer {
             return Ok(Dynamic::from(v as INT));
         }
 
-        #[cfg(feature = "decimal")]
-        if let Some(n) = rust_decimal::Decimal::from_u128(v) {
-            return Ok(Dynamic::from_decimal(n));
-        }
+        #[allow(unreachable_code)]
+        {
+            #[cfg(feature = "bigint")]
+            return Ok(Dynamic::from(num_bigint::BigInt::from(v)));
 
-        #[cfg(not(feature = "no_float"))]
-        return Ok(Dynamic::from_float(v as crate::FLOAT));
+            #[cfg(feature = "decimal")]
+            if let Some(n) = rust_decimal::Decimal::from_u128(v) {
+                return Ok(Dynamic::from_decimal(n));
+            }
 
-        #[allow(unreachable_code)]
-        Err(Error::custom(format!("integer number too large: {v}")))
+            #[cfg(not(feature = "no_float"))]
+            return Ok(Dynamic::from_float(v as crate::FLOAT));
+
+            Err(Error::custom(format!("integer number too large: {v}")))
+        }
     }
 
     #[inline(always)]
@@ -10,7 +10,7 @@ use std::prelude::v1::*;
 #[cfg(not(feature = "no_object"))]
 use serde::ser::SerializeMap;
 
-#[cfg(not(feature = "no_time"))]
+#[cfg(any(not(feature = "no_index"), not(feature = "no_time")))]
 use crate::types::dynamic::Variant;
 
 impl Serialize for Dynamic {
@@ -71,6 +71,47 @@ impl Serialize for Dynamic {
             #[cfg(not(feature = "no_time"))]
             Union::TimeStamp(ref x, ..) => ser.serialize_str(x.as_ref().type_name()),
 
+            #[cfg(not(feature = "no_index"))]
+            Union::Variant(ref v, ..) if (***v).is::<crate::Set>() => (***v)
+                .as_any()
+                .downcast_ref::<crate::Set>()
+                .unwrap()
+                .serialize(ser),
+            #[cfg(not(feature = "no_index"))]
+            Union::Variant(ref v, ..) if (***v).is::<crate::Tuple>() => (***v)
+                .as_any()
+                .downcast_ref::<crate::Tuple>()
+                .unwrap()
+                .serialize(ser),
+            #[cfg(feature = "bigint")]
+            Union::Variant(ref v, ..) if (***v).is::<num_bigint::BigInt>() => {
+                use num_traits::ToPrimitive;
+
+                let x = (***v)
+                    .as_any()
+                    .downcast_ref::<num_bigint::BigInt>()
+                    .unwrap();
+
+                match x.to_i64() {
+                    Some(v) => ser.serialize_i64(v),
+                    None => ser.serialize_str(&x.to_string()),
+                }
+            }
+            #[cfg(feature = "rational")]
+            Union::Variant(ref v, ..) if (***v).is::<crate::Rational>() => {
+                let x = (***v).as_any().downcast_ref::<crate::Rational>().unwrap();
+                ser.serialize_str(&x.to_string())
+            }
+            #[cfg(not(feature = "no_time"))]
+            Union::Variant(ref v, ..) if (***v).is::<crate::DateTime>() => {
+                let x = (***v).as_any().downcast_ref::<crate::DateTime>().unwrap();
+                ser.serialize_str(&x.to_string())
+            }
+            #[cfg(feature = "regex")]
+            Union::Variant(ref v, ..) if (***v).is::<crate::Regex>() => {
+                let x = (***v).as_any().downcast_ref::<crate::Regex>().unwrap();
+                ser.serialize_str(x.as_str())
+            }
             Union::Variant(ref v, ..) => ser.serialize_str((***v).type_name()),
 
             #[cfg(not(feature = "no_closure"))]
@@ -83,6 +124,22 @@ impl Serialize for Dynamic {
     }
 }
 
+#[cfg(not(feature = "no_index"))]
+impl Serialize for crate::Set {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.collect_seq(self.iter())
+    }
+}
+
+#[cfg(not(feature = "no_index"))]
+impl Serialize for crate::Tuple {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.collect_seq(self.iter())
+    }
+}
+
 impl Serialize for ImmutableString {
     #[inline(always)]
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
@@ -22,6 +22,11 @@ pub struct TokenizerControlBlock {
     ///
     /// This flag allows switching the tokenizer back to _text_ parsing after an interpolation stream.
     pub is_within_text: bool,
+    /// Is the current tokenizer position at a format specifier within an interpolation stream
+    /// (e.g. the `.2` in `` `${x:.2}` ``)?
+    ///
+    /// This flag allows reading the format specifier, up to the closing `}`, as raw text.
+    pub is_within_format_spec: bool,
     /// Global comments.
     #[cfg(feature = "metadata")]
     pub global_comments: String,
@@ -38,6 +43,7 @@ impl TokenizerControlBlock {
     pub const fn new() -> Self {
         Self {
             is_within_text: false,
+            is_within_format_spec: false,
             #[cfg(feature = "metadata")]
             global_comments: String::new(),
             compressed: None,
@@ -73,10 +79,24 @@ pub enum Token {
     /// Requires the `decimal` feature, including its text representation.
     #[cfg(feature = "decimal")]
     DecimalConstant(Box<(rust_decimal::Decimal, Identifier)>),
+    /// A [`BigInt`][num_bigint::BigInt] constant, e.g. `123n`.
+    ///
+    /// Requires the `bigint` feature.
+    #[cfg(feature = "bigint")]
+    BigIntConstant(Box<num_bigint::BigInt>),
+    /// A numeric constant followed by a registered literal suffix, e.g. `200ms`.
+    SuffixedConstant(Box<(Token, Identifier)>),
+    /// A regular expression literal, e.g. `r/[a-z]+/`.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    RegexConstant(Box<SmartString>),
     /// An identifier.
     Identifier(Box<Identifier>),
     /// A character constant.
     CharConstant(char),
+    /// A loop label, e.g. `'outer`.
+    Label(Box<Identifier>),
     /// A string constant.
     StringConstant(Box<SmartString>),
     /// An interpolated string.
@@ -123,6 +143,12 @@ pub enum Token {
     DoubleColon,
     /// `=>`
     DoubleArrow,
+    /// `->`
+    Arrow,
+    /// `...`
+    Ellipsis,
+    /// `|>`
+    PipeForward,
     /// `_`
     Underscore,
     /// `,`
@@ -147,6 +173,8 @@ pub enum Token {
     InclusiveRange,
     /// `#{`
     MapStart,
+    /// `#[`
+    SetStart,
     /// `=`
     Equals,
     /// `true`
@@ -218,6 +246,8 @@ pub enum Token {
     Try,
     /// `catch`
     Catch,
+    /// `finally`
+    Finally,
     /// `+=`
     PlusAssign,
     /// `-=`
@@ -255,6 +285,11 @@ pub enum Token {
     /// Reserved under the `no_module` feature.
     #[cfg(not(feature = "no_module"))]
     Export,
+    /// `module`
+    ///
+    /// Reserved under the `no_module` feature.
+    #[cfg(not(feature = "no_module"))]
+    Module,
     /// `as`
     ///
     /// Reserved under the `no_module` feature.
@@ -288,9 +323,15 @@ impl fmt::Display for Token {
             FloatConstant(v) => write!(f, "{}", v.0),
             #[cfg(feature = "decimal")]
             DecimalConstant(d) => write!(f, "{}", d.0),
+            #[cfg(feature = "bigint")]
+            BigIntConstant(n) => write!(f, "{n}n"),
+            SuffixedConstant(x) => write!(f, "{}{}", x.0, x.1),
+            #[cfg(feature = "regex")]
+            RegexConstant(s) => write!(f, "r/{s}/"),
             StringConstant(s) => write!(f, r#""{s}""#),
             InterpolatedString(..) => f.write_str("string"),
             CharConstant(c) => write!(f, "{c}"),
+            Label(s) => write!(f, "'{s}"),
             Identifier(s) => f.write_str(s),
             Reserved(s) => f.write_str(s),
             #[cfg(not(feature = "no_custom_syntax"))]
@@ -312,118 +353,171 @@ impl fmt::Display for Token {
 const MIN_KEYWORD_LEN: usize = 1;
 const MAX_KEYWORD_LEN: usize = 8;
 const MIN_KEYWORD_HASH_VALUE: usize = 1;
-const MAX_KEYWORD_HASH_VALUE: usize = 152;
+const MAX_KEYWORD_HASH_VALUE: usize = 220;
 
 static KEYWORD_ASSOC_VALUES: [u8; 257] = [
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 115, 153, 100, 153, 110,
-    105, 40, 80, 2, 20, 25, 125, 95, 15, 40, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 55,
-    35, 10, 5, 0, 30, 110, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 120, 105, 100, 85, 90, 153, 125, 5,
-    0, 125, 35, 10, 100, 153, 20, 0, 153, 10, 0, 45, 55, 0, 153, 50, 55, 5, 0, 153, 0, 0, 35, 153,
-    45, 50, 30, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153,
-    153,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 92, 221, 4, 221, 14, 66,
+    0, 70, 30, 81, 22, 38, 4, 82, 29, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 60, 42, 3,
+    17, 15, 12, 0, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 13, 5, 44, 0, 7, 221, 11, 37, 11, 4, 71, 36,
+    11, 23, 62, 8, 15, 1, 26, 24, 13, 71, 221, 8, 71, 67, 0, 221, 54, 12, 43, 221, 8, 52, 36, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
+    221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221, 221,
 ];
-static KEYWORDS_LIST: [(&str, Token); 153] = [
+static KEYWORDS_LIST: [(&str, Token); 221] = [
+    ("", Token::EOF),
+    ("^", Token::XOr),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("<", Token::LessThan),
+    ("", Token::EOF),
+    ("-", Token::Minus),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("_", Token::Underscore),
+    ("", Token::EOF),
+    ("{", Token::LeftBrace),
+    ("", Token::EOF),
+    ("until", Token::Until),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("#[", Token::SetStart),
+    ("<<", Token::LeftShift),
+    ("??", Token::DoubleQuestion),
+    ("[", Token::LeftBracket),
+    ("", Token::EOF),
+    ("%", Token::Modulo),
     ("", Token::EOF),
     (">", Token::GreaterThan),
-    (">=", Token::GreaterThanEqualsTo),
-    (")", Token::RightParen),
+    #[cfg(not(feature = "no_index"))]
+    ("?[", Token::QuestionBracket),
+    #[cfg(feature = "no_index")]
     ("", Token::EOF),
-    ("const", Token::Const),
+    ("->", Token::Arrow),
+    ("^=", Token::XOrAssign),
     ("=", Token::Equals),
-    ("==", Token::EqualsTo),
-    ("continue", Token::Continue),
     ("", Token::EOF),
-    ("catch", Token::Catch),
-    ("<", Token::LessThan),
     ("<=", Token::LessThanEqualsTo),
-    ("for", Token::For),
-    ("loop", Token::Loop),
+    ("-=", Token::MinusAssign),
     ("", Token::EOF),
-    (".", Token::Period),
-    ("<<", Token::LeftShift),
     ("<<=", Token::LeftShiftAssign),
     ("", Token::EOF),
-    ("false", Token::False),
-    ("*", Token::Multiply),
-    ("*=", Token::MultiplyAssign),
-    ("let", Token::Let),
     ("", Token::EOF),
-    ("while", Token::While),
-    ("+", Token::Plus),
-    ("+=", Token::PlusAssign),
     ("", Token::EOF),
-    ("", Token::EOF),
-    ("throw", Token::Throw),
-    ("}", Token::RightBrace),
     (">>", Token::RightShift),
+    ("+", Token::Plus),
+    ("=>", Token::DoubleArrow),
     (">>=", Token::RightShiftAssign),
+    ("%=", Token::ModuloAssign),
+    (">=", Token::GreaterThanEqualsTo),
     ("", Token::EOF),
+    ("==", Token::EqualsTo),
     ("", Token::EOF),
-    (";", Token::SemiColon),
-    ("=>", Token::DoubleArrow),
     ("", Token::EOF),
-    ("else", Token::Else),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("+=", Token::PlusAssign),
+    ("", Token::EOF),
     ("", Token::EOF),
     ("/", Token::Divide),
-    ("/=", Token::DivideAssign),
     ("", Token::EOF),
+    (")", Token::RightParen),
     ("", Token::EOF),
+    ("/=", Token::DivideAssign),
     ("", Token::EOF),
-    ("{", Token::LeftBrace),
-    ("**", Token::PowerOf),
-    ("**=", Token::PowerOfAssign),
     ("", Token::EOF),
+    ("#{", Token::MapStart),
     ("", Token::EOF),
-    ("|", Token::Pipe),
-    ("|=", Token::OrAssign),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    (":", Token::Colon),
-    ("..", Token::ExclusiveRange),
-    ("..=", Token::InclusiveRange),
     ("", Token::EOF),
-    ("until", Token::Until),
-    ("switch", Token::Switch),
-    #[cfg(not(feature = "no_function"))]
-    ("private", Token::Private),
-    #[cfg(feature = "no_function")]
     ("", Token::EOF),
-    ("try", Token::Try),
-    ("true", Token::True),
-    ("break", Token::Break),
+    ("}", Token::RightBrace),
     ("return", Token::Return),
     #[cfg(not(feature = "no_function"))]
     ("fn", Token::Fn),
     #[cfg(feature = "no_function")]
     ("", Token::EOF),
+    ("catch", Token::Catch),
+    (",", Token::Comma),
+    ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
+    ("|>", Token::PipeForward),
     ("", Token::EOF),
-    #[cfg(not(feature = "no_module"))]
-    ("import", Token::Import),
-    #[cfg(feature = "no_module")]
+    ("", Token::EOF),
+    ("", Token::EOF),
+    (";", Token::SemiColon),
+    ("|=", Token::OrAssign),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("]", Token::RightBracket),
+    ("do", Token::Do),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("finally", Token::Finally),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("&=", Token::AndAssign),
+    ("in", Token::In),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("|", Token::Pipe),
+    ("", Token::EOF),
+    ("let", Token::Let),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("if", Token::If),
+    ("switch", Token::Switch),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("*=", Token::MultiplyAssign),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("for", Token::For),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    (":", Token::Colon),
+    ("", Token::EOF),
+    ("**=", Token::PowerOfAssign),
     ("", Token::EOF),
     #[cfg(not(feature = "no_object"))]
     ("?.", Token::Elvis),
     #[cfg(feature = "no_object")]
     ("", Token::EOF),
+    ("!=", Token::NotEqualsTo),
+    ("!in", Token::NotIn),
+    ("break", Token::Break),
     ("", Token::EOF),
     ("", Token::EOF),
+    ("..=", Token::InclusiveRange),
+    ("", Token::EOF),
+    ("&", Token::Ampersand),
+    ("&&", Token::And),
+    ("", Token::EOF),
     ("", Token::EOF),
-    #[cfg(not(feature = "no_module"))]
-    ("export", Token::Export),
-    #[cfg(feature = "no_module")]
     ("", Token::EOF),
-    ("in", Token::In),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
@@ -432,79 +526,96 @@ static KEYWORDS_LIST: [(&str, Token); 153] = [
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("^", Token::XOr),
-    ("^=", Token::XOrAssign),
     ("", Token::EOF),
+    ("loop", Token::Loop),
     ("", Token::EOF),
+    ("false", Token::False),
     ("", Token::EOF),
-    ("_", Token::Underscore),
-    ("::", Token::DoubleColon),
+    #[cfg(not(feature = "no_module"))]
+    ("as", Token::As),
+    #[cfg(feature = "no_module")]
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("-", Token::Minus),
-    ("-=", Token::MinusAssign),
+    ("const", Token::Const),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("]", Token::RightBracket),
-    ("()", Token::Unit),
     ("", Token::EOF),
+    #[cfg(not(feature = "no_module"))]
+    ("import", Token::Import),
+    #[cfg(feature = "no_module")]
     ("", Token::EOF),
     ("", Token::EOF),
-    ("&", Token::Ampersand),
-    ("&=", Token::AndAssign),
+    ("continue", Token::Continue),
     ("", Token::EOF),
+    ("*", Token::Multiply),
+    ("::", Token::DoubleColon),
+    (".", Token::Period),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("%", Token::Modulo),
-    ("%=", Token::ModuloAssign),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("!", Token::Bang),
-    ("!=", Token::NotEqualsTo),
-    ("!in", Token::NotIn),
     ("", Token::EOF),
+    ("else", Token::Else),
     ("", Token::EOF),
-    ("[", Token::LeftBracket),
-    ("if", Token::If),
+    #[cfg(not(feature = "no_module"))]
+    ("module", Token::Module),
+    #[cfg(feature = "no_module")]
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    (",", Token::Comma),
-    ("do", Token::Do),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("()", Token::Unit),
+    ("try", Token::Try),
+    ("!", Token::Bang),
+    ("**", Token::PowerOf),
     #[cfg(not(feature = "no_module"))]
-    ("as", Token::As),
+    ("export", Token::Export),
     #[cfg(feature = "no_module")]
     ("", Token::EOF),
+    ("throw", Token::Throw),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
+    ("while", Token::While),
     ("", Token::EOF),
-    #[cfg(not(feature = "no_index"))]
-    ("?[", Token::QuestionBracket),
-    #[cfg(feature = "no_index")]
     ("", Token::EOF),
+    ("..", Token::ExclusiveRange),
+    ("...", Token::Ellipsis),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("??", Token::DoubleQuestion),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("&&", Token::And),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
     ("", Token::EOF),
-    ("#{", Token::MapStart),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("true", Token::True),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    ("", Token::EOF),
+    #[cfg(not(feature = "no_function"))]
+    ("private", Token::Private),
+    #[cfg(feature = "no_function")]
+    ("", Token::EOF),
 ];
 
 // Table-driven reserved symbol recognizer generated by GNU `gperf` on the file `tools/reserved.txt`.
@@ -514,161 +625,161 @@ static KEYWORDS_LIST: [(&str, Token); 153] = [
 const MIN_RESERVED_LEN: usize = 1;
 const MAX_RESERVED_LEN: usize = 10;
 const MIN_RESERVED_HASH_VALUE: usize = 1;
-const MAX_RESERVED_HASH_VALUE: usize = 149;
+const MAX_RESERVED_HASH_VALUE: usize = 148;
 
 static RESERVED_ASSOC_VALUES: [u8; 256] = [
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 10, 150, 5, 35, 150, 150,
-    150, 45, 35, 30, 30, 150, 20, 15, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 35,
-    30, 15, 5, 25, 0, 25, 150, 150, 150, 150, 150, 65, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 40, 150, 150, 150, 150, 150, 0, 150, 0,
-    0, 0, 15, 45, 10, 15, 150, 150, 35, 25, 10, 50, 0, 150, 5, 0, 15, 0, 5, 25, 45, 15, 150, 150,
-    25, 150, 20, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
-    150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150, 150,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 55, 149, 6, 3, 149, 149,
+    149, 0, 3, 32, 10, 149, 30, 34, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 39, 2,
+    32, 39, 149, 39, 0, 149, 149, 149, 149, 149, 1, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 29, 149, 149, 149, 149, 149, 0, 149, 0,
+    30, 12, 2, 22, 3, 54, 149, 149, 24, 0, 49, 44, 1, 149, 4, 36, 0, 7, 8, 0, 3, 14, 149, 149, 19,
+    149, 35, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
+    149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149, 149,
 ];
-static RESERVED_LIST: [(&str, bool, bool, bool); 150] = [
+static RESERVED_LIST: [(&str, bool, bool, bool); 149] = [
     ("", false, false, false),
-    ("?", true, false, false),
-    ("as", cfg!(feature = "no_module"), false, false),
-    ("use", true, false, false),
-    ("case", true, false, false),
-    ("async", true, false, false),
-    ("public", true, false, false),
-    ("package", true, false, false),
+    ("@", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
-    ("super", true, false, false),
-    ("#", true, false, false),
-    ("private", cfg!(feature = "no_function"), false, false),
-    ("var", true, false, false),
-    ("protected", true, false, false),
-    ("spawn", true, false, false),
-    ("shared", true, false, false),
-    ("is", true, false, false),
-    ("===", true, false, false),
-    ("sync", true, false, false),
-    ("curry", true, true, true),
-    ("static", true, false, false),
-    ("default", true, false, false),
-    ("!==", true, false, false),
-    ("is_shared", cfg!(not(feature = "no_closure")), true, true),
-    ("print", true, true, false),
     ("", false, false, false),
-    ("#!", true, false, false),
+    ("await", true, false, false),
     ("", false, false, false),
-    ("this", true, false, false),
-    ("is_def_var", true, true, false),
-    ("thread", true, false, false),
-    ("?.", cfg!(feature = "no_object"), false, false),
+    ("$", true, false, false),
+    ("match", true, false, false),
     ("", false, false, false),
-    ("is_def_fn", cfg!(not(feature = "no_function")), true, false),
-    ("yield", true, false, false),
+    ("print", true, true, false),
     ("", false, false, false),
-    ("fn", cfg!(feature = "no_function"), false, false),
-    ("new", true, false, false),
-    ("call", true, true, true),
-    ("match", true, false, false),
-    ("~", true, false, false),
-    ("!.", true, false, false),
     ("", false, false, false),
-    ("eval", true, true, false),
-    ("await", true, false, false),
+    ("#", true, false, false),
+    ("public", true, false, false),
+    ("var", true, false, false),
+    ("case", true, false, false),
+    ("", false, false, false),
+    ("", false, false, false),
+    ("exit", false, false, false),
+    ("package", true, false, false),
+    ("export", cfg!(feature = "no_module"), false, false),
     ("", false, false, false),
-    (":=", true, false, false),
-    ("...", true, false, false),
-    ("null", true, false, false),
-    ("debug", true, true, false),
-    ("@", true, false, false),
     ("type_of", true, true, true),
+    ("private", cfg!(feature = "no_function"), false, false),
     ("", false, false, false),
-    ("with", true, false, false),
+    ("curry", true, true, true),
     ("", false, false, false),
+    ("call", true, true, true),
     ("", false, false, false),
-    ("<-", true, false, false),
     ("", false, false, false),
-    ("void", true, false, false),
     ("", false, false, false),
-    ("import", cfg!(feature = "no_module"), false, false),
-    ("--", true, false, false),
-    ("nil", true, false, false),
-    ("exit", false, false, false),
+    ("++", true, false, false),
     ("", false, false, false),
-    ("export", cfg!(feature = "no_module"), false, false),
-    ("<|", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
-    ("$", true, false, false),
-    ("->", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("thread", true, false, false),
+    ("*)", true, false, false),
+    ("async", true, false, false),
+    ("static", true, false, false),
+    ("this", true, false, false),
+    ("protected", true, false, false),
+    (":;", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
-    ("|>", true, false, false),
+    ("eval", true, true, false),
+    ("default", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("super", true, false, false),
     ("", false, false, false),
-    ("module", true, false, false),
-    ("?[", cfg!(feature = "no_index"), false, false),
+    ("sync", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("use", true, false, false),
     ("", false, false, false),
-    ("Fn", true, true, false),
-    ("::<", true, false, false),
+    ("import", cfg!(feature = "no_module"), false, false),
+    ("with", true, false, false),
+    ("module", cfg!(feature = "no_module"), false, false),
     ("", false, false, false),
+    ("new", true, false, false),
     ("", false, false, false),
+    ("(*", true, false, false),
     ("", false, false, false),
-    ("++", true, false, false),
     ("", false, false, false),
+    ("debug", true, true, false),
     ("", false, false, false),
+    ("~", true, false, false),
+    ("<|", true, false, false),
     ("", false, false, false),
+    ("as", cfg!(feature = "no_module"), false, false),
+    ("shared", true, false, false),
     ("", false, false, false),
-    (":;", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("?", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
-    ("*)", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("null", true, false, false),
     ("", false, false, false),
+    ("void", true, false, false),
     ("", false, false, false),
-    ("(*", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("spawn", true, false, false),
+    ("--", true, false, false),
     ("", false, false, false),
+    ("<-", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("?[", cfg!(feature = "no_index"), false, false),
     ("", false, false, false),
+    ("Fn", true, true, false),
+    ("fn", cfg!(feature = "no_function"), false, false),
+    ("yield", true, false, false),
+    ("is_def_var", true, true, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("?.", cfg!(feature = "no_object"), false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("go", true, false, false),
+    ("::<", true, false, false),
+    ("goto", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("#!", true, false, false),
+    (":=", true, false, false),
+    ("===", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("!.", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("is", true, false, false),
+    ("is_shared", cfg!(not(feature = "no_closure")), true, true),
+    ("nil", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
+    ("!==", true, false, false),
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
@@ -679,9 +790,8 @@ static RESERVED_LIST: [(&str, bool, bool, bool); 150] = [
     ("", false, false, false),
     ("", false, false, false),
     ("", false, false, false),
-    ("go", true, false, false),
     ("", false, false, false),
-    ("goto", true, false, false),
+    ("is_def_fn", cfg!(not(feature = "no_function")), true, false),
 ];
 
 impl Token {
@@ -697,9 +807,15 @@ impl Token {
             FloatConstant(..) => false,
             #[cfg(feature = "decimal")]
             DecimalConstant(..) => false,
+            #[cfg(feature = "bigint")]
+            BigIntConstant(..) => false,
+            SuffixedConstant(..) => false,
+            #[cfg(feature = "regex")]
+            RegexConstant(..) => false,
             StringConstant(..)
             | InterpolatedString(..)
             | CharConstant(..)
+            | Label(..)
             | Identifier(..)
             | Reserved(..) => false,
             #[cfg(not(feature = "no_custom_syntax"))]
@@ -739,6 +855,9 @@ impl Token {
             Colon => ":",
             DoubleColon => "::",
             DoubleArrow => "=>",
+            Arrow => "->",
+            Ellipsis => "...",
+            PipeForward => "|>",
             Underscore => "_",
             Comma => ",",
             Period => ".",
@@ -750,6 +869,7 @@ impl Token {
             ExclusiveRange => "..",
             InclusiveRange => "..=",
             MapStart => "#{",
+            SetStart => "#[",
             Equals => "=",
             True => "true",
             False => "false",
@@ -782,6 +902,7 @@ impl Token {
             Throw => "throw",
             Try => "try",
             Catch => "catch",
+            Finally => "finally",
             PlusAssign => "+=",
             MinusAssign => "-=",
             MultiplyAssign => "*=",
@@ -809,6 +930,8 @@ impl Token {
             #[cfg(not(feature = "no_module"))]
             Export => "export",
             #[cfg(not(feature = "no_module"))]
+            Module => "module",
+            #[cfg(not(feature = "no_module"))]
             As => "as",
 
             _ => panic!("token is not a literal symbol"),
@@ -838,6 +961,37 @@ impl Token {
         )
     }
 
+    /// Is this token an operator that can be overloaded by a script-defined function?
+    #[inline]
+    #[must_use]
+    pub const fn is_overloadable_operator(&self) -> bool {
+        #[allow(clippy::enum_glob_use)]
+        use Token::*;
+
+        matches!(
+            self,
+            Plus | UnaryPlus
+                | Minus
+                | UnaryMinus
+                | Multiply
+                | Divide
+                | Modulo
+                | PowerOf
+                | LeftShift
+                | RightShift
+                | Ampersand
+                | Pipe
+                | XOr
+                | Bang
+                | EqualsTo
+                | NotEqualsTo
+                | LessThan
+                | LessThanEqualsTo
+                | GreaterThan
+                | GreaterThanEqualsTo
+        )
+    }
+
     /// Get the corresponding operator of the token if it is an op-assignment operator.
     #[must_use]
     pub const fn get_base_op_from_assignment(&self) -> Option<Self> {
@@ -924,6 +1078,7 @@ impl Token {
             _ => hash_val += KEYWORD_ASSOC_VALUES[(utf8[1] as usize) + 1] as usize,
         }
         hash_val += KEYWORD_ASSOC_VALUES[utf8[0] as usize] as usize;
+        hash_val += KEYWORD_ASSOC_VALUES[utf8[len - 1] as usize] as usize;
 
         if !(MIN_KEYWORD_HASH_VALUE..=MAX_KEYWORD_HASH_VALUE).contains(&hash_val) {
             return None;
@@ -956,11 +1111,14 @@ impl Token {
             DoubleQuestion   | // ?? - is unary
             ExclusiveRange   | // .. - is unary
             InclusiveRange   | // ..= - is unary
+            Ellipsis         | // ... - is unary
+            PipeForward      | // |> - is unary
             LeftBrace        | // { -expr } - is unary
             // RightBrace    | // { expr } - expr not unary & is closing
             LeftParen        | // ( -expr ) - is unary
             // RightParen    | // ( expr ) - expr not unary & is closing
             LeftBracket      | // [ -expr ] - is unary
+            SetStart         | // #[ -expr ] - is unary
             // RightBracket  | // [ expr ] - expr not unary & is closing
             Plus             |
             PlusAssign       |
@@ -1021,6 +1179,8 @@ impl Token {
         use Token::*;
 
         Precedence::new(match self {
+            PipeForward => 20,
+
             Or | XOr | Pipe => 30,
 
             And | Ampersand => 60,
@@ -1071,11 +1231,11 @@ impl Token {
             LeftBrace | RightBrace | LeftParen | RightParen | LeftBracket | RightBracket | Plus
             | UnaryPlus | Minus | UnaryMinus | Multiply | Divide | Modulo | PowerOf | LeftShift
             | RightShift | SemiColon | Colon | DoubleColon | Comma | Period | DoubleQuestion
-            | ExclusiveRange | InclusiveRange | MapStart | Equals | LessThan | GreaterThan
-            | LessThanEqualsTo | GreaterThanEqualsTo | EqualsTo | NotEqualsTo | Bang | Pipe
-            | Or | XOr | Ampersand | And | PlusAssign | MinusAssign | MultiplyAssign
-            | DivideAssign | LeftShiftAssign | RightShiftAssign | AndAssign | OrAssign
-            | XOrAssign | ModuloAssign | PowerOfAssign => true,
+            | ExclusiveRange | InclusiveRange | MapStart | SetStart | Equals | LessThan
+            | GreaterThan | LessThanEqualsTo | GreaterThanEqualsTo | EqualsTo | NotEqualsTo
+            | Bang | Pipe | Or | XOr | Ampersand | And | PlusAssign | MinusAssign
+            | MultiplyAssign | DivideAssign | LeftShiftAssign | RightShiftAssign | AndAssign
+            | OrAssign | XOrAssign | ModuloAssign | PowerOfAssign | PipeForward => true,
 
             #[cfg(not(feature = "no_object"))]
             Elvis => true,
@@ -1099,10 +1259,10 @@ impl Token {
             Fn | Private => true,
 
             #[cfg(not(feature = "no_module"))]
-            Import | Export | As => true,
+            Import | Export | Module | As => true,
 
             True | False | Let | Const | If | Else | Do | While | Until | Loop | For | In
-            | Continue | Break | Return | Throw | Try | Catch => true,
+            | Continue | Break | Return | Throw | Try | Catch | Finally => true,
 
             _ => false,
         }
@@ -1595,6 +1755,7 @@ fn get_next_token_inner(
                 let mut valid: fn(char) -> bool = is_numeric_digit;
                 let mut _has_period = false;
                 let mut _has_e = false;
+                let mut _is_bigint = false;
 
                 result.push(c);
 
@@ -1694,6 +1855,22 @@ fn get_next_token_inner(
                             });
                         }
 
+                        // n suffix - big integer
+                        #[cfg(feature = "bigint")]
+                        'n' if !_has_period && !_has_e => {
+                            stream.get_next().unwrap();
+
+                            // Not a big integer if followed by more identifier characters (e.g. `ns`)
+                            if stream.peek_next().map_or(false, is_id_continue) {
+                                stream.unget('n');
+                                break;
+                            }
+
+                            pos.advance();
+                            _is_bigint = true;
+                            break;
+                        }
+
                         _ => break,
                     }
                 }
@@ -1708,6 +1885,30 @@ fn get_next_token_inner(
                 }
 
                 // Parse number
+                #[cfg(feature = "bigint")]
+                if _is_bigint {
+                    let (negative, digits) = match result.strip_prefix('-') {
+                        Some(digits) => (true, digits),
+                        None => (false, &*result),
+                    };
+                    let digits = if radix_base.is_some() {
+                        &digits[2..]
+                    } else {
+                        digits
+                    };
+
+                    let token = num_bigint::BigInt::parse_bytes(
+                        digits.as_bytes(),
+                        radix_base.unwrap_or(10),
+                    )
+                    .map_or_else(
+                        || Token::LexError(LERR::MalformedNumber(result.to_string()).into()),
+                        |v| Token::BigIntConstant(if negative { -v } else { v }.into()),
+                    );
+
+                    return (token, num_pos);
+                }
+
                 let token = if let Some(radix) = radix_base {
                     let result = &result[2..];
 
@@ -1795,6 +1996,11 @@ fn get_next_token_inner(
                 );
             }
 
+            // 'label or 'c' - loop label or character literal
+            ('\'', ch) if is_id_first_alphabetic(ch) || ch == '_' => {
+                return parse_label_or_char_token(stream, state, pos, start_pos);
+            }
+
             // ' - character literal
             ('\'', '\'') => {
                 return (
@@ -1850,6 +2056,12 @@ fn get_next_token_inner(
                 stream.eat_next_and_advance(pos);
                 return (Token::MapStart, start_pos);
             }
+            // Set literal
+            #[cfg(not(feature = "no_index"))]
+            ('#', '[') => {
+                stream.eat_next_and_advance(pos);
+                return (Token::SetStart, start_pos);
+            }
             // Shebang
             ('#', '!') => return (Token::Reserved(Box::new("#!".into())), start_pos),
 
@@ -1886,7 +2098,7 @@ fn get_next_token_inner(
             }
             ('-', '>') => {
                 stream.eat_next_and_advance(pos);
-                return (Token::Reserved(Box::new("->".into())), start_pos);
+                return (Token::Arrow, start_pos);
             }
             ('-', '-') => {
                 stream.eat_next_and_advance(pos);
@@ -2020,7 +2232,7 @@ fn get_next_token_inner(
                     match stream.peek_next() {
                         Some('.') => {
                             stream.eat_next_and_advance(pos);
-                            Token::Reserved(Box::new("...".into()))
+                            Token::Ellipsis
                         }
                         Some('=') => {
                             stream.eat_next_and_advance(pos);
@@ -2163,7 +2375,7 @@ fn get_next_token_inner(
             }
             ('|', '>') => {
                 stream.eat_next_and_advance(pos);
-                return (Token::Reserved(Box::new("|>".into())), start_pos);
+                return (Token::PipeForward, start_pos);
             }
             ('|', ..) => return (Token::Pipe, start_pos),
 
@@ -2292,6 +2504,62 @@ fn parse_identifier_token(
     (Token::Identifier(identifier.into()), start_pos)
 }
 
+/// Get the next token, parsing it as either a loop label (`'label`) or a character literal.
+fn parse_label_or_char_token(
+    stream: &mut (impl InputStream + ?Sized),
+    state: &mut TokenizeState,
+    pos: &mut Position,
+    start_pos: Position,
+) -> (Token, Position) {
+    let mut label = SmartString::new_const();
+    if let Some(ref mut last) = state.last_token {
+        last.clear();
+        last.push('\'');
+    }
+
+    while let Some(next_char) = stream.peek_next() {
+        match next_char {
+            x if is_id_continue(x) => {
+                stream.eat_next_and_advance(pos);
+                label.push(x);
+                if let Some(ref mut last) = state.last_token {
+                    last.push(x);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    // 'c' - character literal
+    if stream.peek_next() == Some('\'') {
+        stream.eat_next_and_advance(pos);
+        if let Some(ref mut last) = state.last_token {
+            last.push('\'');
+        }
+
+        let mut chars = label.chars();
+        let first = chars.next().unwrap();
+
+        return if chars.next().is_some() {
+            (
+                Token::LexError(LERR::MalformedChar(label.to_string()).into()),
+                start_pos,
+            )
+        } else {
+            (Token::CharConstant(first), start_pos)
+        };
+    }
+
+    if !is_valid_identifier(&label) {
+        return (
+            Token::LexError(LERR::MalformedIdentifier(format!("'{label}")).into()),
+            start_pos,
+        );
+    }
+
+    (Token::Label(label.into()), start_pos)
+}
+
 /// _(internals)_ Is a text string a valid identifier?
 /// Exported under the `internals` feature only.
 #[must_use]
@@ -2480,9 +2748,12 @@ impl<'a> Iterator for TokenIterator<'a> {
     type Item = (Token, Position);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (within_interpolated, compress_script) = {
+        let (within_interpolated, within_format_spec, compress_script) = {
             let control = &mut *self.state.tokenizer_control.borrow_mut();
 
+            let within_format_spec = control.is_within_format_spec;
+            control.is_within_format_spec = false;
+
             if control.is_within_text {
                 // Switch to text mode terminated by back-tick
                 self.state.is_within_text_terminated_by = Some('`');
@@ -2492,10 +2763,18 @@ impl<'a> Iterator for TokenIterator<'a> {
 
             (
                 self.state.is_within_text_terminated_by.is_some(),
+                within_format_spec,
                 control.compressed.is_some(),
             )
         };
 
+        if within_format_spec {
+            return Some(self.scan_format_spec(compress_script));
+        }
+
+        #[cfg(feature = "regex")]
+        let can_be_unary = !self.state.next_token_cannot_be_unary;
+
         let (token, pos) = match get_next_token(&mut self.stream, &mut self.state, &mut self.pos) {
             // {EOF}
             r @ (Token::EOF, _) => return Some(r),
@@ -2523,8 +2802,6 @@ impl<'a> Iterator for TokenIterator<'a> {
                 ("!==", false) => Token::LexError(LERR::ImproperSymbol(s.to_string(),
                     "'!==' is not a valid operator. This is not JavaScript! Should it be '!='?".to_string(),
                 ).into()),
-                ("->", false) => Token::LexError(LERR::ImproperSymbol(s.to_string(),
-                    "'->' is not a valid symbol. This is not C or C++!".to_string()).into()),
                 ("<-", false) => Token::LexError(LERR::ImproperSymbol(s.to_string(),
                     "'<-' is not a valid symbol. This is not Go! Should it be '<='?".to_string(),
                 ).into()),
@@ -2577,6 +2854,36 @@ impl<'a> Iterator for TokenIterator<'a> {
             r => r,
         };
 
+        // Numeric literal immediately followed by a literal suffix?
+        let token = match token {
+            Token::IntegerConstant(..) if !self.engine.literal_suffixes.is_empty() => {
+                self.scan_literal_suffix(token)
+            }
+            #[cfg(not(feature = "no_float"))]
+            Token::FloatConstant(..) if !self.engine.literal_suffixes.is_empty() => {
+                self.scan_literal_suffix(token)
+            }
+            #[cfg(feature = "decimal")]
+            Token::DecimalConstant(..) if !self.engine.literal_suffixes.is_empty() => {
+                self.scan_literal_suffix(token)
+            }
+            token => token,
+        };
+
+        // Regular expression literal, e.g. `r/[a-z]+/`?
+        #[cfg(feature = "regex")]
+        let token = match token {
+            Token::Identifier(ref s)
+                if can_be_unary
+                    && s.as_str() == "r"
+                    && self.engine.allow_regex_literal()
+                    && self.stream.peek_next() == Some('/') =>
+            {
+                self.scan_regex_literal()
+            }
+            token => token,
+        };
+
         // Run the mapper, if any
         let token = match self.token_mapper {
             Some(func) => func(token, pos, &self.state),
@@ -2630,6 +2937,131 @@ impl<'a> Iterator for TokenIterator<'a> {
 
 impl FusedIterator for TokenIterator<'_> {}
 
+impl TokenIterator<'_> {
+    /// Scan a format specifier within an interpolation stream as raw text, up to and including the
+    /// closing `}`.
+    fn scan_format_spec(&mut self, compress_script: bool) -> (Token, Position) {
+        let start = self.pos;
+        let mut spec = SmartString::new_const();
+
+        loop {
+            match self.stream.get_next() {
+                Some('}') => {
+                    self.pos.advance();
+                    break;
+                }
+                Some(ch) if ch != '\n' && ch != '`' => {
+                    self.pos.advance();
+                    spec.push(ch);
+                }
+                _ => return (Token::LexError(LERR::UnterminatedString.into()), start),
+            }
+        }
+
+        if compress_script {
+            let control = &mut *self.state.tokenizer_control.borrow_mut();
+
+            if let Some(ref mut compressed) = control.compressed {
+                *compressed += &spec;
+                *compressed += "}";
+            }
+        }
+
+        (Token::StringConstant(spec.into()), start)
+    }
+
+    /// Scan a literal suffix immediately following a numeric constant, if any.
+    ///
+    /// An unregistered suffix is a malformed number.
+    fn scan_literal_suffix(&mut self, token: Token) -> Token {
+        if !self
+            .stream
+            .peek_next()
+            .map_or(false, is_id_first_alphabetic)
+        {
+            return token;
+        }
+
+        let mut suffix = Identifier::new_const();
+
+        while let Some(ch) = self.stream.peek_next() {
+            if !is_id_continue(ch) {
+                break;
+            }
+            suffix.push(ch);
+            self.stream.eat_next_and_advance(&mut self.pos);
+        }
+
+        if let Some(ref mut last) = self.state.last_token {
+            last.push_str(&suffix);
+        }
+
+        if self.engine.literal_suffixes.contains_key(&suffix) {
+            Token::SuffixedConstant((token, suffix).into())
+        } else {
+            Token::LexError(LERR::MalformedNumber(format!("{token}{suffix}")).into())
+        }
+    }
+}
+
+impl TokenIterator<'_> {
+    /// Scan the body of a regular expression literal following `r`, up to the closing `/`.
+    ///
+    /// Within the literal, `\/` stands for a `/` character.
+    #[cfg(feature = "regex")]
+    fn scan_regex_literal(&mut self) -> Token {
+        // Skip the opening '/'
+        self.stream.eat_next_and_advance(&mut self.pos);
+
+        let mut pattern = SmartString::new_const();
+        let mut last = SmartString::new_const();
+        last.push('/');
+
+        loop {
+            match self.stream.get_next() {
+                Some('/') => {
+                    self.pos.advance();
+                    last.push('/');
+                    break;
+                }
+                Some('\\') if self.stream.peek_next() == Some('/') => {
+                    self.pos.advance();
+                    self.stream.eat_next_and_advance(&mut self.pos);
+                    pattern.push('/');
+                    last.push_str("\\/");
+                }
+                Some(ch) if ch != '\n' => {
+                    self.pos.advance();
+                    pattern.push(ch);
+                    last.push(ch);
+                }
+                _ => {
+                    return Token::LexError(
+                        LERR::ImproperSymbol(
+                            format!("r{last}"),
+                            "Open regular expression literal is not terminated".to_string(),
+                        )
+                        .into(),
+                    )
+                }
+            }
+
+            #[cfg(not(feature = "unchecked"))]
+            if let Some(max) = self.state.max_string_len {
+                if pattern.len() > max.get() {
+                    return Token::LexError(LERR::StringTooLong(max.get()).into());
+                }
+            }
+        }
+
+        if let Some(ref mut last_token) = self.state.last_token {
+            last_token.push_str(&last);
+        }
+
+        Token::RegexConstant(pattern.into())
+    }
+}
+
 impl Engine {
     /// _(internals)_ Tokenize an input text stream.
     /// Exported under the `internals` feature only.
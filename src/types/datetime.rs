@@ -0,0 +1,519 @@
+//! The [`DateTime`] type for holding a calendar date/time with a fixed UTC offset.
+#![cfg(not(feature = "no_time"))]
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Number of nanoseconds in a second.
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+
+/// Maximum absolute calendar year supported.
+const MAX_YEAR: i64 = 999_999_999;
+
+/// A point in time ([`SystemTime`]) together with a fixed offset from UTC.
+///
+/// Calendar components (year, month, day etc.) are calculated in the proleptic Gregorian
+/// calendar at the fixed offset, so no OS time zone database is needed.
+///
+/// Two [`DateTime`]'s are equal if they refer to the same point in time, regardless of their
+/// offsets.
+#[derive(Debug, Clone, Copy)]
+pub struct DateTime {
+    /// The point in time.
+    time: SystemTime,
+    /// Offset from UTC in seconds (positive east of UTC).
+    offset: i32,
+}
+
+impl DateTime {
+    /// Maximum absolute offset from UTC, in seconds (exclusive).
+    pub const MAX_OFFSET: i32 = SECONDS_PER_DAY as i32;
+
+    /// Create a new [`DateTime`] in UTC from a [`SystemTime`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_system_time(time: SystemTime) -> Self {
+        Self { time, offset: 0 }
+    }
+    /// Create a new [`DateTime`] in UTC containing the current system time.
+    ///
+    /// Not available under `wasm32-unknown-unknown`.
+    #[cfg(any(not(target_family = "wasm"), not(target_os = "unknown")))]
+    #[inline(always)]
+    #[must_use]
+    pub fn now() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+    /// Create a new [`DateTime`] in UTC from the number of seconds and nanoseconds since the
+    /// UNIX epoch (1970-01-01T00:00:00Z).
+    ///
+    /// Returns [`None`] if `nanos` is not less than one second or the time is out of range.
+    #[must_use]
+    pub fn from_unix(secs: i64, nanos: u32) -> Option<Self> {
+        if nanos >= NANOS_PER_SECOND {
+            return None;
+        }
+
+        let time = if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(secs.unsigned_abs(), nanos))?
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+                .checked_add(Duration::from_nanos(nanos.into()))?
+        };
+
+        Some(Self::from_system_time(time))
+    }
+    /// Create a new [`DateTime`] from calendar components at the specified offset from UTC
+    /// (in seconds).
+    ///
+    /// Returns [`None`] if any component is out of range.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_parts(
+        year: i64,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanos: u32,
+        offset: i32,
+    ) -> Option<Self> {
+        if year.abs() > MAX_YEAR
+            || !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 59
+            || offset.abs() >= Self::MAX_OFFSET
+        {
+            return None;
+        }
+
+        let days = days_from_civil(year, month, day);
+        let secs = days
+            .checked_mul(SECONDS_PER_DAY)?
+            .checked_add(i64::from(hour * 3600 + minute * 60 + second))?
+            .checked_sub(offset.into())?;
+
+        Self::from_unix(secs, nanos)?.with_offset(offset)
+    }
+    /// Return a copy of this [`DateTime`] at a different offset from UTC (in seconds).
+    ///
+    /// The point in time is not changed.
+    ///
+    /// Returns [`None`] if the offset is not within ±24 hours.
+    #[inline]
+    #[must_use]
+    pub fn with_offset(self, offset: i32) -> Option<Self> {
+        if offset.abs() < Self::MAX_OFFSET {
+            Some(Self { offset, ..self })
+        } else {
+            None
+        }
+    }
+    /// Parse a UTC offset in the form `Z`, `±HH`, `±HH:MM` or `±HHMM`, returning it in seconds.
+    #[must_use]
+    pub fn parse_offset(s: &str) -> Option<i32> {
+        let mut p = Parser(s.trim().as_bytes());
+        let offset = p.offset()?;
+
+        if p.0.is_empty() {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+    /// Add a [`Duration`] to this [`DateTime`], keeping the offset.
+    ///
+    /// Returns [`None`] if the result is out of range.
+    #[inline]
+    #[must_use]
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.time
+            .checked_add(duration)
+            .map(|time| Self { time, ..*self })
+    }
+    /// Subtract a [`Duration`] from this [`DateTime`], keeping the offset.
+    ///
+    /// Returns [`None`] if the result is out of range.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.time
+            .checked_sub(duration)
+            .map(|time| Self { time, ..*self })
+    }
+    /// Get the [`Duration`] elapsed from an earlier [`DateTime`] to this one.
+    ///
+    /// Returns [`None`] if `earlier` is later than this [`DateTime`].
+    #[inline]
+    #[must_use]
+    pub fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        self.time.duration_since(earlier.time).ok()
+    }
+    /// Get the point in time as a [`SystemTime`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn system_time(&self) -> SystemTime {
+        self.time
+    }
+    /// Get the offset from UTC in seconds (positive east of UTC).
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> i32 {
+        self.offset
+    }
+    /// Get the number of whole seconds since the UNIX epoch (rounded towards negative infinity)
+    /// together with the remaining nanoseconds.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn unix(&self) -> (i64, u32) {
+        match self.time.duration_since(UNIX_EPOCH) {
+            Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
+            Err(err) => {
+                let d = err.duration();
+                let secs = -(d.as_secs() as i64);
+
+                match d.subsec_nanos() {
+                    0 => (secs, 0),
+                    n => (secs - 1, NANOS_PER_SECOND - n),
+                }
+            }
+        }
+    }
+    /// Get the number of days since the UNIX epoch and the number of seconds into the day,
+    /// at the offset from UTC.
+    #[inline]
+    #[must_use]
+    fn local_days_and_seconds(&self) -> (i64, u32) {
+        let secs = self.unix().0 + i64::from(self.offset);
+        let days = secs.div_euclid(SECONDS_PER_DAY);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let seconds = secs.rem_euclid(SECONDS_PER_DAY) as u32;
+        (days, seconds)
+    }
+    /// Get the calendar date as `(year, month, day)`.
+    #[inline]
+    #[must_use]
+    pub fn date(&self) -> (i64, u32, u32) {
+        civil_from_days(self.local_days_and_seconds().0)
+    }
+    /// Get the time of day as `(hour, minute, second, nanosecond)`.
+    #[inline]
+    #[must_use]
+    pub fn time_of_day(&self) -> (u32, u32, u32, u32) {
+        let seconds = self.local_days_and_seconds().1;
+        (
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.unix().1,
+        )
+    }
+    /// Get the day of the week, from 1 (Monday) to 7 (Sunday).
+    #[inline]
+    #[must_use]
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 is a Thursday
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let weekday = (self.local_days_and_seconds().0 + 3).rem_euclid(7) as u32;
+        weekday + 1
+    }
+    /// Get the day of the year, from 1 to 366.
+    #[inline]
+    #[must_use]
+    pub fn day_of_year(&self) -> u32 {
+        let days = self.local_days_and_seconds().0;
+        let (year, ..) = civil_from_days(days);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let day = (days - days_from_civil(year, 1, 1)) as u32;
+        day + 1
+    }
+    /// Return a copy of this [`DateTime`] moved by a number of calendar months, keeping the
+    /// time of day and offset.
+    ///
+    /// If the day does not exist in the target month, the last day of that month is used.
+    ///
+    /// Returns [`None`] if the result is out of range.
+    #[must_use]
+    pub fn add_months(&self, months: i64) -> Option<Self> {
+        let (year, month, day) = self.date();
+        let (hour, minute, second, nanos) = self.time_of_day();
+
+        let total = year
+            .checked_mul(12)?
+            .checked_add(i64::from(month - 1))?
+            .checked_add(months)?;
+        let year = total.div_euclid(12);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let month = total.rem_euclid(12) as u32 + 1;
+        let day = day.min(days_in_month(year, month));
+
+        Self::from_parts(year, month, day, hour, minute, second, nanos, self.offset)
+    }
+}
+
+impl PartialEq for DateTime {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.time == other.time
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time.cmp(&other.time)
+    }
+}
+
+impl Hash for DateTime {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.time.hash(state);
+    }
+}
+
+impl fmt::Display for DateTime {
+    /// Format the [`DateTime`] in ISO-8601 format, e.g. `2024-02-29T13:45:00.5+05:30`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.date();
+        let (hour, minute, second, nanos) = self.time_of_day();
+
+        match year {
+            0..=9999 => write!(f, "{year:04}")?,
+            _ if year < 0 => write!(f, "-{:04}", year.unsigned_abs())?,
+            _ => write!(f, "+{year}")?,
+        }
+        write!(f, "-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}")?;
+
+        if nanos > 0 {
+            let fraction = format!("{nanos:09}");
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        if self.offset == 0 {
+            return f.write_str("Z");
+        }
+
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let offset = self.offset.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", offset / 3600, offset / 60 % 60)?;
+
+        match offset % 60 {
+            0 => Ok(()),
+            seconds => write!(f, ":{seconds:02}"),
+        }
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = String;
+
+    /// Parse a date/time in ISO-8601 format.
+    ///
+    /// Accepted formats are `YYYY-MM-DD`, optionally followed by `T` (or a space) and
+    /// `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff` (up to nine fractional digits), optionally
+    /// followed by `Z` or an offset in the form `±HH`, `±HH:MM` or `±HHMM`.
+    ///
+    /// A missing offset is taken as UTC.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid date/time: {s}");
+
+        let mut p = Parser(s.trim().as_bytes());
+
+        let negative = p.eat(b'-');
+        if !negative {
+            p.eat(b'+');
+        }
+        let year = p.digits(4, 9).ok_or_else(invalid)?;
+        #[allow(clippy::cast_possible_wrap)]
+        let year = if negative {
+            -(year as i64)
+        } else {
+            year as i64
+        };
+        p.expect(b'-').ok_or_else(invalid)?;
+        let month = p.number(2).ok_or_else(invalid)?;
+        p.expect(b'-').ok_or_else(invalid)?;
+        let day = p.number(2).ok_or_else(invalid)?;
+
+        let (mut hour, mut minute, mut second, mut nanos, mut offset) = (0, 0, 0, 0, 0);
+
+        if p.eat(b'T') || p.eat(b't') || p.eat(b' ') {
+            hour = p.number(2).ok_or_else(invalid)?;
+            p.expect(b':').ok_or_else(invalid)?;
+            minute = p.number(2).ok_or_else(invalid)?;
+
+            if p.eat(b':') {
+                second = p.number(2).ok_or_else(invalid)?;
+
+                if p.eat(b'.') || p.eat(b',') {
+                    let start = p.0.len();
+                    let fraction = p.digits(1, 9).ok_or_else(invalid)?;
+                    let len = start - p.0.len();
+                    #[allow(clippy::cast_possible_truncation)]
+                    let fraction = fraction as u32 * 10_u32.pow(9 - len as u32);
+                    nanos = fraction;
+                }
+            }
+
+            if !p.0.is_empty() {
+                offset = p.offset().ok_or_else(invalid)?;
+            }
+        }
+
+        if !p.0.is_empty() {
+            return Err(invalid());
+        }
+
+        Self::from_parts(year, month, day, hour, minute, second, nanos, offset).ok_or_else(invalid)
+    }
+}
+
+/// Simple cursor over the bytes of a date/time string.
+struct Parser<'a>(&'a [u8]);
+
+impl Parser<'_> {
+    /// Consume the byte `b` if it is next.
+    #[inline]
+    fn eat(&mut self, b: u8) -> bool {
+        if self.0.first() == Some(&b) {
+            self.0 = &self.0[1..];
+            true
+        } else {
+            false
+        }
+    }
+    /// Consume the byte `b`, which must be next.
+    #[inline]
+    fn expect(&mut self, b: u8) -> Option<()> {
+        if self.eat(b) {
+            Some(())
+        } else {
+            None
+        }
+    }
+    /// Consume between `min` and `max` decimal digits.
+    fn digits(&mut self, min: usize, max: usize) -> Option<u64> {
+        let len = self
+            .0
+            .iter()
+            .take(max)
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+
+        if len < min {
+            return None;
+        }
+
+        let value = self.0[..len]
+            .iter()
+            .fold(0, |n, &c| n * 10 + u64::from(c - b'0'));
+        self.0 = &self.0[len..];
+        Some(value)
+    }
+    /// Consume a UTC offset in the form `Z`, `±HH`, `±HH:MM` or `±HHMM`, returning it in seconds.
+    fn offset(&mut self) -> Option<i32> {
+        if self.eat(b'Z') || self.eat(b'z') {
+            return Some(0);
+        }
+
+        let sign = if self.eat(b'+') {
+            1
+        } else {
+            self.expect(b'-')?;
+            -1
+        };
+
+        let hours = self.number(2)?;
+        let minutes = if self.eat(b':') || !self.0.is_empty() {
+            self.number(2)?
+        } else {
+            0
+        };
+
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        #[allow(clippy::cast_possible_wrap)]
+        Some(sign * (hours * 3600 + minutes * 60) as i32)
+    }
+    /// Consume a number with exactly `len` decimal digits.
+    #[inline]
+    fn number(&mut self, len: usize) -> Option<u32> {
+        #[allow(clippy::cast_possible_truncation)]
+        self.digits(len, len).map(|n| n as u32)
+    }
+}
+
+/// Is the year a leap year in the proleptic Gregorian calendar?
+#[inline]
+#[must_use]
+const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days in a month of a year.
+#[inline]
+#[must_use]
+const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+#[must_use]
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic Gregorian calendar, as `(year, month, day)`, of a number of days
+/// since 1970-01-01.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
@@ -466,6 +466,32 @@ impl Hash for Dynamic {
                     return range.hash(state);
                 }
 
+                #[cfg(not(feature = "no_index"))]
+                if let Some(set) = _value_any.downcast_ref::<crate::Set>() {
+                    return set.hash(state);
+                } else if let Some(tuple) = _value_any.downcast_ref::<crate::Tuple>() {
+                    return tuple.hash(state);
+                }
+
+                #[cfg(feature = "bigint")]
+                if let Some(value) = _value_any.downcast_ref::<num_bigint::BigInt>() {
+                    return value.hash(state);
+                }
+
+                #[cfg(feature = "rational")]
+                if let Some(value) = _value_any.downcast_ref::<crate::Rational>() {
+                    return value.hash(state);
+                }
+
+                #[cfg(not(feature = "no_time"))]
+                if let Some(value) = _value_any.downcast_ref::<crate::DateTime>() {
+                    return value.hash(state);
+                }
+                #[cfg(not(feature = "no_time"))]
+                if let Some(value) = _value_any.downcast_ref::<std::time::Duration>() {
+                    return value.hash(state);
+                }
+
                 unimplemented!("Custom type {} cannot be hashed", self.type_name())
             }
 
@@ -555,6 +581,50 @@ impl fmt::Display for Dynamic {
                     };
                 }
 
+                #[cfg(not(feature = "no_index"))]
+                if let Some(set) = _value_any.downcast_ref::<crate::Set>() {
+                    return fmt::Debug::fmt(set, f);
+                } else if let Some(tuple) = _value_any.downcast_ref::<crate::Tuple>() {
+                    return fmt::Debug::fmt(tuple, f);
+                } else if let Some(iter) = _value_any.downcast_ref::<crate::LazyIterator>() {
+                    return fmt::Debug::fmt(iter, f);
+                }
+
+                #[cfg(feature = "bigint")]
+                if let Some(value) = _value_any.downcast_ref::<num_bigint::BigInt>() {
+                    return fmt::Display::fmt(value, f);
+                }
+
+                #[cfg(feature = "rational")]
+                if let Some(value) = _value_any.downcast_ref::<crate::Rational>() {
+                    return fmt::Display::fmt(value, f);
+                }
+
+                #[cfg(not(feature = "no_time"))]
+                if let Some(value) = _value_any.downcast_ref::<crate::DateTime>() {
+                    return fmt::Display::fmt(value, f);
+                }
+                #[cfg(not(feature = "no_time"))]
+                if let Some(value) = _value_any.downcast_ref::<std::time::Duration>() {
+                    return fmt::Debug::fmt(value, f);
+                }
+
+                #[cfg(feature = "regex")]
+                if let Some(value) = _value_any.downcast_ref::<crate::Regex>() {
+                    return fmt::Display::fmt(value, f);
+                }
+
+                #[cfg(not(feature = "no_float"))]
+                if let Some(value) = _value_any.downcast_ref::<crate::Vec2>() {
+                    return fmt::Display::fmt(value, f);
+                } else if let Some(value) = _value_any.downcast_ref::<crate::Vec3>() {
+                    return fmt::Display::fmt(value, f);
+                } else if let Some(value) = _value_any.downcast_ref::<crate::Vec4>() {
+                    return fmt::Display::fmt(value, f);
+                } else if let Some(value) = _value_any.downcast_ref::<crate::Mat>() {
+                    return fmt::Display::fmt(value, f);
+                }
+
                 f.write_str((***v).type_name())
             }
 
@@ -723,6 +793,50 @@ impl fmt::Debug for Dynamic {
                     };
                 }
 
+                #[cfg(not(feature = "no_index"))]
+                if let Some(set) = _value_any.downcast_ref::<crate::Set>() {
+                    return fmt::Debug::fmt(set, f);
+                } else if let Some(tuple) = _value_any.downcast_ref::<crate::Tuple>() {
+                    return fmt::Debug::fmt(tuple, f);
+                } else if let Some(iter) = _value_any.downcast_ref::<crate::LazyIterator>() {
+                    return fmt::Debug::fmt(iter, f);
+                }
+
+                #[cfg(feature = "bigint")]
+                if let Some(value) = _value_any.downcast_ref::<num_bigint::BigInt>() {
+                    return write!(f, "{value}n");
+                }
+
+                #[cfg(feature = "rational")]
+                if let Some(value) = _value_any.downcast_ref::<crate::Rational>() {
+                    return fmt::Display::fmt(value, f);
+                }
+
+                #[cfg(not(feature = "no_time"))]
+                if let Some(value) = _value_any.downcast_ref::<crate::DateTime>() {
+                    return fmt::Display::fmt(value, f);
+                }
+                #[cfg(not(feature = "no_time"))]
+                if let Some(value) = _value_any.downcast_ref::<std::time::Duration>() {
+                    return fmt::Debug::fmt(value, f);
+                }
+
+                #[cfg(feature = "regex")]
+                if let Some(value) = _value_any.downcast_ref::<crate::Regex>() {
+                    return write!(f, "r/{}/", value.as_str().replace('/', "\\/"));
+                }
+
+                #[cfg(not(feature = "no_float"))]
+                if let Some(value) = _value_any.downcast_ref::<crate::Vec2>() {
+                    return fmt::Debug::fmt(value, f);
+                } else if let Some(value) = _value_any.downcast_ref::<crate::Vec3>() {
+                    return fmt::Debug::fmt(value, f);
+                } else if let Some(value) = _value_any.downcast_ref::<crate::Vec4>() {
+                    return fmt::Debug::fmt(value, f);
+                } else if let Some(value) = _value_any.downcast_ref::<crate::Mat>() {
+                    return fmt::Debug::fmt(value, f);
+                }
+
                 f.write_str((***v).type_name())
             }
 
@@ -1268,6 +1382,30 @@ impl Dynamic {
                     return true;
                 }
 
+                #[cfg(not(feature = "no_index"))]
+                if _type_id == TypeId::of::<crate::Set>() {
+                    return true;
+                } else if let Some(tuple) = _value_any.downcast_ref::<crate::Tuple>() {
+                    return tuple.iter().all(Self::is_hashable);
+                }
+
+                #[cfg(feature = "bigint")]
+                if _type_id == TypeId::of::<num_bigint::BigInt>() {
+                    return true;
+                }
+
+                #[cfg(feature = "rational")]
+                if _type_id == TypeId::of::<crate::Rational>() {
+                    return true;
+                }
+
+                #[cfg(not(feature = "no_time"))]
+                if _type_id == TypeId::of::<crate::DateTime>()
+                    || _type_id == TypeId::of::<std::time::Duration>()
+                {
+                    return true;
+                }
+
                 false
             }
 
@@ -3057,3 +3195,80 @@ impl From<InclusiveRange> for Dynamic {
         Self::from(value)
     }
 }
+#[cfg(not(feature = "no_index"))]
+impl From<crate::Set> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Set) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_index"))]
+impl From<crate::Tuple> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Tuple) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_index"))]
+impl From<crate::LazyIterator> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::LazyIterator) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_float"))]
+impl From<crate::Vec2> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Vec2) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_float"))]
+impl From<crate::Vec3> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Vec3) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_float"))]
+impl From<crate::Vec4> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Vec4) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_float"))]
+impl From<crate::Mat> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Mat) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(feature = "bigint")]
+impl From<num_bigint::BigInt> for Dynamic {
+    #[inline(always)]
+    fn from(value: num_bigint::BigInt) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(feature = "rational")]
+impl From<crate::Rational> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Rational) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(not(feature = "no_time"))]
+impl From<crate::DateTime> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::DateTime) -> Self {
+        Self::from(value)
+    }
+}
+#[cfg(feature = "regex")]
+impl From<crate::Regex> for Dynamic {
+    #[inline(always)]
+    fn from(value: crate::Regex) -> Self {
+        Self::from(value)
+    }
+}
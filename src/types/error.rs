@@ -1,6 +1,6 @@
 //! Module containing error definitions for the evaluation process.
 
-use crate::{Dynamic, ParseErrorType, Position, INT};
+use crate::{Dynamic, ImmutableString, ParseErrorType, Position, INT};
 #[cfg(feature = "no_std")]
 use core_error::Error;
 #[cfg(not(feature = "no_std"))]
@@ -39,16 +39,25 @@ pub enum EvalAltResult {
     ErrorVariableExists(String, Position),
     /// Forbidden variable name. Wrapped value is the variable name.
     ErrorForbiddenVariable(String, Position),
-    /// Access of an unknown variable. Wrapped value is the variable name.
-    ErrorVariableNotFound(String, Position),
-    /// Access of an unknown object map property. Wrapped value is the property name.
-    ErrorPropertyNotFound(String, Position),
+    /// Access of an unknown variable.
+    /// Wrapped values are the variable name and a list of suggested similar names (if any).
+    ErrorVariableNotFound(String, Vec<String>, Position),
+    /// Access of an unknown object map property.
+    /// Wrapped values are the property name and a list of suggested similar names (if any).
+    ErrorPropertyNotFound(String, Vec<String>, Position),
     /// Access of an invalid index. Wrapped value is the index name.
     ErrorIndexNotFound(Dynamic, Position),
-    /// Call to an unknown function. Wrapped value is the function signature.
-    ErrorFunctionNotFound(String, Position),
+    /// Call to an unknown function.
+    /// Wrapped values are the function signature and a list of suggestions (if any).
+    ///
+    /// Suggestions are signatures of functions with the same name (if only the arguments are
+    /// mismatched), or names of similarly-named functions.
+    ErrorFunctionNotFound(String, Vec<String>, Position),
     /// Usage of an unknown [module][crate::Module]. Wrapped value is the [module][crate::Module] name.
     ErrorModuleNotFound(String, Position),
+    /// An item imported into the unqualified namespace conflicts with an item imported earlier.
+    /// Wrapped value is the name of the item.
+    ErrorImportConflict(String, Position),
 
     /// An error has occurred inside a called function.
     /// Wrapped values are the function name, function source, and the interior error.
@@ -119,7 +128,8 @@ pub enum EvalAltResult {
     /// Breaking out of loops - not an error if within a loop.
     /// The wrapped value, if true, means breaking clean out of the loop (i.e. a `break` statement).
     /// The wrapped value, if false, means breaking the current context (i.e. a `continue` statement).
-    LoopBreak(bool, Dynamic, Position),
+    /// The wrapped label, if any, names the target loop (e.g. `break 'outer`).
+    LoopBreak(bool, Option<ImmutableString>, Dynamic, Position),
     /// Not an error: Value returned from a script via the `return` keyword.
     /// Wrapped value is the result value.
     Return(Dynamic, Position),
@@ -157,11 +167,23 @@ impl fmt::Display for EvalAltResult {
 
             Self::ErrorVariableExists(s, ..) => write!(f, "Variable already defined: {s}")?,
             Self::ErrorForbiddenVariable(s, ..) => write!(f, "Forbidden variable name: {s}")?,
-            Self::ErrorVariableNotFound(s, ..) => write!(f, "Variable not found: {s}")?,
-            Self::ErrorPropertyNotFound(s, ..) => write!(f, "Property not found: {s}")?,
+            Self::ErrorVariableNotFound(s, x, ..) => {
+                write!(f, "Variable not found: {s}")?;
+                write_suggestions(f, x)?
+            }
+            Self::ErrorPropertyNotFound(s, x, ..) => {
+                write!(f, "Property not found: {s}")?;
+                write_suggestions(f, x)?
+            }
             Self::ErrorIndexNotFound(s, ..) => write!(f, "Invalid index: {s}")?,
-            Self::ErrorFunctionNotFound(s, ..) => write!(f, "Function not found: {s}")?,
+            Self::ErrorFunctionNotFound(s, x, ..) => {
+                write!(f, "Function not found: {s}")?;
+                write_suggestions(f, x)?
+            }
             Self::ErrorModuleNotFound(s, ..) => write!(f, "Module not found: {s}")?,
+            Self::ErrorImportConflict(s, ..) => {
+                write!(f, "Imported item conflicts with an existing import: {s}")?
+            }
             Self::ErrorDataRace(s, ..) if s.is_empty() => write!(f, "Data race detected")?,
             Self::ErrorDataRace(s, ..) => write!(f, "Data race detected on variable '{s}'")?,
 
@@ -272,6 +294,21 @@ impl fmt::Display for EvalAltResult {
     }
 }
 
+/// Write a list of suggestions (if any) for an unknown name.
+fn write_suggestions(f: &mut fmt::Formatter<'_>, suggestions: &[String]) -> fmt::Result {
+    match suggestions {
+        [] => Ok(()),
+        [s] => write!(f, " - did you mean '{s}'?"),
+        [s @ .., last] => {
+            f.write_str(" - did you mean ")?;
+            for s in s {
+                write!(f, "'{s}', ")?;
+            }
+            write!(f, "or '{last}'?")
+        }
+    }
+}
+
 impl<T: AsRef<str>> From<T> for EvalAltResult {
     #[cold]
     #[inline(never)]
@@ -326,6 +363,7 @@ impl EvalAltResult {
             | Self::ErrorPropertyNotFound(..)
             | Self::ErrorIndexNotFound(..)
             | Self::ErrorModuleNotFound(..)
+            | Self::ErrorImportConflict(..)
             | Self::ErrorDataRace(..)
             | Self::ErrorNonPureMethodCallOnConstant(..)
             | Self::ErrorAssignmentToConstant(..)
@@ -373,14 +411,19 @@ impl EvalAltResult {
     #[cold]
     #[inline(never)]
     pub(crate) fn dump_fields(&self, map: &mut crate::Map) {
-        map.insert(
-            "error".into(),
-            format!("{self:?}")
-                .split('(')
-                .next()
-                .expect("`ErrorXXX(...)`")
-                .into(),
-        );
+        fn insert_suggestions(map: &mut crate::Map, suggestions: &[String]) {
+            if !suggestions.is_empty() {
+                map.insert(
+                    "suggestions".into(),
+                    #[cfg(not(feature = "no_index"))]
+                    Dynamic::from_array(suggestions.iter().map(Into::into).collect()),
+                    #[cfg(feature = "no_index")]
+                    suggestions.join(", ").into(),
+                );
+            }
+        }
+
+        map.insert("error".into(), self.kind_name().into());
 
         match self {
             Self::LoopBreak(..) | Self::Return(..) | Self::Exit(..) => (),
@@ -396,7 +439,11 @@ impl EvalAltResult {
             | Self::ErrorStackOverflow(..)
             | Self::ErrorRuntime(..) => (),
 
-            Self::ErrorFunctionNotFound(f, ..) | Self::ErrorNonPureMethodCallOnConstant(f, ..) => {
+            Self::ErrorFunctionNotFound(f, x, ..) => {
+                map.insert("function".into(), f.into());
+                insert_suggestions(map, x);
+            }
+            Self::ErrorNonPureMethodCallOnConstant(f, ..) => {
                 map.insert("function".into(), f.into());
             }
             Self::ErrorInFunctionCall(f, s, ..) => {
@@ -413,10 +460,12 @@ impl EvalAltResult {
                 map.insert("length".into(), (*n as INT).into());
                 map.insert("index".into(), (*i as INT).into());
             }
+            Self::ErrorVariableNotFound(v, x, ..) | Self::ErrorPropertyNotFound(v, x, ..) => {
+                map.insert("variable".into(), v.into());
+                insert_suggestions(map, x);
+            }
             Self::ErrorVariableExists(v, ..)
             | Self::ErrorForbiddenVariable(v, ..)
-            | Self::ErrorVariableNotFound(v, ..)
-            | Self::ErrorPropertyNotFound(v, ..)
             | Self::ErrorDataRace(v, ..)
             | Self::ErrorAssignmentToConstant(v, ..) => {
                 map.insert("variable".into(), v.into());
@@ -424,6 +473,9 @@ impl EvalAltResult {
             Self::ErrorIndexNotFound(v, ..) => {
                 map.insert("index".into(), v.clone());
             }
+            Self::ErrorImportConflict(v, ..) => {
+                map.insert("item".into(), v.into());
+            }
             Self::ErrorInModule(m, ..) | Self::ErrorModuleNotFound(m, ..) => {
                 map.insert("module".into(), m.into());
             }
@@ -453,6 +505,17 @@ impl EvalAltResult {
             }
         };
     }
+    /// Get the name of the kind of this error, e.g. `ErrorArithmetic`.
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn kind_name(&self) -> String {
+        format!("{self:?}")
+            .split('(')
+            .next()
+            .expect("`ErrorXXX(...)`")
+            .into()
+    }
     /// Unwrap this error and get the very base error.
     #[cold]
     #[inline(never)]
@@ -489,6 +552,7 @@ impl EvalAltResult {
             | Self::ErrorPropertyNotFound(.., pos)
             | Self::ErrorIndexNotFound(.., pos)
             | Self::ErrorModuleNotFound(.., pos)
+            | Self::ErrorImportConflict(.., pos)
             | Self::ErrorDataRace(.., pos)
             | Self::ErrorNonPureMethodCallOnConstant(.., pos)
             | Self::ErrorAssignmentToConstant(.., pos)
@@ -551,6 +615,7 @@ impl EvalAltResult {
             | Self::ErrorPropertyNotFound(.., pos)
             | Self::ErrorIndexNotFound(.., pos)
             | Self::ErrorModuleNotFound(.., pos)
+            | Self::ErrorImportConflict(.., pos)
             | Self::ErrorDataRace(.., pos)
             | Self::ErrorNonPureMethodCallOnConstant(.., pos)
             | Self::ErrorAssignmentToConstant(.., pos)
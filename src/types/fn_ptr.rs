@@ -243,7 +243,7 @@ impl FnPtr {
         // Linked to scripted function?
         #[cfg(not(feature = "no_function"))]
         match self.fn_def {
-            Some(ref fn_def) if fn_def.params.len() == args.len() => {
+            Some(ref fn_def) if fn_def.accepts_num_args(args.len()) => {
                 let global = &mut context.global_runtime_state().clone();
                 global.level += 1;
 
@@ -428,7 +428,7 @@ impl TryFrom<ImmutableString> for FnPtr {
         {
             Err(ERR::ErrorParsing(PERR::Reserved(value.to_string()), Position::NONE).into())
         } else {
-            Err(ERR::ErrorFunctionNotFound(value.to_string(), Position::NONE).into())
+            Err(ERR::ErrorFunctionNotFound(value.to_string(), Vec::new(), Position::NONE).into())
         }
     }
 }
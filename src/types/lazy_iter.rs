@@ -0,0 +1,381 @@
+//! The [`LazyIterator`] type for lazily iterating over a stream of values.
+#![cfg(not(feature = "no_index"))]
+
+use crate::eval::GlobalRuntimeState;
+use crate::{Dynamic, Engine, FnPtr, NativeCallContext, Position, RhaiResultOf, Tuple, ERR, INT};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{
+    fmt,
+    iter::{from_fn, once},
+};
+
+/// An adapter applied to the values produced by a [`LazyIterator`].
+#[derive(Debug, Clone)]
+enum Adapter {
+    Map(FnPtr),
+    Filter(FnPtr),
+    FlatMap(FnPtr),
+    Take(usize),
+    Skip(usize),
+    Enumerate,
+    Zip(LazyIterator),
+    Chain(LazyIterator),
+}
+
+/// A lazy iterator over the values of an iterable (e.g. an array, a range, a string, an object
+/// map or any type with a registered type iterator), with a chain of adapters (e.g. `map`,
+/// `filter`, `take`) applied to them.
+///
+/// No values are produced until the iterator is consumed, e.g. via `collect` or a `for` loop.
+///
+/// Consuming a [`LazyIterator`] does not exhaust it. Each consumption starts afresh from the
+/// source, so the same iterator can be consumed multiple times.
+#[derive(Clone)]
+pub struct LazyIterator {
+    /// The iterable producing the values.
+    source: Dynamic,
+    /// Adapters applied to the values, in order.
+    adapters: Vec<Adapter>,
+}
+
+impl Default for LazyIterator {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Dynamic::from_array(crate::Array::new()))
+    }
+}
+
+impl fmt::Debug for LazyIterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}.iter()", self.source)?;
+
+        for adapter in &self.adapters {
+            match adapter {
+                Adapter::Map(func) => write!(f, ".map({func:?})")?,
+                Adapter::Filter(func) => write!(f, ".filter({func:?})")?,
+                Adapter::FlatMap(func) => write!(f, ".flat_map({func:?})")?,
+                Adapter::Take(n) => write!(f, ".take({n})")?,
+                Adapter::Skip(n) => write!(f, ".skip({n})")?,
+                Adapter::Enumerate => f.write_str(".enumerate()")?,
+                Adapter::Zip(other) => write!(f, ".zip({other:?})")?,
+                Adapter::Chain(other) => write!(f, ".chain({other:?})")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl LazyIterator {
+    /// Create a new [`LazyIterator`] over the values of an iterable.
+    ///
+    /// The iterable is not checked.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn new(source: Dynamic) -> Self {
+        Self {
+            source,
+            adapters: Vec::new(),
+        }
+    }
+    /// Create a new [`LazyIterator`] over the values of an iterable.
+    ///
+    /// If the value is already a [`LazyIterator`], it is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not iterable.
+    pub(crate) fn from_iterable(ctx: &NativeCallContext, value: Dynamic) -> RhaiResultOf<Self> {
+        let value = value.flatten();
+
+        if value.is::<Self>() {
+            return Ok(value.cast::<Self>());
+        }
+
+        #[cfg(not(feature = "no_object"))]
+        if value.is_map() {
+            return Ok(Self::new(value));
+        }
+
+        match ctx
+            .engine()
+            .get_type_iterator(ctx.global_runtime_state(), value.type_id())
+        {
+            Some(..) => Ok(Self::new(value)),
+            None => Err(not_iterable_err(ctx.engine(), &value)),
+        }
+    }
+    /// Add an adapter to the end of the chain.
+    #[inline]
+    #[must_use]
+    fn with(&self, adapter: Adapter) -> Self {
+        let mut iter = self.clone();
+        iter.adapters.push(adapter);
+        iter
+    }
+    /// Map each value via a function.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn map(&self, func: FnPtr) -> Self {
+        self.with(Adapter::Map(func))
+    }
+    /// Keep only the values for which a function returns `true`.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn filter(&self, func: FnPtr) -> Self {
+        self.with(Adapter::Filter(func))
+    }
+    /// Map each value via a function returning an iterable, then flatten the results.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn flat_map(&self, func: FnPtr) -> Self {
+        self.with(Adapter::FlatMap(func))
+    }
+    /// Keep only the first `n` values.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn take(&self, n: usize) -> Self {
+        self.with(Adapter::Take(n))
+    }
+    /// Skip the first `n` values.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn skip(&self, n: usize) -> Self {
+        self.with(Adapter::Skip(n))
+    }
+    /// Pair each value with its zero-based index, as `(index, value)`.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn enumerate(&self) -> Self {
+        self.with(Adapter::Enumerate)
+    }
+    /// Pair each value with a value of another iterator, as `(value, other)`, stopping when
+    /// either runs out.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn zip(&self, other: Self) -> Self {
+        self.with(Adapter::Zip(other))
+    }
+    /// Follow the values with those of another iterator.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn chain(&self, other: Self) -> Self {
+        self.with(Adapter::Chain(other))
+    }
+    /// Consume the [`LazyIterator`], calling a function on each value in turn.
+    ///
+    /// Iteration stops when the function returns `false`.
+    pub(crate) fn for_each(
+        &self,
+        ctx: &NativeCallContext,
+        mut func: impl FnMut(Dynamic) -> RhaiResultOf<bool>,
+    ) -> RhaiResultOf<()> {
+        let mut cursor = Cursor::start(self, ctx)?;
+
+        while let Some(value) = cursor.next(ctx)? {
+            if !func(value)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+    /// Consume the [`LazyIterator`] in a `for` loop.
+    ///
+    /// Functions in adapters are called with a copy of the [`GlobalRuntimeState`] because the
+    /// original is needed by the body of the loop.
+    pub(crate) fn into_loop_iter<'a>(
+        self,
+        engine: &'a Engine,
+        global: &GlobalRuntimeState,
+    ) -> Box<dyn Iterator<Item = RhaiResultOf<Dynamic>> + 'a> {
+        let global = global.clone();
+        let ctx = NativeCallContext::from((engine, "iter", None, &global, Position::NONE));
+
+        let mut cursor = match Cursor::start(&self, &ctx) {
+            Ok(cursor) => cursor,
+            Err(err) => return Box::new(once(Err(err))),
+        };
+
+        Box::new(from_fn(move || {
+            let ctx = NativeCallContext::from((engine, "iter", None, &global, Position::NONE));
+            cursor.next(&ctx).transpose()
+        }))
+    }
+}
+
+/// Make an error for a value that is not iterable.
+#[cold]
+#[inline(never)]
+fn not_iterable_err(engine: &Engine, value: &Dynamic) -> crate::RhaiError {
+    ERR::ErrorMismatchDataType(
+        "iterable".to_string(),
+        engine.map_type_name(value.type_name()).to_string(),
+        Position::NONE,
+    )
+    .into()
+}
+
+/// The state of an iteration over a [`LazyIterator`].
+enum Cursor {
+    Source(Box<dyn Iterator<Item = RhaiResultOf<Dynamic>>>),
+    Map(Box<Self>, FnPtr),
+    Filter(Box<Self>, FnPtr),
+    FlatMap(Box<Self>, FnPtr, Option<Box<Self>>),
+    Take(Box<Self>, usize),
+    Skip(Box<Self>, usize),
+    Enumerate(Box<Self>, INT),
+    Zip(Box<Self>, Box<Self>),
+    Chain(Option<Box<Self>>, Box<Self>),
+}
+
+impl Cursor {
+    /// Start iterating over a [`LazyIterator`].
+    fn start(iter: &LazyIterator, ctx: &NativeCallContext) -> RhaiResultOf<Self> {
+        let mut cursor = Self::Source(source_values(ctx, iter.source.clone())?);
+
+        for adapter in &iter.adapters {
+            let inner = Box::new(cursor);
+
+            cursor = match adapter {
+                Adapter::Map(func) => Self::Map(inner, func.clone()),
+                Adapter::Filter(func) => Self::Filter(inner, func.clone()),
+                Adapter::FlatMap(func) => Self::FlatMap(inner, func.clone(), None),
+                Adapter::Take(n) => Self::Take(inner, *n),
+                Adapter::Skip(n) => Self::Skip(inner, *n),
+                Adapter::Enumerate => Self::Enumerate(inner, 0),
+                Adapter::Zip(other) => Self::Zip(inner, Self::start(other, ctx)?.into()),
+                Adapter::Chain(other) => Self::Chain(Some(inner), Self::start(other, ctx)?.into()),
+            };
+        }
+
+        Ok(cursor)
+    }
+    /// Start iterating over the values of an iterable.
+    fn from_value(ctx: &NativeCallContext, value: Dynamic) -> RhaiResultOf<Self> {
+        let value = value.flatten();
+
+        if value.is::<LazyIterator>() {
+            Self::start(&value.cast::<LazyIterator>(), ctx)
+        } else {
+            source_values(ctx, value).map(Self::Source)
+        }
+    }
+    /// Get the next value, if any.
+    fn next(&mut self, ctx: &NativeCallContext) -> RhaiResultOf<Option<Dynamic>> {
+        match self {
+            Self::Source(values) => values.next().transpose(),
+            Self::Map(cursor, func) => match cursor.next(ctx)? {
+                Some(mut value) => func
+                    .call_raw_with_extra_args("map", ctx, Some(&mut value), [], [], Some(0))
+                    .map(Some),
+                None => Ok(None),
+            },
+            Self::Filter(cursor, func) => loop {
+                let Some(mut value) = cursor.next(ctx)? else {
+                    return Ok(None);
+                };
+
+                if func
+                    .call_raw_with_extra_args("filter", ctx, Some(&mut value), [], [], Some(0))?
+                    .as_bool()
+                    .unwrap_or(false)
+                {
+                    return Ok(Some(value));
+                }
+            },
+            Self::FlatMap(cursor, func, inner) => loop {
+                if let Some(ref mut inner) = inner {
+                    if let Some(value) = inner.next(ctx)? {
+                        return Ok(Some(value));
+                    }
+                }
+
+                let Some(mut value) = cursor.next(ctx)? else {
+                    return Ok(None);
+                };
+
+                let values = func.call_raw_with_extra_args(
+                    "flat_map",
+                    ctx,
+                    Some(&mut value),
+                    [],
+                    [],
+                    Some(0),
+                )?;
+
+                *inner = Some(Self::from_value(ctx, values)?.into());
+            },
+            Self::Take(cursor, n) => {
+                if *n == 0 {
+                    return Ok(None);
+                }
+                *n -= 1;
+                cursor.next(ctx)
+            }
+            Self::Skip(cursor, n) => {
+                while *n > 0 {
+                    *n -= 1;
+
+                    if cursor.next(ctx)?.is_none() {
+                        return Ok(None);
+                    }
+                }
+                cursor.next(ctx)
+            }
+            Self::Enumerate(cursor, index) => match cursor.next(ctx)? {
+                Some(value) => {
+                    let pair = Tuple::from(vec![Dynamic::from_int(*index), value]);
+                    *index += 1;
+                    Ok(Some(pair.into()))
+                }
+                None => Ok(None),
+            },
+            Self::Zip(first, second) => {
+                let Some(value) = first.next(ctx)? else {
+                    return Ok(None);
+                };
+                let Some(other) = second.next(ctx)? else {
+                    return Ok(None);
+                };
+                Ok(Some(Tuple::from(vec![value, other]).into()))
+            }
+            Self::Chain(first, second) => {
+                if let Some(ref mut cursor) = first {
+                    if let Some(value) = cursor.next(ctx)? {
+                        return Ok(Some(value));
+                    }
+                    *first = None;
+                }
+                second.next(ctx)
+            }
+        }
+    }
+}
+
+/// Get the values of an iterable via its type iterator.
+///
+/// Object maps produce `(name, value)` tuples.
+fn source_values(
+    ctx: &NativeCallContext,
+    value: Dynamic,
+) -> RhaiResultOf<Box<dyn Iterator<Item = RhaiResultOf<Dynamic>>>> {
+    #[cfg(not(feature = "no_object"))]
+    if value.is_map() {
+        let map = value.cast::<crate::Map>();
+
+        return Ok(Box::new(map.into_iter().map(|(name, value)| {
+            let name: crate::ImmutableString = name.into();
+            Ok(Tuple::from(vec![name.into(), value]).into())
+        })));
+    }
+
+    match ctx
+        .engine()
+        .get_type_iterator(ctx.global_runtime_state(), value.type_id())
+    {
+        Some(func) => Ok(func(value)),
+        None => Err(not_iterable_err(ctx.engine(), &value)),
+    }
+}
@@ -109,6 +109,9 @@ pub enum ParseErrorType {
     MalformedInExpr(String),
     /// A capturing  has syntax error. Wrapped value is the error description (if any).
     MalformedCapture(String),
+    /// A named argument in a function call has syntax error.
+    /// Wrapped value is the error description (if any).
+    MalformedNamedArg(String),
     /// A map definition has duplicated property names. Wrapped value is the property name.
     DuplicatedProperty(String),
     /// A `switch` case is duplicated.
@@ -156,10 +159,24 @@ pub enum ParseErrorType {
     /// A function definition has duplicated parameters. Wrapped values are the function name and
     /// parameter name.
     FnDuplicatedParam(String, String),
+    /// A function parameter without a default value follows one with a default value.
+    /// Wrapped values are the function name and parameter name.
+    FnMissingParamDefault(String, String),
+    /// The default value of a function parameter is not a constant.
+    /// Wrapped values are the function name and parameter name.
+    FnNonConstantParamDefault(String, String),
+    /// An operator function does not have the right number of parameters for the operator.
+    /// Wrapped value is the operator.
+    FnWrongOperatorParams(String),
     /// A function definition is missing the body. Wrapped value is the function name.
     FnMissingBody(String),
     /// Export statement not at global level.
     WrongExport,
+    /// Defining a `module` block in an appropriate place (e.g. inside a block or a function).
+    WrongModuleDefinition,
+    /// A statement inside a `module` block that is not a function definition, a constant or
+    /// another `module` block.
+    WrongModuleStatement,
     /// Assignment to an a constant variable. Wrapped value is the constant variable name.
     AssignmentToConstant(String),
     /// Assignment to an inappropriate LHS (left-hand-side) expression.
@@ -185,6 +202,9 @@ pub enum ParseErrorType {
     LiteralTooLarge(String, usize),
     /// Break statement not inside a loop.
     LoopBreak,
+    /// A `break` or `continue` statement targets a label that is not defined on any enclosing loop.
+    /// Wrapped value is the label name.
+    LabelUndefined(String),
 }
 
 impl fmt::Display for ParseErrorType {
@@ -200,6 +220,9 @@ impl fmt::Display for ParseErrorType {
             Self::MalformedCapture(s) if s.is_empty()  => f.write_str("Invalid capturing"),
             Self::MalformedCapture(s) => f.write_str(s),
 
+            Self::MalformedNamedArg(s) if s.is_empty()  => f.write_str("Invalid named argument"),
+            Self::MalformedNamedArg(s) => f.write_str(s),
+
             Self::FnDuplicatedDefinition(s, n) => {
                 write!(f, "Function {s} with ")?;
                 match n {
@@ -214,6 +237,11 @@ impl fmt::Display for ParseErrorType {
 
             Self::FnMissingParams(s) => write!(f, "Expecting parameters for function {s}"),
             Self::FnDuplicatedParam(s, arg) => write!(f, "Duplicated parameter {arg} for function {s}"),
+            Self::FnMissingParamDefault(s, arg) => write!(f, "Missing default value for parameter {arg} of function {s}"),
+            Self::FnNonConstantParamDefault(s, arg) => write!(f, "Default value for parameter {arg} of function {s} must be a constant"),
+            Self::FnWrongOperatorParams(s) if s == "+" || s == "-" => write!(f, "Operator function {s} must have one or two parameters"),
+            Self::FnWrongOperatorParams(s) if s == "!" => write!(f, "Operator function {s} must have one parameter"),
+            Self::FnWrongOperatorParams(s) => write!(f, "Operator function {s} must have two parameters"),
 
             Self::DuplicatedProperty(s) => write!(f, "Duplicated property for object map literal: {s}"),
             Self::DuplicatedVariable(s) => write!(f, "Duplicated variable name: {s}"),
@@ -249,9 +277,12 @@ impl fmt::Display for ParseErrorType {
             Self::FnMissingName => f.write_str("Expecting function name in function declaration"),
             Self::WrongDocComment => f.write_str("Doc-comment must be followed immediately by a function definition"),
             Self::WrongExport => f.write_str("Export statement can only appear at global level"),
+            Self::WrongModuleDefinition => f.write_str("Module blocks must be at global level and cannot be inside a block or a function"),
+            Self::WrongModuleStatement => f.write_str("Only function definitions, constants and modules are allowed in a module block"),
             Self::ExprTooDeep => f.write_str("Expression exceeds maximum complexity"),
             Self::TooManyFunctions => f.write_str("Number of functions defined exceeds maximum limit"),
             Self::LoopBreak => f.write_str("Break statement should only be used inside a loop"),
+            Self::LabelUndefined(s) => write!(f, "Undefined loop label: '{s}"),
 
             #[allow(deprecated)]
             Self::DuplicatedSwitchCase => f.write_str("Duplicated switch case"),
@@ -0,0 +1,321 @@
+//! The [`Set`] type for holding a collection of unique values.
+#![cfg(not(feature = "no_index"))]
+
+use crate::types::dynamic::Union;
+use crate::{Dynamic, Engine, ImmutableString, Position, RhaiError, ERR, INT};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{
+    cmp::Ordering,
+    collections::{btree_set, BTreeSet},
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+};
+
+/// Names of the data types that can be kept inside a [`Set`].
+const ITEM_TYPES: &str = "()/bool/int/float/char/string/tuple";
+
+/// A value kept inside a [`Set`].
+///
+/// Items of different types never compare equal, so `1` and `1.0` are distinct items.
+#[derive(Debug, Clone)]
+enum SetItem {
+    Unit,
+    Bool(bool),
+    Int(INT),
+    #[cfg(not(feature = "no_float"))]
+    Float(crate::FLOAT),
+    Char(char),
+    Str(ImmutableString),
+    Tuple(Box<[SetItem]>),
+}
+
+impl SetItem {
+    /// Rank of the item's data type, used to order items of different types.
+    #[inline(always)]
+    #[must_use]
+    const fn rank(&self) -> u8 {
+        match self {
+            Self::Unit => 0,
+            Self::Bool(..) => 1,
+            Self::Int(..) => 2,
+            #[cfg(not(feature = "no_float"))]
+            Self::Float(..) => 3,
+            Self::Char(..) => 4,
+            Self::Str(..) => 5,
+            Self::Tuple(..) => 6,
+        }
+    }
+}
+
+impl Ord for SetItem {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            #[cfg(not(feature = "no_float"))]
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+            (Self::Char(a), Self::Char(b)) => a.cmp(b),
+            (Self::Str(a), Self::Str(b)) => a.cmp(b),
+            (Self::Tuple(a), Self::Tuple(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+impl PartialOrd for SetItem {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl PartialEq for SetItem {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for SetItem {}
+
+impl Hash for SetItem {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+
+        match self {
+            Self::Unit => (),
+            Self::Bool(v) => v.hash(state),
+            Self::Int(v) => v.hash(state),
+            #[cfg(not(feature = "no_float"))]
+            Self::Float(v) => v.to_ne_bytes().hash(state),
+            Self::Char(v) => v.hash(state),
+            Self::Str(v) => v.hash(state),
+            Self::Tuple(v) => v.hash(state),
+        }
+    }
+}
+
+impl TryFrom<Dynamic> for SetItem {
+    type Error = Dynamic;
+
+    #[inline]
+    fn try_from(value: Dynamic) -> Result<Self, Self::Error> {
+        match value.0 {
+            Union::Unit(..) => Ok(Self::Unit),
+            Union::Bool(v, ..) => Ok(Self::Bool(v)),
+            Union::Int(v, ..) => Ok(Self::Int(v)),
+            #[cfg(not(feature = "no_float"))]
+            Union::Float(v, ..) => Ok(Self::Float(*v)),
+            Union::Char(v, ..) => Ok(Self::Char(v)),
+            Union::Str(v, ..) => Ok(Self::Str(v)),
+            Union::Variant(ref v, ..) if (***v).is::<crate::Tuple>() => {
+                let tuple = (***v).as_any().downcast_ref::<crate::Tuple>().unwrap();
+
+                match tuple.iter().cloned().map(Self::try_from).collect() {
+                    Ok(items) => Ok(Self::Tuple(items)),
+                    Err(_) => Err(value),
+                }
+            }
+            #[cfg(not(feature = "no_closure"))]
+            Union::Shared(..) => Self::try_from(value.flatten()),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<SetItem> for Dynamic {
+    #[inline]
+    fn from(value: SetItem) -> Self {
+        match value {
+            SetItem::Unit => Self::UNIT,
+            SetItem::Bool(v) => v.into(),
+            SetItem::Int(v) => v.into(),
+            #[cfg(not(feature = "no_float"))]
+            SetItem::Float(v) => v.into(),
+            SetItem::Char(v) => v.into(),
+            SetItem::Str(v) => v.into(),
+            SetItem::Tuple(v) => {
+                crate::Tuple::from_iter(v.into_vec().into_iter().map(Self::from)).into()
+            }
+        }
+    }
+}
+
+/// A collection of unique values, kept in sorted order.
+///
+/// Only the following data types can be kept inside a [`Set`]: `()`, `bool`,
+/// [`INT`][crate::INT], [`FLOAT`][crate::FLOAT], `char`, [`ImmutableString`] and
+/// [`Tuple`][crate::Tuple]'s of these.
+///
+/// Not available under `no_index`.
+///
+/// # Example
+///
+/// ```
+/// use rhai::{Dynamic, Set};
+///
+/// let mut set = Set::new();
+///
+/// assert!(set.insert(Dynamic::from_int(42)).unwrap());
+/// assert!(!set.insert(Dynamic::from_int(42)).unwrap());
+/// assert!(set.insert(Dynamic::from_array(vec![])).is_err());
+///
+/// assert!(set.contains(&Dynamic::from_int(42)));
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Set(BTreeSet<SetItem>);
+
+impl fmt::Debug for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("#[")?;
+
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Debug::fmt(&item, f)?;
+        }
+
+        f.write_str("]")
+    }
+}
+
+impl Set {
+    /// Create a new, empty [`Set`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeSet::new())
+    }
+    /// Number of items in the [`Set`].
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Is the [`Set`] empty?
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Remove all items from the [`Set`].
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+    /// Does the [`Set`] contain a value?
+    ///
+    /// Values of data types that cannot be kept inside a [`Set`] are never contained.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, value: &Dynamic) -> bool {
+        SetItem::try_from(value.clone()).map_or(false, |item| self.0.contains(&item))
+    }
+    /// Add a value to the [`Set`].
+    ///
+    /// Returns `true` if the value was not already present.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if its data type cannot be kept inside a [`Set`].
+    #[inline]
+    pub fn insert(&mut self, value: Dynamic) -> Result<bool, Dynamic> {
+        SetItem::try_from(value).map(|item| self.0.insert(item))
+    }
+    /// Remove a value from the [`Set`].
+    ///
+    /// Returns `true` if the value was present.
+    #[inline]
+    pub fn remove(&mut self, value: &Dynamic) -> bool {
+        SetItem::try_from(value.clone()).map_or(false, |item| self.0.remove(&item))
+    }
+    /// Get an iterator over all the items in the [`Set`], in sorted order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Dynamic> + '_ {
+        self.0.iter().cloned().map(Into::into)
+    }
+    /// Return a new [`Set`] containing all the items in either [`Set`].
+    #[inline]
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0.union(&other.0).cloned().collect())
+    }
+    /// Return a new [`Set`] containing only the items in both [`Set`]'s.
+    #[inline]
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(self.0.intersection(&other.0).cloned().collect())
+    }
+    /// Return a new [`Set`] containing the items in this [`Set`] but not in the other.
+    #[inline]
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        Self(self.0.difference(&other.0).cloned().collect())
+    }
+    /// Is every item in this [`Set`] also in the other?
+    #[inline(always)]
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+    /// Is every item in the other [`Set`] also in this one?
+    #[inline(always)]
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        self.0.is_superset(&other.0)
+    }
+    /// Create a [`Set`] from a collection of values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first value whose data type cannot be kept inside a [`Set`].
+    #[inline]
+    pub fn try_from_iter(iter: impl IntoIterator<Item = Dynamic>) -> Result<Self, Dynamic> {
+        iter.into_iter()
+            .map(SetItem::try_from)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+    /// Create an error for a value whose data type cannot be kept inside a [`Set`].
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn make_item_err(engine: &Engine, value: &Dynamic, pos: Position) -> RhaiError {
+        ERR::ErrorMismatchDataType(
+            ITEM_TYPES.into(),
+            engine.map_type_name(value.type_name()).into(),
+            pos,
+        )
+        .into()
+    }
+}
+
+/// An iterator over the items of a [`Set`].
+pub struct SetIntoIter(btree_set::IntoIter<SetItem>);
+
+impl Iterator for SetIntoIter {
+    type Item = Dynamic;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Into::into)
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl IntoIterator for Set {
+    type Item = Dynamic;
+    type IntoIter = SetIntoIter;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        SetIntoIter(self.0.into_iter())
+    }
+}
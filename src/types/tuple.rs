@@ -0,0 +1,196 @@
+//! The [`Tuple`] type for holding a fixed-size, immutable sequence of values.
+#![cfg(not(feature = "no_index"))]
+
+use crate::types::dynamic::Variant;
+use crate::{Dynamic, Position, RhaiError, ERR};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{
+    any::type_name,
+    convert::{TryFrom, TryInto},
+    fmt,
+    iter::FromIterator,
+    ops::Deref,
+};
+
+/// An immutable, fixed-size sequence of [`Dynamic`] values.
+///
+/// Unlike an [`Array`][crate::Array], items cannot be added, removed or modified.
+///
+/// Not available under `no_index`.
+///
+/// # Example
+///
+/// ```
+/// use rhai::{Dynamic, Tuple, INT};
+/// use std::convert::TryInto;
+///
+/// let value: Dynamic = (42 as INT, "hello".to_string()).into();
+///
+/// assert_eq!(value.clone_cast::<Tuple>().len(), 2);
+///
+/// let (x, s): (INT, String) = value.try_into().unwrap();
+///
+/// assert_eq!(x, 42);
+/// assert_eq!(s, "hello");
+/// ```
+#[derive(Clone, Default, Hash)]
+pub struct Tuple(Box<[Dynamic]>);
+
+impl fmt::Debug for Tuple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Debug::fmt(item, f)?;
+        }
+
+        if self.len() == 1 {
+            f.write_str(",")?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl Deref for Tuple {
+    type Target = [Dynamic];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[Dynamic]> for Tuple {
+    #[inline(always)]
+    fn as_ref(&self) -> &[Dynamic] {
+        &self.0
+    }
+}
+
+impl From<Vec<Dynamic>> for Tuple {
+    #[inline(always)]
+    fn from(value: Vec<Dynamic>) -> Self {
+        Self(value.into_boxed_slice())
+    }
+}
+
+impl From<Tuple> for Vec<Dynamic> {
+    #[inline(always)]
+    fn from(value: Tuple) -> Self {
+        value.0.into_vec()
+    }
+}
+
+impl FromIterator<Dynamic> for Tuple {
+    #[inline(always)]
+    fn from_iter<T: IntoIterator<Item = Dynamic>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Tuple {
+    type Item = Dynamic;
+    type IntoIter = std::vec::IntoIter<Dynamic>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_vec().into_iter()
+    }
+}
+
+impl Tuple {
+    /// Create an error for a [`Tuple`] that does not have the required number of items.
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn make_len_err(expected: usize, actual: usize, pos: Position) -> RhaiError {
+        ERR::ErrorMismatchDataType(
+            format!("a tuple of {expected} items"),
+            format!("a tuple of {actual} items"),
+            pos,
+        )
+        .into()
+    }
+}
+
+/// Implement conversions between a Rust tuple and a [`Tuple`].
+macro_rules! impl_tuple {
+    ($n:literal: $($t:ident),+) => {
+        impl<$($t: Variant + Clone),+> From<($($t,)+)> for Tuple {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn from(($($t,)+): ($($t,)+)) -> Self {
+                Self(Box::new([$(Dynamic::from($t)),+]))
+            }
+        }
+
+        impl<$($t: Variant + Clone),+> From<($($t,)+)> for Dynamic {
+            #[inline(always)]
+            fn from(value: ($($t,)+)) -> Self {
+                Self::from(Tuple::from(value))
+            }
+        }
+
+        impl<$($t: Variant + Clone),+> TryFrom<Tuple> for ($($t,)+) {
+            type Error = RhaiError;
+
+            #[inline]
+            fn try_from(value: Tuple) -> Result<Self, Self::Error> {
+                if value.len() != $n {
+                    return Err(Tuple::make_len_err($n, value.len(), Position::NONE));
+                }
+
+                let mut iter = value.into_iter();
+
+                Ok(($({
+                    let item = iter.next().unwrap();
+                    let typ = item.type_name();
+
+                    item.try_cast::<$t>().ok_or_else(|| -> RhaiError {
+                        ERR::ErrorMismatchDataType(
+                            type_name::<$t>().into(),
+                            typ.into(),
+                            Position::NONE,
+                        )
+                        .into()
+                    })?
+                },)+))
+            }
+        }
+
+        impl<$($t: Variant + Clone),+> TryFrom<Dynamic> for ($($t,)+) {
+            type Error = RhaiError;
+
+            #[inline]
+            fn try_from(value: Dynamic) -> Result<Self, Self::Error> {
+                let typ = value.type_name();
+
+                value
+                    .try_cast::<Tuple>()
+                    .ok_or_else(|| -> RhaiError {
+                        ERR::ErrorMismatchDataType(
+                            type_name::<Tuple>().into(),
+                            typ.into(),
+                            Position::NONE,
+                        )
+                        .into()
+                    })?
+                    .try_into()
+            }
+        }
+    };
+}
+
+impl_tuple!(1: A);
+impl_tuple!(2: A, B);
+impl_tuple!(3: A, B, C);
+impl_tuple!(4: A, B, C, D);
+impl_tuple!(5: A, B, C, D, E);
+impl_tuple!(6: A, B, C, D, E, F);
+impl_tuple!(7: A, B, C, D, E, F, G);
+impl_tuple!(8: A, B, C, D, E, F, G, H);
@@ -0,0 +1,428 @@
+//! The vector and matrix types of the [`VectorMathPackage`][crate::packages::VectorMathPackage].
+#![cfg(not(feature = "no_float"))]
+
+use crate::FLOAT;
+use std::fmt;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+
+/// A vector of `N` floating-point components.
+///
+/// Components are stored inline, without any allocation.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vector<const N: usize>(pub [FLOAT; N]);
+
+/// A two-dimensional vector, with components `x` and `y`.
+pub type Vec2 = Vector<2>;
+/// A three-dimensional vector, with components `x`, `y` and `z`.
+pub type Vec3 = Vector<3>;
+/// A four-dimensional vector, with components `x`, `y`, `z` and `w`.
+pub type Vec4 = Vector<4>;
+
+impl<const N: usize> Default for Vector<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self([0.0; N])
+    }
+}
+
+impl<const N: usize> fmt::Debug for Vector<N> {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vec{N}(")?;
+
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Debug::fmt(v, f)?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl<const N: usize> fmt::Display for Vector<N> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    /// Create a new [`Vector`] by applying a function to each pair of components of two vectors.
+    #[inline]
+    #[must_use]
+    pub fn zip_with(&self, other: &Self, f: impl Fn(FLOAT, FLOAT) -> FLOAT) -> Self {
+        let mut result = *self;
+        result
+            .0
+            .iter_mut()
+            .zip(other.0)
+            .for_each(|(x, y)| *x = f(*x, y));
+        result
+    }
+    /// Create a new [`Vector`] by applying a function to each component.
+    #[inline]
+    #[must_use]
+    pub fn map(&self, f: impl Fn(FLOAT) -> FLOAT) -> Self {
+        let mut result = *self;
+        result.0.iter_mut().for_each(|x| *x = f(*x));
+        result
+    }
+    /// Dot product of two vectors.
+    #[inline]
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> FLOAT {
+        self.0.iter().zip(other.0).map(|(x, y)| x * y).sum()
+    }
+    /// Length (magnitude) of the vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn length(&self) -> FLOAT {
+        self.dot(self).sqrt()
+    }
+    /// Unit vector in the same direction, or [`None`] if the vector has zero length.
+    #[inline]
+    #[must_use]
+    pub fn normalize(&self) -> Option<Self> {
+        let length = self.length();
+
+        if length == 0.0 || !length.is_finite() {
+            None
+        } else {
+            Some(self.map(|x| x / length))
+        }
+    }
+}
+
+impl Vec3 {
+    /// Cross product of two three-dimensional vectors.
+    #[inline]
+    #[must_use]
+    pub fn cross(&self, other: &Self) -> Self {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+/// A matrix of floating-point elements, stored contiguously in row-major order.
+#[derive(Clone, PartialEq)]
+pub struct Mat {
+    /// Number of rows.
+    rows: usize,
+    /// Number of columns.
+    cols: usize,
+    /// Elements in row-major order.
+    data: Box<[FLOAT]>,
+}
+
+impl fmt::Debug for Mat {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("mat([")?;
+
+        for r in 0..self.rows {
+            if r > 0 {
+                f.write_str(", ")?;
+            }
+            f.debug_list().entries(self.row(r)).finish()?;
+        }
+
+        f.write_str("])")
+    }
+}
+
+impl fmt::Display for Mat {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl Mat {
+    /// Create a new [`Mat`] with all elements zero.
+    #[inline]
+    #[must_use]
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols].into(),
+        }
+    }
+    /// Create a new square identity [`Mat`].
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        let mut mat = Self::zeros(n, n);
+        (0..n).for_each(|i| mat.data[i * n + i] = 1.0);
+        mat
+    }
+    /// Create a new [`Mat`] from elements in row-major order.
+    ///
+    /// Returns [`None`] if the number of elements is not `rows` × `cols`.
+    #[inline]
+    #[must_use]
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<FLOAT>) -> Option<Self> {
+        (data.len() == rows * cols).then(|| Self {
+            rows,
+            cols,
+            data: data.into(),
+        })
+    }
+    /// Number of rows.
+    #[inline(always)]
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+    /// Number of columns.
+    #[inline(always)]
+    #[must_use]
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+    /// Is this a square matrix?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+    /// Elements in row-major order.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slice(&self) -> &[FLOAT] {
+        &self.data
+    }
+    /// Elements of a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn row(&self, r: usize) -> &[FLOAT] {
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+    /// Get an element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&self, r: usize, c: usize) -> FLOAT {
+        self.data[r * self.cols + c]
+    }
+    /// Set an element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    #[inline(always)]
+    pub fn set(&mut self, r: usize, c: usize, value: FLOAT) {
+        self.data[r * self.cols + c] = value;
+    }
+    /// Create a new [`Mat`] by applying a function to each element.
+    #[inline]
+    #[must_use]
+    pub fn map(&self, f: impl Fn(FLOAT) -> FLOAT) -> Self {
+        let mut result = self.clone();
+        result.data.iter_mut().for_each(|x| *x = f(*x));
+        result
+    }
+    /// Create a new [`Mat`] by applying a function to each pair of elements of two matrices.
+    ///
+    /// Returns [`None`] if the dimensions of the matrices differ.
+    #[inline]
+    #[must_use]
+    pub fn zip_with(&self, other: &Self, f: impl Fn(FLOAT, FLOAT) -> FLOAT) -> Option<Self> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return None;
+        }
+
+        let mut result = self.clone();
+        result
+            .data
+            .iter_mut()
+            .zip(other.data.iter())
+            .for_each(|(x, &y)| *x = f(*x, y));
+        Some(result)
+    }
+    /// Multiply two matrices.
+    ///
+    /// Returns [`None`] if the number of columns of this matrix differs from the number of rows
+    /// of the other.
+    #[must_use]
+    pub fn mul(&self, other: &Self) -> Option<Self> {
+        if self.cols != other.rows {
+            return None;
+        }
+
+        let mut result = Self::zeros(self.rows, other.cols);
+
+        for r in 0..self.rows {
+            for (k, &x) in self.row(r).iter().enumerate() {
+                for (c, &y) in other.row(k).iter().enumerate() {
+                    result.data[r * other.cols + c] += x * y;
+                }
+            }
+        }
+
+        Some(result)
+    }
+    /// Multiply this matrix by a column vector.
+    ///
+    /// Returns [`None`] if the matrix is not `N` × `N`.
+    #[must_use]
+    pub fn mul_vector<const N: usize>(&self, v: &Vector<N>) -> Option<Vector<N>> {
+        if self.rows != N || self.cols != N {
+            return None;
+        }
+
+        let mut result = Vector::<N>::default();
+
+        for (r, x) in result.0.iter_mut().enumerate() {
+            *x = self.row(r).iter().zip(v.0).map(|(a, b)| a * b).sum();
+        }
+
+        Some(result)
+    }
+    /// Transform a vector by this matrix.
+    ///
+    /// An `N` × `N` matrix multiplies the vector directly.
+    ///
+    /// An (`N` + 1) × (`N` + 1) matrix is treated as a homogeneous transformation (e.g. with
+    /// translation) of the vector as a point, which is extended with a `1` component before
+    /// multiplication and projected back afterwards.
+    ///
+    /// Returns [`None`] if the matrix has any other dimensions.
+    #[must_use]
+    pub fn transform<const N: usize>(&self, v: &Vector<N>) -> Option<Vector<N>> {
+        if self.rows == N {
+            return self.mul_vector(v);
+        }
+        if self.rows != N + 1 || self.cols != N + 1 {
+            return None;
+        }
+
+        let project = |r: usize| -> FLOAT {
+            let row = self.row(r);
+            row.iter().zip(v.0).map(|(a, b)| a * b).sum::<FLOAT>() + row[N]
+        };
+
+        let w = project(N);
+        let w = if w == 0.0 { 1.0 } else { w };
+
+        let mut result = Vector::<N>::default();
+        result
+            .0
+            .iter_mut()
+            .enumerate()
+            .for_each(|(r, x)| *x = project(r) / w);
+        Some(result)
+    }
+    /// Transpose of this matrix.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::zeros(self.cols, self.rows);
+
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                result.data[c * self.rows + r] = self.get(r, c);
+            }
+        }
+
+        result
+    }
+    /// Determinant of this matrix, via Gaussian elimination with partial pivoting.
+    ///
+    /// Returns [`None`] if the matrix is not square.
+    #[must_use]
+    pub fn determinant(&self) -> Option<FLOAT> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut m = self.clone();
+        let mut det = 1.0;
+
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| m.get(a, col).abs().total_cmp(&m.get(b, col).abs()))
+                .unwrap();
+
+            if m.get(pivot, col) == 0.0 {
+                return Some(0.0);
+            }
+            if pivot != col {
+                m.swap_rows(pivot, col);
+                det = -det;
+            }
+
+            let p = m.get(col, col);
+            det *= p;
+
+            for r in col + 1..n {
+                let factor = m.get(r, col) / p;
+                (col..n).for_each(|c| m.data[r * n + c] -= factor * m.get(col, c));
+            }
+        }
+
+        Some(det)
+    }
+    /// Inverse of this matrix, via Gauss-Jordan elimination with partial pivoting.
+    ///
+    /// Returns [`None`] if the matrix is not square or is singular.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut m = self.clone();
+        let mut inv = Self::identity(n);
+
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| m.get(a, col).abs().total_cmp(&m.get(b, col).abs()))
+                .unwrap();
+
+            if m.get(pivot, col) == 0.0 {
+                return None;
+            }
+            m.swap_rows(pivot, col);
+            inv.swap_rows(pivot, col);
+
+            let p = m.get(col, col);
+            (0..n).for_each(|c| {
+                m.data[col * n + c] /= p;
+                inv.data[col * n + c] /= p;
+            });
+
+            for r in (0..n).filter(|&r| r != col) {
+                let factor = m.get(r, col);
+                (0..n).for_each(|c| {
+                    m.data[r * n + c] -= factor * m.get(col, c);
+                    inv.data[r * n + c] -= factor * inv.get(col, c);
+                });
+            }
+        }
+
+        inv.data.iter().all(|x| x.is_finite()).then_some(inv)
+    }
+    /// Swap two rows.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            (0..self.cols).for_each(|c| self.data.swap(a * self.cols + c, b * self.cols + c));
+        }
+    }
+}
@@ -140,6 +140,69 @@ fn test_arrays() {
     assert_eq!(array.len(), 4);
 }
 
+#[test]
+fn test_array_spread() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let a = [1, 2]; let b = [3]; let c = [..a, 0, ...b]; len(c) * 10 + c[2]").unwrap(), 40);
+    assert_eq!(engine.eval::<Array>("let a = []; [..a, ..a]").unwrap().len(), 0);
+    assert!(matches!(*engine.eval::<Array>("let a = 42; [..a]").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+}
+
+#[test]
+fn test_array_comprehension() {
+    let mut engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<Array>("let items = [3, -1, 4, 0, 5]; [x * 2 for x in items if x > 0]")
+            .unwrap()
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [6, 8, 10]
+    );
+    assert_eq!(engine.eval::<INT>("let a = [x * i for (x, i) in 10..15]; len(a) * 100 + a[4]").unwrap(), 556);
+    assert_eq!(engine.eval::<INT>("let x = 42; let a = [x for x in 0..3]; x + a[2]").unwrap(), 44);
+    assert_eq!(engine.eval::<INT>("const x = 42; let a = [x + 1 for x in 0..3]; a[2]").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("let x = 1; let y = 2; let a = [x * 10 + y for x in 3..5]; a[1]").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("let n = 40; let a = [x + i + n for (x, i) in 0..2]; let b = n; a[1] + b").unwrap(), 82);
+    assert_eq!(engine.eval::<INT>("let n = 10; let a = [x + n for x in [1, 2, 3] if x != n - 8]; a[0] + a[1]").unwrap(), 24);
+    assert_eq!(engine.eval::<Array>("[x for x in []]").unwrap().len(), 0);
+    assert_eq!(engine.eval::<INT>("let a = [x for x in 0..3]; let b = [y for y in a]; b[1]").unwrap(), 1);
+    assert_eq!(engine.eval::<INT>("let a = [[x * y for y in 0..3] for x in 0..3]; a[2][2]").unwrap(), 4);
+    assert_eq!(engine.eval::<INT>("let a = [1, 2]; let b = [x for x in 1..3]; len(a) + len(b)").unwrap(), 4);
+    assert_eq!(engine.eval::<INT>("let a = [if x > 1 { x } else { 0 } for x in 0..3]; a[1] + a[2]").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>("let n = 5; let a = [{ let y = x * 2; y + n } for x in 0..3]; a[2]").unwrap(), 9);
+
+    #[cfg(not(feature = "no_function"))]
+    #[cfg(not(feature = "no_closure"))]
+    assert_eq!(engine.eval::<INT>("let n = 100; let fs = [|| x + n for x in 0..3]; call(fs[2])").unwrap(), 102);
+
+    assert!(matches!(*engine.eval::<Array>("let n = 42; [x for x in n]").unwrap_err(), EvalAltResult::ErrorFor(..)));
+    assert!(matches!(*engine.eval::<Array>("[x for x in 0..3 if x]").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.eval::<Array>("[y for x in 0..3]").unwrap_err(), EvalAltResult::ErrorVariableNotFound(..)));
+    assert!(matches!(engine.compile("[x for x in 0..3").unwrap_err().err_type(), ParseErrorType::MissingToken(..)));
+
+    engine.register_iterator::<Vec<INT>>();
+    engine.register_fn("digits", || vec![1 as INT, 2, 3]);
+
+    assert_eq!(engine.eval::<INT>("let a = [d * d for d in digits() if d != 2]; a[0] + a[1]").unwrap(), 10);
+
+    engine.set_strict_variables(true);
+
+    assert_eq!(engine.eval::<INT>("let n = 1; let a = [x + n for x in 0..3]; a[2]").unwrap(), 3);
+    assert!(matches!(engine.compile("[x + y for x in 0..3]").unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+    assert!(matches!(engine.compile("let a = [x for x in 0..3]; x").unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+
+    #[cfg(not(feature = "no_function"))]
+    #[cfg(not(feature = "no_closure"))]
+    {
+        assert_eq!(engine.eval::<INT>("let n = 100; let fs = [|| x + n for x in 0..3]; call(fs[2])").unwrap(), 102);
+        assert!(matches!(engine.compile("let fs = [|| x + y for x in 0..3];").unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+    }
+}
+
 #[cfg(not(feature = "no_float"))]
 #[cfg(not(feature = "no_object"))]
 #[test]
@@ -492,6 +555,87 @@ fn test_arrays_map_reduce() {
         .unwrap();
 }
 
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_arrays_stats() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("[1, 2, 3, 4, 5].sum()").unwrap(), 15);
+    assert_eq!(engine.eval::<INT>("[1, 2, 3, 4, 5].product()").unwrap(), 120);
+    assert_eq!(engine.eval::<INT>("[].sum()").unwrap(), 0);
+    assert_eq!(engine.eval::<INT>("[].product()").unwrap(), 1);
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        assert_eq!(engine.eval::<String>(r#"["apple", "fig", "banana", "kiwi"].min_by(|a, b| a.len() - b.len())"#).unwrap(), "fig");
+        assert_eq!(engine.eval::<String>(r#"["apple", "fig", "banana", "kiwi"].max_by(|a, b| a.len() - b.len())"#).unwrap(), "banana");
+        assert_eq!(engine.eval::<INT>("[3, 1, 2].max_by(|a, b| a < b)").unwrap(), 3);
+        assert!(engine.eval::<bool>("[].min_by(|a, b| a - b) == ()").unwrap());
+        assert!(matches!(*engine.run(r#"[1, 2].min_by(|a, b| "x")"#).unwrap_err(), EvalAltResult::ErrorMismatchOutputType(..)));
+    }
+
+    assert!(matches!(*engine.run(r#"[1, "x"].sum()"#).unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    #[cfg(not(feature = "unchecked"))]
+    #[cfg(not(feature = "only_i32"))]
+    assert!(matches!(*engine.run("[9223372036854775807, 1].sum()").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+}
+
+#[cfg(not(feature = "no_object"))]
+#[cfg(not(feature = "no_float"))]
+#[test]
+fn test_arrays_stats_float() {
+    use rhai::FLOAT;
+
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<FLOAT>("[1, 2.5, 3].sum()").unwrap(), 6.5);
+    assert_eq!(engine.eval::<FLOAT>("[1, 2, 3, 4].mean()").unwrap(), 2.5);
+    assert_eq!(engine.eval::<FLOAT>("[3, 1, 4, 1, 5, 9].median()").unwrap(), 3.5);
+    assert_eq!(engine.eval::<FLOAT>("[5, 1, 3].median()").unwrap(), 3.0);
+    assert_eq!(engine.eval::<FLOAT>("[10, 20, 30, 40, 50].percentile(90)").unwrap(), 46.0);
+    assert_eq!(engine.eval::<FLOAT>("[10, 20, 30, 40, 50].percentile(62.5)").unwrap(), 35.0);
+    assert_eq!(engine.eval::<FLOAT>("[10, 20, 30, 40, 50].percentile(100)").unwrap(), 50.0);
+    assert_eq!(engine.eval::<FLOAT>("[2, 4, 4, 4, 5, 5, 7, 9].variance()").unwrap(), 4.0);
+    assert_eq!(engine.eval::<FLOAT>("[2, 4, 4, 4, 5, 5, 7, 9].stddev()").unwrap(), 2.0);
+    assert_eq!(engine.eval::<String>("[1, 2, 2, 3, 3, 3, 4, 4, 5].histogram(4).to_string()").unwrap(), "[1, 2, 3, 3]");
+    assert_eq!(engine.eval::<String>("[7, 7].histogram(3).to_string()").unwrap(), "[2, 0, 0]");
+    assert_eq!(engine.eval::<String>("[0.0, 0.5, 1.0].histogram(2).to_string()").unwrap(), "[1, 2]");
+    assert!(engine.eval::<bool>("[].mean() == () && [].median() == () && [].stddev() == ()").unwrap());
+
+    assert!(matches!(*engine.run("[1, 2].percentile(101)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("[1, 2].histogram(0)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("[1, 2].histogram(2147483647)").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("[].histogram(2147483647)").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("[1, true].mean()").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+
+    for script in [
+        r#"[1, parse_float("NaN")].median()"#,
+        r#"[1, 2, 3, parse_float("NaN")].percentile(0)"#,
+        r#"[1, 2, parse_float("inf")].percentile(50.0)"#,
+        r#"[parse_float("-inf"), 1, 2].histogram(2)"#,
+        r#"[1, parse_float("NaN")].histogram(2)"#,
+    ] {
+        assert!(matches!(*engine.run(script).unwrap_err(), EvalAltResult::ErrorArithmetic(..)), "{}", script);
+    }
+}
+
+#[cfg(not(feature = "no_object"))]
+#[cfg(feature = "decimal")]
+#[test]
+fn test_arrays_stats_decimal() {
+    use rust_decimal::Decimal;
+
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<Decimal>(r#"[1, parse_decimal("2.5")].sum()"#).unwrap(), Decimal::new(35, 1));
+    assert_eq!(engine.eval::<Decimal>(r#"[1, 2, parse_decimal("0")].mean()"#).unwrap(), Decimal::ONE);
+    assert_eq!(engine.eval::<Decimal>(r#"[1, 2, 3, parse_decimal("4")].median()"#).unwrap(), Decimal::new(25, 1));
+    assert_eq!(engine.eval::<Decimal>(r#"[2, 4, 4, 4, 5, 5, 7, parse_decimal("9")].stddev()"#).unwrap(), Decimal::TWO);
+
+    #[cfg(not(feature = "no_float"))]
+    assert!(matches!(*engine.run(r#"[1.0, parse_decimal("2")].sum()"#).unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+}
+
 #[test]
 fn test_arrays_elvis() {
     let engine = Engine::new();
@@ -0,0 +1,99 @@
+#![cfg(feature = "bigint")]
+use num_bigint::BigInt;
+use rhai::{Engine, EvalAltResult, INT};
+
+#[test]
+fn test_bigint_literals() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<BigInt>("123n").unwrap(), BigInt::from(123));
+    assert_eq!(engine.eval::<BigInt>("-5n").unwrap(), BigInt::from(-5));
+    assert_eq!(engine.eval::<BigInt>("0xffn").unwrap(), BigInt::from(255));
+    assert_eq!(engine.eval::<BigInt>("0b1010n").unwrap(), BigInt::from(10));
+    assert_eq!(engine.eval::<BigInt>("1_000_000n").unwrap(), BigInt::from(1_000_000));
+    assert_eq!(engine.eval::<String>("123456789012345678901234567890n.to_string()").unwrap(), "123456789012345678901234567890");
+    assert_eq!(engine.eval::<String>("42n.to_debug()").unwrap(), "42n");
+    assert_eq!(engine.eval::<String>("type_of(42n)").unwrap(), "bigint");
+}
+
+#[test]
+fn test_bigint_ops() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<String>("let x = 2n ** 100; x.to_string()").unwrap(), "1267650600228229401496703205376");
+    assert_eq!(engine.eval::<BigInt>("(10n + 5n) * 3n - 1n").unwrap(), BigInt::from(44));
+    assert_eq!(engine.eval::<BigInt>("17n / 5n").unwrap(), BigInt::from(3));
+    assert_eq!(engine.eval::<BigInt>("17n % 5n").unwrap(), BigInt::from(2));
+    assert_eq!(engine.eval::<BigInt>("1n << 4").unwrap(), BigInt::from(16));
+    assert_eq!(engine.eval::<BigInt>("-16n >> 2").unwrap(), BigInt::from(-4));
+    assert_eq!(engine.eval::<BigInt>("0b1100n & 0b1010n | 1n").unwrap(), BigInt::from(9));
+    assert_eq!(engine.eval::<BigInt>("let x = 1n; x += 2; x *= 10n; x").unwrap(), BigInt::from(30));
+    assert_eq!(engine.eval::<BigInt>("abs(-7n)").unwrap(), BigInt::from(7));
+    assert_eq!(engine.eval::<INT>("sign(-7n)").unwrap(), -1);
+    assert!(engine.eval::<bool>("0n.is_zero && 3n.is_odd && -4n.is_even").unwrap());
+
+    assert!(matches!(*engine.run("1n / 0n").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("1n % 0").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("2n ** -1").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+}
+
+#[test]
+fn test_bigint_limits() {
+    let mut engine = Engine::new();
+
+    assert!(matches!(*engine.run("2n ** 100000000").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("1n << 4294967295").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("let x = 3n; x **= 4000000000;").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("1n >> -4294967295").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert_eq!(engine.eval::<BigInt>("(-1n) ** 4000000001").unwrap(), BigInt::from(-1));
+    assert_eq!(engine.eval::<BigInt>("0n << 4294967295").unwrap(), BigInt::from(0));
+    assert_eq!(engine.eval::<INT>("(2n ** 1000000).to_string(2).len()").unwrap(), 1_000_001);
+
+    #[cfg(not(feature = "unchecked"))]
+    {
+        engine.set_max_bigint_bits(64);
+
+        assert_eq!(engine.eval::<BigInt>("2n ** 63").unwrap(), BigInt::from(1) << 63);
+        assert_eq!(engine.eval::<BigInt>("1n << 63").unwrap(), BigInt::from(1) << 63);
+        assert!(matches!(*engine.run("2n ** 64").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+        assert!(matches!(*engine.run("let x = 1n; x <<= 64;").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    }
+}
+
+#[test]
+fn test_bigint_mixed() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<BigInt>("1n + 2").unwrap(), BigInt::from(3));
+    assert_eq!(engine.eval::<BigInt>("2 * 21n").unwrap(), BigInt::from(42));
+    assert!(engine.eval::<bool>("42n == 42").unwrap());
+    assert!(engine.eval::<bool>("41 < 42n").unwrap());
+    assert!(engine.eval::<bool>("42n != 43").unwrap());
+    assert_eq!(engine.eval::<String>("let x = 9223372036854775807n; (x + 1).to_string()").unwrap(), "9223372036854775808");
+}
+
+#[test]
+fn test_bigint_conversions() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<BigInt>("to_bigint(42)").unwrap(), BigInt::from(42));
+    assert_eq!(engine.eval::<INT>("42n.to_int()").unwrap(), 42);
+    assert!(matches!(*engine.run("(2n ** 100).to_int()").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+
+    assert_eq!(engine.eval::<BigInt>(r#"parse_bigint("123456789")"#).unwrap(), BigInt::from(123_456_789));
+    assert_eq!(engine.eval::<BigInt>(r#"parse_bigint("-ff", 16)"#).unwrap(), BigInt::from(-255));
+    assert!(engine.run(r#"parse_bigint("xyz")"#).is_err());
+    assert!(engine.run(r#"parse_bigint("10", 37)"#).is_err());
+
+    assert_eq!(engine.eval::<String>("255n.to_string(16)").unwrap(), "ff");
+    assert_eq!(engine.eval::<String>("(-5n).to_string(2)").unwrap(), "-101");
+
+    #[cfg(not(feature = "no_float"))]
+    {
+        assert_eq!(engine.eval::<BigInt>("to_bigint(42.9)").unwrap(), BigInt::from(42));
+        assert_eq!(engine.eval::<rhai::FLOAT>("1024n.to_float()").unwrap(), 1024.0);
+    }
+
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>("#{a: 42n, b: 12345678901234567890n}.to_json()").unwrap(), r#"{"a":42,"b":"12345678901234567890"}"#);
+}
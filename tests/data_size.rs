@@ -243,6 +243,27 @@ fn test_max_array_size() {
     );
 }
 
+#[test]
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+fn test_max_set_size() {
+    let mut engine = Engine::new();
+    engine.set_max_set_size(5);
+
+    assert_eq!(*engine.compile("let x = #[1,2,3,4,5,6,7];").unwrap_err().err_type(), ParseErrorType::LiteralTooLarge("Size of set literal".to_string(), 5));
+
+    assert!(matches!(*engine.run("let s = #[1,2,3,4,5]; s.insert(6);").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("let s = #[1,2,3]; s.union(#[4,5,6])").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("[1,2,3,4,5,6].to_set()").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+
+    assert_eq!(engine.eval::<INT>("let s = #[1,2,3,4,5]; s.insert(5); s.len").unwrap(), 5);
+    assert_eq!(engine.eval::<INT>("[1,2,3,4,5,5,5].to_set().len").unwrap(), 5);
+
+    engine.set_max_set_size(0);
+
+    assert_eq!(engine.eval::<INT>("let s = #[1,2,3,4,5]; s.insert(6); s.len").unwrap(), 6);
+}
+
 #[test]
 #[cfg(not(feature = "no_object"))]
 fn test_max_map_size() {
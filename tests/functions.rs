@@ -433,6 +433,140 @@ fn test_functions_params() {
         ParseErrorType::FnDuplicatedParam(a, b) if a == "hello" && b == "x"));
 }
 
+#[test]
+fn test_functions_default_params() {
+    let engine = Engine::new();
+
+    let script = r#"
+        fn connect(host, port = 80, tls = false) {
+            `${host}:${port}:${tls}`
+        }
+    "#;
+
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} connect("x")"#)).unwrap(), "x:80:false");
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} connect("x", 8080)"#)).unwrap(), "x:8080:false");
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} connect("x", 443, true)"#)).unwrap(), "x:443:true");
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} let f = Fn("connect"); call(f, "x")"#)).unwrap(), "x:80:false");
+
+    // Exact overloads take precedence over default values
+    assert_eq!(engine.eval::<INT>("fn foo(x) { 1 } fn foo(x, y = 0) { 2 } foo(0) * 10 + foo(0, 0)").unwrap(), 12);
+
+    // Native functions with exact signatures take precedence over default values
+    assert_eq!(engine.eval::<INT>(r#"fn len(x, y = 0) { 42 } len("abc")"#).unwrap(), 3);
+
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("fn add(x, y = 1) { this + x + y } let z = 1; z.add(2)").unwrap(), 4);
+
+    assert!(matches!(*engine.eval::<INT>("fn add(x, y = 1) { x + y } add()").unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+
+    let ast = engine.compile("fn add(x, y = 1) { x + y }").unwrap();
+    assert_eq!(engine.call_fn::<INT>(&mut rhai::Scope::new(), &ast, "add", (41 as INT,)).unwrap(), 42);
+
+    assert!(matches!(
+        engine.compile("fn add(x = 1, y) { x + y }").unwrap_err().err_type(),
+        ParseErrorType::FnMissingParamDefault(f, p) if f == "add" && p == "y"
+    ));
+    assert!(matches!(
+        engine.compile("fn add(x, y = x) { x + y }").unwrap_err().err_type(),
+        ParseErrorType::FnNonConstantParamDefault(f, p) if f == "add" && p == "y"
+    ));
+
+    #[cfg(not(feature = "no_index"))]
+    #[cfg(not(feature = "no_object"))]
+    {
+        let result = engine.eval::<rhai::Array>(&format!(r#"{script} get_fn_metadata_list("connect", 1)"#)).unwrap();
+        assert_eq!(result.len(), 1);
+        let defaults = result[0].read_lock::<rhai::Map>().unwrap()["param_defaults"].clone_cast::<rhai::Map>();
+        assert_eq!(defaults["port"].as_int().unwrap(), 80);
+        assert!(!defaults["tls"].as_bool().unwrap());
+
+        assert!(engine.eval::<rhai::Array>(&format!(r#"{script} get_fn_metadata_list("connect", 0)"#)).unwrap().is_empty());
+    }
+}
+
+#[test]
+fn test_functions_named_args() {
+    let engine = Engine::new();
+
+    let script = r#"
+        fn connect(host, port = 80, tls = false) {
+            `${host}:${port}:${tls}`
+        }
+    "#;
+
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} connect("x", tls: true)"#)).unwrap(), "x:80:true");
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} connect(port: 8080, host: "x")"#)).unwrap(), "x:8080:false");
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} connect("x", tls: true, port: 443)"#)).unwrap(), "x:443:true");
+
+    assert!(matches!(
+        *engine.eval::<String>(&format!(r#"{script} connect("x", secure: true)"#)).unwrap_err(),
+        EvalAltResult::ErrorFunctionNotFound(f, ..) if f.starts_with("connect (") && f.contains("secure: bool")
+    ));
+    assert!(matches!(*engine.eval::<String>(&format!(r#"{script} connect(tls: true)"#)).unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+
+    assert!(matches!(engine.compile(r#"connect(tls: true, "x")"#).unwrap_err().err_type(), ParseErrorType::MalformedNamedArg(..)));
+    assert!(matches!(engine.compile(r#"connect("x", tls: true, tls: false)"#).unwrap_err().err_type(), ParseErrorType::MalformedNamedArg(..)));
+    #[cfg(not(feature = "no_object"))]
+    assert!(matches!(engine.compile(r#"let x = 1; x.connect(tls: true)"#).unwrap_err().err_type(), ParseErrorType::MalformedNamedArg(..)));
+
+    // Argument names are not variables, but everything else still is
+    let mut engine = engine;
+    engine.set_strict_variables(true);
+
+    assert_eq!(engine.eval::<String>(&format!(r#"{script} let p = 1; connect("x", port: p, tls: true)"#)).unwrap(), "x:1:true");
+    assert!(matches!(engine.compile(r#"connect("x", port: p)"#).unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+    assert!(matches!(engine.compile(r#"connect("x", 1 + p: 2)"#).unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_functions_variadic() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("fn log(level, ...args) { level + len(args) } log(10)").unwrap(), 10);
+    assert_eq!(engine.eval::<INT>("fn log(level, ...args) { level + len(args) } log(10, 1, 2, 3)").unwrap(), 13);
+    assert_eq!(engine.eval::<INT>("fn sum(...x) { let s = 0; for v in x { s += v } s } sum(1, 2, 3, 4)").unwrap(), 10);
+    assert_eq!(engine.eval::<INT>("fn f(a, b = 2, ...c) { a + b + len(c) } f(1) + f(1, 1) * 10 + f(1, 1, 1, 1) * 100").unwrap(), 423);
+    assert_eq!(engine.eval::<INT>("fn f(a, b = 2, ...c) { a + b + len(c) } f(1, b: 5)").unwrap(), 6);
+    assert_eq!(engine.eval::<INT>("fn f(...x) { len(x) } let f = curry(Fn(\"f\"), 1, 2); call(f, 3, 4)").unwrap(), 4);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("fn f(...x) { this + x.len() } let z = 10; z.f(1, 2)").unwrap(), 12);
+
+    assert!(engine.eval::<INT>("fn f(...x: int) { len(x) } f(1, 2, 3)").is_ok());
+    assert!(matches!(*engine.eval::<INT>("fn f(...x: int) { len(x) } f(1, \"x\")").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+
+    #[cfg(not(feature = "no_object"))]
+    assert!(engine.eval::<bool>("fn f(a, ...c) { a } get_fn_metadata_list(\"f\", 5)[0].is_variadic").unwrap());
+    assert!(matches!(engine.compile("fn f(...x, y) {}").unwrap_err().err_type(), ParseErrorType::MissingToken(..)));
+    assert!(matches!(engine.compile("fn f(...x = 1) {}").unwrap_err().err_type(), ParseErrorType::MissingToken(..)));
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_functions_spread_args() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("fn add(a, b, c) { a + b + c } add(...[1, 2, 3])").unwrap(), 6);
+    assert_eq!(engine.eval::<INT>("fn add(a, b, c) { a + b + c } let x = [2, 3]; add(1, ...x)").unwrap(), 6);
+    assert_eq!(
+        engine
+            .eval::<INT>("fn sum(...x) { let s = 0; for v in x { s += v } s } let a = [1, 2, 3]; sum(...a, 4, ...[5])")
+            .unwrap(),
+        15
+    );
+    assert_eq!(engine.eval::<INT>("max(...[1, 5])").unwrap(), 5);
+    assert_eq!(engine.eval::<INT>("let f = |x, y| x * y; call(f, ...[3, 4])").unwrap(), 12);
+    assert_eq!(engine.eval::<INT>(r#"call(Fn("max"), ...[3, 4])"#).unwrap(), 4);
+    assert_eq!(engine.eval::<INT>("fn f(...x) { len(x) } let f = curry(Fn(\"f\"), ...[1, 2]); call(f, ...[3, 4])").unwrap(), 4);
+    #[cfg(not(feature = "no_object"))]
+    {
+        assert_eq!(engine.eval::<INT>("let f = |x, y| x * y; f.call(...[3, 4])").unwrap(), 12);
+        assert_eq!(engine.eval::<INT>("let a = [1, 2]; a.push(...[3]); a.len()").unwrap(), 3);
+    }
+
+    assert!(matches!(*engine.eval::<INT>("fn f(...x) { len(x) } f(...42)").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+}
+
 #[test]
 fn test_function_pointers() {
     let engine = Engine::new();
@@ -574,3 +708,26 @@ fn test_functions_max() {
         ParseErrorType::TooManyFunctions
     ))
 }
+
+#[test]
+fn test_functions_suggestions() {
+    let engine = Engine::new();
+
+    assert!(matches!(
+        *engine.eval::<INT>("fn calculate(x) { x * 2 } calculat(21)").unwrap_err(),
+        EvalAltResult::ErrorFunctionNotFound(f, x, ..) if f.starts_with("calculat (") && x == ["calculate"]
+    ));
+
+    assert!(matches!(
+        *engine.eval::<INT>("fn calculate(x, y) { x * y } calculate(21)").unwrap_err(),
+        EvalAltResult::ErrorFunctionNotFound(f, x, ..) if f.starts_with("calculate (") && x == ["calculate(x, y)"]
+    ));
+
+    assert!(matches!(
+        *engine.eval::<INT>("fn calculate(x) { x * 2 } foo(21)").unwrap_err(),
+        EvalAltResult::ErrorFunctionNotFound(f, x, ..) if f.starts_with("foo (") && x.is_empty()
+    ));
+
+    let err = engine.eval::<INT>("fn calculate(x) { x * 2 } calculat(21)").unwrap_err();
+    assert!(err.to_string().contains("did you mean 'calculate'?"));
+}
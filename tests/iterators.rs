@@ -0,0 +1,107 @@
+#![cfg(not(feature = "no_index"))]
+#![cfg(not(feature = "no_object"))]
+#![cfg(not(feature = "no_function"))]
+use rhai::{Array, Engine, EvalAltResult, LazyIterator, INT};
+
+fn ints(array: Array) -> Vec<INT> {
+    array.into_iter().map(|v| v.as_int().unwrap()).collect()
+}
+
+#[test]
+fn test_lazy_iterators() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<String>("type_of([1, 2].iter())").unwrap(), "iterator");
+    assert_eq!(ints(engine.eval::<Array>("[1, 2, 3].iter().collect()").unwrap()), [1, 2, 3]);
+    assert_eq!(ints(engine.eval::<Array>("(1..1000).iter().filter(|v| v % 7 == 0).map(|v| v * v).take(3).collect()").unwrap()), [49, 196, 441]);
+    assert_eq!(ints(engine.eval::<Array>("(1..).iter().skip(2).take(3).collect()").unwrap()), [3, 4, 5]);
+    assert_eq!(ints(engine.eval::<Array>("[1, 2, 3].iter().flat_map(|v| 0..v).collect()").unwrap()), [0, 0, 1, 0, 1, 2]);
+    assert_eq!(ints(engine.eval::<Array>("[1, 2].iter().chain(5..7).chain([9].iter()).collect()").unwrap()), [1, 2, 5, 6, 9]);
+    assert_eq!(ints(engine.eval::<Array>("[1, 2, 3].iter().map(|v| v * 10).take(0).collect()").unwrap()), Vec::<INT>::new());
+    assert_eq!(engine.eval::<INT>("(1..100).iter().filter(|v| v % 10 == 0).count()").unwrap(), 9);
+    assert_eq!(engine.eval::<String>(r#"let s = ""; for c in "hello".iter().skip(1).take(3) { s += c; } s"#).unwrap(), "ell");
+    assert_eq!(engine.eval::<String>(r#"let s = ""; for p in #{a: 1, b: 2}.iter() { s += `${p[0]}=${p[1]};`; } s"#).unwrap(), "a=1;b=2;");
+    assert_eq!(engine.eval::<String>(r#"[1, 2].iter().zip("abc").enumerate().collect().to_string()"#).unwrap(), "[(0, (1, 'a')), (1, (2, 'b'))]");
+    assert_eq!(engine.eval::<String>("[1, 2, 3].iter().map(|v| v * 2).collect().to_string()").unwrap(), "[2, 4, 6]");
+
+    assert!(matches!(*engine.run("42.iter()").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.run("[1].iter().zip(true)").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.run("[1].iter().flat_map(|v| v).collect()").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+}
+
+#[test]
+fn test_lazy_iterators_laziness() {
+    let engine = Engine::new();
+
+    // Only as many values as needed are produced
+    #[cfg(not(feature = "no_closure"))]
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                "
+                    let calls = [];
+                    let it = (0..1000000).iter().map(|v| { calls.push(v); v }).take(3);
+                    let first = it.collect();
+                    calls.len() * 10 + first.len()
+                "
+            )
+            .unwrap(),
+        33
+    );
+
+    // Consuming an iterator starts afresh each time
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                "
+                    let it = [1, 2, 3].iter().map(|v| v + 1);
+                    let total = 0;
+                    for v in it { total += v; }
+                    for (v, i) in it { total += v * i; }
+                    total + it.count()
+                "
+            )
+            .unwrap(),
+        9 + 11 + 3
+    );
+
+    // Errors in adapters stop iteration
+    assert!(engine.run(r#"for v in [1, 2, 3].iter().map(|v| if v == 2 { throw "oops" } else { v }) { }"#).is_err());
+
+    // Loops over iterators can be broken out of
+    assert_eq!(engine.eval::<INT>("let n = 0; for v in (0..).iter().map(|v| v * v) { if v > 50 { break; } n += 1; } n").unwrap(), 8);
+}
+
+#[test]
+fn test_lazy_iterators_registered() {
+    #[derive(Debug, Clone)]
+    struct Countdown(INT);
+
+    impl IntoIterator for Countdown {
+        type Item = INT;
+        type IntoIter = std::iter::Rev<std::ops::RangeInclusive<INT>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            (1..=self.0).rev()
+        }
+    }
+
+    let mut engine = Engine::new();
+    engine.register_iterator::<Countdown>().register_fn("countdown", Countdown);
+
+    assert_eq!(ints(engine.eval::<Array>("countdown(5).iter().filter(|v| v % 2 == 1).collect()").unwrap()), [5, 3, 1]);
+
+    let iter = engine.eval::<LazyIterator>("countdown(3).iter().map(|v| v * 2)").unwrap();
+    assert_eq!(format!("{iter:?}").matches(".map(").count(), 1);
+}
+
+#[cfg(not(feature = "unchecked"))]
+#[test]
+fn test_lazy_iterators_limits() {
+    let mut engine = Engine::new();
+
+    engine.set_max_array_size(10);
+
+    assert_eq!(ints(engine.eval::<Array>("(1..).iter().take(10).collect()").unwrap()).len(), 10);
+    assert!(matches!(*engine.run("(1..).iter().collect()").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+}
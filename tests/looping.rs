@@ -1,4 +1,4 @@
-use rhai::{Engine, ParseErrorType, INT};
+use rhai::{Engine, LexError, ParseErrorType, INT};
 
 #[test]
 fn test_loop() {
@@ -89,3 +89,71 @@ fn test_loop_expression() {
         )
         .is_err());
 }
+
+#[test]
+fn test_loop_labels() {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                "
+                    let found = 0;
+
+                    'outer: for x in 1..10 {
+                        for y in 1..10 {
+                            if x * y == 42 {
+                                found = x * 100 + y;
+                                break 'outer;
+                            }
+                        }
+                    }
+
+                    found
+                "
+            )
+            .unwrap(),
+        607
+    );
+
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                "
+                    let count = 0;
+
+                    'rows: for x in 0..5 {
+                        let y = 0;
+
+                        'cols: loop {
+                            y += 1;
+                            if y > 3 { break 'cols; }
+                            if x % 2 == 1 { continue 'rows; }
+                            count += 1;
+                        }
+                    }
+
+                    count
+                "
+            )
+            .unwrap(),
+        9
+    );
+
+    assert_eq!(
+        engine
+            .eval::<INT>("let x = 'outer: loop { let i = 0; while i < 10 { i += 1; if i == 5 { break 'outer i * 2; } } }; x")
+            .unwrap(),
+        10
+    );
+    assert_eq!(engine.eval::<INT>("let n = 0; 'a: do { n += 1; loop { break 'a n * 10; } } while n < 3;").unwrap(), 10);
+    assert_eq!(engine.eval::<char>("'a: loop { break 'a'; }").unwrap(), 'a');
+
+    assert_eq!(*engine.compile("'outer: loop { break 'inner; }").unwrap_err().err_type(), ParseErrorType::LabelUndefined("inner".into()));
+    assert_eq!(*engine.compile("'outer: loop {} loop { continue 'outer; }").unwrap_err().err_type(), ParseErrorType::LabelUndefined("outer".into()));
+    assert!(matches!(engine.compile("'outer: let x = 42;").unwrap_err().err_type(), ParseErrorType::BadInput(LexError::ImproperSymbol(..))));
+    assert!(matches!(engine.compile("'outer loop {}").unwrap_err().err_type(), ParseErrorType::MissingToken(..)));
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(*engine.compile("'outer: loop { let f = || { loop { break 'outer; } }; }").unwrap_err().err_type(), ParseErrorType::LabelUndefined("outer".into()));
+}
@@ -102,6 +102,48 @@ b`: 1};
     );
 }
 
+#[test]
+fn test_map_spread() {
+    let engine = Engine::new();
+
+    let map = engine.eval::<Map>("let m = #{a: 1, b: 2}; #{..m, b: 42, c: 3}").unwrap();
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["b"].as_int().unwrap(), 42);
+
+    let map = engine.eval::<Map>("let m = #{a: 1, b: 2}; #{b: 42, ...m}").unwrap();
+    assert_eq!(map["b"].as_int().unwrap(), 2);
+
+    assert!(matches!(*engine.eval::<Map>("let m = 42; #{..m}").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_map_comprehension() {
+    let mut engine = Engine::new();
+
+    let map = engine.eval::<Map>(r#"let keys = ["a", "b", "c"]; #{ k: i * 10 for (k, i) in keys if k != "b" }"#).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["a"].as_int().unwrap(), 0);
+    assert_eq!(map["c"].as_int().unwrap(), 20);
+
+    let map = engine.eval::<Map>(r#"let m = #{}; for x in 1..4 { m[`k${x}`] = x; } #{ k: m[k] * 2 for k in m.keys() }"#).unwrap();
+    assert_eq!(map["k3"].as_int().unwrap(), 6);
+
+    assert!(matches!(*engine.eval::<Map>("#{ k: k for k in 0..3 }").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(engine.compile("#{ k: 1 for k in [] ").unwrap_err().err_type(), ParseErrorType::MissingToken(..)));
+
+    let map = engine.eval::<Map>("let m = #{a: 1, b: 2, c: 3}; #{ k: v * 2 for (k, v) in m if v != 2 }").unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["c"].as_int().unwrap(), 6);
+
+    assert_eq!(engine.eval::<INT>("let m = #{a: 1, b: 2}; let a = [k for k in m]; a.len()").unwrap(), 2);
+
+    engine.set_strict_variables(true);
+
+    assert_eq!(engine.eval::<INT>(r#"let n = 1; let m = #{ k: n for k in ["x", "y"] }; m.x + m.y"#).unwrap(), 2);
+    assert!(matches!(engine.compile(r#"#{ z: 1 for k in ["x"] }"#).unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+}
+
 #[test]
 fn test_map_prop() {
     let mut engine = Engine::new();
@@ -112,7 +154,12 @@ fn test_map_prop() {
 
     assert!(matches!(
         *engine.eval::<()>("let x = #{a: 42}; x.b").unwrap_err(),
-        EvalAltResult::ErrorPropertyNotFound(prop, _) if prop == "b"
+        EvalAltResult::ErrorPropertyNotFound(prop, ..) if prop == "b"
+    ));
+
+    assert!(matches!(
+        *engine.eval::<()>("let x = #{alpha: 1, beta: 2}; x.alpah").unwrap_err(),
+        EvalAltResult::ErrorPropertyNotFound(prop, x, ..) if prop == "alpah" && x == ["alpha"]
     ));
 }
 
@@ -176,6 +223,17 @@ fn test_map_for() {
             .len(),
         11
     );
+
+    assert_eq!(
+        engine
+            .eval::<INT>(r#"let map = #{a: 1, b: 2, c: 3}; let s = 0; for (key, value) in map { if key != "b" { s += value; } } s"#)
+            .unwrap(),
+        4
+    );
+    assert_eq!(engine.eval::<String>(r#"let map = #{a: 1, b: 2, c: 3}; let s = ""; for key in map { s += key; } s"#).unwrap(), "abc");
+    assert_eq!(engine.eval::<INT>(r#"let map = #{a: 1, b: 2}; for (key, value) in map { value *= 10; } map.a + map.b"#).unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("let s = 0; for (item, index) in [5, 6, 7] { s += index; } s").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("let s = 0; for (key, value) in #{} { s += 1; } s").unwrap(), 0);
 }
 
 #[test]
@@ -268,7 +326,7 @@ fn test_map_missing_property_callback() {
             map.get_mut("y").unwrap().try_into()
         }
         "z" => Ok(Dynamic::from(100 as INT).into()),
-        _ => Err(EvalAltResult::ErrorPropertyNotFound(prop.to_string(), Position::NONE).into()),
+        _ => Err(EvalAltResult::ErrorPropertyNotFound(prop.to_string(), Vec::new(), Position::NONE).into()),
     });
 
     assert_eq!(
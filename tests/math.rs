@@ -57,3 +57,45 @@ fn test_math_parse() {
     assert_eq!(engine.eval::<INT>(r#"parse_int("42", 16)"#).unwrap(), 0x42);
     assert_eq!(engine.eval::<INT>(r#"parse_int("abcdef", 16)"#).unwrap(), 0xabcdef);
 }
+
+#[test]
+fn test_math_integer_ops() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("wrapping_add(1, 2)").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>(&format!("wrapping_add({}, 1)", INT::MAX)).unwrap(), INT::MIN);
+    assert_eq!(engine.eval::<INT>(&format!("wrapping_sub({}, 1)", INT::MIN)).unwrap(), INT::MAX);
+    assert_eq!(engine.eval::<INT>(&format!("saturating_mul({}, 2)", INT::MAX)).unwrap(), INT::MAX);
+    assert_eq!(engine.eval::<INT>(&format!("saturating_sub({}, 1)", INT::MIN)).unwrap(), INT::MIN);
+    assert_eq!(engine.eval::<INT>("saturating_pow(2, 1000)").unwrap(), INT::MAX);
+    assert_eq!(engine.eval::<INT>("wrapping_pow(2, 3)").unwrap(), 8);
+    assert!(matches!(*engine.run("wrapping_pow(2, -1)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+
+    assert_eq!(engine.eval::<INT>("checked_sub(5, 3)").unwrap(), 2);
+    assert!(engine.eval::<bool>(&format!("checked_add({}, 1) == ()", INT::MAX)).unwrap());
+    assert!(engine.eval::<bool>("checked_div(1, 0) == ()").unwrap());
+    assert!(engine.eval::<bool>("checked_pow(2, -1) == ()").unwrap());
+
+    #[cfg(not(feature = "no_index"))]
+    {
+        assert!(engine
+            .eval::<bool>(&format!("let (x, overflow) = overflowing_add({}, 1); overflow && x == {}", INT::MAX, INT::MIN))
+            .unwrap());
+        assert!(engine.eval::<bool>("overflowing_mul(6, 7) == (42, false)").unwrap());
+    }
+
+    assert_eq!(engine.eval::<INT>("rotate_left(1, 1)").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>("rotate_right(1, 1)").unwrap(), INT::MIN);
+    assert_eq!(engine.eval::<INT>("rotate_left(1, -1)").unwrap(), INT::MIN);
+    assert_eq!(engine.eval::<INT>("count_ones(0b1011)").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("trailing_zeros(0b1000)").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("leading_zeros(1)").unwrap(), INT::BITS as INT - 1);
+    assert_eq!(engine.eval::<INT>("swap_bytes(1)").unwrap(), (1 as INT).swap_bytes());
+    assert_eq!(engine.eval::<INT>("reverse_bits(1)").unwrap(), INT::MIN);
+
+    assert_eq!(engine.eval::<INT>("pow_mod(4, 13, 497)").unwrap(), 445);
+    assert_eq!(engine.eval::<INT>("pow_mod(-2, 3, 5)").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>(&format!("pow_mod({}, 3, {})", INT::MAX - 1, INT::MAX)).unwrap(), INT::MAX - 1);
+    assert!(matches!(*engine.run("pow_mod(2, 3, 0)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("pow_mod(2, -3, 5)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+}
@@ -565,6 +565,114 @@ fn test_module_environ() {
     );
 }
 
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_inline() {
+    let mut engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                "
+                    module utils {
+                        const FACTOR = 2;
+                        export const ANSWER = 42;
+
+                        fn helper(x) { double(x) + 1 }
+                        private fn double(x) { x * global::FACTOR }
+                    }
+
+                    utils::helper(20) + utils::ANSWER
+                "
+            )
+            .unwrap(),
+        83
+    );
+
+    assert!(engine.eval::<INT>("module utils { private fn secret() { 42 } } utils::secret()").is_err());
+    assert!(engine.eval::<INT>("module utils { const FACTOR = 2; } utils::FACTOR").is_err());
+
+    assert_eq!(engine.eval::<INT>("module a { module b { fn f() { 42 } } fn g() { b::f() - 1 } } a::b::f() + a::g()").unwrap(), 83);
+    assert_eq!(engine.eval::<INT>("module a { fn f() { 1 } } module b { fn f() { a::f() + 41 } } b::f()").unwrap(), 42);
+
+    let ast = engine.compile("module utils { fn helper() { 42 } }").unwrap();
+    assert_eq!(engine.eval_ast::<INT>(&ast.merge(&engine.compile("utils::helper()").unwrap())).unwrap(), 42);
+
+    engine.set_strict_variables(true);
+
+    assert_eq!(engine.eval::<INT>("module utils { fn helper() { 42 } } utils::helper()").unwrap(), 42);
+    assert!(matches!(engine.compile("module utils { fn helper() { 42 } } other::helper()").unwrap_err().err_type(), ParseErrorType::ModuleUndefined(..)));
+
+    assert!(matches!(engine.compile("{ module utils { } }").unwrap_err().err_type(), ParseErrorType::WrongModuleDefinition));
+    assert!(matches!(engine.compile("fn foo() { module utils { } }").unwrap_err().err_type(), ParseErrorType::WrongModuleDefinition));
+    assert!(matches!(engine.compile("module utils { print(42); }").unwrap_err().err_type(), ParseErrorType::WrongModuleStatement));
+    assert!(matches!(engine.compile("module utils { let x = 40 + foo(); }").unwrap_err().err_type(), ParseErrorType::ExprExpected(..)));
+}
+
+#[test]
+fn test_module_import_items() {
+    let mut engine = Engine::new();
+    let mut resolver = StaticModuleResolver::new();
+
+    let mut module = Module::new();
+    module.set_var("PI", 3 as INT);
+    module.set_native_fn("sqr", |x: INT| Ok(x * x));
+    resolver.insert("math", module);
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        let ast = engine.compile("export const ANSWER = 21; fn double(x) { x * 2 }").unwrap();
+        resolver.insert("script", Module::eval_ast_as_new(Scope::new(), &ast, &engine).unwrap());
+    }
+
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "math" for { sqr, PI as pi }; sqr(pi)"#).unwrap(), 9);
+    assert_eq!(engine.eval::<INT>(r#"import "math" as *; sqr(PI)"#).unwrap(), 9);
+    assert_eq!(engine.eval::<INT>(r#"import "math" for { sqr as square }; import "math" for { sqr }; square(2) + sqr(3)"#).unwrap(), 13);
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<INT>(r#"import "script" as *; double(ANSWER)"#).unwrap(), 42);
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<INT>(r#"import "math" for { sqr }; fn f(x) { sqr(x) } f(4)"#).unwrap(), 16);
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<INT>(r#"import "math" for { PI }; let f = || PI; call(f)"#).unwrap(), 3);
+
+    assert!(matches!(*engine.eval::<INT>(r#"import "math" for { sqr }; PI"#).unwrap_err(), EvalAltResult::ErrorVariableNotFound(..)));
+    assert!(matches!(*engine.eval::<INT>(r#"{ import "math" as *; } sqr(2)"#).unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+    assert!(matches!(*engine.run(r#"import "math" for { foo }"#).unwrap_err(), EvalAltResult::ErrorVariableNotFound(..)));
+    assert!(matches!(*engine.run(r#"import "math" as *; import "math" for { sqr }"#).unwrap_err(), EvalAltResult::ErrorImportConflict(..)));
+    assert!(matches!(*engine.run(r#"import "math" for { PI }; import "math" for { PI }"#).unwrap_err(), EvalAltResult::ErrorImportConflict(..)));
+    assert!(matches!(engine.compile(r#"import "math" for { sqr, PI as sqr }"#).unwrap_err().err_type(), ParseErrorType::DuplicatedVariable(..)));
+
+    engine.set_strict_variables(true);
+
+    assert_eq!(engine.eval::<INT>(r#"import "math" for { PI as pi }; pi"#).unwrap(), 3);
+    assert_eq!(engine.eval::<INT>(r#"import "math" as *; PI"#).unwrap(), 3);
+    assert!(matches!(engine.compile(r#"import "math" for { sqr }; PI"#).unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+    assert!(matches!(engine.compile(r#"{ import "math" for { PI }; } PI"#).unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+}
+
+#[cfg(all(feature = "internals", feature = "metadata"))]
+#[test]
+fn test_module_import_items_definitions() {
+    let mut engine = Engine::new();
+
+    let mut module = Module::new();
+    module.set_var("PI", 3 as INT);
+    module.set_native_fn("sqr", |x: INT| Ok(x * x));
+    module.set_native_fn("cube", |x: INT| Ok(x * x * x));
+    engine.register_static_module("math", module.into());
+
+    let def = engine.definitions().all_imported_items("math").unwrap();
+    assert!(def.contains("const PI") && def.contains("fn sqr(") && def.contains("fn cube("));
+
+    let def = engine.definitions().imported_items("math", &[("sqr", Some("square")), ("cube", None)]).unwrap();
+    assert!(!def.contains("PI") && def.contains("fn square(") && !def.contains("fn sqr(") && def.contains("fn cube("));
+
+    assert!(engine.definitions().imported_items("foo", &[("sqr", None)]).is_none());
+    assert!(engine.definitions().all_imported_items("foo").is_none());
+}
+
 #[test]
 fn test_module_dynamic() {
     fn test_fn(input: Dynamic, x: INT) -> Result<INT, Box<EvalAltResult>> {
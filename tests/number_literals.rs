@@ -55,3 +55,59 @@ fn test_binary_literal() {
     #[cfg(not(feature = "no_float"))]
     assert!(engine.compile("0b101.101").is_err());
 }
+
+#[test]
+fn test_literal_suffix() {
+    use rhai::{LexError, ParseErrorType};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let mut engine = Engine::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+
+    engine.register_fn("megabytes", move |x: INT| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        x * 1024 * 1024
+    });
+    engine.register_literal_suffix("MB", "megabytes").unwrap();
+    engine.register_literal_suffix("k", "thousands").unwrap();
+
+    assert_eq!(engine.eval::<INT>("10MB").unwrap(), 10 * 1024 * 1024);
+    assert_eq!(engine.eval::<INT>("-1MB + 0x1MB").unwrap(), 0);
+
+    // Suffixed constants are folded at parse time
+    calls.store(0, Ordering::SeqCst);
+    let ast = engine.compile("let x = 0; for i in 0..10 { x += 1MB; } x").unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(engine.eval_ast::<INT>(&ast).unwrap(), 10 * 1024 * 1024);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // The constructor need not exist at parse time
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<INT>("fn thousands(x) { x * 1000 } 42k").unwrap(), 42_000);
+
+    assert!(matches!(
+        engine.compile("let x = 10GB;").unwrap_err().err_type(),
+        ParseErrorType::BadInput(LexError::MalformedNumber(s)) if s == "10GB"
+    ));
+    assert!(engine.register_literal_suffix("_x", "megabytes").is_err());
+    assert!(engine.register_literal_suffix("x", "if").is_err());
+
+    #[cfg(not(feature = "no_time"))]
+    {
+        engine.register_literal_suffix("ms", "milliseconds").unwrap();
+        engine.register_literal_suffix("s", "seconds").unwrap();
+        engine.register_literal_suffix("ns", "nanoseconds").unwrap();
+
+        assert_eq!(engine.eval::<std::time::Duration>("1s + 200ms").unwrap(), std::time::Duration::from_millis(1200));
+        assert_eq!(engine.eval::<std::time::Duration>("5ns").unwrap(), std::time::Duration::from_nanos(5));
+
+        #[cfg(not(feature = "no_float"))]
+        assert_eq!(engine.eval::<std::time::Duration>("1.5s").unwrap(), std::time::Duration::from_millis(1500));
+
+        assert!(engine.run("-1s").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, Scope, INT};
+use rhai::{Engine, EvalAltResult, ParseErrorType, Scope, INT};
 
 #[test]
 fn test_ops() {
@@ -71,3 +71,103 @@ fn test_ops_custom_types() {
     assert!(!engine.eval::<bool>("let x = new_ts1(); x == ()").unwrap());
     assert!(engine.eval::<bool>("let x = new_ts1(); x != ()").unwrap());
 }
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_ops_script_overload() {
+    #[derive(Debug, Clone)]
+    struct Test1(INT);
+
+    let mut engine = Engine::new();
+
+    engine.register_type_with_name::<Test1>("Test1").register_fn("new_ts1", Test1);
+
+    assert_eq!(engine.eval::<INT>("fn -(t) { 42 } -new_ts1(1)").unwrap(), 42);
+
+    #[cfg(not(feature = "no_object"))]
+    {
+        engine.register_get("value", |t: &mut Test1| t.0);
+
+        assert_eq!(engine.eval::<INT>("fn *(t, n) { t.value * n } let x = new_ts1(21); x * 2").unwrap(), 42);
+        assert_eq!(engine.eval::<INT>("fn -(t) { -t.value } -new_ts1(42)").unwrap(), -42);
+    }
+
+    assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } 1 + 2").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("fn -(a) { 42 } let x = 1; -x").unwrap(), -1);
+    assert_eq!(engine.eval::<String>(r#"fn +(a, b) { 42 } "a" + "b""#).unwrap(), "ab");
+
+    #[cfg(not(feature = "no_object"))]
+    {
+        // Only object maps tagged with a type are overloadable
+        assert_eq!(
+            engine
+                .eval::<INT>("fn +(a, b) { #{x: a.x + b.x, y: a.y + b.y} } let p = #{x: 1, y: 2}; p.set_tag(1); p += #{x: 3, y: 4}; p.x * 10 + p.y")
+                .unwrap(),
+            46
+        );
+        assert_eq!(engine.eval::<INT>("fn +(a, b) { #{x: a.x + b.x} } let p = #{x: 1}; p.set_tag(1); let q = p + #{x: 2}; q.x").unwrap(), 3);
+        assert!(engine.eval::<bool>("fn ==(a, b) { a.x == b.x } let a = #{x: 1, y: 2}; a.set_tag(1); a == #{x: 1, y: 3}").unwrap());
+        assert!(engine.eval::<bool>("fn <(a, b) { a.x < b.x } let a = #{x: 1}; let b = #{x: 2}; b.set_tag(1); a < b").unwrap());
+        assert_eq!(engine.eval::<INT>("fn +(a, b) { a.x + b } let m = #{x: 1}; m.set_tag(1); m + 2").unwrap(), 3);
+        assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } let m = #{a: 1} + #{b: 2}; m.len()").unwrap(), 2);
+        assert!(!engine.eval::<bool>("fn ==(a, b) { true } #{x: 1} == #{x: 2}").unwrap());
+
+        #[cfg(not(feature = "no_optimize"))]
+        {
+            engine.set_optimization_level(rhai::OptimizationLevel::Full);
+            assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } let m = #{x: 1}; m.set_tag(1); m + #{y: 2}").unwrap(), 42);
+            assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } (#{x: 1} + #{y: 2}).len()").unwrap(), 2);
+            engine.set_optimization_level(rhai::OptimizationLevel::Simple);
+        }
+
+        engine.set_fast_operators(false);
+        assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } 1 + 2").unwrap(), 3);
+        assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } let m = #{x: 1}; m.set_tag(1); m + #{y: 2}").unwrap(), 42);
+        assert_eq!(engine.eval::<INT>("fn +(a, b) { 42 } (#{x: 1} + #{y: 2}).len()").unwrap(), 2);
+    }
+
+    assert!(matches!(engine.compile("fn *(x) { x }").unwrap_err().err_type(), ParseErrorType::FnWrongOperatorParams(op) if op == "*"));
+    assert!(matches!(engine.compile("fn !(x, y) { x }").unwrap_err().err_type(), ParseErrorType::FnWrongOperatorParams(..)));
+    #[cfg(not(feature = "no_index"))]
+    assert!(matches!(engine.compile("fn ==(x, ...y) { x }").unwrap_err().err_type(), ParseErrorType::FnWrongOperatorParams(..)));
+    assert!(matches!(engine.compile("fn =(x, y) { x }").unwrap_err().err_type(), ParseErrorType::FnMissingName));
+}
+
+#[test]
+fn test_ops_pipeline() {
+    let mut engine = Engine::new();
+
+    engine.register_fn("add", |x: INT, y: INT| x + y);
+
+    assert_eq!(engine.eval::<INT>("40 |> add(2)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("1 + 2 |> add(3) |> add(4)").unwrap(), 10);
+    assert_eq!(engine.eval::<INT>("let x = 20; x |> add(1) * 2").unwrap(), 42);
+    assert!(engine.eval::<bool>("2 * 3 |> add(1) == 7").unwrap());
+    assert_eq!(engine.eval::<String>("1 < 2 |> type_of()").unwrap(), "bool");
+    assert_eq!(engine.eval::<INT>(r#""hello" |> len()"#).unwrap(), 5);
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<INT>("fn double(x) { x * 2 } fn sub(x, y) { x - y } 11 |> double() |> sub(1) |> double()").unwrap(), 42);
+
+    #[cfg(not(feature = "no_index"))]
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine
+            .eval::<INT>("[1, 2, 3, 4, 5] |> filter(|x| x % 2 == 1) |> map(|x| x * x) |> reduce(|sum, x| sum + x, 0)")
+            .unwrap(),
+        35
+    );
+
+    #[cfg(not(feature = "no_module"))]
+    {
+        let mut module = rhai::Module::new();
+        module.set_native_fn("triple", |x: INT| Ok(x * 3));
+        engine.register_static_module("m", module.into());
+
+        assert_eq!(engine.eval::<INT>("14 |> m::triple()").unwrap(), 42);
+    }
+
+    assert!(matches!(engine.compile("42 |> 1").unwrap_err().err_type(), ParseErrorType::ExprExpected(..)));
+    assert!(matches!(engine.compile("42 |> -add(1)").unwrap_err().err_type(), ParseErrorType::ExprExpected(..)));
+    assert!(matches!(engine.compile("let x = 42; x |> add").unwrap_err().err_type(), ParseErrorType::ExprExpected(..)));
+}
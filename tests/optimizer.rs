@@ -71,7 +71,7 @@ fn test_optimizer_parse() {
 
     let ast = engine.compile("const DECISION = false; if DECISION { 42 } else { 123 }").unwrap();
 
-    assert_eq!(format!("{ast:?}"), r#"AST { source: None, doc: "", resolver: None, body: [Var(("DECISION" @ 1:7, false @ 1:18, None), CONSTANT, 1:1), Expr(123 @ 1:51)] }"#);
+    assert_eq!(format!("{ast:?}"), r#"AST { source: None, doc: "", resolver: None, body: [Var(("DECISION" @ 1:7, false @ 1:18, None, None), CONSTANT, 1:1), Expr(123 @ 1:51)] }"#);
 
     let ast = engine.compile("if 1 == 2 { 42 }").unwrap();
 
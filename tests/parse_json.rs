@@ -93,7 +93,7 @@ mod without_metadata {
             )
             .unwrap_err();
 
-        assert!(matches!(err.as_ref(), EvalAltResult::ErrorFunctionNotFound(msg, pos)
+        assert!(matches!(err.as_ref(), EvalAltResult::ErrorFunctionNotFound(msg, _, pos)
             if msg == "parse_json (&str | ImmutableString | String)" && *pos == Position::new(2, 13)));
     }
 }
@@ -187,7 +187,7 @@ mod with_metadata {
             )
             .unwrap_err();
 
-        assert!(matches!(err.as_ref(), EvalAltResult::ErrorFunctionNotFound(msg, pos)
+        assert!(matches!(err.as_ref(), EvalAltResult::ErrorFunctionNotFound(msg, _, pos)
             if msg == "parse_json (&str | ImmutableString | String)" && *pos == Position::new(2, 17)));
     }
 }
@@ -0,0 +1,110 @@
+#![cfg(feature = "rational")]
+use rhai::{Engine, EvalAltResult, Rational, Scope, INT};
+
+#[test]
+fn test_rational() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<Rational>("rational(6, 8)").unwrap(), Rational::new(3, 4));
+    assert_eq!(engine.eval::<Rational>("rational(1, -3)").unwrap(), Rational::new(-1, 3));
+    assert_eq!(engine.eval::<String>("rational(2, 6).to_string()").unwrap(), "1/3");
+    assert_eq!(engine.eval::<String>("to_rational(5).to_string()").unwrap(), "5");
+    assert_eq!(engine.eval::<String>("type_of(rational(1, 2))").unwrap(), "rational");
+    assert_eq!(engine.eval::<INT>("let x = rational(6, 8); x.numer * 10 + x.denom").unwrap(), 34);
+    assert_eq!(engine.eval::<Rational>(r#"parse_rational("-4/6")"#).unwrap(), Rational::new(-2, 3));
+
+    assert!(matches!(*engine.run("rational(1, 0)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(engine.run(r#"parse_rational("1/x")"#).is_err());
+}
+
+#[test]
+fn test_rational_ops() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<Rational>("rational(1, 3) + rational(1, 6)").unwrap(), Rational::new(1, 2));
+    assert_eq!(engine.eval::<Rational>("rational(1, 3) - 1").unwrap(), Rational::new(-2, 3));
+    assert_eq!(engine.eval::<Rational>("2 * rational(1, 3)").unwrap(), Rational::new(2, 3));
+    assert_eq!(engine.eval::<Rational>("rational(1, 3) / rational(2, 3)").unwrap(), Rational::new(1, 2));
+    assert_eq!(engine.eval::<Rational>("1 / rational(3, 4)").unwrap(), Rational::new(4, 3));
+    assert_eq!(engine.eval::<Rational>("rational(7, 2) % 2").unwrap(), Rational::new(3, 2));
+    assert_eq!(engine.eval::<Rational>("rational(2, 3) ** 3").unwrap(), Rational::new(8, 27));
+    assert_eq!(engine.eval::<Rational>("rational(2, 3) ** -2").unwrap(), Rational::new(9, 4));
+    assert_eq!(engine.eval::<Rational>("-rational(1, 2)").unwrap(), Rational::new(-1, 2));
+    assert_eq!(engine.eval::<Rational>("let x = rational(1, 2); x += 1; x *= rational(2, 3); x").unwrap(), Rational::new(1, 1));
+
+    assert!(engine.eval::<bool>("rational(1, 3) < rational(1, 2)").unwrap());
+    assert!(engine.eval::<bool>("rational(4, 2) == 2").unwrap());
+    assert!(engine.eval::<bool>("1 != rational(1, 2)").unwrap());
+    assert!(engine.eval::<bool>("rational(3, 2) >= 1").unwrap());
+
+    assert!(matches!(*engine.run("rational(1, 2) / 0").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("rational(1, 2) % rational(0, 1)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("rational(1, 2) ** rational(1, 2)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+
+    #[cfg(not(feature = "unchecked"))]
+    #[cfg(not(feature = "only_i32"))]
+    assert!(matches!(*engine.run("rational(1, 3) + 9223372036854775807").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+}
+
+#[test]
+fn test_rational_functions() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<Rational>("rational(7, 2).floor").unwrap(), Rational::from_integer(3));
+    assert_eq!(engine.eval::<Rational>("rational(-7, 2).ceiling").unwrap(), Rational::from_integer(-3));
+    assert_eq!(engine.eval::<Rational>("rational(7, 2).round").unwrap(), Rational::from_integer(4));
+    assert_eq!(engine.eval::<Rational>("rational(-7, 2).floor").unwrap(), Rational::from_integer(-4));
+    assert_eq!(engine.eval::<Rational>("rational(7, 2).ceiling").unwrap(), Rational::from_integer(4));
+    assert_eq!(engine.eval::<Rational>("rational(-7, 2).round").unwrap(), Rational::from_integer(-4));
+    assert_eq!(engine.eval::<Rational>("rational(7, 3).round").unwrap(), Rational::from_integer(2));
+    assert_eq!(engine.eval::<Rational>("rational(-7, 2).int").unwrap(), Rational::from_integer(-3));
+    assert_eq!(engine.eval::<Rational>("rational(7, 2).fraction").unwrap(), Rational::new(1, 2));
+    assert_eq!(engine.eval::<Rational>("rational(-2, 3).recip()").unwrap(), Rational::new(-3, 2));
+    assert_eq!(engine.eval::<Rational>("abs(rational(-2, 3))").unwrap(), Rational::new(2, 3));
+    assert_eq!(engine.eval::<INT>("sign(rational(-2, 3))").unwrap(), -1);
+    assert_eq!(engine.eval::<INT>("rational(7, 2).to_int()").unwrap(), 3);
+    assert!(engine.eval::<bool>("rational(4, 2).is_integer && rational(0, 5).is_zero").unwrap());
+
+    #[cfg(not(feature = "no_float"))]
+    {
+        assert_eq!(engine.eval::<rhai::FLOAT>("rational(1, 4).to_float()").unwrap(), 0.25);
+        assert_eq!(engine.eval::<Rational>("to_rational(0.75)").unwrap(), Rational::new(3, 4));
+    }
+
+    #[cfg(feature = "decimal")]
+    assert_eq!(engine.eval::<String>("rational(1, 8).to_decimal().to_string()").unwrap(), "0.125");
+
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>("#{a: rational(1, 3)}.to_json()").unwrap(), r#"{"a":"1/3"}"#);
+}
+
+#[test]
+fn test_rational_overflow() {
+    let engine = Engine::new();
+    let mut scope = Scope::new();
+    scope.push_constant("MIN", INT::MIN);
+
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(MIN, 2)").unwrap(), Rational::from_integer(INT::MIN / 2));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(2, MIN)").unwrap(), Rational::new(-1, INT::MIN / -2));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(MIN, MIN)").unwrap(), Rational::from_integer(1));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "-rational(MIN + 1, 1)").unwrap(), Rational::from_integer(INT::MAX));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(MIN, 1).floor").unwrap(), Rational::from_integer(INT::MIN));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(MIN, 1).ceiling").unwrap(), Rational::from_integer(INT::MIN));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(MIN, 1).round").unwrap(), Rational::from_integer(INT::MIN));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(MIN + 1, 2).round").unwrap(), Rational::from_integer(INT::MIN / 2));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "(-rational(MIN + 1, 1)).ceiling").unwrap(), Rational::from_integer(INT::MAX));
+    assert_eq!(engine.eval_with_scope::<Rational>(&mut scope, "rational(-(MIN + 1), 2).round").unwrap(), Rational::from_integer(-(INT::MIN / 2)));
+
+    for script in [
+        "rational(MIN, -1)",
+        "rational(1, MIN)",
+        "rational(MIN, 1).recip()",
+        "-rational(MIN, 1)",
+        "rational(MIN, 1).abs()",
+        "rational(-1, 2) ** MIN",
+        "parse_rational(`${MIN}/-1`)",
+        "parse_rational(`1/${MIN}`)",
+    ] {
+        assert!(matches!(*engine.run_with_scope(&mut scope, script).unwrap_err(), EvalAltResult::ErrorArithmetic(..)), "{}", script);
+    }
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "regex")]
+#![cfg(not(feature = "no_object"))]
+use rhai::{Engine, EvalAltResult, ParseErrorType, Regex, INT};
+
+#[cfg(not(feature = "no_index"))]
+use rhai::Array;
+
+#[test]
+fn test_regex() {
+    let engine = Engine::new();
+
+    assert!(engine.eval::<bool>(r#"regex("^[0-9]+$").is_match("12345")"#).unwrap());
+    assert!(!engine.eval::<bool>(r#"regex("^[0-9]+$").is_match("123x5")"#).unwrap());
+    assert_eq!(engine.eval::<String>(r#"type_of(regex("a+"))"#).unwrap(), "regex");
+    assert_eq!(engine.eval::<String>(r#"regex("a+").pattern"#).unwrap(), "a+");
+    assert_eq!(engine.eval::<String>(r#"regex("a+").to_string()"#).unwrap(), "a+");
+    assert_eq!(engine.eval::<Regex>(r#"regex("a+")"#).unwrap().as_str(), "a+");
+    assert_eq!(engine.eval::<String>(r#"regex("[0-9]+").find("abc 123 def 456")"#).unwrap(), "123");
+    assert!(engine.eval::<bool>(r#"regex("[0-9]+").find("abc def") == ()"#).unwrap());
+    assert_eq!(engine.eval::<String>(r#"regex("(?P<w>[a-z]+)").replace("abc def", "<$w>")"#).unwrap(), "<abc> def");
+    assert_eq!(engine.eval::<String>(r#"regex("(?P<w>[a-z]+)").replace_all("abc def", "<$w>")"#).unwrap(), "<abc> <def>");
+    assert_eq!(engine.eval::<String>(r#"regex("[0-9]+").replace_all("a1 b22 c333", |n| n.len())"#).unwrap(), "a1 b2 c3");
+    assert_eq!(engine.eval::<String>(r#"regex("(?P<k>[a-z])=(?P<v>[0-9])").replace_all("a=1, b=2", |m, c| `${c.v}${c.k}`)"#).unwrap(), "1a, 2b");
+
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                r#"
+                    let m = regex("(?P<key>[a-z]+)=(?P<value>[0-9]+)(x)?").captures("x = 1, abc=42");
+                    if m["0"] != "abc=42" || m["1"] != "abc" || m["3"] != () { throw "bad captures"; }
+                    m.key.len() * 100 + parse_int(m.value)
+                "#
+            )
+            .unwrap(),
+        342
+    );
+    assert!(engine.eval::<bool>(r#"regex("[0-9]").captures("abc") == ()"#).unwrap());
+
+    assert!(matches!(*engine.run(r#"regex("a(b")"#).unwrap_err(), EvalAltResult::ErrorRuntime(..)));
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_regex_arrays() {
+    let engine = Engine::new();
+
+    let a = engine.eval::<Array>(r#"regex("[0-9]+").find_all("abc 123 def 456")"#).unwrap();
+    assert_eq!(a.into_iter().map(|v| v.into_string().unwrap()).collect::<Vec<_>>(), ["123", "456"]);
+
+    let a = engine.eval::<Array>(r#"regex("[, ]+").split("a, b,c  d")"#).unwrap();
+    assert_eq!(a.into_iter().map(|v| v.into_string().unwrap()).collect::<Vec<_>>(), ["a", "b", "c", "d"]);
+
+    let a = engine.eval::<Array>(r#""a, b,c  d".split(regex("[, ]+"))"#).unwrap();
+    assert_eq!(a.len(), 4);
+
+    assert_eq!(
+        engine
+            .eval::<String>(
+                r#"
+                    let s = "";
+                    for m in regex("(?P<k>[a-z]+)=(?P<v>[0-9]+)").captures_all("a=1, bc=22") {
+                        s += `${m.k}:${m.v};`;
+                    }
+                    s
+                "#
+            )
+            .unwrap(),
+        "a:1;bc:22;"
+    );
+}
+
+#[test]
+fn test_regex_cache() {
+    let mut engine = Engine::new();
+
+    assert_eq!(engine.max_regex_cached(), 64);
+
+    engine.set_max_regex_cached(2);
+    assert_eq!(engine.max_regex_cached(), 2);
+    assert!(engine.eval::<bool>(r#"let x = true; for p in ["a", "b", "c", "a", "b"] { x = x && regex(p).is_match(p); } x"#).unwrap());
+
+    engine.set_max_regex_cached(0);
+    assert_eq!(engine.max_regex_cached(), 0);
+    assert!(engine.eval::<bool>(r#"regex("a").is_match("a")"#).unwrap());
+}
+
+#[cfg(not(feature = "unchecked"))]
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_regex_limits() {
+    let mut engine = Engine::new();
+
+    engine.run(r#"regex("[a-z]{500}")"#).unwrap();
+
+    engine.set_max_regex_size(1000);
+    assert_eq!(engine.max_regex_size(), 1000);
+    assert!(matches!(*engine.run(r#"regex("[a-z]{500}")"#).unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+
+    engine.set_max_regex_size(0);
+    engine.set_max_expr_depths(5, 5);
+    assert!(matches!(*engine.run(r#"regex("((((((a))))))")"#).unwrap_err(), EvalAltResult::ErrorRuntime(..)));
+    engine.run(r#"regex("((a))")"#).unwrap();
+}
+
+#[test]
+fn test_regex_literal() {
+    let mut engine = Engine::new();
+
+    assert!(engine.compile("r/[a-z]+/").is_err());
+
+    engine.set_allow_regex_literal(true);
+
+    assert!(engine.eval::<bool>(r#"r/^[a-z]+$/.is_match("hello")"#).unwrap());
+    assert!(engine.eval::<bool>(r#"let re = r/a\/b/; re.is_match("a/b")"#).unwrap());
+    assert_eq!(engine.eval::<String>(r#"r/\d+/.pattern"#).unwrap(), r"\d+");
+    assert_eq!(engine.eval::<INT>("let r = 10; r / 2").unwrap(), 5);
+
+    assert!(matches!(engine.compile("r/a(b/").unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+    assert!(matches!(engine.compile("r/abc").unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+}
@@ -643,15 +643,24 @@ fn test_serde_json() -> serde_json::Result<()> {
 #[cfg(feature = "decimal")]
 #[cfg(not(feature = "no_float"))]
 fn test_serde_json_numbers() -> serde_json::Result<()> {
-    use std::str::FromStr;
-
     let d: Dynamic = serde_json::from_str("100000000000").unwrap();
     assert!(d.is::<INT>());
     assert_eq!(d.as_int().unwrap(), 100000000000);
 
     let d: Dynamic = serde_json::from_str("10000000000000000000").unwrap();
-    assert!(d.is::<Decimal>());
-    assert_eq!(d.as_decimal().unwrap(), Decimal::from_str("10000000000000000000").unwrap());
+    #[cfg(not(feature = "bigint"))]
+    {
+        use std::str::FromStr;
+
+        assert!(d.is::<Decimal>());
+        assert_eq!(d.as_decimal().unwrap(), Decimal::from_str("10000000000000000000").unwrap());
+    }
+    // Integers too large for `INT` are deserialized into `BigInt` instead
+    #[cfg(feature = "bigint")]
+    {
+        assert!(!d.is::<Decimal>());
+        assert_eq!(d.cast::<num_bigint::BigInt>(), num_bigint::BigInt::from(10_000_000_000_000_000_000_u64));
+    }
 
     let d: Dynamic = serde_json::from_str("10000000000000000000000000").unwrap();
     assert!(d.is::<FLOAT>());
@@ -756,3 +765,52 @@ fn test_serde_scope() {
     assert!(scope.get_value::<bool>("y").unwrap());
     assert_eq!(scope.get_value::<String>("z").unwrap(), "serde::test_serde_scope::TestStruct");
 }
+
+#[test]
+#[cfg(not(feature = "no_index"))]
+fn test_serde_set() {
+    let engine = Engine::new();
+
+    let set = engine.eval::<Dynamic>(r#"#[3, "x", 1, 3]"#).unwrap();
+    assert_eq!(serde_json::to_string(&set).unwrap(), r#"[1,3,"x"]"#);
+
+    let set: rhai::Set = serde_json::from_str(r#"[2, "y", 2, true]"#).unwrap();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&Dynamic::TRUE));
+
+    assert!(serde_json::from_str::<rhai::Set>("[1, [2]]").is_err());
+
+    let value = to_dynamic(set).unwrap();
+    assert!(value.is_array());
+    assert_eq!(value.into_array().unwrap().len(), 3);
+}
+
+#[test]
+#[cfg(not(feature = "no_index"))]
+fn test_serde_tuple() {
+    let engine = Engine::new();
+
+    let tuple = engine.eval::<Dynamic>(r#"(1, "x", [true])"#).unwrap();
+    assert_eq!(serde_json::to_string(&tuple).unwrap(), r#"[1,"x",[true]]"#);
+
+    let tuple: rhai::Tuple = serde_json::from_str(r#"[2, "y"]"#).unwrap();
+    assert_eq!(tuple.len(), 2);
+    assert_eq!(tuple[1].clone().into_string().unwrap(), "y");
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn test_serde_bigint() {
+    use num_bigint::BigInt;
+
+    let engine = Engine::new();
+
+    let x = engine.eval::<Dynamic>("#{a: 42n, b: 2n ** 100}").unwrap();
+    assert_eq!(serde_json::to_string(&x).unwrap(), r#"{"a":42,"b":"1267650600228229401496703205376"}"#);
+
+    let x = to_dynamic(u64::MAX).unwrap();
+    assert_eq!(x.cast::<BigInt>(), BigInt::from(u64::MAX));
+
+    let x: u128 = from_dynamic(&BigInt::from(u128::MAX).into()).unwrap();
+    assert_eq!(x, u128::MAX);
+}
@@ -0,0 +1,58 @@
+#![cfg(not(feature = "no_index"))]
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Set, INT};
+
+#[test]
+fn test_sets() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let s = #[3, 1, 2, 1, 3]; len(s)").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("let s = #[]; len(s)").unwrap(), 0);
+    assert!(engine.eval::<bool>("let s = #[]; is_empty(s)").unwrap());
+    assert!(engine.eval::<bool>("let x = 2; let s = #[1, x, 3,]; 2 in s").unwrap());
+    assert!(engine.eval::<bool>(r#"let s = #["a", 'b', true, ()]; "c" !in s"#).unwrap());
+    assert!(!engine.eval::<bool>("let s = #[1, 2, 3]; [1] in s").unwrap());
+    assert_eq!(engine.eval::<String>(r#"let s = #["b", "a", 42, "b"]; to_debug(s)"#).unwrap(), r#"#[42, "a", "b"]"#);
+    assert_eq!(engine.eval::<String>("type_of(#[1, 2])").unwrap(), "set");
+
+    assert!(engine.eval::<bool>("let s = #[1, 2]; insert(s, 3)").unwrap());
+    assert!(!engine.eval::<bool>("let s = #[1, 2]; insert(s, 2)").unwrap());
+    assert!(engine.eval::<bool>("let s = #[1, 2, 3]; remove(s, 2); s == #[1, 3]").unwrap());
+    assert!(engine.eval::<bool>("let s = #[1, 2, 3]; clear(s); s == #[]").unwrap());
+
+    assert!(matches!(*engine.run("let s = #[1, [2]];").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    #[cfg(not(feature = "no_object"))]
+    assert!(matches!(*engine.run("let s = #[1]; insert(s, #{});").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+
+    let set = engine.eval::<Set>("#[1, 2, 3]").unwrap();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&Dynamic::from(2 as INT)));
+}
+
+#[test]
+fn test_sets_algebra() {
+    let engine = Engine::new();
+
+    assert!(engine.eval::<bool>("union(#[1, 2, 3], #[3, 4]) == #[1, 2, 3, 4]").unwrap());
+    assert!(engine.eval::<bool>("intersection(#[1, 2, 3], #[3, 4]) == #[3]").unwrap());
+    assert!(engine.eval::<bool>("difference(#[1, 2, 3], #[3, 4]) == #[1, 2]").unwrap());
+    assert!(engine.eval::<bool>("is_subset(#[1, 2], #[1, 2, 3])").unwrap());
+    assert!(!engine.eval::<bool>("is_subset(#[1, 4], #[1, 2, 3])").unwrap());
+    assert!(engine.eval::<bool>("is_superset(#[1, 2, 3], #[3])").unwrap());
+    assert!(engine.eval::<bool>("#[1, 2] != #[1, 2, 3]").unwrap());
+
+    assert_eq!(
+        engine
+            .eval::<Array>("to_array(to_set([3, 1, 2, 1]))")
+            .unwrap()
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert_eq!(engine.eval::<INT>("let sum = 0; for x in #[1, 2, 3, 2, 1] { sum += x; } sum").unwrap(), 6);
+
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>(r#"#[3, "x", 1].to_json()"#).unwrap(), r#"[1,3,"x"]"#);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>(r#"#{a: #[2, 1]}.to_json()"#).unwrap(), r#"{"a":[1,2]}"#);
+}
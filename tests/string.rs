@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, ImmutableString, Scope, INT};
+use rhai::{Engine, EvalAltResult, ImmutableString, ParseErrorType, Scope, INT};
 
 #[test]
 fn test_string() {
@@ -219,6 +219,67 @@ fn test_string_format() {
     assert_eq!(engine.eval::<String>(r#"let x = [new_ts()]; "foo" + x"#).unwrap(), "foo[!!!TS=42!!!]");
 }
 
+#[test]
+fn test_string_format_fn() {
+    let mut engine = Engine::new();
+
+    assert_eq!(engine.eval::<String>(r#"format("{:>6}|{:<6}|{:^7}|{:*^7}", 42, "ab", "abc", 1)"#).unwrap(), "    42|ab    |  abc  |***1***");
+    assert_eq!(engine.eval::<String>(r#"format("{:x} {:X} {:#06x} {:b} {:#o} {:+}", 255, 255, 255, 5, 8, 3)"#).unwrap(), "ff FF 0x00ff 101 0o10 +3");
+    assert_eq!(engine.eval::<String>(r#"format("{:05}|{:.2}|{:?}", -42, "hello", "hi")"#).unwrap(), r#"-0042|he|"hi""#);
+    assert_eq!(engine.eval::<String>(r#"format("{1}{0}{1} {{}}", "a", "b")"#).unwrap(), "bab {}");
+    assert_eq!(engine.eval::<String>(r#"format("no args")"#).unwrap(), "no args");
+    #[cfg(not(feature = "no_float"))]
+    assert_eq!(engine.eval::<String>(r#"format("{:>8.2}|{:.1e}|{}", 3.14159, 1234.5, 0.5)"#).unwrap(), "    3.14|1.2e3|0.5");
+
+    assert!(matches!(*engine.run(r#"format("{:x}", "hello")"#).unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.run(r#"let f = "{} {}"; format(f, 1)"#).unwrap_err(), EvalAltResult::ErrorRuntime(..)));
+
+    assert!(matches!(engine.compile(r#"format("{:q}", 1)"#).unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+    assert!(matches!(engine.compile(r#"format("{1}", 1)"#).unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+    assert!(matches!(engine.compile(r#"format("{", 1)"#).unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+
+    #[derive(Debug, Clone)]
+    struct TestStruct {
+        field: INT,
+    }
+
+    engine
+        .register_type_with_name::<TestStruct>("TestStruct")
+        .register_fn("new_ts", || TestStruct { field: 42 })
+        .register_fn("to_string", |ts: TestStruct| format!("TS={}", ts.field))
+        .register_fn("to_debug", |ts: TestStruct| format!("!!!TS={}!!!", ts.field));
+
+    assert_eq!(engine.eval::<String>(r#"format("[{:>7}] {:?}", new_ts(), new_ts())"#).unwrap(), "[  TS=42] !!!TS=42!!!");
+}
+
+#[test]
+fn test_string_interpolated_format_spec() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<String>("let n = 5; `[${n:08b}] [${n * 51:#x}] [${n:>3}]`").unwrap(), "[00000101] [0xff] [  5]");
+    assert_eq!(engine.eval::<String>(r#"let s = "abc"; `${s:?} ${s:-^7} ${ len(s) :03}`"#).unwrap(), r#""abc" --abc-- 003"#);
+    #[cfg(not(feature = "no_float"))]
+    assert_eq!(engine.eval::<String>("let x = 3.14159; `x = ${x:.2}!`").unwrap(), "x = 3.14!");
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>("let m = #{a: 42}; `${m.a:>4}|${#{b: 1}.b}`").unwrap(), "  42|1");
+
+    assert!(matches!(engine.compile("let x = 1; `${x:.}`").unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+    assert!(matches!(engine.compile("let x = 1; `${x:z}`").unwrap_err().err_type(), ParseErrorType::BadInput(..)));
+    assert!(engine.compile("let x = 1; `${x:5").is_err());
+    assert!(engine.compile("let x = 1; `${{x:5}}`").is_err());
+
+    // Variables with format specifiers are captured by closures and checked like any other
+    #[cfg(not(feature = "no_closure"))]
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<String>("let x = 5; let f = |a| `${x:03}${a:>2}`; call(f, 1)").unwrap(), "005 1");
+
+    let mut engine = engine;
+    engine.set_strict_variables(true);
+
+    assert_eq!(engine.eval::<String>("let x = 5; `${x:03}`").unwrap(), "005");
+    assert!(matches!(engine.compile("`${undefined:03}`").unwrap_err().err_type(), ParseErrorType::VariableUndefined(..)));
+}
+
 #[test]
 fn test_string_fn() {
     let mut engine = Engine::new();
@@ -95,3 +95,74 @@ fn test_try_catch() {
     #[cfg(not(feature = "unchecked"))]
     assert!(matches!(*engine.run("try { 42/0; } catch { throw; }").expect_err("expects error"), EvalAltResult::ErrorArithmetic(..)));
 }
+
+#[test]
+fn test_try_catch_finally() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let x = 0; try { throw 42; } catch (e) { x = e } finally { x += 1 } x").unwrap(), 43);
+    assert_eq!(engine.eval::<INT>("let x = 0; try { x = 1 } finally { x += 10 } x").unwrap(), 11);
+    assert_eq!(engine.eval::<INT>("let n = 0; try { try { throw 1 } finally { n += 1 } } catch { n += 10 } n").unwrap(), 11);
+    assert_eq!(engine.eval::<INT>("let n = 0; for i in 0..5 { try { if i == 2 { break; } } finally { n += 1 } } n").unwrap(), 3);
+
+    #[cfg(not(feature = "no_function"))]
+    #[cfg(not(feature = "no_index"))]
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("let log = []; fn f() { try { return 1; } finally { this.push(1) } 2 } log.f() + log.len()").unwrap(), 2);
+
+    assert!(engine.compile("try { }").is_err());
+}
+
+#[test]
+fn test_try_catch_filters() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>(r#"let r = 0; try { throw "x" } catch (e: int) { r = 1 } catch (e: string) { r = 2 } r"#).unwrap(), 2);
+    assert_eq!(
+        engine
+            .eval::<INT>("let r = 0; try { throw 42 } catch (e if e > 100) { r = 1 } catch (e if e > 10) { r = 2 } catch { r = 3 } r")
+            .unwrap(),
+        2
+    );
+
+    #[cfg(not(feature = "unchecked"))]
+    assert_eq!(
+        engine
+            .eval::<INT>("let r = 0; try { let x = 1 / 0; } catch (e: string) { r = 1 } catch (e: ErrorArithmetic) { r = 2 } r")
+            .unwrap(),
+        2
+    );
+
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(
+        engine
+            .eval::<INT>("let r = 0; try { throw #{code: 404} } catch (e: map if e.code == 500) { r = 1 } catch (e if e.code == 404) { r = 2 } r")
+            .unwrap(),
+        2
+    );
+
+    // Uncaught errors are re-thrown
+    assert!(matches!(*engine.run("try { throw 42 } catch (e: string) { }").unwrap_err(), EvalAltResult::ErrorRuntime(v, ..) if v.as_int().unwrap() == 42));
+    assert_eq!(
+        engine
+            .eval::<INT>("let n = 0; try { try { throw 42 } catch (e: string) { } finally { n += 1 } } catch (e) { n += e } n")
+            .unwrap(),
+        43
+    );
+
+    assert!(engine.compile("try { } catch (e if 42) { }").is_err());
+}
+
+#[cfg(not(feature = "unchecked"))]
+#[test]
+fn test_try_catch_uncatchable() {
+    let mut engine = Engine::new();
+
+    engine.set_max_operations(100);
+
+    assert!(matches!(*engine.eval::<INT>("let n = 0; try { loop { } } catch { n = 1 } finally { n = 2 } n").unwrap_err(), EvalAltResult::ErrorTooManyOperations(..)));
+
+    engine.on_progress(|_| Some(42.into()));
+
+    assert!(matches!(*engine.run("try { loop { } } catch { } finally { }").unwrap_err(), EvalAltResult::ErrorTerminated(..)));
+}
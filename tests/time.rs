@@ -121,3 +121,94 @@ fn test_timestamp_op() {
     #[cfg(not(feature = "unchecked"))]
     let _ = engine.run("timestamp()-24>>-60");
 }
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_duration() {
+    use std::time::Duration;
+
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<Duration>("seconds(90)").unwrap(), Duration::from_secs(90));
+    assert_eq!(engine.eval::<Duration>("milliseconds(1500) + minutes(1)").unwrap(), Duration::from_millis(61_500));
+    assert_eq!(engine.eval::<Duration>("hours(1) - minutes(15)").unwrap(), Duration::from_secs(45 * 60));
+    assert_eq!(engine.eval::<Duration>("days(2) * 3 / 4").unwrap(), Duration::from_secs(36 * 3600));
+    assert_eq!(engine.eval::<rhai::INT>("weeks(1).as_days()").unwrap(), 7);
+    assert_eq!(engine.eval::<rhai::INT>("seconds(2).as_millis()").unwrap(), 2000);
+    assert_eq!(engine.eval::<rhai::INT>("microseconds(3).as_nanos()").unwrap(), 3000);
+    assert_eq!(engine.eval::<String>("type_of(seconds(1))").unwrap(), "duration");
+    assert_eq!(engine.eval::<String>("milliseconds(1500).to_string()").unwrap(), "1.5s");
+    assert!(engine.eval::<bool>("seconds(60) == minutes(1) && seconds(1) < seconds(2) && nanoseconds(0).is_zero").unwrap());
+
+    assert!(engine.run("seconds(-1)").is_err());
+    assert!(engine.run("seconds(1) - seconds(2)").is_err());
+    assert!(engine.run("seconds(1) / 0").is_err());
+
+    #[cfg(not(feature = "no_float"))]
+    {
+        assert_eq!(engine.eval::<Duration>("seconds(0.25)").unwrap(), Duration::from_millis(250));
+        assert_eq!(engine.eval::<FLOAT>("minutes(1).as_seconds()").unwrap(), 60.0);
+        assert_eq!(engine.eval::<FLOAT>("minutes(1) / seconds(40)").unwrap(), 1.5);
+    }
+
+    #[cfg(not(feature = "no_float"))]
+    assert!(
+        (engine
+            .eval::<FLOAT>(
+                "
+                    let time1 = timestamp();
+                    let time2 = time1 + seconds(42);
+                    time2 - time1
+                "
+            )
+            .unwrap()
+            - 42.0)
+            .abs()
+            < 0.001
+    );
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_datetime() {
+    use rhai::{DateTime, INT};
+
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<String>("datetime(2024, 2, 29).to_string()").unwrap(), "2024-02-29T00:00:00Z");
+    assert_eq!(engine.eval::<String>("type_of(datetime(2024, 2, 29))").unwrap(), "datetime");
+    assert_eq!(engine.eval::<INT>("from_unix(0).year").unwrap(), 1970);
+    assert_eq!(engine.eval::<INT>("datetime(2000, 1, 1, 12, 30, 15).unix").unwrap(), 946_729_815);
+    assert_eq!(engine.eval::<String>("from_unix(-1).to_string()").unwrap(), "1969-12-31T23:59:59Z");
+    assert_eq!(engine.eval::<String>("from_unix_millis(1500).to_string()").unwrap(), "1970-01-01T00:00:01.5Z");
+    assert_eq!(engine.eval::<INT>("from_unix_millis(-1500).unix_millis").unwrap(), -1500);
+
+    let d = engine.eval::<DateTime>(r#"parse_datetime("2024-03-10T23:15:30.25+05:30")"#).unwrap();
+    assert_eq!(d.date(), (2024, 3, 10));
+    assert_eq!(d.time_of_day(), (23, 15, 30, 250_000_000));
+    assert_eq!(d.offset(), 19800);
+    assert_eq!(d.to_string(), "2024-03-10T23:15:30.25+05:30");
+
+    assert_eq!(engine.eval::<String>(r#"parse_datetime("2024-03-10T23:15:30+05:30").to_utc().to_string()"#).unwrap(), "2024-03-10T17:45:30Z");
+    assert_eq!(engine.eval::<String>(r#"parse_datetime("2024-03-10 20:00").with_offset("-0800").to_string()"#).unwrap(), "2024-03-10T12:00:00-08:00");
+    assert_eq!(engine.eval::<INT>("datetime(2024, 3, 10).weekday").unwrap(), 7);
+    assert_eq!(engine.eval::<INT>("datetime(2024, 12, 31).day_of_year").unwrap(), 366);
+    assert_eq!(engine.eval::<INT>("datetime(2023, 12, 31, 23, 0, 0).with_offset(3600).year").unwrap(), 2024);
+    assert!(engine.eval::<bool>(r#"parse_datetime("2024-01-01T01:00+01:00") == datetime(2024, 1, 1)"#).unwrap());
+
+    assert_eq!(engine.eval::<String>("datetime(2024, 1, 31).add_months(1).to_string()").unwrap(), "2024-02-29T00:00:00Z");
+    assert_eq!(engine.eval::<String>("datetime(2024, 2, 29).add_years(-1).to_string()").unwrap(), "2023-02-28T00:00:00Z");
+    assert_eq!(engine.eval::<String>("(datetime(2024, 2, 28) + hours(36)).to_string()").unwrap(), "2024-02-29T12:00:00Z");
+    assert_eq!(engine.eval::<INT>("(datetime(2024, 3, 1) - datetime(2024, 2, 1)).as_days()").unwrap(), 29);
+    assert!(engine.eval::<bool>("datetime(2024, 1, 1) < datetime(2024, 1, 2)").unwrap());
+
+    assert!(engine.run("datetime(2023, 2, 29)").is_err());
+    assert!(engine.run("datetime(2024, 1, 1, 24, 0, 0)").is_err());
+    assert!(engine.run(r#"parse_datetime("2024-1-1")"#).is_err());
+    assert!(engine.run(r#"parse_datetime("2024-01-01T10:00+25:00")"#).is_err());
+    assert!(engine.run("datetime(2024, 1, 1) - datetime(2024, 1, 2)").is_err());
+
+    assert_eq!(engine.eval::<String>("#{d: datetime(2024, 2, 29)}.to_json()").unwrap(), r#"{"d":"2024-02-29T00:00:00Z"}"#);
+
+    assert!(engine.eval::<INT>("now().year").unwrap() >= 2024);
+}
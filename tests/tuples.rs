@@ -0,0 +1,100 @@
+#![cfg(not(feature = "no_index"))]
+use rhai::{Array, Dynamic, Engine, EvalAltResult, ParseErrorType, Tuple, INT};
+use std::convert::TryInto;
+
+#[test]
+fn test_tuples() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let t = (1, 2, 3); len(t)").unwrap(), 3);
+    assert_eq!(engine.eval::<INT>("let t = (42,); len(t)").unwrap(), 1);
+    assert_eq!(engine.eval::<INT>("let t = (42); t").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("let x = 2; let t = (1, x * 10, 3,); t[1]").unwrap(), 20);
+    assert_eq!(engine.eval::<String>(r#"let t = (1, "a", true); t[-2]"#).unwrap(), "a");
+    assert_eq!(engine.eval::<String>(r#"let t = (1, "a", (true,)); to_debug(t)"#).unwrap(), r#"(1, "a", (true,))"#);
+    assert_eq!(engine.eval::<String>("type_of((1, 2))").unwrap(), "tuple");
+
+    assert!(engine.eval::<bool>("(1, 2) == (1, 2)").unwrap());
+    assert!(engine.eval::<bool>("(1, 2) != (2, 1)").unwrap());
+    assert!(engine.eval::<bool>("(1, 2) != (1, 2, 3)").unwrap());
+    assert!(engine.eval::<bool>("let t = (1, [2]); t == (1, [2])").unwrap());
+
+    assert!(matches!(*engine.run("let t = (1, 2); t[2]").unwrap_err(), EvalAltResult::ErrorArrayBounds(2, 2, ..)));
+    assert!(engine.run("let t = (1, 2); t[0] = 42;").is_err());
+    assert!(engine.run("let t = (1, 2); push(t, 3);").is_err());
+
+    assert_eq!(engine.eval::<INT>("let sum = 0; for x in (1, 2, 3) { sum += x; } sum").unwrap(), 6);
+    assert_eq!(engine.eval::<Array>("to_array((1, 2, 3))").unwrap().len(), 3);
+    assert!(engine.eval::<bool>("to_tuple([1, 2]) == (1, 2)").unwrap());
+
+    assert!(engine.eval::<bool>(r#"let s = #[(1, "a"), (2, "b"), (1, "a")]; len(s) == 2 && (1, "a") in s"#).unwrap());
+    assert_eq!(
+        engine
+            .eval::<String>(
+                r#"
+                    switch (1, "x") {
+                        (1, "y") => "wrong",
+                        (1, "x") => "right",
+                        _ => "default"
+                    }
+                "#
+            )
+            .unwrap(),
+        "right"
+    );
+
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>(r#"#{a: (1, "x")}.to_json()"#).unwrap(), r#"{"a":[1,"x"]}"#);
+}
+
+#[test]
+fn test_tuples_destructure() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let (a, b) = (40, 2); a + b").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("let a = 1; let b = 2; let (a, b) = (b, a); a * 10 + b").unwrap(), 21);
+    assert_eq!(engine.eval::<INT>("let (x, y) = (1, 2); x = 42; x + y").unwrap(), 44);
+    assert_eq!(engine.eval::<String>(r#"let t = ("a", 'b'); let (x, y) = t; x + y"#).unwrap(), "ab");
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine
+            .eval::<INT>(
+                "
+                    fn div_rem(a, b) { (a / b, a % b) }
+                    let (q, r) = div_rem(17, 5);
+                    q * 10 + r
+                "
+            )
+            .unwrap(),
+        32
+    );
+
+    assert!(matches!(
+        *engine.run("const (x, y) = (1, 2); x = 42;").unwrap_err(),
+        EvalAltResult::ErrorParsing(ParseErrorType::AssignmentToConstant(x), ..) if x == "x"
+    ));
+    assert!(matches!(*engine.run("let (x, y) = (1, 2, 3);").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.run("let (x, y) = [1, 2];").unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.compile("let (x, x) = (1, 2);").unwrap_err().0, ParseErrorType::DuplicatedVariable(..)));
+    assert!(matches!(*engine.compile("let (x, y);").unwrap_err().0, ParseErrorType::MissingToken(..)));
+}
+
+#[test]
+fn test_tuples_rust() {
+    let engine = Engine::new();
+
+    let (a, s, b): (INT, String, bool) = engine.eval::<Dynamic>(r#"(42, "hello", true)"#).unwrap().try_into().unwrap();
+    assert_eq!(a, 42);
+    assert_eq!(s, "hello");
+    assert!(b);
+
+    let tuple = engine.eval::<Tuple>("(1, 2)").unwrap();
+    assert_eq!(tuple.len(), 2);
+    assert!(TryInto::<(INT, INT, INT)>::try_into(tuple).is_err());
+
+    let mut engine = Engine::new();
+    engine.register_fn("min_max", |x: INT, y: INT| -> Dynamic { (x.min(y), x.max(y)).into() });
+
+    assert_eq!(engine.eval::<INT>("let (lo, hi) = min_max(9, 2); hi - lo").unwrap(), 7);
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, INT};
+use rhai::{Engine, EvalAltResult, ParseErrorType, INT};
 
 #[test]
 fn test_type_of() {
@@ -47,3 +47,210 @@ fn test_type_of() {
     #[cfg(feature = "only_i32")]
     assert_eq!(engine.eval::<String>("let x = 123; type_of(x)").unwrap(), "i32");
 }
+
+#[test]
+fn test_type_annotations() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let x: int = 40 + 2; x").unwrap(), 42);
+    assert_eq!(engine.eval::<String>(r#"const NAME: string = "hello"; NAME"#).unwrap(), "hello");
+    assert_eq!(engine.eval::<INT>("let x: Dynamic = 42; x").unwrap(), 42);
+
+    assert!(matches!(
+        engine.compile("let x: int = true;").unwrap_err().err_type(),
+        ParseErrorType::MismatchedType(expected, actual) if expected == "int" && actual == "bool"
+    ));
+    assert!(matches!(engine.compile("let x: = 42;").unwrap_err().err_type(), ParseErrorType::MissingSymbol(..)));
+    assert!(matches!(engine.compile("let x: int;").unwrap_err().err_type(), ParseErrorType::MissingToken(t, ..) if t == "="));
+    assert!(matches!(engine.compile("const X: int;").unwrap_err().err_type(), ParseErrorType::MissingToken(t, ..) if t == "="));
+
+    assert!(matches!(
+        *engine.run(r#"let s = "hello"; let x: int = len(s) > 0;"#).unwrap_err(),
+        EvalAltResult::ErrorMismatchDataType(expected, actual, ..) if expected == "int" && actual == "bool"
+    ));
+
+    #[cfg(not(feature = "no_object"))]
+    {
+        #[derive(Clone)]
+        struct TestStruct;
+
+        let mut engine = Engine::new();
+        engine.register_type_with_name::<TestStruct>("Hello").register_fn("new_ts", || TestStruct);
+
+        engine.run("let x: Hello = new_ts();").unwrap();
+        assert!(matches!(
+            *engine.run("let x: string = new_ts();").unwrap_err(),
+            EvalAltResult::ErrorMismatchDataType(expected, actual, ..) if expected == "string" && actual == "Hello"
+        ));
+    }
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_type_annotations_functions() {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("fn add(x: int, y: int) -> int { x + y } add(40, 2)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("fn add(x: int, y) { x + y } add(40, 2)").unwrap(), 42);
+    assert_eq!(engine.eval::<()>("fn nothing() -> () { } nothing()").unwrap(), ());
+
+    assert!(matches!(
+        *engine.run(r#"fn add(x: int, y: int) -> int { x + y } add(40, "2")"#).unwrap_err(),
+        EvalAltResult::ErrorMismatchDataType(expected, actual, ..) if expected == "int" && actual == "string"
+    ));
+    assert!(matches!(
+        *engine.run("fn foo(x) -> string { x } foo(42)").unwrap_err(),
+        EvalAltResult::ErrorMismatchDataType(expected, ..) if expected == "string"
+    ));
+    assert!(matches!(
+        *engine.run("fn foo() -> int { } foo()").unwrap_err(),
+        EvalAltResult::ErrorMismatchDataType(expected, actual, ..) if expected == "int" && actual == "()"
+    ));
+
+    #[cfg(not(feature = "no_float"))]
+    {
+        let ast = engine.compile("fn area(w: float, h: float) -> float { w * h }").unwrap();
+        let f = ast.iter_functions().next().unwrap();
+
+        assert_eq!(f.param_types, [Some("float"), Some("float")]);
+        assert_eq!(f.return_type, Some("float"));
+        assert_eq!(f.to_string(), "area(w: float, h: float) -> float");
+
+        assert_eq!(engine.call_fn::<rhai::FLOAT>(&mut rhai::Scope::new(), &ast, "area", (2.0 as rhai::FLOAT, 3.0 as rhai::FLOAT)).unwrap(), 6.0);
+        assert!(engine.call_fn::<rhai::FLOAT>(&mut rhai::Scope::new(), &ast, "area", (2 as INT, 3.0 as rhai::FLOAT)).is_err());
+    }
+}
+
+#[test]
+fn test_type_check() {
+    let mut engine = Engine::new();
+
+    engine.register_fn("add", |x: INT, y: INT| x + y);
+    engine.register_fn("add", |x: INT, y: INT, z: INT| x + y + z);
+
+    let ast = engine.compile("let x = add(40, 2); add(x, 1, 2)").unwrap();
+    assert!(engine.type_check(&ast).is_empty());
+
+    // Wrong number of arguments
+    let ast = engine.compile("add(1)").unwrap();
+    let errors = engine.type_check(&ast);
+    assert_eq!(errors.len(), 1);
+    // Package functions with the same name may also be suggested
+    #[cfg(not(feature = "metadata"))]
+    let expected = ["add(_, _)".to_string(), "add(_, _, _)".to_string()];
+    #[cfg(feature = "metadata")]
+    let expected = {
+        let int = std::any::type_name::<INT>();
+        [format!("add(_: {int}, _: {int}) -> {int}"), format!("add(_: {int}, _: {int}, _: {int}) -> {int}")]
+    };
+    assert!(matches!(&*errors[0], EvalAltResult::ErrorFunctionNotFound(sig, suggestions, ..) if sig.starts_with("add (") && expected.iter().all(|s| suggestions.contains(s))));
+
+    // No matching overload
+    let ast = engine.compile(r#"let x = 40; let y = x; add(y, "hello")"#).unwrap();
+    let errors = engine.type_check(&ast);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&*errors[0], EvalAltResult::ErrorFunctionNotFound(sig, ..) if sig == "add (i64, &str | ImmutableString | String)" || sig == "add (i32, &str | ImmutableString | String)"));
+    assert!(engine.run_ast(&ast).is_err());
+
+    // Return types of registered functions are only available with metadata
+    #[cfg(feature = "metadata")]
+    {
+        let ast = engine.compile(r#"let x = add(40, 2); add(x, "hello")"#).unwrap();
+        assert_eq!(engine.type_check(&ast).len(), 1);
+    }
+
+    // Variables that are re-assigned have no fixed type
+    let ast = engine.compile(r#"let x = "hello"; x = 42; add(x, 1)"#).unwrap();
+    assert!(engine.type_check(&ast).is_empty());
+
+    // Unknown functions are not checked
+    let ast = engine.compile("foo(1, 2, 3)").unwrap();
+    assert!(engine.type_check(&ast).is_empty());
+
+    // Errors inside blocks and expressions
+    let ast = engine.compile(r#"let x = 1; if x > 0 { let y = 1 + add(1, "a"); } else { add() }"#).unwrap();
+    assert_eq!(engine.type_check(&ast).len(), 2);
+
+    // Type annotations
+    let ast = engine.compile("let x = 40 + 2; let y: string = x;").unwrap();
+    let errors = engine.type_check(&ast);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&*errors[0], EvalAltResult::ErrorMismatchDataType(expected, ..) if expected == "string"));
+
+    // Scope constants
+    let mut scope = rhai::Scope::new();
+    scope.push_constant("NAME", "hello");
+    scope.push("counter", 42 as INT);
+
+    let ast = engine.compile("add(NAME, 1)").unwrap();
+    assert_eq!(engine.type_check_with_scope(&scope, &ast).len(), 1);
+    assert!(engine.type_check(&ast).is_empty());
+
+    let ast = engine.compile("add(counter, 1)").unwrap();
+    assert!(engine.type_check_with_scope(&scope, &ast).is_empty());
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_type_check_functions() {
+    let engine = Engine::new();
+
+    let ast = engine.compile("fn area(w: int, h: int) -> int { w * h } fn foo(x) { x } area(2, 3); foo(1)").unwrap();
+    assert!(engine.type_check(&ast).is_empty());
+
+    let ast = engine.compile(r#"fn area(w: int, h: int) -> int { w * h } area(2, "3")"#).unwrap();
+    let errors = engine.type_check(&ast);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&*errors[0], EvalAltResult::ErrorFunctionNotFound(..)));
+
+    let ast = engine.compile("fn foo(x) { x } foo(1, 2)").unwrap();
+    assert_eq!(engine.type_check(&ast).len(), 1);
+
+    // Return type annotations
+    let ast = engine.compile(r#"fn foo(x: int) -> string { if x > 0 { return x + 1; } "hello" }"#).unwrap();
+    let errors = engine.type_check(&ast);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&*errors[0], EvalAltResult::ErrorMismatchDataType(expected, ..) if expected == "string"));
+
+    // Return types of script-defined functions are inferred
+    let ast = engine.compile(r#"fn foo() -> string { "hello" } let x: int = foo();"#).unwrap();
+    assert_eq!(engine.type_check(&ast).len(), 1);
+}
+
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_type_check_properties() {
+    #[derive(Clone)]
+    struct TestStruct {
+        x: INT,
+    }
+
+    let mut engine = Engine::new();
+
+    engine
+        .register_type_with_name::<TestStruct>("TestStruct")
+        .register_fn("new_ts", || TestStruct { x: 1 })
+        .register_get_set("x", |t: &mut TestStruct| t.x, |t: &mut TestStruct, v: INT| t.x = v)
+        .register_fn("double", |t: &mut TestStruct| t.x * 2);
+
+    let mut scope = rhai::Scope::new();
+    scope.push_constant("t", TestStruct { x: 1 });
+
+    let ast = engine.compile("let t2 = new_ts(); t2.x = 42; t.double() + t.x").unwrap();
+    assert!(engine.type_check_with_scope(&scope, &ast).is_empty());
+
+    let ast = engine.compile("t.y = 42; t.z").unwrap();
+    let errors = engine.type_check_with_scope(&scope, &ast);
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|err| matches!(**err, EvalAltResult::ErrorDotExpr(..))));
+
+    let ast = engine.compile(r#"t.double(1); "hello".len; "hello".foo"#).unwrap();
+    let errors = engine.type_check_with_scope(&scope, &ast);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&*errors[0], EvalAltResult::ErrorFunctionNotFound(..)));
+    assert!(matches!(&*errors[1], EvalAltResult::ErrorDotExpr(..)));
+
+    // Object map properties are not checked
+    let ast = engine.compile("let m = #{a: 1}; m.b; m.foo(1)").unwrap();
+    assert!(engine.type_check(&ast).is_empty());
+}
@@ -344,13 +344,13 @@ fn test_var_resolver1() {
             #[cfg(not(feature = "no_closure"))]
             "HELLO" => Ok(Some(shared.clone())),
             // Override a variable - make it not found even if it exists!
-            "DO_NOT_USE" => Err(EvalAltResult::ErrorVariableNotFound(name.to_string(), Position::NONE).into()),
+            "DO_NOT_USE" => Err(EvalAltResult::ErrorVariableNotFound(name.to_string(), Vec::new(), Position::NONE).into()),
             // Silently maps 'chameleon' into 'innocent'.
             "chameleon" => context
                 .scope()
                 .get_value("innocent")
                 .map(Some)
-                .ok_or_else(|| EvalAltResult::ErrorVariableNotFound(name.to_string(), Position::NONE).into()),
+                .ok_or_else(|| EvalAltResult::ErrorVariableNotFound(name.to_string(), Vec::new(), Position::NONE).into()),
             // Return Ok(None) to continue with the normal variable resolution process.
             _ => Ok(None),
         }
@@ -452,3 +452,20 @@ fn test_var_scope_cloning() {
     engine.run_with_scope(&mut scope, "let x = 42; print(x + foo.field);").unwrap();
     assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "let x = 42; x + foo.field").unwrap(), 43);
 }
+
+#[test]
+fn test_var_suggestions() {
+    let engine = Engine::new();
+    let mut scope = Scope::new();
+
+    scope.push("counter", 42 as INT);
+
+    assert!(matches!(
+        *engine.eval_with_scope::<INT>(&mut scope, "let total = 1; countr + totl").unwrap_err(),
+        EvalAltResult::ErrorVariableNotFound(v, x, ..) if v == "countr" && x == ["counter"]
+    ));
+    assert!(matches!(
+        *engine.eval_with_scope::<INT>(&mut scope, "let total = 1; counter + totl").unwrap_err(),
+        EvalAltResult::ErrorVariableNotFound(v, x, ..) if v == "totl" && x == ["total"]
+    ));
+}
@@ -0,0 +1,118 @@
+#![cfg(not(feature = "no_float"))]
+#![cfg(not(feature = "no_index"))]
+#![cfg(not(feature = "no_object"))]
+use rhai::packages::{Package, VectorMathPackage};
+use rhai::{Engine, EvalAltResult, Mat, Vec2, Vec3, Vector, FLOAT, INT};
+
+fn make_engine() -> Engine {
+    let mut engine = Engine::new();
+    VectorMathPackage::new().register_into_engine(&mut engine);
+    engine
+}
+
+#[test]
+fn test_vectors() {
+    let engine = make_engine();
+
+    assert_eq!(engine.eval::<Vec3>("vec3(1, 2.5, 3)").unwrap(), Vector([1.0, 2.5, 3.0]));
+    assert_eq!(engine.eval::<String>("type_of(vec2(0, 0))").unwrap(), "vec2");
+    assert_eq!(engine.eval::<String>("vec3(1, 2, 3).to_string()").unwrap(), "vec3(1.0, 2.0, 3.0)");
+    assert_eq!(engine.eval::<Vec2>("vec2(1, 2) + vec2(3, 4) * 2 - vec2(1, 1) / 2").unwrap(), Vector([6.5, 9.5]));
+    assert_eq!(engine.eval::<Vec2>("let v = vec2(1, 2); v += vec2(1, 1); -(2 * v)").unwrap(), Vector([-4.0, -6.0]));
+    assert_eq!(engine.eval::<FLOAT>("vec3(1, 2, 3).dot(vec3(4, 5, 6))").unwrap(), 32.0);
+    assert_eq!(engine.eval::<Vec3>("vec3(1, 0, 0).cross(vec3(0, 1, 0))").unwrap(), Vector([0.0, 0.0, 1.0]));
+    assert_eq!(engine.eval::<FLOAT>("vec2(3, 4).length").unwrap(), 5.0);
+    assert_eq!(engine.eval::<FLOAT>("vec2(3, 4).length_squared").unwrap(), 25.0);
+    assert_eq!(engine.eval::<FLOAT>("vec2(1, 1).distance(vec2(4, 5))").unwrap(), 5.0);
+    assert_eq!(engine.eval::<Vec2>("vec2(0, 5).normalize()").unwrap(), Vector([0.0, 1.0]));
+    assert!(engine.eval::<bool>("vec4(1, 2, 3, 4) == vec4(1, 2, 3, 4)").unwrap());
+    assert!(engine.eval::<bool>("vec4(1, 2, 3, 4) != vec4(1, 2, 3, 5)").unwrap());
+
+    assert_eq!(engine.eval::<FLOAT>("let v = vec4(1, 2, 3, 4); v.x + v.y * 10 + v.z * 100 + v.w * 1000").unwrap(), 4321.0);
+    assert_eq!(engine.eval::<Vec3>("let v = vec3(0, 0, 0); v.x = 1; v.y = 2.5; v.z += 3; v").unwrap(), Vector([1.0, 2.5, 3.0]));
+    assert_eq!(engine.eval::<FLOAT>("let v = vec3(1, 2, 3); v[0] + v[-1]").unwrap(), 4.0);
+    assert_eq!(engine.eval::<Vec2>("let v = vec2(1, 2); v[1] = 42; v").unwrap(), Vector([1.0, 42.0]));
+    assert_eq!(engine.eval::<String>("vec2(1, 2).to_array().to_string()").unwrap(), "[1.0, 2.0]");
+
+    assert!(matches!(*engine.run("vec2(0, 0).normalize()").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("vec2(1, 2)[2]").unwrap_err(), EvalAltResult::ErrorArrayBounds(2, 2, ..)));
+    assert!(matches!(*engine.run(r#"vec2(1, "x")"#).unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(engine.run("vec2(1, 2) + vec3(1, 2, 3)").is_err());
+    assert!(engine.run("vec2(1, 2).z").is_err());
+}
+
+#[test]
+fn test_matrices() {
+    let engine = make_engine();
+
+    assert_eq!(engine.eval::<String>("type_of(mat_identity(3))").unwrap(), "mat");
+    assert_eq!(engine.eval::<String>("mat([[1, 2], [3, 4]]).to_string()").unwrap(), "mat([[1.0, 2.0], [3.0, 4.0]])");
+    assert_eq!(engine.eval::<String>("mat([[1, 2, 3]]).transpose().to_string()").unwrap(), "mat([[1.0], [2.0], [3.0]])");
+    assert_eq!(engine.eval::<String>("mat([[1, 2], [3, 4]]).to_array().to_string()").unwrap(), "[[1.0, 2.0], [3.0, 4.0]]");
+
+    let m = engine.eval::<Mat>("mat([[1, 2], [3, 4]]) * mat([[5, 6], [7, 8]])").unwrap();
+    assert_eq!(m.as_slice(), [19.0, 22.0, 43.0, 50.0]);
+    let m = engine.eval::<Mat>("2 * (mat_identity(2) + mat([[0, 1], [1, 0]])) - mat_zeros(2, 2)").unwrap();
+    assert_eq!(m.as_slice(), [2.0, 2.0, 2.0, 2.0]);
+    let m = engine.eval::<Mat>("let m = mat_zeros(2, 3); m.set(1, -1, 42); m").unwrap();
+    assert_eq!((m.rows(), m.cols(), m.get(1, 2)), (2, 3, 42.0));
+
+    assert!((engine.eval::<FLOAT>("mat([[1, 2], [3, 4]]).determinant()").unwrap() + 2.0).abs() < 1e-5);
+    assert!((engine.eval::<FLOAT>("mat([[2, 0, 0], [0, 3, 0], [0, 0, 4]]).determinant()").unwrap() - 24.0).abs() < 1e-5);
+    assert!((engine.eval::<FLOAT>("let m = mat([[4, 7], [2, 6]]); (m * m.inverse()).get(1, 1)").unwrap() - 1.0).abs() < 1e-5);
+    assert!(engine.eval::<bool>("let m = mat([[2, 0], [0, 4]]); m.inverse() == mat([[0.5, 0], [0, 0.25]])").unwrap());
+    assert_eq!(engine.eval::<INT>("let m = mat_zeros(2, 3); m.rows * 10 + m.cols").unwrap(), 23);
+
+    assert!(matches!(*engine.run("mat([[1, 2], [2, 4]]).inverse()").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat([[1, 2, 3]]).determinant()").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat([[1, 2], [3]])").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat([])").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat_identity(2) * mat_zeros(3, 3)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat_identity(2).get(2, 0)").unwrap_err(), EvalAltResult::ErrorArrayBounds(2, 2, ..)));
+    assert!(matches!(*engine.run("mat_zeros(0, 2)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat_zeros(2147483647, 2147483647)").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+    assert!(matches!(*engine.run("mat_identity(2000000)").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+}
+
+#[test]
+fn test_matrices_transform() {
+    let engine = make_engine();
+
+    // Square matrix: plain multiplication
+    assert_eq!(engine.eval::<Vec2>("mat([[0, -1], [1, 0]]) * vec2(1, 0)").unwrap(), Vector([0.0, 1.0]));
+    assert_eq!(engine.eval::<Vec3>("mat_identity(3).transform(vec3(1, 2, 3))").unwrap(), Vector([1.0, 2.0, 3.0]));
+
+    // Homogeneous matrix: translation of a point
+    assert_eq!(
+        engine
+            .eval::<Vec3>(
+                "
+                    let m = mat([[1, 0, 0, 10], [0, 1, 0, 20], [0, 0, 1, 30], [0, 0, 0, 1]]);
+                    m.transform(vec3(1, 2, 3))
+                "
+            )
+            .unwrap(),
+        Vector([11.0, 22.0, 33.0])
+    );
+
+    assert!(matches!(*engine.run("mat_identity(4) * vec2(1, 2)").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+    assert!(matches!(*engine.run("mat_identity(4).transform(vec2(1, 2))").unwrap_err(), EvalAltResult::ErrorArithmetic(..)));
+}
+
+#[cfg(not(feature = "unchecked"))]
+#[test]
+fn test_matrices_limits() {
+    let mut engine = make_engine();
+
+    engine.set_max_array_size(100);
+
+    assert!(engine.run("mat_zeros(10, 10)").is_ok());
+    assert!(matches!(*engine.run("mat_identity(1000000)").unwrap_err(), EvalAltResult::ErrorDataTooLarge(..)));
+}
+
+#[test]
+fn test_vectors_not_standard() {
+    let engine = Engine::new();
+
+    assert!(matches!(*engine.run("vec2(1, 2)").unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+}